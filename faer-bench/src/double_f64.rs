@@ -471,6 +471,12 @@ mod faer_impl {
             self / rhs
         }
 
+        #[inline(always)]
+        fn faer_ln(self) -> Self {
+            // ln(hi + lo) = ln(hi) + ln(1 + lo / hi) ≈ ln(hi) + lo / hi
+            Self(self.0.ln(), 0.0) + Self(self.1 / self.0, 0.0)
+        }
+
         #[inline(always)]
         fn faer_usize_to_index(a: usize) -> Self::Index {
             a as _
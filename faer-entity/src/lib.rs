@@ -464,6 +464,21 @@ pub trait ComplexField: Entity + Conjugate<Canonical = Self> {
     /// Returns a complex number whose real part is equal to `real`, and a zero imaginary part.
     fn faer_from_real(real: Self::Real) -> Self;
 
+    /// Returns a complex number whose real part is equal to `real`, and whose imaginary part is
+    /// equal to `imag`. The imaginary part is ignored if `Self` is real-valued.
+    ///
+    /// The default implementation forms the imaginary unit as the square root of `-1`, and should
+    /// be overridden by complex types that can construct the value directly.
+    #[inline(always)]
+    fn faer_from_real_imag(real: Self::Real, imag: Self::Real) -> Self {
+        if coe::is_same::<Self, Self::Real>() {
+            Self::faer_from_real(real)
+        } else {
+            let i = Self::faer_from_f64(-1.0).faer_sqrt();
+            Self::faer_from_real(real).faer_add(i.faer_mul(Self::faer_from_real(imag)))
+        }
+    }
+
     /// Returns the real part.
     fn faer_real(self) -> Self::Real;
     /// Returns the imaginary part.
@@ -678,6 +693,59 @@ pub trait RealField: ComplexField<Real = Self> + PartialOrd {
 
     fn faer_div(self, rhs: Self) -> Self;
 
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// The default implementation reduces the argument to the interval `[1, 2)` by repeated
+    /// halving or doubling, then sums the series of `2 * atanh((x - 1) / (x + 1))`. Types with a
+    /// native logarithm should override it.
+    fn faer_ln(self) -> Self {
+        let zero = Self::faer_zero();
+        let one = Self::faer_one();
+        let two = one.faer_add(one);
+
+        if self.faer_is_nan() || self < zero {
+            return Self::faer_nan();
+        }
+        if self == zero {
+            return one.faer_neg().faer_div(zero);
+        }
+        if !self.faer_is_finite() {
+            return self;
+        }
+
+        // ln(x) for x in [1, 2]
+        let ln_reduced = |x: Self| {
+            let s = x.faer_sub(one).faer_div(x.faer_add(one));
+            let s2 = s.faer_mul(s);
+            let mut power = s;
+            let mut sum = zero;
+            let mut denom = one;
+            for _ in 0..256 {
+                let next = sum.faer_add(power.faer_div(denom));
+                if next == sum {
+                    break;
+                }
+                sum = next;
+                power = power.faer_mul(s2);
+                denom = denom.faer_add(two);
+            }
+            sum.faer_add(sum)
+        };
+
+        let mut x = self;
+        let mut exponent = 0i64;
+        while x >= two {
+            x = x.faer_div(two);
+            exponent += 1;
+        }
+        while x < one {
+            x = x.faer_mul(two);
+            exponent -= 1;
+        }
+
+        ln_reduced(x).faer_add(Self::faer_from_f64(exponent as f64).faer_mul(ln_reduced(two)))
+    }
+
     fn faer_usize_to_index(a: usize) -> IndexFor<Self>;
     fn faer_index_to_usize(a: IndexFor<Self>) -> usize;
     fn faer_max_index() -> IndexFor<Self>;
@@ -1404,6 +1472,18 @@ impl RealField for f32 {
         self / rhs
     }
 
+    #[inline(always)]
+    fn faer_ln(self) -> Self {
+        #[cfg(feature = "std")]
+        {
+            self.ln()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            libm::logf(self)
+        }
+    }
+
     #[inline(always)]
     fn faer_usize_to_index(a: usize) -> IndexFor<Self> {
         a as _
@@ -1538,6 +1618,18 @@ impl RealField for f64 {
         self / rhs
     }
 
+    #[inline(always)]
+    fn faer_ln(self) -> Self {
+        #[cfg(feature = "std")]
+        {
+            self.ln()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            libm::log(self)
+        }
+    }
+
     #[inline(always)]
     fn faer_usize_to_index(a: usize) -> IndexFor<Self> {
         a as _
@@ -2246,6 +2338,11 @@ impl<E: RealField> ComplexField for Complex<E> {
         }
     }
 
+    #[inline(always)]
+    fn faer_from_real_imag(real: Self::Real, imag: Self::Real) -> Self {
+        Self { re: real, im: imag }
+    }
+
     #[inline(always)]
     fn faer_real(self) -> Self::Real {
         self.re
@@ -2806,6 +2903,11 @@ impl RealField for Symbolic {
         Self
     }
 
+    #[inline(always)]
+    fn faer_ln(self) -> Self {
+        Self
+    }
+
     #[inline(always)]
     fn faer_usize_to_index(a: usize) -> Self::Index {
         a
@@ -0,0 +1,613 @@
+//! Aasen's decomposition of a hermitian matrix $A$ is such that:
+//! $$P A P^\top = LTL^H,$$
+//! where $P$ is a permutation matrix, $T$ is a hermitian tridiagonal matrix, and $L$ is a unit
+//! lower triangular matrix whose first column is the first column of the identity.
+
+use crate::piv_llt::compute::hermitian_swap;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    mul::{
+        self,
+        triangular::{self, BlockStructure},
+    },
+    permutation::{permute_rows, swap_rows, Index, PermutationMut, PermutationRef, SignedIndex},
+    solve::{
+        solve_unit_lower_triangular_in_place_with_conj,
+        solve_unit_upper_triangular_in_place_with_conj,
+    },
+    temp_mat_req, temp_mat_uninit, Conj, MatMut, MatRef, Parallelism,
+};
+use faer_entity::{ComplexField, Entity};
+use reborrow::*;
+
+pub mod compute {
+    use super::*;
+    use faer_core::assert;
+
+    #[derive(Copy, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct AasenParams {
+        pub blocksize: usize,
+    }
+
+    impl Default for AasenParams {
+        fn default() -> Self {
+            Self { blocksize: 64 }
+        }
+    }
+
+    fn blocksize(dim: usize, params: AasenParams) -> usize {
+        let bs = params.blocksize;
+        if bs < 2 || dim <= bs {
+            0
+        } else {
+            bs
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct AasenInfo {
+        pub transposition_count: usize,
+    }
+
+    /// Computes the size and alignment of required workspace for performing an $LTL^H$
+    /// decomposition with Aasen's algorithm.
+    pub fn cholesky_in_place_req<I: Index, E: Entity>(
+        dim: usize,
+        parallelism: Parallelism,
+        params: AasenParams,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = parallelism;
+        StackReq::try_all_of([
+            temp_mat_req::<E>(dim, 1)?,
+            temp_mat_req::<E>(dim, 1)?,
+            temp_mat_req::<E>(blocksize(dim, params), dim)?,
+        ])
+    }
+
+    /// Computes the $LTL^H$ factorization of the input matrix with Aasen's algorithm, with
+    /// partial pivoting, and stores the factorization in `matrix`.
+    ///
+    /// The diagonal of $T$ is stored on the diagonal of `matrix`, and its subdiagonal is stored
+    /// on the subdiagonal of `matrix`. Since the first column of $L$ is the first column of the
+    /// identity, the strictly lower triangular part of the remaining columns of $L$ is stored
+    /// below the subdiagonal of `matrix`, shifted one column to the left.
+    ///
+    /// The input matrix is interpreted as hermitian and only the lower triangular part is read.
+    ///
+    /// The strictly upper triangular part of the matrix is clobbered and may be filled with garbage
+    /// values.
+    ///
+    /// The columns are processed in panels of `params.blocksize` columns, after which the trailing
+    /// submatrix is updated with a matrix-matrix product. A block size smaller than `2` selects
+    /// the unblocked algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input matrix is not square, or if `perm` or `perm_inv` don't have a length
+    /// equal to the matrix dimension.
+    ///
+    /// This can also panic if the provided memory in `stack` is insufficient (see
+    /// [`cholesky_in_place_req`]).
+    #[track_caller]
+    pub fn cholesky_in_place<'out, I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        perm: &'out mut [I],
+        perm_inv: &'out mut [I],
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+        params: AasenParams,
+    ) -> (AasenInfo, PermutationMut<'out, I, E>) {
+        let truncate = <I::Signed as SignedIndex>::truncate;
+
+        let n = matrix.nrows();
+        assert!(all(
+            matrix.nrows() == matrix.ncols(),
+            perm.len() == n,
+            perm_inv.len() == n,
+        ));
+
+        #[cfg(feature = "perf-warn")]
+        if matrix.row_stride().unsigned_abs() != 1 && faer_core::__perf_warn!(CHOLESKY_WARN) {
+            if matrix.col_stride().unsigned_abs() == 1 {
+                log::warn!(target: "faer_perf", "Aasen decomposition prefers column-major matrix. Found row-major matrix.");
+            } else {
+                log::warn!(target: "faer_perf", "Aasen decomposition prefers column-major matrix. Found matrix with generic strides.");
+            }
+        }
+
+        let mut matrix = matrix;
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = I::from_signed(truncate(i));
+        }
+
+        let bs = blocksize(n, params);
+
+        // column `j` of H = T L^H, which is upper Hessenberg and satisfies A = L H
+        let (mut h, stack) = temp_mat_uninit::<E>(n, 1, stack);
+        let mut h = h.as_mut();
+        let (mut v, stack) = temp_mat_uninit::<E>(n, 1, stack);
+        let mut v = v.as_mut();
+        // rows of H corresponding to the current panel, restricted to the trailing columns
+        let (mut h_panel, _) = temp_mat_uninit::<E>(bs, n, stack);
+        let mut h_panel = h_panel.as_mut();
+
+        let bs = if bs == 0 { n } else { bs };
+
+        let mut transposition_count = 0usize;
+        let mut j0 = 0;
+        while j0 < n {
+            let j1 = Ord::min(n, j0 + bs);
+
+            // the trailing submatrix already accounts for the contributions of the columns of L
+            // before `k0`, as well as the coupling term T[k0, k0 - 1], so that it stays hermitian.
+            // the columns of H below are restricted to the rows of T at or after `k0`
+            let k0 = Ord::max(j0, 1);
+
+            for j in j0..j1 {
+                // L[j, k] for k <= j
+                let l_row = |matrix: MatRef<'_, E>, k: usize| {
+                    if k == j {
+                        E::faer_one()
+                    } else if k == 0 {
+                        E::faer_zero()
+                    } else {
+                        matrix.read(j, k - 1)
+                    }
+                };
+
+                // H[i, j] = sum_{k >= k0} T[i, k] conj(L[j, k]), for k0 <= i < j
+                for i in k0..j {
+                    let mut acc = l_row(matrix.rb(), i)
+                        .faer_conj()
+                        .faer_scale_real(matrix.read(i, i).faer_real());
+                    if i > k0 {
+                        acc = acc.faer_add(
+                            matrix
+                                .read(i, i - 1)
+                                .faer_mul(l_row(matrix.rb(), i - 1).faer_conj()),
+                        );
+                    }
+                    acc = acc.faer_add(
+                        matrix
+                            .read(i + 1, i)
+                            .faer_conj()
+                            .faer_mul(l_row(matrix.rb(), i + 1).faer_conj()),
+                    );
+                    h.write(i, 0, acc);
+                }
+
+                // H[j, j] = A[j, j] - sum_{k < j} L[j, k] H[k, j]
+                let mut hjj = matrix.read(j, j);
+                for k in k0..j {
+                    hjj = hjj.faer_sub(l_row(matrix.rb(), k).faer_mul(h.read(k, 0)));
+                }
+                h.write(j, 0, hjj);
+
+                // T[j, j] = H[j, j] - T[j, j - 1] conj(L[j, j - 1])
+                let mut tjj = hjj;
+                if j > k0 {
+                    tjj = tjj.faer_sub(
+                        matrix
+                            .read(j, j - 1)
+                            .faer_mul(l_row(matrix.rb(), j - 1).faer_conj()),
+                    );
+                }
+                matrix.write(j, j, E::faer_from_real(tjj.faer_real()));
+
+                if j + 1 == n {
+                    break;
+                }
+
+                // v = A[j + 1.., j] - L[j + 1.., 1..=j] H[1..=j, j] = L[j + 1.., j + 1] H[j + 1, j]
+                let m = n - j - 1;
+                let mut v = v.rb_mut().subrows_mut(j + 1, m);
+                v.copy_from(matrix.rb().submatrix(j + 1, j, m, 1));
+                mul::matmul(
+                    v.rb_mut(),
+                    matrix.rb().submatrix(j + 1, k0 - 1, m, j + 1 - k0),
+                    h.rb().subrows(k0, j + 1 - k0),
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+
+                let mut max_idx = 0;
+                let mut max_val = v.read(0, 0).faer_abs();
+                for i in 1..m {
+                    let val = v.read(i, 0).faer_abs();
+                    if val > max_val {
+                        max_idx = i;
+                        max_val = val;
+                    }
+                }
+
+                if max_idx != 0 {
+                    let p = j + 1 + max_idx;
+                    let tmp = v.read(0, 0);
+                    v.write(0, 0, v.read(max_idx, 0));
+                    v.write(max_idx, 0, tmp);
+                    swap_rows(matrix.rb_mut().subcols_mut(0, j), j + 1, p);
+                    hermitian_swap(
+                        matrix.rb_mut().submatrix_mut(j + 1, j + 1, m, m),
+                        0,
+                        max_idx,
+                    );
+                    perm.swap(j + 1, p);
+                    transposition_count += 1;
+                }
+
+                let beta = v.read(0, 0);
+                matrix.write(j + 1, j, beta);
+                let inv = if beta == E::faer_zero() {
+                    E::faer_zero()
+                } else {
+                    beta.faer_inv()
+                };
+                for i in 1..m {
+                    matrix.write(j + 1 + i, j, v.read(i, 0).faer_mul(inv));
+                }
+            }
+
+            if j1 < n && k0 < j1 {
+                let m = n - j1;
+                let nk = j1 - k0;
+
+                // H[k0..j1, j1..], computed from T and the rows of L below the panel
+                let mut h_panel = h_panel.rb_mut().submatrix_mut(0, 0, nk, m);
+                for c in 0..m {
+                    let row = j1 + c;
+                    // L[row, k] for k <= j1
+                    let l_row = |matrix: MatRef<'_, E>, k: usize| {
+                        if k == row {
+                            E::faer_one()
+                        } else if k == 0 {
+                            E::faer_zero()
+                        } else {
+                            matrix.read(row, k - 1)
+                        }
+                    };
+
+                    for i in k0..j1 {
+                        let mut acc = l_row(matrix.rb(), i)
+                            .faer_conj()
+                            .faer_scale_real(matrix.read(i, i).faer_real());
+                        if i > k0 {
+                            acc = acc.faer_add(
+                                matrix
+                                    .read(i, i - 1)
+                                    .faer_mul(l_row(matrix.rb(), i - 1).faer_conj()),
+                            );
+                        }
+                        acc = acc.faer_add(
+                            matrix
+                                .read(i + 1, i)
+                                .faer_conj()
+                                .faer_mul(l_row(matrix.rb(), i + 1).faer_conj()),
+                        );
+                        h_panel.write(i - k0, c, acc);
+                    }
+                }
+
+                // A22 -= L[j1.., k0..j1] H[k0..j1, j1..]
+                let (_, _, bottom_left, bottom_right) = matrix.rb_mut().split_at_mut(j1, j1);
+                triangular::matmul(
+                    bottom_right,
+                    BlockStructure::TriangularLower,
+                    bottom_left.into_const().subcols(k0 - 1, nk),
+                    BlockStructure::Rectangular,
+                    h_panel.rb(),
+                    BlockStructure::Rectangular,
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+
+                // A22 -= L[j1.., j1] T[j1, j1 - 1] L[j1.., j1 - 1]^H, the conjugate of the
+                // coupling term included in the previous product. L[j1.., 0] is zero, so there is
+                // nothing to do for the first column
+                if j1 >= 2 {
+                    let t = matrix.read(j1, j1 - 1);
+                    for c in 0..m {
+                        let coeff = t.faer_mul(matrix.read(j1 + c, j1 - 2).faer_conj());
+                        for r in c..m {
+                            let l = if r == 0 {
+                                E::faer_one()
+                            } else {
+                                matrix.read(j1 + r, j1 - 1)
+                            };
+                            matrix.write(
+                                j1 + r,
+                                j1 + c,
+                                matrix.read(j1 + r, j1 + c).faer_sub(l.faer_mul(coeff)),
+                            );
+                        }
+                    }
+                }
+            }
+
+            j0 = j1;
+        }
+
+        for (i, &p) in perm.iter().enumerate() {
+            perm_inv[p.to_signed().zx()] = I::from_signed(truncate(i));
+        }
+
+        (
+            AasenInfo {
+                transposition_count,
+            },
+            unsafe { PermutationMut::new_unchecked(perm, perm_inv) },
+        )
+    }
+}
+
+pub mod solve {
+    use super::*;
+    use faer_core::assert;
+
+    /// Computes the size and alignment of required workspace for solving a linear system defined
+    /// by a matrix in place, given its Aasen decomposition.
+    #[track_caller]
+    pub fn solve_in_place_req<I: Index, E: Entity>(
+        dim: usize,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = parallelism;
+        StackReq::try_all_of([
+            temp_mat_req::<E>(dim, rhs_ncols)?,
+            temp_mat_req::<E>(dim, 4)?,
+        ])
+    }
+
+    /// Solves the tridiagonal system $TX = B$ in place, using gaussian elimination with partial
+    /// pivoting. `work` must have at least `4` columns.
+    fn solve_tridiagonal_in_place<E: ComplexField>(
+        factors: MatRef<'_, E>,
+        conj: Conj,
+        rhs: MatMut<'_, E>,
+        work: MatMut<'_, E>,
+    ) {
+        let n = factors.nrows();
+        let k = rhs.ncols();
+        let mut rhs = rhs;
+
+        if n == 0 {
+            return;
+        }
+
+        let (mut d, work) = work.split_at_col_mut(1);
+        let (mut dl, work) = work.split_at_col_mut(1);
+        let (mut du, mut du2) = work.split_at_col_mut(1);
+
+        for i in 0..n {
+            d.write(i, 0, E::faer_from_real(factors.read(i, i).faer_real()));
+            du2.write(i, 0, E::faer_zero());
+        }
+        for i in 0..n - 1 {
+            let beta = factors.read(i + 1, i);
+            let beta = if conj == Conj::Yes {
+                beta.faer_conj()
+            } else {
+                beta
+            };
+            dl.write(i, 0, beta);
+            du.write(i, 0, beta.faer_conj());
+        }
+
+        for i in 0..n - 1 {
+            let di = d.read(i, 0);
+            let dli = dl.read(i, 0);
+            if di.faer_abs() >= dli.faer_abs() {
+                // no row interchange
+                let fact = if di == E::faer_zero() {
+                    E::faer_zero()
+                } else {
+                    dli.faer_mul(di.faer_inv())
+                };
+                d.write(
+                    i + 1,
+                    0,
+                    d.read(i + 1, 0).faer_sub(fact.faer_mul(du.read(i, 0))),
+                );
+                for j in 0..k {
+                    rhs.write(
+                        i + 1,
+                        j,
+                        rhs.read(i + 1, j).faer_sub(fact.faer_mul(rhs.read(i, j))),
+                    );
+                }
+            } else {
+                // interchange rows i and i + 1
+                let fact = di.faer_mul(dli.faer_inv());
+                d.write(i, 0, dli);
+                let tmp = d.read(i + 1, 0);
+                d.write(i + 1, 0, du.read(i, 0).faer_sub(fact.faer_mul(tmp)));
+                if i + 2 < n {
+                    du2.write(i, 0, du.read(i + 1, 0));
+                    du.write(i + 1, 0, fact.faer_neg().faer_mul(du2.read(i, 0)));
+                }
+                du.write(i, 0, tmp);
+                for j in 0..k {
+                    let tmp = rhs.read(i, j);
+                    rhs.write(i, j, rhs.read(i + 1, j));
+                    rhs.write(i + 1, j, tmp.faer_sub(fact.faer_mul(rhs.read(i + 1, j))));
+                }
+            }
+        }
+
+        for j in 0..k {
+            for i in (0..n).rev() {
+                let mut x = rhs.read(i, j);
+                if i + 1 < n {
+                    x = x.faer_sub(du.read(i, 0).faer_mul(rhs.read(i + 1, j)));
+                }
+                if i + 2 < n {
+                    x = x.faer_sub(du2.read(i, 0).faer_mul(rhs.read(i + 2, j)));
+                }
+                rhs.write(i, j, x.faer_mul(d.read(i, 0).faer_inv()));
+            }
+        }
+    }
+
+    /// Given the Aasen factors of a matrix $A$ and a matrix $B$ stored in `rhs`, this function
+    /// computes the solution of the linear system:
+    /// $$\text{Op}_A(A)X = B.$$
+    ///
+    /// $\text{Op}_A$ is either the identity or the conjugation depending on the value of `conj`.
+    ///
+    /// The solution of the linear system is stored in `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of these conditions is violated:
+    ///
+    /// * `lt_factors` must be square of dimension `n`.
+    /// * `rhs` must have `n` rows.
+    /// * `perm` must have length `n`.
+    ///
+    /// This can also panic if the provided memory in `stack` is insufficient (see
+    /// [`solve_in_place_req`]).
+    #[track_caller]
+    pub fn solve_in_place_with_conj<I: Index, E: ComplexField>(
+        lt_factors: MatRef<'_, E>,
+        conj: Conj,
+        perm: PermutationRef<'_, I, E>,
+        rhs: MatMut<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let n = lt_factors.nrows();
+        let k = rhs.ncols();
+
+        assert!(all(
+            lt_factors.nrows() == lt_factors.ncols(),
+            rhs.nrows() == n,
+            perm.len() == n
+        ));
+
+        let par = parallelism;
+        let not_conj = conj.compose(Conj::Yes);
+
+        let mut rhs = rhs;
+        let (mut x, stack) = temp_mat_uninit::<E>(n, k, stack);
+        let mut x = x.as_mut();
+        let (work, _) = temp_mat_uninit::<E>(n, 4, stack);
+
+        permute_rows(x.rb_mut(), rhs.rb(), perm);
+
+        // the first column of L is the first column of the identity, and the remaining columns
+        // are stored shifted to the left
+        let l = if n > 0 {
+            lt_factors.submatrix(1, 0, n - 1, n - 1)
+        } else {
+            lt_factors
+        };
+
+        if n > 0 {
+            solve_unit_lower_triangular_in_place_with_conj(
+                l,
+                conj,
+                x.rb_mut().subrows_mut(1, n - 1),
+                par,
+            );
+        }
+        solve_tridiagonal_in_place(lt_factors, conj, x.rb_mut(), work);
+        if n > 0 {
+            solve_unit_upper_triangular_in_place_with_conj(
+                l.transpose(),
+                not_conj,
+                x.rb_mut().subrows_mut(1, n - 1),
+                par,
+            );
+        }
+
+        permute_rows(rhs.rb_mut(), x.rb(), perm.inverse());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dyn_stack::GlobalPodBuffer;
+    use faer_core::{assert, c64, Mat};
+    use rand::random;
+
+    #[test]
+    fn test_cplx() {
+        for (n, blocksize) in [
+            (0, 64),
+            (1, 64),
+            (2, 64),
+            (3, 64),
+            (6, 64),
+            (19, 64),
+            (100, 64),
+            (19, 2),
+            (19, 4),
+            (100, 16),
+            (100, 1),
+        ] {
+            let a = Mat::<c64>::from_fn(n, n, |_, _| c64::new(random(), random()));
+            let a = &a + a.adjoint();
+            let rhs = Mat::<c64>::from_fn(n, 2, |_, _| c64::new(random(), random()));
+
+            let mut lt = a.clone();
+            let mut perm = vec![0usize; n];
+            let mut perm_inv = vec![0; n];
+
+            let params = compute::AasenParams { blocksize };
+            let mut mem = GlobalPodBuffer::new(
+                compute::cholesky_in_place_req::<usize, c64>(n, Parallelism::None, params).unwrap(),
+            );
+            let (_, perm) = compute::cholesky_in_place(
+                lt.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                Parallelism::None,
+                PodStack::new(&mut mem),
+                params,
+            );
+
+            for conj in [Conj::No, Conj::Yes] {
+                let mut x = rhs.clone();
+                let mut mem = GlobalPodBuffer::new(
+                    solve::solve_in_place_req::<usize, c64>(n, rhs.ncols(), Parallelism::None)
+                        .unwrap(),
+                );
+                solve::solve_in_place_with_conj(
+                    lt.as_ref(),
+                    conj,
+                    perm.rb(),
+                    x.as_mut(),
+                    Parallelism::None,
+                    PodStack::new(&mut mem),
+                );
+
+                let err = if conj == Conj::Yes {
+                    a.conjugate() * &x - &rhs
+                } else {
+                    &a * &x - &rhs
+                };
+                let mut max = 0.0;
+                for j in 0..err.ncols() {
+                    for i in 0..n {
+                        let e = err.read(i, j).abs();
+                        if e > max {
+                            max = e;
+                        }
+                    }
+                }
+                assert!(max < 1e-9);
+            }
+
+            // the entries of L are bounded by one thanks to the partial pivoting
+            for j in 0..n {
+                for i in j + 2..n {
+                    assert!(lt.read(i, j).abs() <= 1.0 + 1e-12);
+                }
+            }
+        }
+    }
+}
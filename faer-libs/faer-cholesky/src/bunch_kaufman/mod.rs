@@ -22,10 +22,20 @@ pub mod compute {
     use super::*;
     use faer_core::assert;
 
-    #[derive(Copy, Clone)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     #[non_exhaustive]
     pub enum PivotingStrategy {
+        /// Bunch-Kaufman partial pivoting. This strategy only requires searching two columns of
+        /// the matrix at each step, but the entries of $L$ may be unbounded.
         Diagonal,
+        /// Rook pivoting, as in LAPACK's `sytrf_rook`. The search alternates between rows and
+        /// columns until it finds a diagonal entry or an off-diagonal entry that is large enough,
+        /// which bounds the entries of $L$.
+        Rook,
+        /// Bounded Bunch-Kaufman pivoting, as described by Ashcraft, Grimes and Lewis. The
+        /// Bunch-Kaufman pivot is kept when it leads to bounded entries in $L$, otherwise the
+        /// search continues as with rook pivoting.
+        Bounded,
     }
 
     #[derive(Copy, Clone)]
@@ -116,6 +126,29 @@ pub mod compute {
         best_score
     }
 
+    /// Returns the index and the magnitude of the largest off-diagonal element in the row and
+    /// column `i` of the hermitian matrix whose lower triangular half is stored in `a`.
+    fn offdiag_best_score_idx<E: ComplexField>(a: MatRef<'_, E>, i: usize) -> (usize, E::Real) {
+        let n = a.nrows();
+        let mut best_idx = if i == 0 { 1 } else { 0 };
+        let mut best_score = E::Real::faer_zero();
+        for j in 0..i {
+            let score = a.read(i, j).faer_abs();
+            if score > best_score {
+                best_idx = j;
+                best_score = score;
+            }
+        }
+        for j in i + 1..n {
+            let score = a.read(j, i).faer_abs();
+            if score > best_score {
+                best_idx = j;
+                best_score = score;
+            }
+        }
+        (best_idx, best_score)
+    }
+
     #[inline(always)]
     fn max<E: RealField>(a: E, b: E) -> E {
         if a > b {
@@ -424,7 +457,7 @@ pub mod compute {
             if k_step == 1 {
                 pivots[k] = I::from_signed(truncate(kp));
             } else {
-                pivots[k] = I::from_signed(truncate(!kp));
+                pivots[k] = I::from_signed(truncate(!k));
                 pivots[k + 1] = I::from_signed(truncate(!kp));
             }
 
@@ -475,6 +508,7 @@ pub mod compute {
         regularization: BunchKaufmanRegularization<'_, E>,
         pivots: &mut [I],
         alpha: E::Real,
+        pivoting: PivotingStrategy,
     ) -> (usize, usize) {
         let truncate = <I::Signed as SignedIndex>::truncate;
 
@@ -498,6 +532,8 @@ pub mod compute {
             };
 
             let mut k_step = 1;
+            // first index of a 2x2 pivot, before it is moved to position `k`
+            let mut kfirst = k;
 
             let abs_akk = a.read(k, k).faer_abs();
             let imax;
@@ -533,7 +569,7 @@ pub mod compute {
             } else {
                 if abs_akk >= colmax.faer_mul(alpha) {
                     kp = k;
-                } else {
+                } else if pivoting == PivotingStrategy::Diagonal {
                     let rowmax = max(
                         best_score(a.rb().row(imax).subcols(k, imax - k).as_2d()),
                         best_score(a.rb().subrows(imax + 1, n - imax - 1).col(imax).as_2d()),
@@ -547,6 +583,59 @@ pub mod compute {
                         kp = imax;
                         k_step = 2;
                     }
+                } else {
+                    let trailing = a.rb().submatrix(k, k, n - k, n - k);
+                    let mut p = k;
+                    let mut imax = imax;
+                    let mut colmax = colmax;
+                    let mut first = true;
+                    loop {
+                        let (jmax, rowmax) = offdiag_best_score_idx(trailing, imax - k);
+                        let jmax = jmax + k;
+                        let abs_aii = a.read(imax, imax).faer_real().faer_abs();
+
+                        // the bunch-kaufman pivot leads to bounded entries in L when the
+                        // largest element of the column `imax` is not much larger than the
+                        // largest element of the column `k`
+                        if first
+                            && pivoting == PivotingStrategy::Bounded
+                            && alpha.faer_mul(rowmax) <= colmax
+                        {
+                            if abs_akk >= alpha.faer_mul(colmax).faer_mul(colmax.faer_div(rowmax)) {
+                                kp = k;
+                            } else if abs_aii >= alpha.faer_mul(rowmax) {
+                                kp = imax;
+                            } else {
+                                kp = imax;
+                                k_step = 2;
+                            }
+                            break;
+                        }
+                        first = false;
+
+                        if abs_aii >= alpha.faer_mul(rowmax) {
+                            kp = imax;
+                            break;
+                        } else if p == jmax || rowmax <= colmax {
+                            kp = imax;
+                            kfirst = p;
+                            k_step = 2;
+                            break;
+                        }
+
+                        p = imax;
+                        colmax = rowmax;
+                        imax = jmax;
+                    }
+                }
+
+                if kfirst != k {
+                    pivot_count += 1;
+                    crate::piv_llt::compute::hermitian_swap(
+                        a.rb_mut().submatrix_mut(k, k, n - k, n - k),
+                        0,
+                        kfirst - k,
+                    );
                 }
 
                 let kk = k + k_step - 1;
@@ -692,7 +781,7 @@ pub mod compute {
             if k_step == 1 {
                 pivots[k] = I::from_signed(truncate(kp));
             } else {
-                pivots[k] = I::from_signed(truncate(!kp));
+                pivots[k] = I::from_signed(truncate(!kfirst));
                 pivots[k + 1] = I::from_signed(truncate(!kp));
             }
 
@@ -727,8 +816,9 @@ pub mod compute {
         while i < n {
             let p = pivots[i].to_signed().sx();
             if (p as isize) < 0 {
-                let p = !p;
-                swap_rows(a.rb_mut().subcols_mut(0, i), i + 1, p);
+                let q = !pivots[i + 1].to_signed().sx();
+                swap_rows(a.rb_mut().subcols_mut(0, i), i, !p);
+                swap_rows(a.rb_mut().subcols_mut(0, i), i + 1, q);
                 i += 2;
             } else {
                 swap_rows(a.rb_mut().subcols_mut(0, i), i, p);
@@ -746,7 +836,7 @@ pub mod compute {
     ) -> Result<StackReq, SizeOverflow> {
         let _ = parallelism;
         let mut bs = params.blocksize;
-        if bs < 2 || dim <= bs {
+        if bs < 2 || dim <= bs || params.pivoting != PivotingStrategy::Diagonal {
             bs = 0;
         }
         StackReq::try_new::<I>(dim)?.try_and(temp_mat_req::<E>(dim, bs)?)
@@ -764,6 +854,9 @@ pub mod compute {
     /// The inverses of the diagonal blocks of the block diagonal matrix are stored on the diagonal
     /// of `matrix`, while the subdiagonal elements of those inverses are stored in `subdiag`.
     ///
+    /// Only [`PivotingStrategy::Diagonal`] has a blocked implementation, the other pivoting
+    /// strategies ignore `params.blocksize`.
+    ///
     /// # Panics
     ///
     /// Panics if the input matrix is not square.
@@ -811,8 +904,9 @@ pub mod compute {
 
         let (pivots, stack) = stack.make_raw::<I>(n);
 
+        // only bunch-kaufman pivoting has a blocked implementation
         let mut bs = params.blocksize;
-        if bs < 2 || n <= bs {
+        if bs < 2 || n <= bs || params.pivoting != PivotingStrategy::Diagonal {
             bs = 0;
         }
         let mut work = temp_mat_uninit(n, bs, stack).0;
@@ -848,6 +942,7 @@ pub mod compute {
                     regularization,
                     &mut pivots[k..],
                     alpha,
+                    params.pivoting,
                 );
                 kb = n - k;
             }
@@ -875,8 +970,9 @@ pub mod compute {
         while i < n {
             let p = pivots[i].to_signed().sx();
             if (p as isize) < 0 {
-                let p = !p;
-                perm.swap(i + 1, p);
+                let q = !pivots[i + 1].to_signed().sx();
+                perm.swap(i, !p);
+                perm.swap(i + 1, q);
                 i += 2;
             } else {
                 perm.swap(i, p);
@@ -1121,4 +1217,75 @@ mod tests {
             assert!(max < 1e-9);
         }
     }
+
+    #[test]
+    fn test_rook_bounded() {
+        for pivoting in [
+            compute::PivotingStrategy::Rook,
+            compute::PivotingStrategy::Bounded,
+        ] {
+            for n in [1, 2, 3, 6, 19, 100] {
+                let a = Mat::<c64>::from_fn(n, n, |_, _| c64::new(random(), random()));
+                let a = &a + a.adjoint();
+                let rhs = Mat::<c64>::from_fn(n, 2, |_, _| c64::new(random(), random()));
+
+                let mut ldl = a.clone();
+                let mut subdiag = Mat::<c64>::zeros(n, 1);
+
+                let mut perm = vec![0usize; n];
+                let mut perm_inv = vec![0; n];
+
+                let params = BunchKaufmanParams {
+                    pivoting,
+                    blocksize: 32,
+                };
+                let mut mem = GlobalPodBuffer::new(
+                    compute::cholesky_in_place_req::<usize, c64>(n, Parallelism::None, params)
+                        .unwrap(),
+                );
+                let (_, perm) = compute::cholesky_in_place(
+                    ldl.as_mut(),
+                    subdiag.as_mut(),
+                    Default::default(),
+                    &mut perm,
+                    &mut perm_inv,
+                    Parallelism::None,
+                    PodStack::new(&mut mem),
+                    params,
+                );
+
+                let mut x = rhs.clone();
+                let mut mem = GlobalPodBuffer::new(
+                    solve::solve_in_place_req::<usize, c64>(n, rhs.ncols(), Parallelism::None)
+                        .unwrap(),
+                );
+                solve::solve_in_place_with_conj(
+                    ldl.as_ref(),
+                    subdiag.as_ref(),
+                    Conj::No,
+                    perm.rb(),
+                    x.as_mut(),
+                    Parallelism::None,
+                    PodStack::new(&mut mem),
+                );
+
+                let err = &a * &x - &rhs;
+                let mut max = 0.0;
+                zipped!(err.as_ref()).for_each(|unzipped!(err)| {
+                    let err = err.read().abs();
+                    if err > max {
+                        max = err
+                    }
+                });
+                assert!(max < 1e-9);
+
+                // the entries of L are bounded
+                for j in 0..n {
+                    for i in j + 1..n {
+                        assert!(ldl.read(i, j).abs() < 10.0);
+                    }
+                }
+            }
+        }
+    }
 }
@@ -71,6 +71,8 @@ impl<E: ComplexField> pulp::WithSimd for RankUpdate<'_, E> {
 }
 
 fn cholesky_in_place_left_looking_impl<E: ComplexField>(
+    offset: usize,
+    first_zero_pivot: &mut Option<(usize, f64)>,
     matrix: MatMut<'_, E>,
     regularization: LdltRegularization<'_, E>,
     parallelism: Parallelism,
@@ -169,7 +171,11 @@ fn cholesky_in_place_left_looking_impl<E: ComplexField>(
             }
         }
 
-        let d = d.faer_inv();
+        let d_inv = d.faer_inv();
+        if first_zero_pivot.is_none() && !d_inv.faer_is_nan() && !d_inv.faer_is_finite() {
+            *first_zero_pivot = Some((offset + idx, faer_core::real_to_f64(d)));
+        }
+        let d = d_inv;
         a11.write(0, 0, E::faer_from_real(d));
 
         if idx + block_size == n {
@@ -221,9 +227,11 @@ pub fn raw_cholesky_in_place_req<E: Entity>(
     temp_mat_req::<E>(dim, dim)
 }
 
-// uses an out parameter for tail recursion
+// uses out parameters for tail recursion
 fn cholesky_in_place_impl<E: ComplexField>(
     count: &mut usize,
+    offset: usize,
+    first_zero_pivot: &mut Option<(usize, f64)>,
     matrix: MatMut<'_, E>,
     regularization: LdltRegularization<'_, E>,
     parallelism: Parallelism,
@@ -238,7 +246,14 @@ fn cholesky_in_place_impl<E: ComplexField>(
 
     let n = matrix.nrows();
     if n < 32 {
-        *count += cholesky_in_place_left_looking_impl(matrix, regularization, parallelism, params)
+        *count += cholesky_in_place_left_looking_impl(
+            offset,
+            first_zero_pivot,
+            matrix,
+            regularization,
+            parallelism,
+            params,
+        )
     } else {
         let block_size = Ord::min(n / 2, 128);
         let rem = n - block_size;
@@ -246,6 +261,8 @@ fn cholesky_in_place_impl<E: ComplexField>(
 
         cholesky_in_place_impl(
             count,
+            offset,
+            first_zero_pivot,
             l00.rb_mut(),
             regularization,
             parallelism,
@@ -296,6 +313,8 @@ fn cholesky_in_place_impl<E: ComplexField>(
 
         cholesky_in_place_impl(
             count,
+            offset + block_size,
+            first_zero_pivot,
             a11,
             LdltRegularization {
                 dynamic_regularization_signs: regularization
@@ -320,8 +339,18 @@ pub struct LdltRegularization<'a, E: ComplexField> {
 }
 
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct LdltInfo {
+    /// Number of pivots whose value or sign had to be corrected.
     pub dynamic_regularization_count: usize,
+    /// Index and value of the first pivot that is zero, or whose reciprocal overflows, if any, in
+    /// which case the matrix is singular to working precision and solving a system with the
+    /// factors produces non-finite values. The value is converted to `f64`, and is NaN if the
+    /// scalar type cannot be converted to `f64`.
+    pub first_zero_pivot: Option<(usize, f64)>,
+    /// Whether the factors contain NaN values, which indicates that the input contained
+    /// non-finite values, or that an overflow occurred.
+    pub nan_encountered: bool,
 }
 
 impl<E: ComplexField> Default for LdltRegularization<'_, E> {
@@ -380,16 +409,31 @@ pub fn raw_cholesky_in_place<E: ComplexField>(
         }
     }
 
+    let mut matrix = matrix;
     let mut count = 0;
+    let mut first_zero_pivot = None;
     cholesky_in_place_impl(
         &mut count,
-        matrix,
+        0,
+        &mut first_zero_pivot,
+        matrix.rb_mut(),
         regularization,
         parallelism,
         stack,
         params,
     );
+
+    let n = matrix.nrows();
+    let mut nan_encountered = false;
+    for j in 0..n {
+        for i in j..n {
+            nan_encountered |= matrix.read(i, j).faer_is_nan();
+        }
+    }
+
     LdltInfo {
         dynamic_regularization_count: count,
+        first_zero_pivot,
+        nan_encountered,
     }
 }
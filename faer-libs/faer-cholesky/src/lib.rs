@@ -9,9 +9,12 @@ use faer_core::{
     ComplexField, MatRef,
 };
 
+pub mod aasen;
 pub mod bunch_kaufman;
 pub mod ldlt_diagonal;
 pub mod llt;
+pub mod modified_llt;
+pub mod piv_llt;
 
 /// Computes a permutation that reduces the chance of numerical errors during the $LDL^H$
 /// factorization with diagonal $D$, then stores the result in `perm_indices` and
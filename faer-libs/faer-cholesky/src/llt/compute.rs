@@ -83,7 +83,7 @@ fn cholesky_in_place_left_looking_impl<E: ComplexField>(
         if real > E::Real::faer_zero() {
             a11.write(0, 0, E::faer_from_real(real.faer_sqrt()));
         } else {
-            return Err(CholeskyError);
+            return Err(CholeskyError::new(idx, real));
         };
 
         if idx + block_size == n {
@@ -212,6 +212,7 @@ fn cholesky_in_place_impl<E: ComplexField>(
         );
 
         cholesky_in_place_impl(count, a11, regularization, parallelism, stack, params)
+            .map_err(|err| err.offset(block_size))
     }
 }
 
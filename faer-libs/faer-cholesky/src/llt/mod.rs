@@ -8,10 +8,46 @@ pub mod reconstruct;
 pub mod solve;
 pub mod update;
 
+use faer_core::RealField;
+
 /// This error signifies that the LLT decomposition could not be computed due to the matrix not
 /// being numerically positive definite.
-#[derive(Debug, Clone, Copy)]
-pub struct CholeskyError;
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CholeskyError {
+    /// Index of the first pivot that is not strictly positive, i.e., the order of the first
+    /// leading principal minor that is not positive definite.
+    pub non_positive_definite_minor: usize,
+    /// Value of the failing pivot, converted to `f64`. This is NaN if the scalar type cannot be
+    /// converted to `f64`.
+    pub pivot: f64,
+    /// Whether the failing pivot is NaN, which indicates that the input contained non-finite
+    /// values, or that an overflow occurred.
+    pub nan_encountered: bool,
+}
+
+impl CholeskyError {
+    /// Creates an error reporting that the pivot at index `non_positive_definite_minor` has the
+    /// value `pivot`, which is not strictly positive.
+    #[inline]
+    pub fn new<E: RealField>(non_positive_definite_minor: usize, pivot: E) -> Self {
+        Self {
+            non_positive_definite_minor,
+            pivot: faer_core::real_to_f64(pivot),
+            nan_encountered: pivot.faer_is_nan(),
+        }
+    }
+
+    /// Shifts the index of the failing pivot by `offset`, for errors returned by the
+    /// factorization of a trailing diagonal block.
+    #[inline]
+    pub fn offset(self, offset: usize) -> Self {
+        Self {
+            non_positive_definite_minor: self.non_positive_definite_minor + offset,
+            ..self
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -377,4 +413,24 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let n = 64;
+        for (k, value) in [(0, -1.0), (5, 0.0), (40, -1.0), (50, f64::NAN)] {
+            let mut a = Mat::<E>::identity(n, n);
+            a.write(k, k, c64::new(value, 0.0));
+            let err = cholesky_in_place(
+                a.as_mut(),
+                Default::default(),
+                Parallelism::None,
+                PodStack::new(&mut []),
+                Default::default(),
+            )
+            .unwrap_err();
+            assert!(err.non_positive_definite_minor == k);
+            assert!(err.nan_encountered == value.is_nan());
+            assert!(err.nan_encountered || err.pivot == value);
+        }
+    }
 }
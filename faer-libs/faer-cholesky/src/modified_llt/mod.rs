@@ -0,0 +1,837 @@
+//! A modified Cholesky decomposition of a hermitian matrix $A$, that is possibly indefinite, is
+//! such that:
+//! $$P (A + E) P^\top = LL^H,$$
+//! where $P$ is a permutation matrix, $L$ is a lower triangular matrix, and $E$ is a hermitian
+//! perturbation chosen to be small, while making $A + E$ sufficiently positive definite.
+//!
+//! These decompositions are typically used by Newton-type optimization methods, where $A$ is a
+//! Hessian matrix that is not guaranteed to be positive definite.
+
+use crate::{bunch_kaufman, piv_llt::compute::hermitian_swap};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    mul,
+    permutation::{Index, PermutationMut, SignedIndex},
+    temp_mat_req, temp_mat_uninit, MatMut, MatRef, Parallelism,
+};
+use faer_entity::{ComplexField, Entity, RealField};
+use reborrow::*;
+
+pub mod compute {
+    use super::*;
+    use faer_core::assert;
+
+    /// Algorithm used for choosing the perturbation.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum ModifiedCholeskyAlgorithm {
+        /// Gill, Murray and Wright (1981). The perturbation is diagonal, and chosen during an
+        /// $LDL^H$ factorization with diagonal pivoting, so that the entries of $L$ remain
+        /// bounded.
+        GillMurrayWright,
+        /// Schnabel and Eskow (1999). The perturbation is diagonal, and is only introduced once
+        /// the matrix stops being numerically positive definite, using Gerschgorin bounds on the
+        /// eigenvalues of the remaining submatrix.
+        SchnabelEskow,
+        /// Cheng and Higham (1998). A Bunch-Kaufman factorization $PAP^\top = LBL^H$ is computed,
+        /// and the eigenvalues of the block diagonal factor $B$ are raised to a minimum value.
+        /// The perturbation is not diagonal in general.
+        ChengHigham,
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct ModifiedLltParams {
+        pub algorithm: ModifiedCholeskyAlgorithm,
+    }
+
+    impl Default for ModifiedLltParams {
+        fn default() -> Self {
+            Self {
+                algorithm: ModifiedCholeskyAlgorithm::SchnabelEskow,
+            }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct ModifiedLltInfo {
+        /// Number of diagonal blocks that were perturbed.
+        pub perturbation_count: usize,
+        pub transposition_count: usize,
+    }
+
+    /// Computes the size and alignment of required workspace for performing a modified Cholesky
+    /// decomposition.
+    pub fn modified_cholesky_in_place_req<I: Index, E: Entity>(
+        dim: usize,
+        parallelism: Parallelism,
+        params: ModifiedLltParams,
+    ) -> Result<StackReq, SizeOverflow> {
+        // real workspaces are sized with `E`, whose storage can hold them
+        match params.algorithm {
+            ModifiedCholeskyAlgorithm::GillMurrayWright => temp_mat_req::<E>(dim, 1),
+            ModifiedCholeskyAlgorithm::SchnabelEskow => StackReq::try_all_of([
+                temp_mat_req::<E>(dim, 1)?,
+                temp_mat_req::<E>(dim, 1)?,
+            ]),
+            ModifiedCholeskyAlgorithm::ChengHigham => StackReq::try_all_of([
+                temp_mat_req::<E>(dim, 1)?,
+                temp_mat_req::<E>(dim, dim)?,
+                temp_mat_req::<E>(dim, dim)?,
+                bunch_kaufman::compute::cholesky_in_place_req::<I, E>(
+                    dim,
+                    parallelism,
+                    Default::default(),
+                )?,
+            ]),
+        }
+    }
+
+    /// Performs a step of the right looking Cholesky decomposition on the column `j`, using `d`
+    /// as the value of the pivot.
+    fn llt_step<E: ComplexField>(matrix: MatMut<'_, E>, j: usize, d: E::Real) {
+        let mut matrix = matrix;
+        let n = matrix.nrows();
+
+        let diag = d.faer_sqrt();
+        let inv = diag.faer_inv();
+        matrix.write(j, j, E::faer_from_real(diag));
+        for i in j + 1..n {
+            matrix.write(i, j, matrix.read(i, j).faer_scale_real(inv));
+        }
+        for k in j + 1..n {
+            let lkj = matrix.read(k, j).faer_conj();
+            for i in k..n {
+                matrix.write(
+                    i,
+                    k,
+                    matrix.read(i, k).faer_sub(matrix.read(i, j).faer_mul(lkj)),
+                );
+            }
+        }
+    }
+
+    fn gill_murray_wright<I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        delta: E::Real,
+        perturbation: MatMut<'_, E::Real>,
+        perm: &mut [I],
+    ) -> (usize, usize) {
+        let mut matrix = matrix;
+        let mut perturbation = perturbation;
+        let n = matrix.nrows();
+        let eps = E::Real::faer_epsilon().unwrap();
+        let zero = E::Real::faer_zero();
+        let one = E::Real::faer_one();
+
+        let mut gamma = zero;
+        let mut xi = zero;
+        for j in 0..n {
+            gamma = max(gamma, matrix.read(j, j).faer_real().faer_abs());
+            for i in j + 1..n {
+                xi = max(xi, matrix.read(i, j).faer_abs());
+            }
+        }
+        let nu = max(
+            one,
+            E::Real::faer_from_f64((n * n) as f64 - 1.0).faer_sqrt(),
+        );
+        let beta2 = max(max(gamma, xi.faer_div(nu)), eps);
+        let delta = if delta < zero {
+            eps.faer_mul(max(gamma.faer_add(xi), one))
+        } else {
+            delta
+        };
+
+        let mut perturbation_count = 0;
+        let mut transposition_count = 0;
+        for j in 0..n {
+            let mut max_idx = j;
+            let mut max_val = matrix.read(j, j).faer_real().faer_abs();
+            for i in j + 1..n {
+                let val = matrix.read(i, i).faer_real().faer_abs();
+                if val > max_val {
+                    max_idx = i;
+                    max_val = val;
+                }
+            }
+            if max_idx != j {
+                hermitian_swap(matrix.rb_mut(), j, max_idx);
+                perm.swap(j, max_idx);
+                transposition_count += 1;
+            }
+
+            let c = matrix.read(j, j).faer_real();
+            let mut theta = zero;
+            for i in j + 1..n {
+                theta = max(theta, matrix.read(i, j).faer_abs());
+            }
+
+            let d = max(
+                max(delta, c.faer_abs()),
+                theta.faer_mul(theta).faer_div(beta2),
+            );
+            perturbation.write(j, 0, d.faer_sub(c));
+            if d != c {
+                perturbation_count += 1;
+            }
+            llt_step(matrix.rb_mut(), j, d);
+        }
+
+        (perturbation_count, transposition_count)
+    }
+
+    fn schnabel_eskow<I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        delta: E::Real,
+        perturbation: MatMut<'_, E::Real>,
+        perm: &mut [I],
+        stack: PodStack<'_>,
+    ) -> (usize, usize) {
+        let mut matrix = matrix;
+        let mut perturbation = perturbation;
+        let n = matrix.nrows();
+        let eps = E::Real::faer_epsilon().unwrap();
+        let zero = E::Real::faer_zero();
+        let one = E::Real::faer_one();
+
+        let tau = cbrt(eps);
+        let tau_bar = tau.faer_mul(tau);
+        let mu = E::Real::faer_from_f64(0.1);
+
+        let mut gamma = zero;
+        for j in 0..n {
+            gamma = max(gamma, matrix.read(j, j).faer_real().faer_abs());
+        }
+        if gamma == zero {
+            gamma = one;
+        }
+        let min_pivot = if delta < zero {
+            tau_bar.faer_mul(gamma)
+        } else {
+            delta
+        };
+
+        let mut perturbation_count = 0;
+        let mut transposition_count = 0;
+        perturbation.fill_zero();
+
+        // phase one: regular pivoted cholesky while the matrix is safely positive definite
+        let mut k = 0;
+        while k < n {
+            let mut max_idx = k;
+            let mut max_val = matrix.read(k, k).faer_real();
+            for i in k + 1..n {
+                let val = matrix.read(i, i).faer_real();
+                if val > max_val {
+                    max_idx = i;
+                    max_val = val;
+                }
+            }
+            if max_val < min_pivot {
+                break;
+            }
+            if max_idx != k {
+                hermitian_swap(matrix.rb_mut(), k, max_idx);
+                perm.swap(k, max_idx);
+                transposition_count += 1;
+            }
+
+            let akk = matrix.read(k, k).faer_real();
+            let mut min_schur = None;
+            for i in k + 1..n {
+                let val = matrix
+                    .read(i, i)
+                    .faer_real()
+                    .faer_sub(matrix.read(i, k).faer_abs2().faer_div(akk));
+                min_schur = Some(match min_schur {
+                    Some(prev) => min(prev, val),
+                    None => val,
+                });
+            }
+            if let Some(min_schur) = min_schur {
+                if min_schur < mu.faer_mul(gamma).faer_neg() {
+                    break;
+                }
+            }
+
+            llt_step(matrix.rb_mut(), k, akk);
+            k += 1;
+        }
+
+        if k == n {
+            return (perturbation_count, transposition_count);
+        }
+
+        // phase two: perturb the remaining submatrix using gerschgorin bounds
+        let mut delta_prev = zero;
+        if k + 1 == n {
+            let a = matrix.read(k, k).faer_real();
+            let delta = max(max(zero, min_pivot.faer_sub(a)), delta_prev);
+            if delta > zero {
+                perturbation.write(k, 0, delta);
+                perturbation_count += 1;
+            }
+            llt_step(matrix.rb_mut(), k, a.faer_add(delta));
+            return (perturbation_count, transposition_count);
+        }
+
+        let (mut g, _) = temp_mat_uninit::<E::Real>(n, 1, stack);
+        let mut g = g.as_mut();
+        for i in k..n {
+            let mut off = zero;
+            for l in k..i {
+                off = off.faer_add(matrix.read(i, l).faer_abs());
+            }
+            for l in i + 1..n {
+                off = off.faer_add(matrix.read(l, i).faer_abs());
+            }
+            g.write(i, 0, matrix.read(i, i).faer_real().faer_sub(off));
+        }
+
+        let mut j = k;
+        while j + 2 < n {
+            let mut max_idx = j;
+            let mut max_val = g.read(j, 0);
+            for i in j + 1..n {
+                let val = g.read(i, 0);
+                if val > max_val {
+                    max_idx = i;
+                    max_val = val;
+                }
+            }
+            if max_idx != j {
+                hermitian_swap(matrix.rb_mut(), j, max_idx);
+                let tmp = g.read(j, 0);
+                g.write(j, 0, g.read(max_idx, 0));
+                g.write(max_idx, 0, tmp);
+                perm.swap(j, max_idx);
+                transposition_count += 1;
+            }
+
+            let mut norm_j = zero;
+            for i in j + 1..n {
+                norm_j = norm_j.faer_add(matrix.read(i, j).faer_abs());
+            }
+
+            let ajj = matrix.read(j, j).faer_real();
+            let delta = max(
+                max(zero, ajj.faer_neg().faer_add(max(norm_j, min_pivot))),
+                delta_prev,
+            );
+            let ajj = if delta > zero {
+                perturbation.write(j, 0, delta);
+                perturbation_count += 1;
+                delta_prev = delta;
+                ajj.faer_add(delta)
+            } else {
+                ajj
+            };
+
+            if ajj != norm_j {
+                let tmp = one.faer_sub(norm_j.faer_div(ajj));
+                for i in j + 1..n {
+                    g.write(
+                        i,
+                        0,
+                        g.read(i, 0)
+                            .faer_add(matrix.read(i, j).faer_abs().faer_mul(tmp)),
+                    );
+                }
+            }
+
+            llt_step(matrix.rb_mut(), j, ajj);
+            j += 1;
+        }
+
+        // final 2x2 submatrix, handled using its exact eigenvalues
+        let a = matrix.read(j, j).faer_real();
+        let b = matrix.read(j + 1, j).faer_abs();
+        let c = matrix.read(j + 1, j + 1).faer_real();
+        let half = E::Real::faer_from_f64(0.5);
+        let mid = a.faer_add(c).faer_mul(half);
+        let rad = a.faer_sub(c).faer_mul(half);
+        let rad = rad.faer_mul(rad).faer_add(b.faer_mul(b)).faer_sqrt();
+        let lambda_lo = mid.faer_sub(rad);
+        let lambda_hi = mid.faer_add(rad);
+
+        let delta = max(
+            max(
+                zero,
+                lambda_lo.faer_neg().faer_add(max(
+                    tau.faer_mul(lambda_hi.faer_sub(lambda_lo))
+                        .faer_div(one.faer_sub(tau)),
+                    min_pivot,
+                )),
+            ),
+            delta_prev,
+        );
+        if delta > zero {
+            perturbation.write(j, 0, delta);
+            perturbation.write(j + 1, 0, delta);
+            perturbation_count += 2;
+            matrix.write(j + 1, j + 1, E::faer_from_real(c.faer_add(delta)));
+        }
+        llt_step(matrix.rb_mut(), j, a.faer_add(delta));
+        let c = matrix.read(j + 1, j + 1).faer_real();
+        llt_step(matrix.rb_mut(), j + 1, c);
+
+        (perturbation_count, transposition_count)
+    }
+
+    /// Raises the eigenvalues of the hermitian matrix `[a, conj(b); b, c]` to `delta`, and
+    /// returns the modified matrix.
+    fn raise_eigenvalues_2x2<E: ComplexField>(
+        a: E::Real,
+        b: E,
+        c: E::Real,
+        delta: E::Real,
+    ) -> (E::Real, E, E::Real) {
+        let zero = E::Real::faer_zero();
+        let half = E::Real::faer_from_f64(0.5);
+
+        let mid = a.faer_add(c).faer_mul(half);
+        let rad = a.faer_sub(c).faer_mul(half);
+        let rad = rad.faer_mul(rad).faer_add(b.faer_abs2()).faer_sqrt();
+
+        if b == E::faer_zero() {
+            return (max(a, delta), b, max(c, delta));
+        }
+
+        let (mut a_new, mut b_new, mut c_new) = (a, b, c);
+        for lambda in [mid.faer_sub(rad), mid.faer_add(rad)] {
+            if lambda >= delta {
+                continue;
+            }
+            let shift = delta.faer_sub(lambda);
+
+            // two candidate eigenvectors, we pick the one with the largest norm for stability
+            let v0 = (b.faer_conj(), E::faer_from_real(lambda.faer_sub(a)));
+            let v1 = (E::faer_from_real(lambda.faer_sub(c)), b);
+            let n0 = v0.0.faer_abs2().faer_add(v0.1.faer_abs2());
+            let n1 = v1.0.faer_abs2().faer_add(v1.1.faer_abs2());
+            let (v, norm2) = if n0 >= n1 { (v0, n0) } else { (v1, n1) };
+            if norm2 == zero {
+                continue;
+            }
+            let scale = shift.faer_div(norm2);
+
+            a_new = a_new.faer_add(v.0.faer_abs2().faer_mul(scale));
+            c_new = c_new.faer_add(v.1.faer_abs2().faer_mul(scale));
+            b_new = b_new.faer_add(v.1.faer_mul(v.0.faer_conj()).faer_scale_real(scale));
+        }
+        (a_new, b_new, c_new)
+    }
+
+    fn cheng_higham<I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        perturbation: MatMut<'_, E>,
+        delta: E::Real,
+        perm: &mut [I],
+        perm_inv: &mut [I],
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) -> (usize, usize) {
+        let mut matrix = matrix;
+        let mut perturbation = perturbation;
+        let n = matrix.nrows();
+        let zero = E::Real::faer_zero();
+
+        let delta = if delta < zero {
+            // infinity norm of the matrix, from its lower triangular half
+            let mut norm = zero;
+            for i in 0..n {
+                let mut row = zero;
+                for j in 0..i + 1 {
+                    row = row.faer_add(matrix.read(i, j).faer_abs());
+                }
+                for j in i + 1..n {
+                    row = row.faer_add(matrix.read(j, i).faer_abs());
+                }
+                norm = max(norm, row);
+            }
+            E::Real::faer_epsilon().unwrap().faer_sqrt().faer_mul(norm)
+        } else {
+            delta
+        };
+
+        let (mut subdiag, stack) = temp_mat_uninit::<E>(n, 1, stack);
+        let mut subdiag = subdiag.as_mut();
+        let (mut l, stack) = temp_mat_uninit::<E>(n, n, stack);
+        let mut l = l.as_mut();
+        let (mut f, stack) = temp_mat_uninit::<E>(n, n, stack);
+        let mut f = f.as_mut();
+
+        let (info, _) = bunch_kaufman::compute::cholesky_in_place(
+            matrix.rb_mut(),
+            subdiag.rb_mut(),
+            Default::default(),
+            perm,
+            perm_inv,
+            parallelism,
+            stack,
+            Default::default(),
+        );
+
+        // unit lower triangular factor
+        for j in 0..n {
+            for i in 0..n {
+                let value = if i == j {
+                    E::faer_one()
+                } else if i > j {
+                    matrix.read(i, j)
+                } else {
+                    E::faer_zero()
+                };
+                l.write(i, j, value);
+            }
+        }
+
+        let inv_or_zero = |x: E::Real| {
+            let inv = x.faer_inv();
+            if inv.faer_is_finite() {
+                inv
+            } else {
+                zero
+            }
+        };
+
+        // compute the modified block diagonal factor B + F, and store F in `f`
+        f.fill_zero();
+        let mut perturbation_count = 0;
+        let mut i = 0;
+        while i < n {
+            if subdiag.read(i, 0) == E::faer_zero() {
+                let b = inv_or_zero(matrix.read(i, i).faer_real());
+                let b_new = max(b, delta);
+                if b_new != b {
+                    perturbation_count += 1;
+                }
+                f.write(i, i, E::faer_from_real(b_new.faer_sub(b)));
+                llt_scale_col(l.rb(), matrix.rb_mut(), i, b_new);
+                i += 1;
+            } else {
+                // the diagonal block stores the inverse of the block of B
+                let ia = matrix.read(i, i).faer_real();
+                let ib = subdiag.read(i, 0);
+                let ic = matrix.read(i + 1, i + 1).faer_real();
+                let det = ia.faer_mul(ic).faer_sub(ib.faer_abs2());
+                let inv_det = inv_or_zero(det);
+                let a = ic.faer_mul(inv_det);
+                let b = ib.faer_neg().faer_scale_real(inv_det);
+                let c = ia.faer_mul(inv_det);
+
+                let (a_new, b_new, c_new) = raise_eigenvalues_2x2::<E>(a, b, c, delta);
+                if a_new != a || b_new != b || c_new != c {
+                    perturbation_count += 1;
+                }
+                f.write(i, i, E::faer_from_real(a_new.faer_sub(a)));
+                f.write(i + 1, i, b_new.faer_sub(b));
+                f.write(i, i + 1, b_new.faer_sub(b).faer_conj());
+                f.write(i + 1, i + 1, E::faer_from_real(c_new.faer_sub(c)));
+
+                // cholesky factor of the modified block
+                let c00 = a_new.faer_sqrt();
+                let c10 = b_new.faer_scale_real(c00.faer_inv());
+                let c11 = max(c_new.faer_sub(c10.faer_abs2()), zero).faer_sqrt();
+                for row in i..n {
+                    let l0 = l.read(row, i);
+                    let l1 = l.read(row, i + 1);
+                    matrix.write(row, i, l0.faer_scale_real(c00).faer_add(l1.faer_mul(c10)));
+                    matrix.write(row, i + 1, l1.faer_scale_real(c11));
+                }
+                i += 2;
+            }
+        }
+
+        // E = P^T L F L^H P
+        mul::matmul(
+            perturbation.rb_mut(),
+            l.rb(),
+            f.rb(),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        f.copy_from(perturbation.rb());
+        mul::matmul(
+            perturbation.rb_mut(),
+            f.rb(),
+            l.rb().adjoint(),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        f.copy_from(perturbation.rb());
+        for j in 0..n {
+            for i in 0..n {
+                perturbation.write(
+                    perm[i].to_signed().zx(),
+                    perm[j].to_signed().zx(),
+                    f.read(i, j),
+                );
+            }
+        }
+        (perturbation_count, info.transposition_count)
+    }
+
+    /// Scales the column `i` of the unit lower triangular factor `l` by `sqrt(b)`, and stores it
+    /// in `matrix`.
+    fn llt_scale_col<E: ComplexField>(
+        l: MatRef<'_, E>,
+        matrix: MatMut<'_, E>,
+        i: usize,
+        b: E::Real,
+    ) {
+        let mut matrix = matrix;
+        let n = matrix.nrows();
+        let s = b.faer_sqrt();
+        for row in i..n {
+            matrix.write(row, i, l.read(row, i).faer_scale_real(s));
+        }
+    }
+
+    /// Computes the cube root of a positive number with Newton's method.
+    fn cbrt<E: RealField>(x: E) -> E {
+        let three = E::faer_from_f64(3.0);
+        let two = E::faer_from_f64(2.0);
+        let mut y = E::faer_one();
+        for _ in 0..200 {
+            let next = two
+                .faer_mul(y)
+                .faer_add(x.faer_div(y.faer_mul(y)))
+                .faer_div(three);
+            if next == y {
+                break;
+            }
+            y = next;
+        }
+        y
+    }
+
+    #[inline]
+    fn max<E: RealField>(a: E, b: E) -> E {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    #[inline]
+    fn min<E: RealField>(a: E, b: E) -> E {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    /// Computes a modified Cholesky factor $L$ of a hermitian input matrix $A$, a permutation $P$
+    /// and a perturbation $E$, such that $L$ is lower triangular, and
+    /// $$P(A + E)P^\top = LL^H,$$
+    /// where $E$ is chosen according to the algorithm selected in `params`, and stored in
+    /// `perturbation`.
+    ///
+    /// `delta` is the minimum pivot (or eigenvalue, for [`ModifiedCholeskyAlgorithm::ChengHigham`])
+    /// that is allowed after the perturbation. If `delta` is negative, a default value depending
+    /// on the algorithm is used instead.
+    ///
+    /// The result is stored back in the lower half of the same matrix. The input matrix is
+    /// interpreted as hermitian and only the lower triangular part is read.
+    ///
+    /// The strictly upper triangular part of the matrix is clobbered and may be filled with garbage
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input matrix is not square, if `perturbation` doesn't have the same shape as
+    /// the matrix, or if `perm` or `perm_inv` don't have a length equal to the matrix dimension.
+    ///
+    /// This can also panic if the provided memory in `stack` is insufficient (see
+    /// [`modified_cholesky_in_place_req`]).
+    #[track_caller]
+    pub fn modified_cholesky_in_place<'out, I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        perturbation: MatMut<'_, E>,
+        delta: E::Real,
+        perm: &'out mut [I],
+        perm_inv: &'out mut [I],
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+        params: ModifiedLltParams,
+    ) -> (ModifiedLltInfo, PermutationMut<'out, I, E>) {
+        let truncate = <I::Signed as SignedIndex>::truncate;
+
+        let n = matrix.nrows();
+        assert!(all(
+            matrix.nrows() == matrix.ncols(),
+            perturbation.nrows() == n,
+            perturbation.ncols() == n,
+            perm.len() == n,
+            perm_inv.len() == n,
+        ));
+
+        let mut perturbation = perturbation;
+
+        let (perturbation_count, transposition_count) = match params.algorithm {
+            ModifiedCholeskyAlgorithm::ChengHigham => cheng_higham(
+                matrix,
+                perturbation.rb_mut(),
+                delta,
+                perm,
+                perm_inv,
+                parallelism,
+                stack,
+            ),
+            algorithm => {
+                for (i, p) in perm.iter_mut().enumerate() {
+                    *p = I::from_signed(truncate(i));
+                }
+
+                let (mut diag, stack) = temp_mat_uninit::<E::Real>(n, 1, stack);
+                let mut diag = diag.as_mut();
+                let counts = if algorithm == ModifiedCholeskyAlgorithm::GillMurrayWright {
+                    gill_murray_wright(matrix, delta, diag.rb_mut(), perm)
+                } else {
+                    schnabel_eskow(matrix, delta, diag.rb_mut(), perm, stack)
+                };
+
+                perturbation.fill_zero();
+                for (i, &p) in perm.iter().enumerate() {
+                    let p = p.to_signed().zx();
+                    perturbation.write(p, p, E::faer_from_real(diag.read(i, 0)));
+                }
+                counts
+            }
+        };
+
+        for (i, &p) in perm.iter().enumerate() {
+            perm_inv[p.to_signed().zx()] = I::from_signed(truncate(i));
+        }
+
+        (
+            ModifiedLltInfo {
+                perturbation_count,
+                transposition_count,
+            },
+            unsafe { PermutationMut::new_unchecked(perm, perm_inv) },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute::*, *};
+    use assert_approx_eq::assert_approx_eq;
+    use dyn_stack::GlobalPodBuffer;
+    use faer_core::{assert, c64, Mat};
+    use rand::random;
+
+    fn factorize(
+        a: &Mat<c64>,
+        algorithm: ModifiedCholeskyAlgorithm,
+    ) -> (Mat<c64>, Mat<c64>, Vec<usize>, ModifiedLltInfo) {
+        let n = a.nrows();
+        let mut l = a.clone();
+        let mut perturbation = Mat::<c64>::zeros(n, n);
+        let mut perm = vec![0usize; n];
+        let mut perm_inv = vec![0; n];
+
+        let params = ModifiedLltParams { algorithm };
+        let mut mem = GlobalPodBuffer::new(
+            modified_cholesky_in_place_req::<usize, c64>(n, Parallelism::None, params).unwrap(),
+        );
+        let (info, _) = modified_cholesky_in_place(
+            l.as_mut(),
+            perturbation.as_mut(),
+            -1.0,
+            &mut perm,
+            &mut perm_inv,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+            params,
+        );
+
+        let l = Mat::from_fn(n, n, |i, j| {
+            if i >= j {
+                l.read(i, j)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        });
+        (l, perturbation, perm, info)
+    }
+
+    const ALGORITHMS: [ModifiedCholeskyAlgorithm; 3] = [
+        ModifiedCholeskyAlgorithm::GillMurrayWright,
+        ModifiedCholeskyAlgorithm::SchnabelEskow,
+        ModifiedCholeskyAlgorithm::ChengHigham,
+    ];
+
+    #[test]
+    fn test_indefinite() {
+        for n in [1, 2, 3, 4, 7, 20, 63] {
+            let b = Mat::<c64>::from_fn(n, n, |_, _| c64::new(random(), random()));
+            let a = Mat::<c64>::from_fn(n, n, |i, j| {
+                let x = b.read(i, j) + b.read(j, i).conj();
+                if i == j {
+                    x - c64::new(n as f64, 0.0)
+                } else {
+                    x
+                }
+            });
+
+            for algorithm in ALGORITHMS {
+                let (l, e, perm, _) = factorize(&a, algorithm);
+                let llh = &l * l.adjoint();
+                for j in 0..n {
+                    for i in 0..n {
+                        let expected = a.read(perm[i], perm[j]) + e.read(perm[i], perm[j]);
+                        assert_approx_eq!(llh.read(i, j), expected, 1e-8);
+                    }
+                }
+                for j in 0..n {
+                    assert!(l.read(j, j).re > 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_positive_definite() {
+        for n in [1, 4, 20, 63] {
+            let b = Mat::<c64>::from_fn(n, n, |_, _| c64::new(random(), random()));
+            let bbh = &b * b.adjoint();
+            let a = Mat::<c64>::from_fn(n, n, |i, j| {
+                if i == j {
+                    bbh.read(i, j) + c64::new(1.0, 0.0)
+                } else {
+                    bbh.read(i, j)
+                }
+            });
+
+            for algorithm in [
+                ModifiedCholeskyAlgorithm::GillMurrayWright,
+                ModifiedCholeskyAlgorithm::SchnabelEskow,
+            ] {
+                let (l, e, perm, info) = factorize(&a, algorithm);
+                if algorithm == ModifiedCholeskyAlgorithm::SchnabelEskow {
+                    assert!(info.perturbation_count == 0);
+                    for j in 0..n {
+                        for i in 0..n {
+                            assert!(e.read(i, j) == c64::new(0.0, 0.0));
+                        }
+                    }
+                }
+                let llh = &l * l.adjoint();
+                for j in 0..n {
+                    for i in 0..n {
+                        let expected = a.read(perm[i], perm[j]) + e.read(perm[i], perm[j]);
+                        assert_approx_eq!(llh.read(i, j), expected, 1e-8);
+                    }
+                }
+            }
+        }
+    }
+}
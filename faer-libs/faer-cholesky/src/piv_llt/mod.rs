@@ -0,0 +1,318 @@
+//! The pivoted Cholesky decomposition of a hermitian positive semidefinite matrix $A$ is such
+//! that:
+//! $$P A P^\top = LL^H,$$
+//! where $P$ is a permutation matrix chosen with diagonal pivoting, and $L$ is a lower triangular
+//! matrix, whose columns past the numerical rank of $A$ are zero.
+
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    mul::{
+        self,
+        triangular::{self, BlockStructure},
+    },
+    permutation::{Index, PermutationMut, SignedIndex},
+    temp_mat_req, temp_mat_uninit, unzipped, zipped, MatMut, Parallelism,
+};
+use faer_entity::{ComplexField, Entity, RealField};
+use reborrow::*;
+
+pub mod compute {
+    use super::*;
+    use faer_core::assert;
+
+    #[derive(Copy, Clone, Debug)]
+    #[non_exhaustive]
+    pub struct PivLltParams {
+        /// Number of columns that are factorized before the trailing matrix is updated.
+        pub blocksize: usize,
+    }
+
+    impl Default for PivLltParams {
+        fn default() -> Self {
+            Self { blocksize: 64 }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct PivLltInfo {
+        /// Numerical rank of the matrix, i.e., the number of nonzero columns of $L$.
+        pub rank: usize,
+        pub transposition_count: usize,
+    }
+
+    /// Computes the size and alignment of required workspace for performing a pivoted Cholesky
+    /// decomposition.
+    pub fn cholesky_in_place_req<I: Index, E: Entity>(
+        dim: usize,
+        parallelism: Parallelism,
+        params: PivLltParams,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = parallelism;
+        let _ = params;
+        // the workspace holds real values, which fit in the storage of `E`
+        temp_mat_req::<E>(dim, 1)
+    }
+
+    /// Swaps the indices `k` and `p` of the hermitian matrix whose lower triangular half is stored
+    /// in `matrix`, where `k < p`.
+    pub(crate) fn hermitian_swap<E: ComplexField>(matrix: MatMut<'_, E>, k: usize, p: usize) {
+        let mut matrix = matrix;
+        let n = matrix.nrows();
+
+        for j in 0..k {
+            let tmp = matrix.read(k, j);
+            matrix.write(k, j, matrix.read(p, j));
+            matrix.write(p, j, tmp);
+        }
+
+        let tmp = matrix.read(k, k);
+        matrix.write(k, k, matrix.read(p, p));
+        matrix.write(p, p, tmp);
+
+        for i in k + 1..p {
+            let tmp = matrix.read(i, k);
+            matrix.write(i, k, matrix.read(p, i).faer_conj());
+            matrix.write(p, i, tmp.faer_conj());
+        }
+        matrix.write(p, k, matrix.read(p, k).faer_conj());
+
+        for i in p + 1..n {
+            let tmp = matrix.read(i, k);
+            matrix.write(i, k, matrix.read(i, p));
+            matrix.write(i, p, tmp);
+        }
+    }
+
+    /// Computes the pivoted Cholesky factor $L$ of a hermitian positive semidefinite input matrix
+    /// $A$, and a permutation $P$, such that $L$ is lower triangular, and
+    /// $$PAP^\top = LL^H.$$
+    ///
+    /// The factorization stops once the largest remaining diagonal entry is less than or equal to
+    /// `tolerance`, in which case the remaining columns of $L$ are set to zero, and the number of
+    /// computed columns is returned as the numerical rank. If `tolerance` is negative, the
+    /// threshold `dim * epsilon * max(diag(A))` is used instead.
+    ///
+    /// The result is stored back in the lower half of the same matrix. The input matrix is
+    /// interpreted as hermitian and only the lower triangular part is read.
+    ///
+    /// The strictly upper triangular part of the matrix is clobbered and may be filled with garbage
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input matrix is not square, or if `perm` or `perm_inv` don't have a length
+    /// equal to the matrix dimension.
+    ///
+    /// This can also panic if the provided memory in `stack` is insufficient (see
+    /// [`cholesky_in_place_req`]).
+    #[track_caller]
+    pub fn cholesky_in_place<'out, I: Index, E: ComplexField>(
+        matrix: MatMut<'_, E>,
+        tolerance: E::Real,
+        perm: &'out mut [I],
+        perm_inv: &'out mut [I],
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+        params: PivLltParams,
+    ) -> (PivLltInfo, PermutationMut<'out, I, E>) {
+        let truncate = <I::Signed as SignedIndex>::truncate;
+
+        let n = matrix.nrows();
+        assert!(all(
+            matrix.nrows() == matrix.ncols(),
+            perm.len() == n,
+            perm_inv.len() == n,
+        ));
+
+        #[cfg(feature = "perf-warn")]
+        if matrix.row_stride().unsigned_abs() != 1 && faer_core::__perf_warn!(CHOLESKY_WARN) {
+            if matrix.col_stride().unsigned_abs() == 1 {
+                log::warn!(target: "faer_perf", "pivoted LLT prefers column-major matrix. Found row-major matrix.");
+            } else {
+                log::warn!(target: "faer_perf", "pivoted LLT prefers column-major matrix. Found matrix with generic strides.");
+            }
+        }
+
+        let mut matrix = matrix;
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = I::from_signed(truncate(i));
+        }
+
+        let tolerance = if tolerance < E::Real::faer_zero() {
+            let mut max_diag = E::Real::faer_zero();
+            for i in 0..n {
+                let d = matrix.read(i, i).faer_real();
+                if d > max_diag {
+                    max_diag = d;
+                }
+            }
+            E::Real::faer_from_f64(n as f64)
+                .faer_mul(E::Real::faer_epsilon().unwrap())
+                .faer_mul(max_diag)
+        } else {
+            tolerance
+        };
+
+        // squared norms of the rows of the current panel of L
+        let (mut work, _) = temp_mat_uninit::<E::Real>(n, 1, stack);
+        let mut work = work.as_mut();
+
+        let blocksize = Ord::max(params.blocksize, 1);
+        let mut rank = n;
+        let mut transposition_count = 0usize;
+
+        let mut j0 = 0;
+        'outer: while j0 < n {
+            let j1 = Ord::min(j0 + blocksize, n);
+            work.fill_zero();
+
+            for j in j0..j1 {
+                let mut max_idx = j;
+                let mut max_val = matrix.read(j, j).faer_real().faer_sub(work.read(j, 0));
+                for i in j + 1..n {
+                    let val = matrix.read(i, i).faer_real().faer_sub(work.read(i, 0));
+                    if val > max_val {
+                        max_idx = i;
+                        max_val = val;
+                    }
+                }
+
+                if max_val <= tolerance || !max_val.faer_is_finite() {
+                    rank = j;
+                    break 'outer;
+                }
+
+                if max_idx != j {
+                    hermitian_swap(matrix.rb_mut(), j, max_idx);
+                    let tmp = work.read(j, 0);
+                    work.write(j, 0, work.read(max_idx, 0));
+                    work.write(max_idx, 0, tmp);
+                    perm.swap(j, max_idx);
+                    transposition_count += 1;
+                }
+
+                let diag = max_val.faer_sqrt();
+                matrix.write(j, j, E::faer_from_real(diag));
+
+                // A21 -= L20 × L10^H, where L10 and L20 are the columns of the current panel
+                let (left, right) = matrix.rb_mut().split_at_col_mut(j);
+                let left = left.into_const();
+                let mut a21 = right.submatrix_mut(j + 1, 0, n - j - 1, 1);
+                mul::matmul(
+                    a21.rb_mut(),
+                    left.submatrix(j + 1, j0, n - j - 1, j - j0),
+                    left.submatrix(j, j0, 1, j - j0).adjoint(),
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+
+                let inv = diag.faer_inv();
+                zipped!(a21.rb_mut())
+                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(inv)));
+
+                for i in j + 1..n {
+                    work.write(
+                        i,
+                        0,
+                        work.read(i, 0).faer_add(matrix.read(i, j).faer_abs2()),
+                    );
+                }
+            }
+
+            // A22 -= L21 × L21^H
+            if j1 < n {
+                let (_, _, bottom_left, bottom_right) = matrix.rb_mut().split_at_mut(j1, j1);
+                let l21 = bottom_left.into_const().subcols(j0, j1 - j0);
+                triangular::matmul(
+                    bottom_right,
+                    BlockStructure::TriangularLower,
+                    l21,
+                    BlockStructure::Rectangular,
+                    l21.adjoint(),
+                    BlockStructure::Rectangular,
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+            }
+
+            j0 = j1;
+        }
+
+        zipped!(matrix
+            .rb_mut()
+            .submatrix_mut(rank, rank, n - rank, n - rank))
+        .for_each_triangular_lower(faer_core::zip::Diag::Include, |unzipped!(mut x)| {
+            x.write(E::faer_zero())
+        });
+
+        for (i, &p) in perm.iter().enumerate() {
+            perm_inv[p.to_signed().zx()] = I::from_signed(truncate(i));
+        }
+
+        (
+            PivLltInfo {
+                rank,
+                transposition_count,
+            },
+            unsafe { PermutationMut::new_unchecked(perm, perm_inv) },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use dyn_stack::GlobalPodBuffer;
+    use faer_core::{assert, c64, Mat};
+    use rand::random;
+
+    #[test]
+    fn test_low_rank() {
+        for (n, r) in [(1, 1), (4, 2), (19, 5), (100, 30), (150, 150)] {
+            let b = Mat::<c64>::from_fn(n, r, |_, _| c64::new(random(), random()));
+            let a = &b * b.adjoint();
+
+            let mut l = a.clone();
+            let mut perm = vec![0usize; n];
+            let mut perm_inv = vec![0; n];
+
+            let params = compute::PivLltParams {
+                blocksize: 8,
+                ..Default::default()
+            };
+            let mut mem = GlobalPodBuffer::new(
+                compute::cholesky_in_place_req::<usize, c64>(n, Parallelism::None, params).unwrap(),
+            );
+            let (info, _) = compute::cholesky_in_place(
+                l.as_mut(),
+                -1.0,
+                &mut perm,
+                &mut perm_inv,
+                Parallelism::None,
+                PodStack::new(&mut mem),
+                params,
+            );
+            assert!(info.rank == r);
+
+            let l = Mat::from_fn(n, n, |i, j| {
+                if i >= j {
+                    l.read(i, j)
+                } else {
+                    c64::new(0.0, 0.0)
+                }
+            });
+            let llh = &l * l.adjoint();
+            for j in 0..n {
+                for i in 0..n {
+                    assert_approx_eq!(llh.read(i, j), a.read(perm[i], perm[j]), 1e-10);
+                }
+            }
+            for k in 1..r {
+                assert!(l.read(k - 1, k - 1).re >= l.read(k, k).re);
+            }
+        }
+    }
+}
@@ -712,6 +712,11 @@ impl ComplexField for c32 {
         Self { re: real, im: 0.0 }
     }
 
+    #[inline(always)]
+    fn faer_from_real_imag(real: Self::Real, imag: Self::Real) -> Self {
+        Self { re: real, im: imag }
+    }
+
     #[inline(always)]
     fn faer_real(self) -> Self::Real {
         self.re
@@ -207,6 +207,8 @@ pub mod matrix_ops;
 
 pub mod sparse;
 
+pub mod linop;
+
 /// Thin wrapper used for scalar multiplication of a matrix by a scalar value.
 pub use matrix_ops::scale;
 
@@ -7532,6 +7534,21 @@ pub fn is_vectorizable<T: 'static>() -> bool {
         || coe::is_same::<c64conj, T>()
 }
 
+/// Converts a real scalar to `f64`, for reporting values in non-generic diagnostics.
+///
+/// Returns NaN for types other than `f32` and `f64`.
+#[doc(hidden)]
+#[inline]
+pub fn real_to_f64<E: RealField>(value: E) -> f64 {
+    if coe::is_same::<E, f64>() {
+        coe::coerce_static::<E, f64>(value)
+    } else if coe::is_same::<E, f32>() {
+        coe::coerce_static::<E, f32>(value) as f64
+    } else {
+        f64::NAN
+    }
+}
+
 // https://rust-lang.github.io/hashbrown/src/crossbeam_utils/cache_padded.rs.html#128-130
 #[doc(hidden)]
 pub const CACHELINE_ALIGN: usize = {
@@ -0,0 +1,946 @@
+//! Linear operators, for algorithms that only need to compute matrix products `A * x` and
+//! `adjoint(A) * x`.
+//!
+//! [`LinearOperator`] is implemented for dense and sparse matrices, permutations and diagonal
+//! matrices, and can be implemented by the user for matrix-free operators. Operators can be
+//! combined with [`Sum`], [`Product`], [`Scaled`], [`Transpose`] and [`Adjoint`].
+
+use crate::{
+    assert, inner,
+    mul::matmul_with_conj,
+    permutation::{permute_rows, Index, PermutationRef},
+    sparse::{
+        mul::{dense_sparse_matmul, sparse_dense_matmul},
+        SparseColMatRef, SparseRowMatRef,
+    },
+    temp_mat_req, temp_mat_uninit, unzipped, zipped, ComplexField, Conj, Conjugate, MatMut, MatRef,
+    Matrix, Parallelism,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use reborrow::*;
+
+/// Linear operator `A`, that can be applied to a dense matrix.
+pub trait LinearOperator<E: ComplexField> {
+    /// Returns the number of rows of the operator.
+    fn nrows(&self) -> usize;
+    /// Returns the number of columns of the operator.
+    fn ncols(&self) -> usize;
+
+    /// Computes the size and alignment of the workspace required to apply the operator to a
+    /// matrix with `rhs_ncols` columns.
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow>;
+    /// Computes the size and alignment of the workspace required to apply the transpose or the
+    /// adjoint of the operator to a matrix with `rhs_ncols` columns.
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow>;
+
+    /// Computes `A * rhs`, or `conjugate(A) * rhs` if `conj == Conj::Yes`, and stores the result
+    /// in `out`.
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    );
+    /// Computes `transpose(A) * rhs`, or `adjoint(A) * rhs` if `conj == Conj::Yes`, and stores the
+    /// result in `out`.
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    );
+
+    /// Computes `A * rhs`, and stores the result in `out`.
+    #[inline]
+    #[track_caller]
+    fn apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_with_conj(out, rhs, Conj::No, parallelism, stack)
+    }
+    /// Computes `adjoint(A) * rhs`, and stores the result in `out`.
+    #[inline]
+    #[track_caller]
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.transpose_apply_with_conj(out, rhs, Conj::Yes, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField, T: ?Sized + LinearOperator<E>> LinearOperator<E> for &T {
+    #[inline]
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        (**self).apply_req(rhs_ncols, parallelism)
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        (**self).transpose_apply_req(rhs_ncols, parallelism)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        (**self).apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+    #[inline]
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        (**self).transpose_apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField, ViewE: Conjugate<Canonical = E>> LinearOperator<E> for MatRef<'_, ViewE> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        (*self).nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        (*self).ncols()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = stack;
+        let (lhs, conj_lhs) = self.canonicalize();
+        matmul_with_conj(
+            out,
+            lhs,
+            conj_lhs.compose(conj),
+            rhs,
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = stack;
+        let (lhs, conj_lhs) = self.canonicalize();
+        matmul_with_conj(
+            out,
+            lhs.transpose(),
+            conj_lhs.compose(conj),
+            rhs,
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+    }
+}
+
+impl<I: Index, E: ComplexField, ViewE: Conjugate<Canonical = E>> LinearOperator<E>
+    for SparseColMatRef<'_, I, ViewE>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.symbolic().nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.symbolic().ncols()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = stack;
+        match conj {
+            Conj::No => sparse_dense_matmul(out, *self, rhs, None, E::faer_one(), parallelism),
+            Conj::Yes => {
+                sparse_dense_matmul(out, self.conjugate(), rhs, None, E::faer_one(), parallelism)
+            }
+        }
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = stack;
+        // transpose(A) * rhs = transpose(transpose(rhs) * A)
+        let out = out.transpose_mut();
+        let rhs = rhs.transpose();
+        match conj {
+            Conj::No => dense_sparse_matmul(out, rhs, *self, None, E::faer_one(), parallelism),
+            Conj::Yes => {
+                dense_sparse_matmul(out, rhs, self.conjugate(), None, E::faer_one(), parallelism)
+            }
+        }
+    }
+}
+
+impl<I: Index, E: ComplexField, ViewE: Conjugate<Canonical = E>> LinearOperator<E>
+    for SparseRowMatRef<'_, I, ViewE>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.symbolic().nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.symbolic().ncols()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.transpose().transpose_apply_req(rhs_ncols, parallelism)
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.transpose().apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.transpose()
+            .transpose_apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.transpose()
+            .apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+}
+
+impl<I: Index, E: ComplexField> LinearOperator<E> for PermutationRef<'_, I, E> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.len()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        // permutation matrices are real
+        let _ = (conj, parallelism, stack);
+        permute_rows(out, rhs, *self);
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = (conj, parallelism, stack);
+        permute_rows(out, rhs, self.inverse());
+    }
+}
+
+impl<E: ComplexField, ViewE: Conjugate<Canonical = E>> LinearOperator<E>
+    for Matrix<inner::DiagRef<'_, ViewE>>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.column_vector().nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.column_vector().nrows()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = (parallelism, stack);
+        let diag = self.column_vector();
+        assert!(all(
+            out.nrows() == diag.nrows(),
+            rhs.nrows() == diag.nrows(),
+            out.ncols() == rhs.ncols(),
+        ));
+
+        let mut out = out;
+        for j in 0..rhs.ncols() {
+            for i in 0..rhs.nrows() {
+                let d = diag.read(i).canonicalize();
+                let d = if conj == Conj::Yes { d.faer_conj() } else { d };
+                out.write(i, j, d.faer_mul(rhs.read(i, j)));
+            }
+        }
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+}
+
+/// Sum of two operators, `A + B`.
+#[derive(Copy, Clone, Debug)]
+pub struct Sum<A, B>(pub A, pub B);
+
+/// Product of two operators, `A * B`.
+#[derive(Copy, Clone, Debug)]
+pub struct Product<A, B>(pub A, pub B);
+
+/// Operator scaled by a factor, `factor * A`.
+#[derive(Copy, Clone, Debug)]
+pub struct Scaled<E, A>(pub E, pub A);
+
+/// Transpose of an operator, `transpose(A)`.
+#[derive(Copy, Clone, Debug)]
+pub struct Transpose<A>(pub A);
+
+/// Adjoint of an operator, `adjoint(A)`.
+#[derive(Copy, Clone, Debug)]
+pub struct Adjoint<A>(pub A);
+
+/// Computes `out += tmp`.
+#[inline]
+fn add_assign<E: ComplexField>(out: MatMut<'_, E>, tmp: MatRef<'_, E>) {
+    zipped!(out, tmp)
+        .for_each(|unzipped!(mut out, tmp)| out.write(out.read().faer_add(tmp.read())));
+}
+
+impl<E: ComplexField, A: LinearOperator<E>, B: LinearOperator<E>> LinearOperator<E> for Sum<A, B> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.0.nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.0.ncols()
+    }
+
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_any_of([
+            self.0.apply_req(rhs_ncols, parallelism)?,
+            StackReq::try_all_of([
+                temp_mat_req::<E>(self.nrows(), rhs_ncols)?,
+                self.1.apply_req(rhs_ncols, parallelism)?,
+            ])?,
+        ])
+    }
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_any_of([
+            self.0.transpose_apply_req(rhs_ncols, parallelism)?,
+            StackReq::try_all_of([
+                temp_mat_req::<E>(self.ncols(), rhs_ncols)?,
+                self.1.transpose_apply_req(rhs_ncols, parallelism)?,
+            ])?,
+        ])
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        assert!(all(
+            self.0.nrows() == self.1.nrows(),
+            self.0.ncols() == self.1.ncols(),
+        ));
+        let mut out = out;
+        let mut stack = stack;
+        self.0
+            .apply_with_conj(out.rb_mut(), rhs, conj, parallelism, stack.rb_mut());
+        let (mut tmp, stack) = temp_mat_uninit::<E>(out.nrows(), out.ncols(), stack);
+        self.1
+            .apply_with_conj(tmp.rb_mut(), rhs, conj, parallelism, stack);
+        add_assign(out, tmp.rb());
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        assert!(all(
+            self.0.nrows() == self.1.nrows(),
+            self.0.ncols() == self.1.ncols(),
+        ));
+        let mut out = out;
+        let mut stack = stack;
+        self.0
+            .transpose_apply_with_conj(out.rb_mut(), rhs, conj, parallelism, stack.rb_mut());
+        let (mut tmp, stack) = temp_mat_uninit::<E>(out.nrows(), out.ncols(), stack);
+        self.1
+            .transpose_apply_with_conj(tmp.rb_mut(), rhs, conj, parallelism, stack);
+        add_assign(out, tmp.rb());
+    }
+}
+
+impl<E: ComplexField, A: LinearOperator<E>, B: LinearOperator<E>> LinearOperator<E>
+    for Product<A, B>
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.0.nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.1.ncols()
+    }
+
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_all_of([
+            temp_mat_req::<E>(self.1.nrows(), rhs_ncols)?,
+            StackReq::try_any_of([
+                self.0.apply_req(rhs_ncols, parallelism)?,
+                self.1.apply_req(rhs_ncols, parallelism)?,
+            ])?,
+        ])
+    }
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        StackReq::try_all_of([
+            temp_mat_req::<E>(self.0.ncols(), rhs_ncols)?,
+            StackReq::try_any_of([
+                self.0.transpose_apply_req(rhs_ncols, parallelism)?,
+                self.1.transpose_apply_req(rhs_ncols, parallelism)?,
+            ])?,
+        ])
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        assert!(self.0.ncols() == self.1.nrows());
+        let (mut tmp, mut stack) = temp_mat_uninit::<E>(self.1.nrows(), rhs.ncols(), stack);
+        self.1
+            .apply_with_conj(tmp.rb_mut(), rhs, conj, parallelism, stack.rb_mut());
+        self.0
+            .apply_with_conj(out, tmp.rb(), conj, parallelism, stack);
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        assert!(self.0.ncols() == self.1.nrows());
+        // transpose(A * B) = transpose(B) * transpose(A)
+        let (mut tmp, mut stack) = temp_mat_uninit::<E>(self.0.ncols(), rhs.ncols(), stack);
+        self.0
+            .transpose_apply_with_conj(tmp.rb_mut(), rhs, conj, parallelism, stack.rb_mut());
+        self.1
+            .transpose_apply_with_conj(out, tmp.rb(), conj, parallelism, stack);
+    }
+}
+
+impl<E: ComplexField, A: LinearOperator<E>> LinearOperator<E> for Scaled<E, A> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.1.nrows()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.1.ncols()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.1.apply_req(rhs_ncols, parallelism)
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.1.transpose_apply_req(rhs_ncols, parallelism)
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let mut out = out;
+        self.1
+            .apply_with_conj(out.rb_mut(), rhs, conj, parallelism, stack);
+        let factor = if conj == Conj::Yes {
+            self.0.faer_conj()
+        } else {
+            self.0
+        };
+        zipped!(out).for_each(|unzipped!(mut out)| out.write(factor.faer_mul(out.read())));
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let mut out = out;
+        self.1
+            .transpose_apply_with_conj(out.rb_mut(), rhs, conj, parallelism, stack);
+        let factor = if conj == Conj::Yes {
+            self.0.faer_conj()
+        } else {
+            self.0
+        };
+        zipped!(out).for_each(|unzipped!(mut out)| out.write(factor.faer_mul(out.read())));
+    }
+}
+
+impl<E: ComplexField, A: LinearOperator<E>> LinearOperator<E> for Transpose<A> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.0.ncols()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.0.nrows()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.0.transpose_apply_req(rhs_ncols, parallelism)
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.0.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.0
+            .transpose_apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+    #[inline]
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.0.apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+}
+
+impl<E: ComplexField, A: LinearOperator<E>> LinearOperator<E> for Adjoint<A> {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.0.ncols()
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.0.nrows()
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.0.transpose_apply_req(rhs_ncols, parallelism)
+    }
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.0.apply_req(rhs_ncols, parallelism)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        // adjoint(A) = conjugate(transpose(A))
+        self.0
+            .transpose_apply_with_conj(out, rhs, conj.compose(Conj::Yes), parallelism, stack)
+    }
+    #[inline]
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        // transpose(adjoint(A)) = conjugate(A)
+        self.0
+            .apply_with_conj(out, rhs, conj.compose(Conj::Yes), parallelism, stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, c64, mul::matmul, sparse::SparseColMat, Mat};
+    use dyn_stack::GlobalPodBuffer;
+
+    fn apply(op: &dyn LinearOperator<c64>, rhs: MatRef<'_, c64>) -> Mat<c64> {
+        let mut out = Mat::zeros(op.nrows(), rhs.ncols());
+        let mut mem = GlobalPodBuffer::new(op.apply_req(rhs.ncols(), Parallelism::None).unwrap());
+        op.apply(
+            out.as_mut(),
+            rhs,
+            Parallelism::None,
+            PodStack::new(&mut mem),
+        );
+        out
+    }
+
+    fn product<LhsE: Conjugate<Canonical = c64>>(
+        lhs: MatRef<'_, LhsE>,
+        rhs: MatRef<'_, c64>,
+    ) -> Mat<c64> {
+        let mut out = Mat::zeros(lhs.nrows(), rhs.ncols());
+        matmul(
+            out.as_mut(),
+            lhs,
+            rhs,
+            None,
+            c64::faer_one(),
+            Parallelism::None,
+        );
+        out
+    }
+
+    fn assert_approx_eq(lhs: MatRef<'_, c64>, rhs: MatRef<'_, c64>) {
+        assert!(all(lhs.nrows() == rhs.nrows(), lhs.ncols() == rhs.ncols()));
+        for j in 0..lhs.ncols() {
+            for i in 0..lhs.nrows() {
+                assert!((lhs.read(i, j) - rhs.read(i, j)).faer_abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_linop() {
+        let a = Mat::from_fn(4, 3, |i, j| {
+            c64::new((i + 2 * j) as f64, i as f64 - j as f64)
+        });
+        let b = Mat::from_fn(3, 4, |i, j| {
+            c64::new(1.0 / (i + j + 1) as f64, (i * j) as f64)
+        });
+        let x = Mat::from_fn(4, 2, |i, j| c64::new(i as f64 + 1.0, -(j as f64)));
+        let y = Mat::from_fn(3, 2, |i, j| c64::new(j as f64, i as f64 - 0.5));
+
+        let mut triplets = alloc::vec::Vec::new();
+        for j in 0..3 {
+            for i in 0..4 {
+                triplets.push((i, j, a.read(i, j)));
+            }
+        }
+        let a_sparse = SparseColMat::<usize, c64>::try_new_from_triplets(4, 3, &triplets).unwrap();
+        let a_sparse = a_sparse.as_ref();
+
+        let ay = product(a.as_ref(), y.as_ref());
+        let ahx = product(a.adjoint(), x.as_ref());
+
+        assert_approx_eq(apply(&a.as_ref(), y.as_ref()).as_ref(), ay.as_ref());
+        assert_approx_eq(apply(&a_sparse, y.as_ref()).as_ref(), ay.as_ref());
+        assert_approx_eq(
+            apply(&Transpose(a_sparse.transpose()), y.as_ref()).as_ref(),
+            ay.as_ref(),
+        );
+        assert_approx_eq(apply(&Adjoint(a_sparse), x.as_ref()).as_ref(), ahx.as_ref());
+        assert_approx_eq(
+            apply(&Adjoint(a.as_ref()), x.as_ref()).as_ref(),
+            ahx.as_ref(),
+        );
+
+        let sum = Sum(a.as_ref(), Scaled(c64::new(0.0, 2.0), a_sparse));
+        let expected = Mat::from_fn(4, 2, |i, j| c64::new(1.0, 2.0) * ay.read(i, j));
+        assert_approx_eq(apply(&sum, y.as_ref()).as_ref(), expected.as_ref());
+        let expected = Mat::from_fn(3, 2, |i, j| c64::new(1.0, -2.0) * ahx.read(i, j));
+        assert_approx_eq(
+            apply(&Adjoint(&sum), x.as_ref()).as_ref(),
+            expected.as_ref(),
+        );
+
+        let ab = product(a.as_ref(), b.as_ref());
+        let prod = Product(a_sparse, b.as_ref());
+        assert_approx_eq(
+            apply(&prod, x.as_ref()).as_ref(),
+            product(ab.as_ref(), x.as_ref()).as_ref(),
+        );
+        assert_approx_eq(
+            apply(&Adjoint(&prod), x.as_ref()).as_ref(),
+            product(ab.adjoint(), x.as_ref()).as_ref(),
+        );
+        assert_approx_eq(
+            apply(&Transpose(&prod), x.as_ref()).as_ref(),
+            product(ab.transpose(), x.as_ref()).as_ref(),
+        );
+
+        let fwd = [2usize, 0, 1];
+        let inv = [1usize, 2, 0];
+        let perm = PermutationRef::<'_, usize, c64>::new_checked(&fwd, &inv);
+        let d = Mat::from_fn(3, 1, |i, _| c64::new(i as f64 + 1.0, 1.0));
+        let diag = d.as_ref().col(0).column_vector_as_diagonal();
+
+        let op = Product(perm, diag);
+        let expected = Mat::from_fn(3, 2, |i, j| d.read(fwd[i], 0) * y.read(fwd[i], j));
+        assert_approx_eq(apply(&op, y.as_ref()).as_ref(), expected.as_ref());
+        let expected = Mat::from_fn(3, 2, |i, j| d.read(i, 0).faer_conj() * y.read(inv[i], j));
+        assert_approx_eq(apply(&Adjoint(op), y.as_ref()).as_ref(), expected.as_ref());
+    }
+}
@@ -127,6 +127,7 @@ fn tridiagonal_evd<E: RealField>(criterion: &mut Criterion) {
                         u.as_mut(),
                         epsilon(),
                         min_positive(),
+                        None,
                         parallelism,
                         stack.rb_mut(),
                     );
@@ -149,6 +150,7 @@ fn tridiagonal_evd<E: RealField>(criterion: &mut Criterion) {
                         u.as_mut(),
                         epsilon(),
                         min_positive(),
+                        None,
                         parallelism,
                         stack.rb_mut(),
                     );
@@ -0,0 +1,272 @@
+//! Balancing of a general square matrix, as a preprocessing step for the computation of its
+//! eigenvalues.
+//!
+//! Balancing consists of two steps. The first one permutes the rows and columns of the matrix to
+//! isolate eigenvalues, which are then found on the diagonal of the leading and trailing
+//! triangular blocks of the permuted matrix. The second one applies a diagonal similarity
+//! transformation to the remaining block, to make the norms of its rows and columns as close to
+//! each other as possible. This can significantly improve the accuracy of the computed
+//! eigenvalues and eigenvectors for badly scaled matrices.
+//!
+//! The balanced matrix is
+//! $$A' = D^{-1} P^\top A P D,$$
+//! where $P$ is a permutation matrix and $D$ is a diagonal matrix. If $v'$ is an eigenvector of
+//! $A'$, then $v = P D v'$ is an eigenvector of $A$ for the same eigenvalue.
+
+use faer_core::{
+    permutation::{swap_cols, swap_rows},
+    ComplexField, MatMut, RealField,
+};
+use reborrow::*;
+
+/// Specifies which steps of the balancing should be performed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Balance {
+    /// The matrix is left unchanged.
+    #[default]
+    None,
+    /// The matrix is only permuted to isolate eigenvalues.
+    Permute,
+    /// The matrix is only scaled.
+    Scale,
+    /// The matrix is both permuted and scaled.
+    Both,
+}
+
+/// Balances `matrix` in place, according to `mode`.
+///
+/// Returns `(ilo, ihi)` such that the balanced matrix is upper triangular in its rows and columns
+/// outside of the range `ilo..ihi`.
+///
+/// `perm` and `scale` must both have length equal to the dimension of the matrix. On output, for
+/// `i` outside of `ilo..ihi`, `perm[i]` contains the index of the row and column that were
+/// interchanged with the `i`-th one, and for `i` in `ilo..ihi`, `scale[i]` contains the scaling
+/// factor that was applied to the `i`-th column. The other entries of `perm` and `scale` are
+/// respectively set to `i` and one.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated.
+pub fn balance_in_place<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    mode: Balance,
+    perm: &mut [usize],
+    scale: &mut [E::Real],
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+) -> (usize, usize) {
+    let n = matrix.nrows();
+    assert!(matrix.ncols() == n);
+    assert!(perm.len() == n);
+    assert!(scale.len() == n);
+
+    let mut matrix = matrix;
+
+    for (i, (p, s)) in perm.iter_mut().zip(scale.iter_mut()).enumerate() {
+        *p = i;
+        *s = E::Real::faer_one();
+    }
+
+    let mut lo = 0usize;
+    let mut hi = n;
+
+    let zero = E::faer_zero();
+
+    if matches!(mode, Balance::Permute | Balance::Both) {
+        // push the rows isolating an eigenvalue to the bottom
+        'rows: while hi > 0 {
+            for j in (0..hi).rev() {
+                if (0..hi).all(|i| i == j || matrix.read(j, i) == zero) {
+                    perm[hi - 1] = j;
+                    if j != hi - 1 {
+                        swap_rows(matrix.rb_mut(), j, hi - 1);
+                        swap_cols(matrix.rb_mut(), j, hi - 1);
+                    }
+                    hi -= 1;
+                    continue 'rows;
+                }
+            }
+            break;
+        }
+
+        // push the columns isolating an eigenvalue to the left
+        'cols: while lo < hi {
+            for j in lo..hi {
+                if (lo..hi).all(|i| i == j || matrix.read(i, j) == zero) {
+                    perm[lo] = j;
+                    if j != lo {
+                        swap_rows(matrix.rb_mut(), j, lo);
+                        swap_cols(matrix.rb_mut(), j, lo);
+                    }
+                    lo += 1;
+                    continue 'cols;
+                }
+            }
+            break;
+        }
+    }
+
+    if matches!(mode, Balance::Scale | Balance::Both) && lo < hi {
+        let radix = E::Real::faer_from_f64(2.0);
+        let radix_inv = E::Real::faer_from_f64(0.5);
+        let factor = E::Real::faer_from_f64(0.95);
+        let one = E::Real::faer_one();
+
+        let sfmin1 = zero_threshold.faer_div(epsilon);
+        let sfmax1 = sfmin1.faer_inv();
+        let sfmin2 = sfmin1.faer_mul(radix);
+        let sfmax2 = sfmin2.faer_inv();
+
+        let max = |x: E::Real, y: E::Real| if x > y { x } else { y };
+        let min = |x: E::Real, y: E::Real| if x < y { x } else { y };
+
+        let mut converged = false;
+        while !converged {
+            converged = true;
+
+            for (i, scale_i) in (lo..hi).zip(&mut scale[lo..hi]) {
+                let mut c = E::Real::faer_zero();
+                let mut r = E::Real::faer_zero();
+                for k in lo..hi {
+                    c = c.faer_add(matrix.read(k, i).faer_abs2());
+                    r = r.faer_add(matrix.read(i, k).faer_abs2());
+                }
+                let mut c = c.faer_sqrt();
+                let mut r = r.faer_sqrt();
+
+                let mut ca = E::Real::faer_zero();
+                for k in 0..hi {
+                    ca = max(ca, matrix.read(k, i).faer_abs());
+                }
+                let mut ra = E::Real::faer_zero();
+                for k in lo..n {
+                    ra = max(ra, matrix.read(i, k).faer_abs());
+                }
+
+                if c == E::Real::faer_zero() || r == E::Real::faer_zero() {
+                    continue;
+                }
+
+                let mut g = r.faer_mul(radix_inv);
+                let mut f = one;
+                let s = c.faer_add(r);
+
+                while c < g && max(f, max(c, ca)) < sfmax2 && min(r, min(g, ra)) > sfmin2 {
+                    f = f.faer_mul(radix);
+                    c = c.faer_mul(radix);
+                    ca = ca.faer_mul(radix);
+                    r = r.faer_mul(radix_inv);
+                    g = g.faer_mul(radix_inv);
+                    ra = ra.faer_mul(radix_inv);
+                }
+
+                g = c.faer_mul(radix_inv);
+                while g >= r && max(r, ra) < sfmax2 && min(min(f, c), min(g, ca)) > sfmin2 {
+                    f = f.faer_mul(radix_inv);
+                    c = c.faer_mul(radix_inv);
+                    g = g.faer_mul(radix_inv);
+                    ca = ca.faer_mul(radix_inv);
+                    r = r.faer_mul(radix);
+                    ra = ra.faer_mul(radix);
+                }
+
+                // skip if the improvement is not significant, or if the scaling factor would
+                // overflow or underflow
+                if c.faer_add(r) >= factor.faer_mul(s) {
+                    continue;
+                }
+                if f < one && *scale_i < one && f.faer_mul(*scale_i) <= sfmin1 {
+                    continue;
+                }
+                if f > one && *scale_i > one && *scale_i >= sfmax1.faer_div(f) {
+                    continue;
+                }
+
+                let f_inv = f.faer_inv();
+                *scale_i = scale_i.faer_mul(f);
+                converged = false;
+
+                for k in lo..n {
+                    matrix.write(i, k, matrix.read(i, k).faer_scale_real(f_inv));
+                }
+                for k in 0..hi {
+                    matrix.write(k, i, matrix.read(k, i).faer_scale_real(f));
+                }
+            }
+        }
+    }
+
+    (lo, hi)
+}
+
+/// Transforms the right eigenvectors of a matrix balanced by [`balance_in_place`], stored in the
+/// columns of `u`, into the eigenvectors of the original matrix.
+///
+/// `ilo`, `ihi`, `perm` and `scale` must be the values produced by [`balance_in_place`].
+///
+/// # Panics
+/// Panics if `u` doesn't have the same number of rows as the length of `perm` and `scale`.
+pub fn balance_back_transform_in_place<E: ComplexField>(
+    u: MatMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    perm: &[usize],
+    scale: &[E::Real],
+) {
+    let n = u.nrows();
+    assert!(perm.len() == n);
+    assert!(scale.len() == n);
+    assert!(ilo <= ihi);
+    assert!(ihi <= n);
+
+    let mut u = u;
+
+    for (i, &s) in (ilo..ihi).zip(&scale[ilo..ihi]) {
+        for j in 0..u.ncols() {
+            u.write(i, j, u.read(i, j).faer_scale_real(s));
+        }
+    }
+
+    for i in (0..ilo).rev().chain(ihi..n) {
+        let k = perm[i];
+        if k != i {
+            swap_rows(u.rb_mut(), i, k);
+        }
+    }
+}
+
+/// Transforms the left eigenvectors of a matrix balanced by [`balance_in_place`], stored in the
+/// columns of `v`, into the left eigenvectors of the original matrix.
+///
+/// `ilo`, `ihi`, `perm` and `scale` must be the values produced by [`balance_in_place`].
+///
+/// # Panics
+/// Panics if `v` doesn't have the same number of rows as the length of `perm` and `scale`.
+pub fn balance_left_back_transform_in_place<E: ComplexField>(
+    v: MatMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    perm: &[usize],
+    scale: &[E::Real],
+) {
+    let n = v.nrows();
+    assert!(perm.len() == n);
+    assert!(scale.len() == n);
+    assert!(ilo <= ihi);
+    assert!(ihi <= n);
+
+    let mut v = v;
+
+    for (i, s) in (ilo..ihi).zip(&scale[ilo..ihi]) {
+        let s = s.faer_inv();
+        for j in 0..v.ncols() {
+            v.write(i, j, v.read(i, j).faer_scale_real(s));
+        }
+    }
+
+    for i in (0..ilo).rev().chain(ihi..n) {
+        let k = perm[i];
+        if k != i {
+            swap_rows(v.rb_mut(), i, k);
+        }
+    }
+}
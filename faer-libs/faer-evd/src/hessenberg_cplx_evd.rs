@@ -116,7 +116,12 @@ fn lahqr_shiftcolumn<E: ComplexField>(h: MatRef<'_, E>, mut v: MatMut<'_, E>, s1
 }
 
 // ret: (eig1_re eig1_im) (eig2_re eig2_im)
-fn lahqr_eig22<E: ComplexField>(mut a00: E, mut a01: E, mut a10: E, mut a11: E) -> (E, E) {
+pub(crate) fn lahqr_eig22<E: ComplexField>(
+    mut a00: E,
+    mut a01: E,
+    mut a10: E,
+    mut a11: E,
+) -> (E, E) {
     let zero = E::Real::faer_zero();
     let half = E::Real::faer_from_f64(0.5);
 
@@ -145,7 +150,12 @@ fn lahqr_eig22<E: ComplexField>(mut a00: E, mut a01: E, mut a10: E, mut a11: E)
     )
 }
 
-fn rotg<E: ComplexField>(a: E, b: E, epsilon: E::Real, zero_threshold: E::Real) -> (E::Real, E, E) {
+pub(crate) fn rotg<E: ComplexField>(
+    a: E,
+    b: E,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+) -> (E::Real, E, E) {
     let safmin = zero_threshold;
     let safmax = zero_threshold.faer_inv();
     let rtmin = zero_threshold.faer_div(epsilon).faer_sqrt();
@@ -374,6 +384,22 @@ pub fn lahqr<E: ComplexField>(
     epsilon: E::Real,
     zero_threshold: E::Real,
 ) -> isize {
+    lahqr_impl(want_t, a, z, w, ilo, ihi, epsilon, zero_threshold, None).0
+}
+
+/// Same as [`lahqr`], with an optional limit on the number of QR iterations. Also returns the
+/// number of iterations that were performed.
+pub(crate) fn lahqr_impl<E: ComplexField>(
+    want_t: bool,
+    a: MatMut<'_, E>,
+    z: Option<MatMut<'_, E>>,
+    w: MatMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+    max_iterations: Option<usize>,
+) -> (isize, usize) {
     assert!(a.nrows() == a.ncols());
     assert!(ilo <= ihi);
 
@@ -400,7 +426,7 @@ pub fn lahqr<E: ComplexField>(
     let dat2 = E::Real::faer_from_f64(-0.4375);
 
     if nh == 0 {
-        return 0;
+        return (0, 0);
     }
 
     if nh == 1 {
@@ -410,7 +436,8 @@ pub fn lahqr<E: ComplexField>(
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
     // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    let itmax = max_iterations.unwrap_or(30 * Ord::max(10, nh));
+    let mut iteration_count = 0;
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0usize;
@@ -428,7 +455,7 @@ pub fn lahqr<E: ComplexField>(
     let arch = E::Simd::default();
     for iter in 0..itmax + 1 {
         if iter == itmax {
-            return istop as isize;
+            return (istop as isize, itmax);
         }
 
         if istart + 1 >= istop {
@@ -436,6 +463,7 @@ pub fn lahqr<E: ComplexField>(
                 w.write(istart, 0, a.read(istart, istart));
             }
             // All eigenvalues have been found, exit and return 0.
+            iteration_count = iter;
             break;
         }
 
@@ -663,7 +691,7 @@ pub fn lahqr<E: ComplexField>(
             }
         }
     }
-    0
+    (0, iteration_count)
 }
 
 #[derive(Default, Clone, Copy, Debug)]
@@ -680,6 +708,12 @@ pub struct EvdParams {
     /// Threshold of percent of aggressive-early-deflation window that must converge to skip a
     /// sweep
     pub nibble_threshold: Option<usize>,
+    /// Balancing that is applied to the matrix before the reduction to Hessenberg form. The
+    /// eigenvectors are transformed back to those of the original matrix.
+    pub balance: crate::balance::Balance,
+    /// Maximum number of QR sweeps before the algorithm gives up. Defaults to `30 * max(10, n)`
+    /// if `None`.
+    pub max_iterations: Option<usize>,
 }
 
 pub fn default_recommended_shift_count(dim: usize, _active_block_dim: usize) -> usize {
@@ -848,7 +882,11 @@ fn aggressive_early_deflation<E: ComplexField>(
             zero_threshold,
             parallelism,
             stack.rb_mut(),
-            params,
+            // the iteration limit only applies to the outer iteration
+            EvdParams {
+                max_iterations: None,
+                ..params
+            },
         )
         .0;
         for j in 0..jw {
@@ -1333,8 +1371,18 @@ pub fn multishift_qr<E: ComplexField>(
 
     // Tiny matrices must use lahqr
     if n < nmin {
-        let err = lahqr(want_t, a, z, w, ilo, ihi, epsilon, zero_threshold);
-        return (err, 0, 0);
+        let (err, count_sweep) = lahqr_impl(
+            want_t,
+            a,
+            z,
+            w,
+            ilo,
+            ihi,
+            epsilon,
+            zero_threshold,
+            params.max_iterations,
+        );
+        return (err, 0, count_sweep);
     }
     if nh == 0 {
         return (0, 0, 0);
@@ -1345,7 +1393,7 @@ pub fn multishift_qr<E: ComplexField>(
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
     // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    let itmax = params.max_iterations.unwrap_or(30 * Ord::max(10, nh));
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0;
@@ -2809,6 +2857,7 @@ mod tests {
                     recommended_deflation_window: None,
                     blocking_threshold: Some(15),
                     nibble_threshold: Some(14),
+                    ..Default::default()
                 };
                 multishift_qr(
                     true,
@@ -13070,6 +13119,7 @@ mod tests {
             recommended_deflation_window: None,
             blocking_threshold: Some(15),
             nibble_threshold: Some(14),
+            ..Default::default()
         };
         let (_, n_aed, n_sweep) = multishift_qr(
             true,
@@ -1508,7 +1508,11 @@ fn aggressive_early_deflation<E: RealField>(
             zero_threshold,
             parallelism,
             stack.rb_mut(),
-            params,
+            // the iteration limit only applies to the outer iteration
+            EvdParams {
+                max_iterations: None,
+                ..params
+            },
         )
         .0;
         for j in 0..jw {
@@ -3114,8 +3118,19 @@ pub fn multishift_qr<E: RealField>(
 
     // Tiny matrices must use lahqr
     if n < nmin {
-        let err = lahqr(want_t, a, z, w_re, w_im, ilo, ihi, epsilon, zero_threshold);
-        return (err, 0, 0);
+        let (err, count_sweep) = lahqr_impl(
+            want_t,
+            a,
+            z,
+            w_re,
+            w_im,
+            ilo,
+            ihi,
+            epsilon,
+            zero_threshold,
+            params.max_iterations,
+        );
+        return (err, 0, count_sweep);
     }
     if nh == 0 {
         return (0, 0, 0);
@@ -3126,7 +3141,7 @@ pub fn multishift_qr<E: RealField>(
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
     // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    let itmax = params.max_iterations.unwrap_or(30 * Ord::max(10, nh));
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0;
@@ -3396,6 +3411,35 @@ pub fn lahqr<E: RealField>(
     epsilon: E,
     zero_threshold: E,
 ) -> isize {
+    lahqr_impl(
+        want_t,
+        a,
+        z,
+        w_re,
+        w_im,
+        ilo,
+        ihi,
+        epsilon,
+        zero_threshold,
+        None,
+    )
+    .0
+}
+
+/// Same as [`lahqr`], with an optional limit on the number of QR iterations. Also returns the
+/// number of iterations that were performed.
+pub(crate) fn lahqr_impl<E: RealField>(
+    want_t: bool,
+    a: MatMut<'_, E>,
+    z: Option<MatMut<'_, E>>,
+    w_re: MatMut<'_, E>,
+    w_im: MatMut<'_, E>,
+    ilo: usize,
+    ihi: usize,
+    epsilon: E,
+    zero_threshold: E,
+    max_iterations: Option<usize>,
+) -> (isize, usize) {
     assert!(a.nrows() == a.ncols());
     assert!(ilo <= ihi);
 
@@ -3426,7 +3470,7 @@ pub fn lahqr<E: RealField>(
     let dat2 = E::faer_from_f64(-0.4375);
 
     if nh == 0 {
-        return 0;
+        return (0, 0);
     }
 
     if nh == 1 {
@@ -3437,7 +3481,8 @@ pub fn lahqr<E: RealField>(
     // itmax is the total number of QR iterations allowed.
     // For most matrices, 3 shifts per eigenvalue is enough, so
     // we set itmax to 30 times nh as a safe limit.
-    let itmax = 30 * Ord::max(10, nh);
+    let itmax = max_iterations.unwrap_or(30 * Ord::max(10, nh));
+    let mut iteration_count = 0;
 
     // k_defl counts the number of iterations since a deflation
     let mut k_defl = 0usize;
@@ -3459,7 +3504,7 @@ pub fn lahqr<E: RealField>(
     let mut v = unsafe { faer_core::mat::from_raw_parts_mut::<'_, E>(v_ptr, 3, 1, 1, 3) };
     for iter in 0..itmax + 1 {
         if iter == itmax {
-            return istop as isize;
+            return (istop as isize, itmax);
         }
 
         if istart + 1 >= istop {
@@ -3468,6 +3513,7 @@ pub fn lahqr<E: RealField>(
                 w_im.write(istart, 0, E::faer_zero());
             }
             // All eigenvalues have been found, exit and return 0.
+            iteration_count = iter;
             break;
         }
 
@@ -3807,7 +3853,7 @@ pub fn lahqr<E: RealField>(
         }
     }
 
-    0
+    (0, iteration_count)
 }
 
 #[cfg(test)]
@@ -3980,6 +4026,7 @@ mod tests {
                     recommended_deflation_window: None,
                     blocking_threshold: Some(15),
                     nibble_threshold: Some(14),
+                    ..Default::default()
                 };
                 dbgf::dbgf!("6.?", &h);
                 multishift_qr(
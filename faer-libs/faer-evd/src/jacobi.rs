@@ -0,0 +1,419 @@
+//! Jacobi eigenvalue algorithms for hermitian matrices.
+//!
+//! Unlike the tridiagonalization based algorithm, these methods compute the eigenvalues of scaled
+//! diagonally dominant and positive definite matrices to high relative accuracy, including the
+//! tiny ones, at the cost of a slower decomposition.
+
+use crate::EvdInfo;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_cholesky::piv_llt::compute as piv_llt;
+use faer_core::{
+    permutation::swap_cols, temp_mat_req, temp_mat_uninit, ComplexField, Entity, MatMut, MatRef,
+    Parallelism, RealField,
+};
+use reborrow::*;
+
+const MAX_SWEEPS: usize = 64;
+
+/// Returns the `k`-th pair of the `round`-th round of the round-robin ordering over `n_even`
+/// indices. The pairs of a given round are disjoint, and every pair of indices appears exactly once
+/// over `n_even - 1` rounds.
+fn round_robin_pair(n_even: usize, round: usize, k: usize) -> (usize, usize) {
+    let m = n_even - 1;
+    let (p, q) = if k == 0 {
+        (round, m)
+    } else {
+        ((round + k) % m, (round + m - k) % m)
+    };
+    (Ord::min(p, q), Ord::max(p, q))
+}
+
+/// Copies the hermitian matrix whose lower triangular half is stored in `src` into `dst`.
+fn copy_hermitian<E: ComplexField>(dst: MatMut<'_, E>, src: MatRef<'_, E>) {
+    let mut dst = dst;
+    let n = src.nrows();
+    for j in 0..n {
+        dst.write(j, j, E::faer_from_real(src.read(j, j).faer_real()));
+        for i in j + 1..n {
+            let x = src.read(i, j);
+            dst.write(i, j, x);
+            dst.write(j, i, x.faer_conj());
+        }
+    }
+}
+
+/// Sorts the eigenvalues in nondecreasing order, permuting the eigenvectors accordingly.
+fn sort_eigenvalues<E: ComplexField>(s: MatMut<'_, E>, u: Option<MatMut<'_, E>>) {
+    let mut s = s;
+    let mut u = u;
+    let n = s.nrows();
+    for j in 0..n {
+        let mut min_idx = j;
+        let mut min_val = s.read(j, 0).faer_real();
+        for k in j + 1..n {
+            let val = s.read(k, 0).faer_real();
+            if val < min_val {
+                min_idx = k;
+                min_val = val;
+            }
+        }
+        if min_idx != j {
+            let tmp = s.read(j, 0);
+            s.write(j, 0, s.read(min_idx, 0));
+            s.write(min_idx, 0, tmp);
+            if let Some(u) = u.rb_mut() {
+                swap_cols(u, j, min_idx);
+            }
+        }
+    }
+}
+
+/// Computes the size and alignment of required workspace for computing the eigenvalue
+/// decomposition of a hermitian matrix with the two-sided Jacobi method.
+pub fn two_sided_jacobi_req<E: Entity>(n: usize) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([temp_mat_req::<E>(n, n)?, temp_mat_req::<E>(6, n / 2 + 1)?])
+}
+
+/// Computes the eigenvalue decomposition of a hermitian matrix with the cyclic two-sided Jacobi
+/// method. Only the lower triangular half of `matrix` is accessed.
+///
+/// The rotations are scheduled with a round-robin ordering, so that the rotations of a round act
+/// on disjoint pairs of rows and columns, and are applied in parallel.
+///
+/// The iteration stops after `max_sweeps` sweeps, or a default limit if `max_sweeps` is `None`. If
+/// the limit is reached, the returned [`EvdInfo`] reports that the method did not converge.
+pub fn compute_hermitian_evd_two_sided_jacobi<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    max_sweeps: Option<usize>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> EvdInfo {
+    let n = matrix.nrows();
+    let mut s = s;
+    let mut u = u;
+
+    let n_even = n + n % 2;
+    let half = n_even / 2;
+
+    let (mut a, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut a = a.as_mut();
+    let (mut rot, _) = temp_mat_uninit::<E>(6, half, stack);
+    let mut rot = rot.as_mut();
+
+    copy_hermitian(a.rb_mut(), matrix);
+    if let Some(u) = u.rb_mut() {
+        let mut u = u;
+        u.fill_zero();
+        for i in 0..n {
+            u.write(i, i, E::faer_one());
+        }
+    }
+
+    let one = E::Real::faer_one();
+    let two = E::Real::faer_from_f64(2.0);
+
+    let max_sweeps = max_sweeps.unwrap_or(MAX_SWEEPS);
+    let mut info = EvdInfo {
+        iteration_count: 0,
+        converged: true,
+    };
+
+    if n >= 2 {
+        info.converged = false;
+        for _ in 0..max_sweeps {
+            let mut rotated = false;
+
+            for round in 0..n_even - 1 {
+                // compute the rotations of the current round. since the pairs are disjoint, the
+                // 2x2 blocks that define them are not affected by the other rotations of the round
+                for k in 0..half {
+                    let (p, q) = round_robin_pair(n_even, round, k);
+                    let mut c = E::faer_one();
+                    let mut sn = E::faer_zero();
+                    let mut s_phase = E::faer_zero();
+                    let mut c_phase = E::faer_one();
+
+                    if q < n {
+                        let app = a.read(p, p).faer_real();
+                        let aqq = a.read(q, q).faer_real();
+                        let apq = a.read(p, q);
+                        let abs_apq = apq.faer_abs();
+
+                        let threshold = epsilon
+                            .faer_mul(app.faer_abs().faer_sqrt())
+                            .faer_mul(aqq.faer_abs().faer_sqrt());
+
+                        if abs_apq > threshold && abs_apq > E::Real::faer_zero() {
+                            rotated = true;
+
+                            // phase that makes the off-diagonal entry real and positive
+                            let phase_conj = apq.faer_scale_real(abs_apq.faer_inv()).faer_conj();
+                            let tau = aqq.faer_sub(app).faer_div(two.faer_mul(abs_apq));
+                            let t = tau
+                                .faer_abs()
+                                .faer_add(one.faer_add(tau.faer_mul(tau)).faer_sqrt())
+                                .faer_inv();
+                            let t = if tau < E::Real::faer_zero() {
+                                t.faer_neg()
+                            } else {
+                                t
+                            };
+                            let cr = one.faer_add(t.faer_mul(t)).faer_sqrt().faer_inv();
+                            let sr = cr.faer_mul(t);
+
+                            c = E::faer_from_real(cr);
+                            sn = E::faer_from_real(sr);
+                            s_phase = phase_conj.faer_scale_real(sr);
+                            c_phase = phase_conj.faer_scale_real(cr);
+
+                            rot.write(4, k, E::faer_from_real(app.faer_sub(t.faer_mul(abs_apq))));
+                            rot.write(5, k, E::faer_from_real(aqq.faer_add(t.faer_mul(abs_apq))));
+                        }
+                    }
+
+                    rot.write(0, k, c);
+                    rot.write(1, k, sn);
+                    rot.write(2, k, s_phase);
+                    rot.write(3, k, c_phase);
+                }
+
+                let rot = rot.rb();
+                let is_identity = |k: usize| rot.read(1, k) == E::faer_zero();
+
+                // a * J, u * J
+                {
+                    let a = a.rb();
+                    let u = u.rb();
+                    faer_core::for_each_raw(
+                        half,
+                        |k| {
+                            if is_identity(k) {
+                                return;
+                            }
+                            let (p, q) = round_robin_pair(n_even, round, k);
+                            let (c, sn, s_phase, c_phase) = (
+                                rot.read(0, k),
+                                rot.read(1, k),
+                                rot.read(2, k),
+                                rot.read(3, k),
+                            );
+
+                            let mut a = unsafe { a.const_cast() };
+                            rotate_cols(a.rb_mut(), p, q, c, sn, s_phase, c_phase);
+                            if let Some(u) = u {
+                                let u = unsafe { u.const_cast() };
+                                rotate_cols(u, p, q, c, sn, s_phase, c_phase);
+                            }
+                        },
+                        parallelism,
+                    );
+                }
+
+                // J^H * a
+                {
+                    let a = a.rb();
+                    faer_core::for_each_raw(
+                        half,
+                        |k| {
+                            if is_identity(k) {
+                                return;
+                            }
+                            let (p, q) = round_robin_pair(n_even, round, k);
+                            let (c, sn, s_phase, c_phase) = (
+                                rot.read(0, k),
+                                rot.read(1, k),
+                                rot.read(2, k),
+                                rot.read(3, k),
+                            );
+
+                            let a = unsafe { a.const_cast() };
+                            rotate_cols(
+                                a.transpose_mut(),
+                                p,
+                                q,
+                                c,
+                                sn,
+                                s_phase.faer_conj(),
+                                c_phase.faer_conj(),
+                            );
+                        },
+                        parallelism,
+                    );
+                }
+
+                for k in 0..half {
+                    if is_identity(k) {
+                        continue;
+                    }
+                    let (p, q) = round_robin_pair(n_even, round, k);
+                    a.write(p, p, rot.read(4, k));
+                    a.write(q, q, rot.read(5, k));
+                    a.write(p, q, E::faer_zero());
+                    a.write(q, p, E::faer_zero());
+                }
+            }
+
+            if !rotated {
+                info.converged = true;
+                break;
+            }
+            info.iteration_count += 1;
+        }
+    }
+
+    for i in 0..n {
+        s.write(i, 0, a.read(i, i));
+    }
+    sort_eigenvalues(s, u);
+
+    info
+}
+
+/// Applies the rotation `[c, sn; -s_phase, c_phase]` to the columns `p` and `q` of `mat`.
+#[inline]
+fn rotate_cols<E: ComplexField>(
+    mat: MatMut<'_, E>,
+    p: usize,
+    q: usize,
+    c: E,
+    sn: E,
+    s_phase: E,
+    c_phase: E,
+) {
+    let mut mat = mat;
+    for i in 0..mat.nrows() {
+        let xp = mat.read(i, p);
+        let xq = mat.read(i, q);
+        mat.write(i, p, c.faer_mul(xp).faer_sub(s_phase.faer_mul(xq)));
+        mat.write(i, q, sn.faer_mul(xp).faer_add(c_phase.faer_mul(xq)));
+    }
+}
+
+/// Computes the size and alignment of required workspace for computing the eigenvalue
+/// decomposition of a hermitian positive definite matrix with the Cholesky and one-sided Jacobi
+/// method.
+pub fn positive_definite_jacobi_req<E: Entity>(
+    n: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        StackReq::try_new::<usize>(n)?,
+        StackReq::try_new::<usize>(n)?,
+        StackReq::try_any_of([
+            piv_llt::cholesky_in_place_req::<usize, E>(n, parallelism, Default::default())?,
+            two_sided_jacobi_req::<E>(n)?,
+        ])?,
+    ])
+}
+
+/// Computes the eigenvalue decomposition of a hermitian positive definite matrix $A$, by first
+/// computing its Cholesky decomposition with diagonal pivoting $PAP^\top = LL^H$, then applying
+/// the one-sided Jacobi method to $L^H$. Only the lower triangular half of `matrix` is accessed.
+///
+/// If the Cholesky decomposition is rank deficient, i.e., the matrix is not numerically positive
+/// definite, this falls back to the two-sided Jacobi method.
+///
+/// The Jacobi iteration stops after `max_sweeps` sweeps, or a default limit if `max_sweeps` is
+/// `None`. If the limit is reached, the returned [`EvdInfo`] reports that the method did not
+/// converge.
+pub fn compute_hermitian_evd_positive_definite_jacobi<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    max_sweeps: Option<usize>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> EvdInfo {
+    let n = matrix.nrows();
+    let mut s = s;
+    let mut u = u;
+
+    let (mut a, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut a = a.as_mut();
+    let (mut w, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut w = w.as_mut();
+    let (perm, stack) = stack.make_raw::<usize>(n);
+    let (perm_inv, mut stack) = stack.make_raw::<usize>(n);
+
+    copy_hermitian(a.rb_mut(), matrix);
+
+    // P * A * P^T = L * L^H, with diagonal pivoting
+    let (chol_info, _) = piv_llt::cholesky_in_place(
+        a.rb_mut(),
+        E::Real::faer_zero(),
+        perm,
+        perm_inv,
+        parallelism,
+        stack.rb_mut(),
+        Default::default(),
+    );
+
+    if chol_info.rank < n {
+        return compute_hermitian_evd_two_sided_jacobi(
+            matrix,
+            s,
+            u,
+            epsilon,
+            max_sweeps,
+            parallelism,
+            stack,
+        );
+    }
+
+    // x = L^H. the upper triangular part is filled first, since it is read from the strictly
+    // lower triangular part that is zeroed afterwards
+    for j in 0..n {
+        for i in 0..j {
+            a.write(i, j, a.read(j, i).faer_conj());
+        }
+        a.write(j, j, a.read(j, j).faer_conj());
+    }
+    for j in 0..n {
+        for i in j + 1..n {
+            a.write(i, j, E::faer_zero());
+        }
+    }
+
+    let compute_u = u.is_some();
+    if compute_u {
+        w.fill_zero();
+        for i in 0..n {
+            w.write(i, i, E::faer_one());
+        }
+    }
+
+    // L^H * W = Y, so that P * A * P^T = W * (Y^H * Y) * W^H, where Y^H * Y is diagonal
+    let info = faer_svd::one_sided_jacobi::one_sided_jacobi(
+        a.rb_mut(),
+        if compute_u { Some(w.rb_mut()) } else { None },
+        epsilon,
+        max_sweeps.unwrap_or(MAX_SWEEPS),
+    );
+
+    for j in 0..n {
+        let norm = a.rb().col(j).norm_l2();
+        s.write(j, 0, E::faer_from_real(norm.faer_mul(norm)));
+    }
+
+    if let Some(u) = u.rb_mut() {
+        let mut u = u;
+        for j in 0..n {
+            for i in 0..n {
+                u.write(perm[i], j, w.read(i, j));
+            }
+        }
+    }
+
+    sort_eigenvalues(s, u);
+
+    EvdInfo {
+        iteration_count: info.iteration_count,
+        converged: info.converged,
+    }
+}
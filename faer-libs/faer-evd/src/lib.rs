@@ -13,6 +13,7 @@
 #![allow(clippy::too_many_arguments)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use balance::Balance;
 use coe::Coerce;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use faer_core::{
@@ -20,12 +21,15 @@ use faer_core::{
     householder::{
         apply_block_householder_sequence_on_the_right_in_place_req,
         apply_block_householder_sequence_on_the_right_in_place_with_conj,
+        apply_block_householder_sequence_transpose_on_the_left_in_place_req,
+        apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj,
         upgrade_householder_factor,
     },
     mul::{
         inner_prod::inner_prod_with_conj,
         triangular::{self, BlockStructure},
     },
+    permutation::swap_cols,
     temp_mat_req, temp_mat_uninit, temp_mat_zeroed, unzipped, zipped, ComplexField, Conj, MatMut,
     MatRef, Parallelism, RealField,
 };
@@ -42,6 +46,9 @@ pub mod tridiag_real_evd;
 #[doc(hidden)]
 pub mod tridiag;
 
+#[doc(hidden)]
+pub mod tridiag_bisection;
+
 #[doc(hidden)]
 pub mod hessenberg;
 
@@ -50,6 +57,12 @@ pub mod hessenberg_cplx_evd;
 #[doc(hidden)]
 pub mod hessenberg_real_evd;
 
+mod jacobi;
+
+pub mod balance;
+pub mod schur;
+pub mod sylvester;
+
 /// Indicates whether the eigenvectors are fully computed, partially computed, or skipped.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ComputeVectors {
@@ -57,9 +70,58 @@ pub enum ComputeVectors {
     Yes,
 }
 
+/// Algorithm used for computing the hermitian eigenvalue decomposition.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SymmetricEvdAlgorithm {
+    /// Reduction to tridiagonal form, followed by a tridiagonal eigenvalue solver.
+    #[default]
+    Tridiagonal,
+    /// Cyclic two-sided Jacobi method, with the rotations of each round applied in parallel.
+    ///
+    /// This is slower than [`SymmetricEvdAlgorithm::Tridiagonal`], but computes the eigenvalues
+    /// of scaled diagonally dominant matrices to high relative accuracy.
+    Jacobi,
+    /// Cholesky decomposition with diagonal pivoting, followed by the one-sided Jacobi method
+    /// applied to the Cholesky factor.
+    ///
+    /// This computes all the eigenvalues of positive definite matrices to high relative accuracy,
+    /// including the tiny ones. If the matrix is not numerically positive definite, this falls back
+    /// to [`SymmetricEvdAlgorithm::Jacobi`].
+    PositiveDefiniteJacobi,
+}
+
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct SymmetricEvdParams {}
+pub struct SymmetricEvdParams {
+    /// Algorithm used for computing the decomposition.
+    pub algorithm: SymmetricEvdAlgorithm,
+    /// Maximum number of iterations allowed for each iterative stage of the algorithm, i.e., the
+    /// number of QR sweeps of each tridiagonal QR subproblem, or the number of Jacobi sweeps. If
+    /// `None`, a default limit depending on the algorithm and the dimension of the matrix is used.
+    pub max_iterations: Option<usize>,
+}
+
+/// Information about the convergence of an eigenvalue decomposition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvdInfo {
+    /// Total number of sweeps performed by the iterative stages of the algorithm, i.e., the QR
+    /// sweeps on the Hessenberg or tridiagonal matrix, or the Jacobi sweeps.
+    pub iteration_count: usize,
+    /// Whether every iterative stage converged before reaching the maximum number of iterations.
+    /// This is also `false` if the input matrix contained non-finite values.
+    pub converged: bool,
+}
+
+impl EvdInfo {
+    #[inline]
+    fn from_qr(info: isize, count_sweep: usize) -> Self {
+        Self {
+            iteration_count: count_sweep,
+            converged: info == 0,
+        }
+    }
+}
 
 /// Computes the size and alignment of required workspace for performing a hermitian eigenvalue
 /// decomposition. The eigenvectors may be optionally computed.
@@ -69,8 +131,14 @@ pub fn compute_hermitian_evd_req<E: ComplexField>(
     parallelism: Parallelism,
     params: SymmetricEvdParams,
 ) -> Result<StackReq, SizeOverflow> {
-    let _ = params;
     let _ = compute_eigenvectors;
+    match params.algorithm {
+        SymmetricEvdAlgorithm::Jacobi => return jacobi::two_sided_jacobi_req::<E>(n),
+        SymmetricEvdAlgorithm::PositiveDefiniteJacobi => {
+            return jacobi::positive_definite_jacobi_req::<E>(n, parallelism)
+        }
+        SymmetricEvdAlgorithm::Tridiagonal => {}
+    }
     let householder_blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(n, n);
 
     let cplx_storage = if coe::is_same::<E::Real, E>() {
@@ -117,6 +185,11 @@ pub fn compute_hermitian_evd_req<E: ComplexField>(
 ///
 /// This can also panic if the provided memory in `stack` is insufficient (see
 /// [`compute_hermitian_evd_req`]).
+///
+/// # Output
+///
+/// The number of sweeps that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
 pub fn compute_hermitian_evd<E: ComplexField>(
     matrix: MatRef<'_, E>,
     s: MatMut<'_, E>,
@@ -124,7 +197,7 @@ pub fn compute_hermitian_evd<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: SymmetricEvdParams,
-) {
+) -> EvdInfo {
     compute_hermitian_evd_custom_epsilon(
         matrix,
         s,
@@ -134,7 +207,7 @@ pub fn compute_hermitian_evd<E: ComplexField>(
         parallelism,
         stack,
         params,
-    );
+    )
 }
 
 /// See [`compute_hermitian_evd`].
@@ -154,8 +227,7 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: SymmetricEvdParams,
-) {
-    let _ = params;
+) -> EvdInfo {
     let n = matrix.nrows();
 
     assert!(all(
@@ -168,7 +240,10 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
     }
 
     if n == 0 {
-        return;
+        return EvdInfo {
+            iteration_count: 0,
+            converged: true,
+        };
     }
 
     #[cfg(feature = "perf-warn")]
@@ -192,7 +267,36 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         if let Some(mut u) = u {
             u.fill(E::faer_nan());
         }
-        return;
+        return EvdInfo {
+            iteration_count: 0,
+            converged: false,
+        };
+    }
+
+    match params.algorithm {
+        SymmetricEvdAlgorithm::Jacobi => {
+            return jacobi::compute_hermitian_evd_two_sided_jacobi(
+                matrix,
+                s,
+                u,
+                epsilon,
+                params.max_iterations,
+                parallelism,
+                stack,
+            );
+        }
+        SymmetricEvdAlgorithm::PositiveDefiniteJacobi => {
+            return jacobi::compute_hermitian_evd_positive_definite_jacobi(
+                matrix,
+                s,
+                u,
+                epsilon,
+                params.max_iterations,
+                parallelism,
+                stack,
+            );
+        }
+        SymmetricEvdAlgorithm::Tridiagonal => {}
     }
 
     let (mut trid, stack) = temp_mat_uninit::<E>(n, n, stack);
@@ -224,18 +328,19 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         None => {
             let (diag, stack) = stack.rb_mut().make_with(n, |i| trid.read(i, i).faer_real());
             let (offdiag, _) = stack.make_with(n - 1, |i| trid.read(i + 1, i).faer_abs());
-            tridiag_qr_algorithm::compute_tridiag_real_evd_qr_algorithm(
+            let info = tridiag_qr_algorithm::compute_tridiag_real_evd_qr_algorithm(
                 diag,
                 offdiag,
                 None,
                 epsilon,
                 zero_threshold,
+                params.max_iterations,
             );
             for (i, &diag) in diag.iter().enumerate() {
                 s.write(i, 0, E::faer_from_real(diag));
             }
 
-            return;
+            return info;
         }
     };
 
@@ -252,18 +357,20 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         j_base += bs;
     }
 
+    let info;
     {
         let (diag, stack) = stack.rb_mut().make_with(n, |i| trid.read(i, i).faer_real());
 
         if coe::is_same::<E::Real, E>() {
             let (offdiag, stack) = stack.make_with(n - 1, |i| trid.read(i + 1, i).faer_real());
 
-            tridiag_real_evd::compute_tridiag_real_evd::<E::Real>(
+            info = tridiag_real_evd::compute_tridiag_real_evd::<E::Real>(
                 diag,
                 offdiag,
                 u.rb_mut().coerce(),
                 epsilon,
                 zero_threshold,
+                params.max_iterations,
                 parallelism,
                 stack,
             );
@@ -289,12 +396,13 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
                 *mul = x.faer_conj();
             }
 
-            tridiag_real_evd::compute_tridiag_real_evd::<E::Real>(
+            info = tridiag_real_evd::compute_tridiag_real_evd::<E::Real>(
                 diag,
                 offdiag,
                 u_real.rb_mut(),
                 epsilon,
                 zero_threshold,
+                params.max_iterations,
                 parallelism,
                 stack,
             );
@@ -326,6 +434,404 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
         parallelism,
         stack.rb_mut(),
     );
+
+    info
+}
+
+/// Information about a partial eigenvalue decomposition computed by
+/// [`compute_hermitian_evd_subset`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvdSubsetInfo {
+    /// Number of computed eigenvalues.
+    pub count: usize,
+    /// Whether the inverse iteration converged for every computed eigenvector. This is also
+    /// `false` if the input matrix contained non-finite values.
+    pub converged: bool,
+}
+
+/// Specifies the subset of eigenvalues that should be computed by
+/// [`compute_hermitian_evd_subset`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EigenRange<E> {
+    /// The eigenvalues with indices in `lo..hi`, when the eigenvalues are sorted in nondecreasing
+    /// order.
+    IndexRange(usize, usize),
+    /// The eigenvalues lying in the half-open interval `[a, b)`.
+    ValueRange(E, E),
+}
+
+/// Computes the size and alignment of required workspace for computing a subset of at most `k`
+/// eigenvalues of a hermitian matrix. The eigenvectors may be optionally computed.
+pub fn compute_hermitian_evd_subset_req<E: ComplexField>(
+    n: usize,
+    k: usize,
+    compute_eigenvectors: ComputeVectors,
+    parallelism: Parallelism,
+    params: SymmetricEvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    let _ = params;
+    let k = Ord::min(k, n);
+    let householder_blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(n, n);
+
+    let vectors_req = if compute_eigenvectors == ComputeVectors::Yes {
+        StackReq::try_all_of([
+            StackReq::try_new::<E>(n)?,
+            temp_mat_req::<E::Real>(n, k)?,
+            tridiag_bisection::compute_tridiag_subset_req::<E::Real>(n)?,
+        ])?
+    } else {
+        StackReq::empty()
+    };
+
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(householder_blocksize, n.saturating_sub(1))?,
+        StackReq::try_any_of([
+            tridiag::tridiagonalize_in_place_req::<E>(n, parallelism)?,
+            StackReq::try_all_of([
+                StackReq::try_new::<E::Real>(n)?,
+                StackReq::try_new::<E::Real>(n.saturating_sub(1))?,
+                StackReq::try_new::<E::Real>(k)?,
+                vectors_req,
+            ])?,
+            faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                n - 1,
+                householder_blocksize,
+                n,
+            )?,
+        ])?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for counting the eigenvalues of a
+/// hermitian matrix with [`hermitian_evd_subset_count`].
+pub fn hermitian_evd_subset_count_req<E: ComplexField>(
+    n: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let householder_blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(n, n);
+
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(householder_blocksize, n.saturating_sub(1))?,
+        StackReq::try_any_of([
+            tridiag::tridiagonalize_in_place_req::<E>(n, parallelism)?,
+            StackReq::try_all_of([
+                StackReq::try_new::<E::Real>(n)?,
+                StackReq::try_new::<E::Real>(n.saturating_sub(1))?,
+            ])?,
+        ])?,
+    ])
+}
+
+/// Returns the number of eigenvalues of a square hermitian `matrix` selected by `range`, which is
+/// the number of eigenvalues computed by [`compute_hermitian_evd_subset`] when `s` has enough
+/// rows. Only the lower triangular half of the matrix is accessed.
+///
+/// For an [`EigenRange::ValueRange`], the eigenvalues are counted with the Sturm sequence of the
+/// tridiagonal form of the matrix, without computing them, so that the outputs of
+/// [`compute_hermitian_evd_subset`] can be sized accordingly.
+///
+/// # Panics
+/// Panics if `matrix` is not square, if an [`EigenRange::IndexRange`] is out of bounds, or if the
+/// type `E` does not have a fixed precision at compile time.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`hermitian_evd_subset_count_req`]).
+pub fn hermitian_evd_subset_count<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    range: EigenRange<E::Real>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> usize {
+    let n = matrix.nrows();
+    assert!(matrix.nrows() == matrix.ncols());
+
+    let (a, b) = match range {
+        EigenRange::IndexRange(lo, hi) => {
+            assert!(all(lo <= hi, hi <= n));
+            return hi - lo;
+        }
+        EigenRange::ValueRange(a, b) => (a, b),
+    };
+
+    if n == 0 {
+        return 0;
+    }
+
+    let mut all_finite = true;
+    zipped!(matrix).for_each_triangular_lower(faer_core::zip::Diag::Include, |unzipped!(x)| {
+        all_finite &= x.read().faer_is_finite();
+    });
+    if !all_finite {
+        return 0;
+    }
+
+    let (mut trid, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let householder_blocksize =
+        faer_qr::no_pivoting::compute::recommended_blocksize::<E>(n - 1, n - 1);
+    let (mut householder, mut stack) = temp_mat_uninit::<E>(householder_blocksize, n - 1, stack);
+    let mut trid = trid.as_mut();
+
+    zipped!(trid.rb_mut(), matrix)
+        .for_each_triangular_lower(faer_core::zip::Diag::Include, |unzipped!(mut dst, src)| {
+            dst.write(src.read())
+        });
+
+    tridiag::tridiagonalize_in_place(
+        trid.rb_mut(),
+        householder.as_mut().transpose_mut(),
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    let (diag, stack) = stack.make_with(n, |i| trid.read(i, i).faer_real());
+    let (offdiag, _) = stack.make_with(n - 1, |i| trid.read(i + 1, i).faer_abs());
+
+    let (lo, hi) = tridiag_bisection::tridiag_index_range(
+        diag,
+        offdiag,
+        a,
+        b,
+        E::Real::faer_zero_threshold().unwrap(),
+    );
+    hi - lo
+}
+
+/// Computes a subset of the eigenvalues of a square hermitian `matrix`, selected by `range`,
+/// as well as the corresponding eigenvectors. Only the lower triangular half of the matrix is
+/// accessed.
+///
+/// The eigenvalues are computed with bisection on the tridiagonal form of the matrix, and the
+/// eigenvectors with inverse iteration, after which only the requested eigenvectors are
+/// transformed back.
+///
+/// Returns the number of computed eigenvalues `k`, as well as whether the computation of the
+/// eigenvectors converged. The eigenvalues are stored in nondecreasing order in the first `k` rows
+/// of `s`, which must be a column vector.
+///
+/// If `range` is an [`EigenRange::IndexRange`], then `s` must have at least as many rows as the
+/// number of requested eigenvalues. If `range` is an [`EigenRange::ValueRange`] that contains more
+/// eigenvalues than the number of rows of `s`, then only the smallest ones are computed.
+///
+/// If `u` is `None`, then only the eigenvalues are computed. Otherwise, the eigenvectors are
+/// computed and stored in the first `k` columns of `u`, which must have as many columns as `s`
+/// has rows.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated, or if the type `E` does not have a
+/// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`compute_hermitian_evd_subset_req`], where `k` is the number of rows of `s`).
+pub fn compute_hermitian_evd_subset<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    range: EigenRange<E::Real>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: SymmetricEvdParams,
+) -> EvdSubsetInfo {
+    compute_hermitian_evd_subset_custom_epsilon(
+        matrix,
+        range,
+        s,
+        u,
+        E::Real::faer_epsilon().unwrap(),
+        E::Real::faer_zero_threshold().unwrap(),
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+/// See [`compute_hermitian_evd_subset`].
+///
+/// This function takes an additional `epsilon` and `zero_threshold` parameters. `epsilon`
+/// represents the precision of the values in the matrix, and `zero_threshold` is the value below
+/// which the precision starts to deteriorate, e.g. due to denormalized numbers.
+///
+/// These values need to be provided manually for types that do not have a known precision at
+/// compile time, e.g. a dynamic multiprecision floating point type.
+pub fn compute_hermitian_evd_subset_custom_epsilon<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    range: EigenRange<E::Real>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: SymmetricEvdParams,
+) -> EvdSubsetInfo {
+    let _ = params;
+    let n = matrix.nrows();
+    let capacity = s.nrows();
+
+    assert!(all(matrix.nrows() == matrix.ncols(), s.ncols() == 1));
+    if let Some(u) = u.rb() {
+        assert!(all(u.nrows() == n, u.ncols() == capacity));
+    }
+    if let EigenRange::IndexRange(lo, hi) = range {
+        assert!(all(lo <= hi, hi <= n, hi - lo <= capacity));
+    }
+
+    if n == 0 {
+        return EvdSubsetInfo {
+            count: 0,
+            converged: true,
+        };
+    }
+
+    let mut all_finite = true;
+    zipped!(matrix).for_each_triangular_lower(faer_core::zip::Diag::Include, |unzipped!(x)| {
+        all_finite &= x.read().faer_is_finite();
+    });
+
+    if !all_finite {
+        { s }.fill(E::faer_nan());
+        if let Some(mut u) = u {
+            u.fill(E::faer_nan());
+        }
+        return EvdSubsetInfo {
+            count: match range {
+                EigenRange::IndexRange(lo, hi) => hi - lo,
+                EigenRange::ValueRange(_, _) => 0,
+            },
+            converged: false,
+        };
+    }
+
+    let (mut trid, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let householder_blocksize =
+        faer_qr::no_pivoting::compute::recommended_blocksize::<E>(n - 1, n - 1);
+
+    let (mut householder, mut stack) = temp_mat_uninit::<E>(householder_blocksize, n - 1, stack);
+    let mut householder = householder.as_mut();
+
+    let mut trid = trid.as_mut();
+
+    zipped!(trid.rb_mut(), matrix)
+        .for_each_triangular_lower(faer_core::zip::Diag::Include, |unzipped!(mut dst, src)| {
+            dst.write(src.read())
+        });
+
+    tridiag::tridiagonalize_in_place(
+        trid.rb_mut(),
+        householder.rb_mut().transpose_mut(),
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    let trid = trid.into_const();
+    let mut s = s;
+    let mut u = u;
+
+    let k;
+    let mut converged = true;
+    {
+        let (diag, stack) = stack.rb_mut().make_with(n, |i| trid.read(i, i).faer_real());
+        let (offdiag, stack) = stack.make_with(n - 1, |i| trid.read(i + 1, i).faer_abs());
+
+        let (lo, hi) = match range {
+            EigenRange::IndexRange(lo, hi) => (lo, hi),
+            EigenRange::ValueRange(a, b) => {
+                let (lo, hi) =
+                    tridiag_bisection::tridiag_index_range(diag, offdiag, a, b, zero_threshold);
+                (lo, Ord::min(hi, lo + capacity))
+            }
+        };
+        k = hi - lo;
+
+        let (eigenvalues, stack) = stack.make_with(k, |_| E::Real::faer_zero());
+        tridiag_bisection::compute_tridiag_eigenvalues_bisection(
+            diag,
+            offdiag,
+            lo,
+            hi,
+            eigenvalues,
+            epsilon,
+            zero_threshold,
+        );
+
+        for (i, &eigenvalue) in eigenvalues.iter().enumerate() {
+            s.write(i, 0, E::faer_from_real(eigenvalue));
+        }
+
+        if let Some(mut u) = u.rb_mut() {
+            let (mul, stack) = stack.make_with(n, |_| E::faer_zero());
+
+            let normalized = |x: E| {
+                if x == E::faer_zero() {
+                    E::faer_one()
+                } else {
+                    x.faer_scale_real(x.faer_abs().faer_inv())
+                }
+            };
+
+            mul[0] = E::faer_one();
+
+            let mut x = E::faer_one();
+            for (i, mul) in mul.iter_mut().enumerate().skip(1) {
+                x = normalized(trid.read(i, i - 1).faer_mul(x.faer_conj())).faer_conj();
+                *mul = x.faer_conj();
+            }
+
+            let (mut u_real, stack) = temp_mat_uninit::<E::Real>(n, k, stack);
+
+            converged = tridiag_bisection::compute_tridiag_eigenvectors_inverse_iteration(
+                diag,
+                offdiag,
+                eigenvalues,
+                u_real.rb_mut(),
+                epsilon,
+                zero_threshold,
+                stack,
+            );
+
+            for j in 0..k {
+                for (i, &mul) in mul.iter().enumerate() {
+                    unsafe {
+                        u.write_unchecked(i, j, mul.faer_scale_real(u_real.read_unchecked(i, j)))
+                    };
+                }
+            }
+        }
+    }
+
+    let info = EvdSubsetInfo {
+        count: k,
+        converged,
+    };
+    let mut u = match u {
+        Some(u) => u.subcols_mut(0, k),
+        None => return info,
+    };
+
+    let mut j_base = 0;
+    while j_base < n - 1 {
+        let bs = Ord::min(householder_blocksize, n - 1 - j_base);
+        let mut householder = householder.rb_mut().submatrix_mut(0, j_base, bs, bs);
+        let full_essentials = trid.submatrix(1, 0, n - 1, n);
+        let essentials = full_essentials.submatrix(j_base, j_base, n - 1 - j_base, bs);
+        for j in 0..bs {
+            householder.write(j, j, householder.read(0, j));
+        }
+        upgrade_householder_factor(householder, essentials, bs, 1, parallelism);
+        j_base += bs;
+    }
+
+    faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+        trid.submatrix(1, 0, n - 1, n - 1),
+        householder.rb(),
+        Conj::No,
+        u.rb_mut().subrows_mut(1, n - 1),
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    info
 }
 
 /// Computes the eigenvalue decomposition of a square real `matrix`.
@@ -349,6 +855,11 @@ pub fn compute_hermitian_evd_custom_epsilon<E: ComplexField>(
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
 ///
 /// This can also panic if the provided memory in `stack` is insufficient (see [`compute_evd_req`]).
+///
+/// # Output
+///
+/// The number of QR sweeps that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
 pub fn compute_evd_real<E: RealField>(
     matrix: MatRef<'_, E>,
     s_re: MatMut<'_, E>,
@@ -357,7 +868,7 @@ pub fn compute_evd_real<E: RealField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: EvdParams,
-) {
+) -> EvdInfo {
     compute_evd_real_custom_epsilon(
         matrix,
         s_re,
@@ -368,7 +879,7 @@ pub fn compute_evd_real<E: RealField>(
         parallelism,
         stack,
         params,
-    );
+    )
 }
 
 fn dot2<E: RealField>(lhs0: MatRef<'_, E>, lhs1: MatRef<'_, E>, rhs: MatRef<'_, E>) -> (E, E) {
@@ -567,7 +1078,76 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: EvdParams,
-) {
+) -> EvdInfo {
+    compute_evd_real_impl(
+        matrix,
+        s_re,
+        s_im,
+        u,
+        None,
+        epsilon,
+        zero_threshold,
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+/// Computes the eigenvalue decomposition of a square real `matrix`, along with its left
+/// eigenvectors.
+///
+/// The eigenvalues and the right eigenvectors are stored in `s_re`, `s_im` and `u` as described in
+/// [`compute_evd_real`]. The left eigenvectors $V$, such that $V^H M = S V^H$, are computed from
+/// the real Schur form of the matrix and stored in `v`, using the same layout as `u`. They are
+/// normalized so that $v_k^H u_k = 1$ for each eigenvalue.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated, or if the type `E` does not have a
+/// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see [`compute_evd_req`]
+/// with [`ComputeVectors::Yes`]).
+///
+/// # Output
+///
+/// The number of QR sweeps that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
+pub fn compute_evd_real_with_left_eigenvectors<E: RealField>(
+    matrix: MatRef<'_, E>,
+    s_re: MatMut<'_, E>,
+    s_im: MatMut<'_, E>,
+    u: MatMut<'_, E>,
+    v: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> EvdInfo {
+    compute_evd_real_impl(
+        matrix,
+        s_re,
+        s_im,
+        Some(u),
+        Some(v),
+        E::faer_epsilon().unwrap(),
+        E::faer_zero_threshold().unwrap(),
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+fn compute_evd_real_impl<E: RealField>(
+    matrix: MatRef<'_, E>,
+    s_re: MatMut<'_, E>,
+    s_im: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+    epsilon: E,
+    zero_threshold: E,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> EvdInfo {
     let n = matrix.nrows();
 
     assert!(all(
@@ -580,9 +1160,15 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
     if let Some(u) = u.rb() {
         assert!(all(u.nrows() == n, u.ncols() == n));
     }
+    if let Some(v) = v.rb() {
+        assert!(all(u.is_some(), v.nrows() == n, v.ncols() == n));
+    }
 
     if n == 0 {
-        return;
+        return EvdInfo {
+            iteration_count: 0,
+            converged: true,
+        };
     }
 
     #[cfg(feature = "perf-warn")]
@@ -602,12 +1188,17 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
         if let Some(mut u) = u {
             u.fill(E::faer_nan());
         }
-        return;
+        if let Some(mut v) = v {
+            v.fill(E::faer_nan());
+        }
+        return EvdInfo {
+            iteration_count: 0,
+            converged: false,
+        };
     }
 
-    let householder_blocksize = recommended_blocksize::<E>(n - 1, n - 1);
-
     let mut u = u;
+    let mut v = v;
     let mut s_re = s_re;
     let mut s_im = s_im;
 
@@ -615,6 +1206,17 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
 
     h.copy_from(matrix);
 
+    let (perm, stack) = stack.make_with(n, |i| i);
+    let (scale, stack) = stack.make_with(n, |_| E::Real::faer_one());
+    let (ilo, ihi) = balance::balance_in_place(
+        h.rb_mut(),
+        params.balance,
+        perm,
+        scale,
+        epsilon,
+        zero_threshold,
+    );
+
     let (mut z, mut stack) = temp_mat_zeroed::<E>(n, if u.is_some() { n } else { 0 }, stack);
     let mut z = z.as_mut();
     z.rb_mut()
@@ -622,44 +1224,73 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
         .column_vector_mut()
         .fill(E::faer_one());
 
-    {
+    // after balancing, the rows and columns outside of `ilo..ihi` are already upper triangular,
+    // so only the diagonal block needs to be reduced
+    let nh = ihi - ilo;
+    if nh > 1 {
+        let householder_blocksize = recommended_blocksize::<E>(nh - 1, nh - 1);
         let (mut householder, mut stack) =
-            temp_mat_uninit(householder_blocksize, n - 1, stack.rb_mut());
+            temp_mat_uninit(householder_blocksize, nh - 1, stack.rb_mut());
         let mut householder = householder.as_mut();
 
         hessenberg::make_hessenberg_in_place(
-            h.rb_mut(),
+            h.rb_mut().submatrix_mut(ilo, ilo, nh, nh),
             householder.rb_mut().transpose_mut(),
             parallelism,
             stack.rb_mut(),
         );
         if u.is_some() {
+            let (h_top, _, h_bot, h_bot_right) = h.rb_mut().split_at_mut(ilo + 1, ihi);
+            let basis = h_bot.rb().submatrix(0, ilo, nh - 1, nh - 1);
+
             apply_block_householder_sequence_on_the_right_in_place_with_conj(
-                h.rb().submatrix(1, 0, n - 1, n - 1),
+                basis,
                 householder.rb(),
                 Conj::No,
-                z.rb_mut().submatrix_mut(1, 1, n - 1, n - 1),
+                h_top.submatrix_mut(0, ilo + 1, ilo, nh - 1),
+                parallelism,
+                stack.rb_mut(),
+            );
+            apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+                basis,
+                householder.rb(),
+                Conj::Yes,
+                h_bot_right.subrows_mut(0, nh - 1),
+                parallelism,
+                stack.rb_mut(),
+            );
+            apply_block_householder_sequence_on_the_right_in_place_with_conj(
+                basis,
+                householder.rb(),
+                Conj::No,
+                z.rb_mut().submatrix_mut(ilo + 1, ilo + 1, nh - 1, nh - 1),
                 parallelism,
                 stack,
             );
         }
+    }
 
-        for j in 0..n {
-            for i in j + 2..n {
-                h.write(i, j, E::faer_zero());
-            }
+    for j in 0..n {
+        for i in j + 2..n {
+            h.write(i, j, E::faer_zero());
         }
     }
 
+    // the eigenvalues isolated by balancing are on the diagonal
+    for i in (0..ilo).chain(ihi..n) {
+        s_re.write(i, 0, h.read(i, i));
+        s_im.write(i, 0, E::faer_zero());
+    }
+
     if let Some(mut u) = u.rb_mut() {
-        hessenberg_real_evd::multishift_qr(
+        let (info, _, count_sweep) = hessenberg_real_evd::multishift_qr(
             true,
             h.rb_mut(),
             Some(z.rb_mut()),
             s_re.rb_mut(),
             s_im.rb_mut(),
-            0,
-            n,
+            ilo,
+            ihi,
             epsilon,
             zero_threshold,
             parallelism,
@@ -701,270 +1332,429 @@ pub fn compute_evd_real_custom_epsilon<E: RealField>(
             }
         }
 
-        {
-            let mut k = n;
+        real_schur_right_eigenvectors(h.rb(), x.rb_mut(), epsilon, norm);
+
+        triangular::matmul(
+            u.rb_mut(),
+            BlockStructure::Rectangular,
+            z.rb(),
+            BlockStructure::Rectangular,
+            x.rb(),
+            BlockStructure::TriangularUpper,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+
+        balance::balance_back_transform_in_place(u.rb_mut(), ilo, ihi, perm, scale);
+
+        if let Some(mut v) = v.rb_mut() {
+            // the left eigenvectors of T are the conjugates of the right eigenvectors of T^T.
+            // reversing the rows and columns of T^T makes it upper quasi-triangular, so its
+            // eigenvectors are computed with the same back substitution, then reversed back.
+            real_schur_right_eigenvectors(
+                h.rb().transpose().reverse_rows_and_cols(),
+                x.rb_mut(),
+                epsilon,
+                norm,
+            );
+
+            triangular::matmul(
+                v.rb_mut(),
+                BlockStructure::Rectangular,
+                z.rb(),
+                BlockStructure::Rectangular,
+                x.rb().reverse_rows_and_cols(),
+                BlockStructure::TriangularLower,
+                None,
+                E::faer_one(),
+                parallelism,
+            );
+
+            // the reversal swaps the real and imaginary parts of each complex pair, and the
+            // imaginary part needs to be conjugated
+            let mut j = 0;
+            while j < n {
+                if s_im.read(j, 0) == E::faer_zero() {
+                    j += 1;
+                } else {
+                    swap_cols(v.rb_mut(), j, j + 1);
+                    zipped!(v.rb_mut().col_mut(j + 1).as_2d_mut())
+                        .for_each(|unzipped!(mut x)| x.write(x.read().faer_neg()));
+                    j += 2;
+                }
+            }
+
+            balance::balance_left_back_transform_in_place(v.rb_mut(), ilo, ihi, perm, scale);
+            normalize_left_eigenvectors_real(s_im.rb(), u.rb(), v.rb_mut());
+        }
+        EvdInfo::from_qr(info, count_sweep)
+    } else {
+        let (info, _, count_sweep) = hessenberg_real_evd::multishift_qr(
+            false,
+            h.rb_mut(),
+            None,
+            s_re.rb_mut(),
+            s_im.rb_mut(),
+            ilo,
+            ihi,
+            epsilon,
+            zero_threshold,
+            parallelism,
+            stack.rb_mut(),
+            params,
+        );
+        EvdInfo::from_qr(info, count_sweep)
+    }
+}
+
+/// Computes the right eigenvectors of the upper quasi-triangular matrix `h` in real Schur form,
+/// using the same layout as [`compute_evd_real`], and stores them in the upper triangular part of
+/// `x`.
+///
+/// Only the upper triangular part and the first subdiagonal of `h` are accessed.
+fn real_schur_right_eigenvectors<E: RealField>(
+    h: MatRef<'_, E>,
+    x: MatMut<'_, E>,
+    epsilon: E,
+    norm: E,
+) {
+    let n = h.nrows();
+    let mut x = x;
+
+    let mut k = n;
+    loop {
+        if k == 0 {
+            break;
+        }
+        k -= 1;
+
+        if k == 0 || h.read(k, k - 1) == E::faer_zero() {
+            // real eigenvalue
+            let p = h.read(k, k);
+
+            x.write(k, k, E::faer_one());
+
+            // solve (h[:k, :k] - p I) X = -h[:i, i]
+            // form RHS
+            for i in 0..k {
+                x.write(i, k, h.read(i, k).faer_neg());
+            }
+
+            // solve in place
+            let mut i = k;
             loop {
-                if k == 0 {
+                if i == 0 {
                     break;
                 }
-                k -= 1;
-
-                if k == 0 || h.read(k, k - 1) == E::faer_zero() {
-                    // real eigenvalue
-                    let p = h.read(k, k);
+                i -= 1;
 
-                    x.write(k, k, E::faer_one());
+                if i == 0 || h.read(i, i - 1) == E::faer_zero() {
+                    // 1x1 block
+                    let dot = inner_prod_with_conj(
+                        h.row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
+                        Conj::No,
+                        x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
+                        Conj::No,
+                    );
 
-                    // solve (h[:k, :k] - p I) X = -h[:i, i]
-                    // form RHS
-                    for i in 0..k {
-                        x.write(i, k, h.read(i, k).faer_neg());
+                    x.write(i, k, x.read(i, k).faer_sub(dot));
+                    let mut z = h.read(i, i).faer_sub(p);
+                    if z == E::faer_zero() {
+                        z = epsilon.faer_mul(norm);
                     }
-
-                    // solve in place
-                    let mut i = k;
-                    loop {
-                        if i == 0 {
-                            break;
-                        }
-                        i -= 1;
-
-                        if i == 0 || h.read(i, i - 1) == E::faer_zero() {
-                            // 1x1 block
-                            let dot = inner_prod_with_conj(
-                                h.rb().row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
-                                Conj::No,
-                                x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
-                                Conj::No,
-                            );
-
-                            x.write(i, k, x.read(i, k).faer_sub(dot));
-                            let mut z = h.read(i, i).faer_sub(p);
-                            if z == E::faer_zero() {
-                                z = epsilon.faer_mul(norm);
-                            }
-                            let z_inv = z.faer_inv();
-                            let x_ = x.read(i, k);
-                            if x_ != E::faer_zero() {
-                                x.write(i, k, x.read(i, k).faer_mul(z_inv));
-                            }
-                        } else {
-                            // 2x2 block
-                            let dot0 = inner_prod_with_conj(
-                                h.rb()
-                                    .row(i - 1)
-                                    .subcols(i + 1, k - i - 1)
-                                    .transpose()
-                                    .as_2d(),
-                                Conj::No,
-                                x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
-                                Conj::No,
-                            );
-                            let dot1 = inner_prod_with_conj(
-                                h.rb().row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
-                                Conj::No,
-                                x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
-                                Conj::No,
-                            );
-
-                            x.write(i - 1, k, x.read(i - 1, k).faer_sub(dot0));
-                            x.write(i, k, x.read(i, k).faer_sub(dot1));
-
-                            // solve
-                            // [a b  [x0    [r0
-                            //  c a]× x1] =  r1]
-                            //
-                            //  [x0    [a  -b  [r0
-                            //   x1] =  -c  a]× r1] / det
-                            let a = h.read(i, i).faer_sub(p);
-                            let b = h.read(i - 1, i);
-                            let c = h.read(i, i - 1);
-
-                            let r0 = x.read(i - 1, k);
-                            let r1 = x.read(i, k);
-
-                            let inv_det = (a.faer_mul(a).faer_sub(b.faer_mul(c))).faer_inv();
-
-                            let x0 = a.faer_mul(r0).faer_sub(b.faer_mul(r1)).faer_mul(inv_det);
-                            let x1 = a.faer_mul(r1).faer_sub(c.faer_mul(r0)).faer_mul(inv_det);
-
-                            x.write(i - 1, k, x0);
-                            x.write(i, k, x1);
-
-                            i -= 1;
-                        }
+                    let z_inv = z.faer_inv();
+                    let x_ = x.read(i, k);
+                    if x_ != E::faer_zero() {
+                        x.write(i, k, x.read(i, k).faer_mul(z_inv));
                     }
                 } else {
-                    // complex eigenvalue pair
-                    let p = h.read(k, k);
-                    let q = h
-                        .read(k, k - 1)
-                        .faer_abs()
-                        .faer_sqrt()
-                        .faer_mul(h.read(k - 1, k).faer_abs().faer_sqrt());
-
-                    if h.read(k - 1, k).faer_abs() >= h.read(k, k - 1) {
-                        x.write(k - 1, k - 1, E::faer_one());
-                        x.write(k, k, q.faer_div(h.read(k - 1, k)));
-                    } else {
-                        x.write(k - 1, k - 1, q.faer_neg().faer_div(h.read(k, k - 1)));
-                        x.write(k, k, E::faer_one());
+                    // 2x2 block
+                    let dot0 = inner_prod_with_conj(
+                        h.row(i - 1).subcols(i + 1, k - i - 1).transpose().as_2d(),
+                        Conj::No,
+                        x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
+                        Conj::No,
+                    );
+                    let dot1 = inner_prod_with_conj(
+                        h.row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
+                        Conj::No,
+                        x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
+                        Conj::No,
+                    );
+
+                    x.write(i - 1, k, x.read(i - 1, k).faer_sub(dot0));
+                    x.write(i, k, x.read(i, k).faer_sub(dot1));
+
+                    // solve
+                    // [a b  [x0    [r0
+                    //  c a]× x1] =  r1]
+                    //
+                    //  [x0    [a  -b  [r0
+                    //   x1] =  -c  a]× r1] / det
+                    let a = h.read(i, i).faer_sub(p);
+                    let b = h.read(i - 1, i);
+                    let c = h.read(i, i - 1);
+
+                    let r0 = x.read(i - 1, k);
+                    let r1 = x.read(i, k);
+
+                    let inv_det = (a.faer_mul(a).faer_sub(b.faer_mul(c))).faer_inv();
+
+                    let x0 = a.faer_mul(r0).faer_sub(b.faer_mul(r1)).faer_mul(inv_det);
+                    let x1 = a.faer_mul(r1).faer_sub(c.faer_mul(r0)).faer_mul(inv_det);
+
+                    x.write(i - 1, k, x0);
+                    x.write(i, k, x1);
+
+                    i -= 1;
+                }
+            }
+        } else {
+            // complex eigenvalue pair
+            let p = h.read(k, k);
+            let q = h
+                .read(k, k - 1)
+                .faer_abs()
+                .faer_sqrt()
+                .faer_mul(h.read(k - 1, k).faer_abs().faer_sqrt());
+
+            if h.read(k - 1, k).faer_abs() >= h.read(k, k - 1) {
+                x.write(k - 1, k - 1, E::faer_one());
+                x.write(k, k, q.faer_div(h.read(k - 1, k)));
+            } else {
+                x.write(k - 1, k - 1, q.faer_neg().faer_div(h.read(k, k - 1)));
+                x.write(k, k, E::faer_one());
+            }
+            x.write(k - 1, k, E::faer_zero());
+            x.write(k, k - 1, E::faer_zero());
+
+            // solve (h[:k-1, :k-1] - (p + iq) I) X = RHS
+            // form RHS
+            for i in 0..k - 1 {
+                x.write(
+                    i,
+                    k - 1,
+                    x.read(k - 1, k - 1).faer_neg().faer_mul(h.read(i, k - 1)),
+                );
+                x.write(i, k, x.read(k, k).faer_neg().faer_mul(h.read(i, k)));
+            }
+
+            // solve in place
+            let mut i = k - 1;
+            loop {
+                use num_complex::Complex;
+
+                if i == 0 {
+                    break;
+                }
+                i -= 1;
+
+                if i == 0 || h.read(i, i - 1) == E::faer_zero() {
+                    // 1x1 block
+                    let start = i + 1;
+                    let len = k - 1 - (i + 1);
+                    let (dot_re, dot_im) = dot2(
+                        x.rb().col(k - 1).subrows(start, len).as_2d(),
+                        x.rb().col(k).subrows(start, len).as_2d(),
+                        h.transpose().col(i).subrows(start, len).as_2d(),
+                    );
+
+                    x.write(i, k - 1, x.read(i, k - 1).faer_sub(dot_re));
+                    x.write(i, k, x.read(i, k).faer_sub(dot_im));
+
+                    let z = Complex {
+                        re: h.read(i, i).faer_sub(p),
+                        im: q.faer_neg(),
+                    };
+                    let z_inv = z.faer_inv();
+                    let x_ = Complex {
+                        re: x.read(i, k - 1),
+                        im: x.read(i, k),
+                    };
+                    if x_ != Complex::<E>::faer_zero() {
+                        let x_ = z_inv.faer_mul(x_);
+                        x.write(i, k - 1, x_.re);
+                        x.write(i, k, x_.im);
                     }
-                    x.write(k - 1, k, E::faer_zero());
-                    x.write(k, k - 1, E::faer_zero());
-
-                    // solve (h[:k-1, :k-1] - (p + iq) I) X = RHS
-                    // form RHS
-                    for i in 0..k - 1 {
-                        x.write(
-                            i,
-                            k - 1,
-                            x.read(k - 1, k - 1).faer_neg().faer_mul(h.read(i, k - 1)),
+                } else {
+                    // 2x2 block
+                    let start = i + 1;
+                    let len = k - 1 - (i + 1);
+                    let (dot0_re, dot0_im, dot1_re, dot1_im) = dot4(
+                        x.rb().col(k - 1).subrows(start, len).as_2d(),
+                        x.rb().col(k).subrows(start, len).as_2d(),
+                        h.transpose().col(i - 1).subrows(start, len).as_2d(),
+                        h.transpose().col(i).subrows(start, len).as_2d(),
+                    );
+                    let mut dot0 = Complex::<E>::faer_zero();
+                    let mut dot1 = Complex::<E>::faer_zero();
+                    for j in i + 1..k - 1 {
+                        dot0 = dot0.faer_add(
+                            Complex {
+                                re: x.read(j, k - 1),
+                                im: x.read(j, k),
+                            }
+                            .faer_scale_real(h.read(i - 1, j)),
+                        );
+                        dot1 = dot1.faer_add(
+                            Complex {
+                                re: x.read(j, k - 1),
+                                im: x.read(j, k),
+                            }
+                            .faer_scale_real(h.read(i, j)),
                         );
-                        x.write(i, k, x.read(k, k).faer_neg().faer_mul(h.read(i, k)));
                     }
 
-                    // solve in place
-                    let mut i = k - 1;
-                    loop {
-                        use num_complex::Complex;
+                    x.write(i - 1, k - 1, x.read(i - 1, k - 1).faer_sub(dot0_re));
+                    x.write(i - 1, k, x.read(i - 1, k).faer_sub(dot0_im));
+                    x.write(i, k - 1, x.read(i, k - 1).faer_sub(dot1_re));
+                    x.write(i, k, x.read(i, k).faer_sub(dot1_im));
+
+                    let a = Complex {
+                        re: h.read(i, i).faer_sub(p),
+                        im: q.faer_neg(),
+                    };
+                    let b = h.read(i - 1, i);
+                    let c = h.read(i, i - 1);
+
+                    let r0 = Complex {
+                        re: x.read(i - 1, k - 1),
+                        im: x.read(i - 1, k),
+                    };
+                    let r1 = Complex {
+                        re: x.read(i, k - 1),
+                        im: x.read(i, k),
+                    };
+
+                    let inv_det = (a
+                        .faer_mul(a)
+                        .faer_sub(Complex::<E>::faer_from_real(b.faer_mul(c))))
+                    .faer_inv();
+
+                    let x0 = a
+                        .faer_mul(r0)
+                        .faer_sub(r1.faer_scale_real(b))
+                        .faer_mul(inv_det);
+                    let x1 = a
+                        .faer_mul(r1)
+                        .faer_sub(r0.faer_scale_real(c))
+                        .faer_mul(inv_det);
+
+                    x.write(i - 1, k - 1, x0.re);
+                    x.write(i - 1, k, x0.im);
+                    x.write(i, k - 1, x1.re);
+                    x.write(i, k, x1.im);
+
+                    i -= 1;
+                }
+            }
 
-                        if i == 0 {
-                            break;
-                        }
-                        i -= 1;
-
-                        if i == 0 || h.read(i, i - 1) == E::faer_zero() {
-                            // 1x1 block
-                            let start = i + 1;
-                            let len = k - 1 - (i + 1);
-                            let (dot_re, dot_im) = dot2(
-                                x.rb().col(k - 1).subrows(start, len).as_2d(),
-                                x.rb().col(k).subrows(start, len).as_2d(),
-                                h.rb().transpose().col(i).subrows(start, len).as_2d(),
-                            );
-
-                            x.write(i, k - 1, x.read(i, k - 1).faer_sub(dot_re));
-                            x.write(i, k, x.read(i, k).faer_sub(dot_im));
-
-                            let z = Complex {
-                                re: h.read(i, i).faer_sub(p),
-                                im: q.faer_neg(),
-                            };
-                            let z_inv = z.faer_inv();
-                            let x_ = Complex {
-                                re: x.read(i, k - 1),
-                                im: x.read(i, k),
-                            };
-                            if x_ != Complex::<E>::faer_zero() {
-                                let x_ = z_inv.faer_mul(x_);
-                                x.write(i, k - 1, x_.re);
-                                x.write(i, k, x_.im);
-                            }
-                        } else {
-                            // 2x2 block
-                            let start = i + 1;
-                            let len = k - 1 - (i + 1);
-                            let (dot0_re, dot0_im, dot1_re, dot1_im) = dot4(
-                                x.rb().col(k - 1).subrows(start, len).as_2d(),
-                                x.rb().col(k).subrows(start, len).as_2d(),
-                                h.rb().transpose().col(i - 1).subrows(start, len).as_2d(),
-                                h.rb().transpose().col(i).subrows(start, len).as_2d(),
-                            );
-                            let mut dot0 = Complex::<E>::faer_zero();
-                            let mut dot1 = Complex::<E>::faer_zero();
-                            for j in i + 1..k - 1 {
-                                dot0 = dot0.faer_add(
-                                    Complex {
-                                        re: x.read(j, k - 1),
-                                        im: x.read(j, k),
-                                    }
-                                    .faer_scale_real(h.read(i - 1, j)),
-                                );
-                                dot1 = dot1.faer_add(
-                                    Complex {
-                                        re: x.read(j, k - 1),
-                                        im: x.read(j, k),
-                                    }
-                                    .faer_scale_real(h.read(i, j)),
-                                );
-                            }
+            k -= 1;
+        }
+    }
+}
 
-                            x.write(i - 1, k - 1, x.read(i - 1, k - 1).faer_sub(dot0_re));
-                            x.write(i - 1, k, x.read(i - 1, k).faer_sub(dot0_im));
-                            x.write(i, k - 1, x.read(i, k - 1).faer_sub(dot1_re));
-                            x.write(i, k, x.read(i, k).faer_sub(dot1_im));
+/// Normalizes the left eigenvectors `v` so that $v_k^H u_k = 1$, where both are stored using the
+/// layout of [`compute_evd_real`].
+fn normalize_left_eigenvectors_real<E: RealField>(
+    s_im: MatRef<'_, E>,
+    u: MatRef<'_, E>,
+    v: MatMut<'_, E>,
+) {
+    let n = u.nrows();
+    let mut v = v;
+
+    let mut j = 0;
+    while j < n {
+        if s_im.read(j, 0) == E::faer_zero() {
+            let d =
+                inner_prod_with_conj(v.rb().col(j).as_2d(), Conj::No, u.col(j).as_2d(), Conj::No);
+            if d != E::faer_zero() {
+                let d_inv = d.faer_inv();
+                zipped!(v.rb_mut().col_mut(j).as_2d_mut())
+                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_mul(d_inv)));
+            }
+            j += 1;
+        } else {
+            // d = v^H u, with v = vr + i vi and u = ur + i ui
+            let (vr, vi) = (v.rb().col(j).as_2d(), v.rb().col(j + 1).as_2d());
+            let (ur, ui) = (u.col(j).as_2d(), u.col(j + 1).as_2d());
+            let d_re = inner_prod_with_conj(vr, Conj::No, ur, Conj::No)
+                .faer_add(inner_prod_with_conj(vi, Conj::No, ui, Conj::No));
+            let d_im = inner_prod_with_conj(vr, Conj::No, ui, Conj::No)
+                .faer_sub(inner_prod_with_conj(vi, Conj::No, ur, Conj::No));
+
+            let d_norm2 = d_re.faer_abs2().faer_add(d_im.faer_abs2());
+            if d_norm2 != E::faer_zero() {
+                // v <- v / conj(d) = v * d / |d|^2
+                let c_re = d_re.faer_div(d_norm2);
+                let c_im = d_im.faer_div(d_norm2);
+
+                let (v_left, v_right) = v.rb_mut().split_at_col_mut(j + 1);
+                zipped!(
+                    v_left.col_mut(j).as_2d_mut(),
+                    v_right.col_mut(0).as_2d_mut()
+                )
+                .for_each(|unzipped!(mut re, mut im)| {
+                    let (r, i) = (re.read(), im.read());
+                    re.write(r.faer_mul(c_re).faer_sub(i.faer_mul(c_im)));
+                    im.write(r.faer_mul(c_im).faer_add(i.faer_mul(c_re)));
+                });
+            }
+            j += 2;
+        }
+    }
+}
 
-                            let a = Complex {
-                                re: h.read(i, i).faer_sub(p),
-                                im: q.faer_neg(),
-                            };
-                            let b = h.read(i - 1, i);
-                            let c = h.read(i, i - 1);
+/// Computes the right eigenvectors of the upper triangular matrix `h` in complex Schur form, or
+/// of its conjugate if `conj_h` is `Conj::Yes`, and stores them in the strictly upper triangular
+/// part of `x`. The diagonal of the eigenvector matrix is implicitly equal to one.
+///
+/// Only the upper triangular part of `h` is accessed.
+fn complex_schur_right_eigenvectors<E: ComplexField>(
+    h: MatRef<'_, E>,
+    conj_h: Conj,
+    x: MatMut<'_, E>,
+    epsilon: E::Real,
+    norm: E::Real,
+) {
+    let n = h.nrows();
+    let mut x = x;
 
-                            let r0 = Complex {
-                                re: x.read(i - 1, k - 1),
-                                im: x.read(i - 1, k),
-                            };
-                            let r1 = Complex {
-                                re: x.read(i, k - 1),
-                                im: x.read(i, k),
-                            };
+    let read = |i: usize, j: usize| {
+        let h = h.read(i, j);
+        if matches!(conj_h, Conj::Yes) {
+            h.faer_conj()
+        } else {
+            h
+        }
+    };
 
-                            let inv_det = (a
-                                .faer_mul(a)
-                                .faer_sub(Complex::<E>::faer_from_real(b.faer_mul(c))))
-                            .faer_inv();
-
-                            let x0 = a
-                                .faer_mul(r0)
-                                .faer_sub(r1.faer_scale_real(b))
-                                .faer_mul(inv_det);
-                            let x1 = a
-                                .faer_mul(r1)
-                                .faer_sub(r0.faer_scale_real(c))
-                                .faer_mul(inv_det);
-
-                            x.write(i - 1, k - 1, x0.re);
-                            x.write(i - 1, k, x0.im);
-                            x.write(i, k - 1, x1.re);
-                            x.write(i, k, x1.im);
-
-                            i -= 1;
-                        }
-                    }
+    for k in (0..n).rev() {
+        x.write(k, k, E::faer_zero());
+        for i in (0..k).rev() {
+            x.write(i, k, read(i, k).faer_neg());
+            if k > i + 1 {
+                let dot = inner_prod_with_conj(
+                    h.row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
+                    conj_h,
+                    x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
+                    Conj::No,
+                );
+                x.write(i, k, x.read(i, k).faer_sub(dot));
+            }
 
-                    k -= 1;
-                }
+            let mut z = read(i, i).faer_sub(read(k, k));
+            if z == E::faer_zero() {
+                z = E::faer_from_real(epsilon.faer_mul(norm));
+            }
+            let z_inv = z.faer_inv();
+            let x_ = x.read(i, k);
+            if x_ != E::faer_zero() {
+                x.write(i, k, x.read(i, k).faer_mul(z_inv));
             }
         }
-
-        triangular::matmul(
-            u.rb_mut(),
-            BlockStructure::Rectangular,
-            z.rb(),
-            BlockStructure::Rectangular,
-            x.rb(),
-            BlockStructure::TriangularUpper,
-            None,
-            E::faer_one(),
-            parallelism,
-        );
-    } else {
-        hessenberg_real_evd::multishift_qr(
-            false,
-            h.rb_mut(),
-            None,
-            s_re.rb_mut(),
-            s_im.rb_mut(),
-            0,
-            n,
-            epsilon,
-            zero_threshold,
-            parallelism,
-            stack.rb_mut(),
-            params,
-        );
     }
 }
 
@@ -984,6 +1774,9 @@ pub fn compute_evd_req<E: ComplexField>(
     StackReq::try_all_of([
         // h
         temp_mat_req::<E>(n, n)?,
+        // balancing permutation and scaling factors
+        StackReq::try_new::<usize>(n)?,
+        StackReq::try_new::<E::Real>(n)?,
         // z
         temp_mat_req::<E>(n, if compute_vecs { n } else { 0 })?,
         StackReq::try_any_of([
@@ -1000,6 +1793,11 @@ pub fn compute_evd_req<E: ComplexField>(
                         householder_blocksize,
                         n,
                     )?,
+                    apply_block_householder_sequence_transpose_on_the_left_in_place_req::<E>(
+                        n - 1,
+                        householder_blocksize,
+                        n,
+                    )?,
                 ])?,
             ])?,
             StackReq::try_any_of([
@@ -1030,6 +1828,11 @@ pub fn compute_evd_req<E: ComplexField>(
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
 ///
 /// This can also panic if the provided memory in `stack` is insufficient (see [`compute_evd_req`]).
+///
+/// # Output
+///
+/// The number of QR sweeps that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
 pub fn compute_evd_complex<E: ComplexField>(
     matrix: MatRef<'_, E>,
     s: MatMut<'_, E>,
@@ -1037,7 +1840,7 @@ pub fn compute_evd_complex<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: EvdParams,
-) {
+) -> EvdInfo {
     compute_evd_complex_custom_epsilon(
         matrix,
         s,
@@ -1047,7 +1850,7 @@ pub fn compute_evd_complex<E: ComplexField>(
         parallelism,
         stack,
         params,
-    );
+    )
 }
 
 /// See [`compute_evd_complex`].
@@ -1067,7 +1870,72 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: EvdParams,
-) {
+) -> EvdInfo {
+    compute_evd_complex_impl(
+        matrix,
+        s,
+        u,
+        None,
+        epsilon,
+        zero_threshold,
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+/// Computes the eigenvalue decomposition of a square complex `matrix`, along with its left
+/// eigenvectors.
+///
+/// The eigenvalues and the right eigenvectors are stored in `s` and `u` as described in
+/// [`compute_evd_complex`]. The left eigenvectors $V$, such that $V^H M = S V^H$, are computed
+/// from the Schur form of the matrix and stored in `v`. They are normalized so that
+/// $v_k^H u_k = 1$ for each eigenvalue.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated, or if the type `E` does not have a
+/// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see [`compute_evd_req`]
+/// with [`ComputeVectors::Yes`]).
+///
+/// # Output
+///
+/// The number of QR sweeps that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
+pub fn compute_evd_complex_with_left_eigenvectors<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: MatMut<'_, E>,
+    v: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> EvdInfo {
+    compute_evd_complex_impl(
+        matrix,
+        s,
+        Some(u),
+        Some(v),
+        E::Real::faer_epsilon().unwrap(),
+        E::Real::faer_zero_threshold().unwrap(),
+        parallelism,
+        stack,
+        params,
+    )
+}
+
+fn compute_evd_complex_impl<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> EvdInfo {
     assert!(!coe::is_same::<E, E::Real>());
     let n = matrix.nrows();
 
@@ -1079,9 +1947,15 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
     if let Some(u) = u.rb() {
         assert!(all(u.nrows() == n, u.ncols() == n));
     }
+    if let Some(v) = v.rb() {
+        assert!(all(u.is_some(), v.nrows() == n, v.ncols() == n));
+    }
 
     if n == 0 {
-        return;
+        return EvdInfo {
+            iteration_count: 0,
+            converged: true,
+        };
     }
 
     #[cfg(feature = "perf-warn")]
@@ -1100,12 +1974,17 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
         if let Some(mut u) = u {
             u.fill(E::faer_nan());
         }
-        return;
+        if let Some(mut v) = v {
+            v.fill(E::faer_nan());
+        }
+        return EvdInfo {
+            iteration_count: 0,
+            converged: false,
+        };
     }
 
-    let householder_blocksize = recommended_blocksize::<E>(n - 1, n - 1);
-
     let mut u = u;
+    let mut v = v;
     let mut s = s;
 
     let (mut h, stack) = temp_mat_uninit(n, n, stack);
@@ -1113,6 +1992,17 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
 
     h.copy_from(matrix);
 
+    let (perm, stack) = stack.make_with(n, |i| i);
+    let (scale, stack) = stack.make_with(n, |_| E::Real::faer_one());
+    let (ilo, ihi) = balance::balance_in_place(
+        h.rb_mut(),
+        params.balance,
+        perm,
+        scale,
+        epsilon,
+        zero_threshold,
+    );
+
     let (mut z, mut stack) = temp_mat_zeroed::<E>(n, if u.is_some() { n } else { 0 }, stack);
     let mut z = z.as_mut();
     z.rb_mut()
@@ -1120,43 +2010,71 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
         .column_vector_mut()
         .fill(E::faer_one());
 
-    {
+    // after balancing, the rows and columns outside of `ilo..ihi` are already upper triangular,
+    // so only the diagonal block needs to be reduced
+    let nh = ihi - ilo;
+    if nh > 1 {
+        let householder_blocksize = recommended_blocksize::<E>(nh - 1, nh - 1);
         let (mut householder, mut stack) =
-            temp_mat_uninit(n - 1, householder_blocksize, stack.rb_mut());
+            temp_mat_uninit(nh - 1, householder_blocksize, stack.rb_mut());
         let mut householder = householder.as_mut();
 
         hessenberg::make_hessenberg_in_place(
-            h.rb_mut(),
+            h.rb_mut().submatrix_mut(ilo, ilo, nh, nh),
             householder.rb_mut(),
             parallelism,
             stack.rb_mut(),
         );
         if u.is_some() {
+            let (h_top, _, h_bot, h_bot_right) = h.rb_mut().split_at_mut(ilo + 1, ihi);
+            let basis = h_bot.rb().submatrix(0, ilo, nh - 1, nh - 1);
+
+            apply_block_householder_sequence_on_the_right_in_place_with_conj(
+                basis,
+                householder.rb().transpose(),
+                Conj::No,
+                h_top.submatrix_mut(0, ilo + 1, ilo, nh - 1),
+                parallelism,
+                stack.rb_mut(),
+            );
+            apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj(
+                basis,
+                householder.rb().transpose(),
+                Conj::Yes,
+                h_bot_right.subrows_mut(0, nh - 1),
+                parallelism,
+                stack.rb_mut(),
+            );
             apply_block_householder_sequence_on_the_right_in_place_with_conj(
-                h.rb().submatrix(1, 0, n - 1, n - 1),
+                basis,
                 householder.rb().transpose(),
                 Conj::No,
-                z.rb_mut().submatrix_mut(1, 1, n - 1, n - 1),
+                z.rb_mut().submatrix_mut(ilo + 1, ilo + 1, nh - 1, nh - 1),
                 parallelism,
                 stack,
             );
         }
+    }
 
-        for j in 0..n {
-            for i in j + 2..n {
-                h.write(i, j, E::faer_zero());
-            }
+    for j in 0..n {
+        for i in j + 2..n {
+            h.write(i, j, E::faer_zero());
         }
     }
 
+    // the eigenvalues isolated by balancing are on the diagonal
+    for i in (0..ilo).chain(ihi..n) {
+        s.write(i, 0, h.read(i, i));
+    }
+
     if let Some(mut u) = u.rb_mut() {
-        hessenberg_cplx_evd::multishift_qr(
+        let (info, _, count_sweep) = hessenberg_cplx_evd::multishift_qr(
             true,
             h.rb_mut(),
             Some(z.rb_mut()),
             s.rb_mut(),
-            0,
-            n,
+            ilo,
+            ihi,
             epsilon,
             zero_threshold,
             parallelism,
@@ -1181,31 +2099,7 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
             }
         }
 
-        for k in (0..n).rev() {
-            x.write(k, k, E::faer_zero());
-            for i in (0..k).rev() {
-                x.write(i, k, h.read(i, k).faer_neg());
-                if k > i + 1 {
-                    let dot = inner_prod_with_conj(
-                        h.rb().row(i).subcols(i + 1, k - i - 1).transpose().as_2d(),
-                        Conj::No,
-                        x.rb().col(k).subrows(i + 1, k - i - 1).as_2d(),
-                        Conj::No,
-                    );
-                    x.write(i, k, x.read(i, k).faer_sub(dot));
-                }
-
-                let mut z = h.read(i, i).faer_sub(h.read(k, k));
-                if z == E::faer_zero() {
-                    z = E::faer_from_real(epsilon.faer_mul(norm));
-                }
-                let z_inv = z.faer_inv();
-                let x_ = x.read(i, k);
-                if x_ != E::faer_zero() {
-                    x.write(i, k, x.read(i, k).faer_mul(z_inv));
-                }
-            }
-        }
+        complex_schur_right_eigenvectors(h.rb(), Conj::No, x.rb_mut(), epsilon, norm);
 
         triangular::matmul(
             u.rb_mut(),
@@ -1218,20 +2112,51 @@ pub fn compute_evd_complex_custom_epsilon<E: ComplexField>(
             E::faer_one(),
             parallelism,
         );
+
+        balance::balance_back_transform_in_place(u.rb_mut(), ilo, ihi, perm, scale);
+
+        if let Some(mut v) = v.rb_mut() {
+            // the left eigenvectors of T are the right eigenvectors of T^H. reversing the rows and
+            // columns of T^H makes it upper triangular, so its eigenvectors are computed with the
+            // same back substitution, then reversed back.
+            complex_schur_right_eigenvectors(
+                h.rb().transpose().reverse_rows_and_cols(),
+                Conj::Yes,
+                x.rb_mut(),
+                epsilon,
+                norm,
+            );
+
+            triangular::matmul(
+                v.rb_mut(),
+                BlockStructure::Rectangular,
+                z.rb(),
+                BlockStructure::Rectangular,
+                x.rb().reverse_rows_and_cols(),
+                BlockStructure::UnitTriangularLower,
+                None,
+                E::faer_one(),
+                parallelism,
+            );
+
+            balance::balance_left_back_transform_in_place(v.rb_mut(), ilo, ihi, perm, scale);
+        }
+        EvdInfo::from_qr(info, count_sweep)
     } else {
-        hessenberg_cplx_evd::multishift_qr(
+        let (info, _, count_sweep) = hessenberg_cplx_evd::multishift_qr(
             false,
             h.rb_mut(),
             None,
             s.rb_mut(),
-            0,
-            n,
+            ilo,
+            ihi,
             epsilon,
             zero_threshold,
             parallelism,
             stack.rb_mut(),
             params,
         );
+        EvdInfo::from_qr(info, count_sweep)
     }
 }
 
@@ -1424,34 +2349,258 @@ mod herm_tests {
     }
 
     #[test]
-    fn test_cplx_zero() {
-        for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
-            let mat = Mat::from_fn(n, n, |_, _| c64::faer_zero());
-
-            let mut s = Mat::zeros(n, n);
-            let mut u = Mat::zeros(n, n);
+    fn test_cplx_zero() {
+        for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |_, _| c64::faer_zero());
+
+            let mut s = Mat::zeros(n, n);
+            let mut u = Mat::zeros(n, n);
+
+            compute_hermitian_evd(
+                mat.as_ref(),
+                s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                Some(u.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_hermitian_evd_req::<c64>(
+                    n,
+                    ComputeVectors::Yes,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            let reconstructed = &u * &s * u.adjoint();
+            dbgf::dbgf!("6.2?", &u, &reconstructed, &mat);
+
+            for j in 0..n {
+                for i in j..n {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_subset() {
+        for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |i, j| {
+                c64::new(rand::random(), if i == j { 0.0 } else { rand::random() })
+            });
+            let herm = Mat::from_fn(n, n, |i, j| {
+                if i >= j {
+                    mat.read(i, j)
+                } else {
+                    mat.read(j, i).faer_conj()
+                }
+            });
+
+            let mut s_full = Mat::zeros(n, 1);
+            compute_hermitian_evd(
+                mat.as_ref(),
+                s_full.as_mut(),
+                None,
+                Parallelism::None,
+                make_stack!(compute_hermitian_evd_req::<c64>(
+                    n,
+                    ComputeVectors::No,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            let lo = n / 4;
+            let hi = n - n / 4;
+            let ranges = [
+                EigenRange::IndexRange(lo, hi),
+                EigenRange::ValueRange(
+                    s_full.read(lo, 0).re - 1e-8,
+                    s_full.read(hi - 1, 0).re + 1e-8,
+                ),
+            ];
+
+            for range in ranges {
+                let count = hermitian_evd_subset_count(
+                    mat.as_ref(),
+                    range,
+                    Parallelism::None,
+                    make_stack!(hermitian_evd_subset_count_req::<c64>(n, Parallelism::None)),
+                );
+                assert!(count == hi - lo);
+
+                let mut s = Mat::zeros(n, 1);
+                let mut u = Mat::zeros(n, n);
+
+                let info = compute_hermitian_evd_subset(
+                    mat.as_ref(),
+                    range,
+                    s.as_mut(),
+                    Some(u.as_mut()),
+                    Parallelism::None,
+                    make_stack!(compute_hermitian_evd_subset_req::<c64>(
+                        n,
+                        n,
+                        ComputeVectors::Yes,
+                        Parallelism::None,
+                        Default::default(),
+                    )),
+                    Default::default(),
+                );
+                let k = info.count;
+                assert!(all(k == hi - lo, info.converged));
+
+                let u = u.as_ref().subcols(0, k);
+                let hu = &herm * u;
+                for j in 0..k {
+                    assert_approx_eq!(s.read(j, 0), s_full.read(lo + j, 0), 1e-10);
+                    for i in 0..n {
+                        assert_approx_eq!(hu.read(i, j), u.read(i, j) * s.read(j, 0), 1e-10);
+                    }
+                }
+
+                let uhu = u.adjoint() * u;
+                for j in 0..k {
+                    for i in 0..k {
+                        let expected = if i == j {
+                            c64::faer_one()
+                        } else {
+                            c64::faer_zero()
+                        };
+                        assert_approx_eq!(uhu.read(i, j), expected, 1e-10);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi() {
+        for algorithm in [
+            SymmetricEvdAlgorithm::Jacobi,
+            SymmetricEvdAlgorithm::PositiveDefiniteJacobi,
+        ] {
+            let mut params = SymmetricEvdParams::default();
+            params.algorithm = algorithm;
+
+            for n in [1, 2, 3, 4, 5, 10, 15, 25] {
+                for positive_definite in [false, true] {
+                    let a = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+                    let mat = if positive_definite {
+                        &a * a.adjoint()
+                    } else {
+                        Mat::from_fn(n, n, |i, j| a.read(i, j) + a.read(j, i).faer_conj())
+                    };
+
+                    let mut s = Mat::zeros(n, n);
+                    let mut u = Mat::zeros(n, n);
+
+                    if n >= 2 {
+                        let mut params = params;
+                        params.max_iterations = Some(0);
+                        let info = compute_hermitian_evd(
+                            mat.as_ref(),
+                            s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                            Some(u.as_mut()),
+                            Parallelism::None,
+                            make_stack!(compute_hermitian_evd_req::<c64>(
+                                n,
+                                ComputeVectors::Yes,
+                                Parallelism::None,
+                                params,
+                            )),
+                            params,
+                        );
+                        assert!(!info.converged);
+                    }
+
+                    let info = compute_hermitian_evd(
+                        mat.as_ref(),
+                        s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                        Some(u.as_mut()),
+                        Parallelism::None,
+                        make_stack!(compute_hermitian_evd_req::<c64>(
+                            n,
+                            ComputeVectors::Yes,
+                            Parallelism::None,
+                            params,
+                        )),
+                        params,
+                    );
+                    assert!(info.converged);
+
+                    let reconstructed = &u * &s * u.adjoint();
+                    let uhu = u.adjoint() * &u;
+                    for j in 0..n {
+                        for i in 0..n {
+                            assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                            let expected = if i == j {
+                                c64::faer_one()
+                            } else {
+                                c64::faer_zero()
+                            };
+                            assert_approx_eq!(uhu.read(i, j), expected, 1e-10);
+                        }
+                    }
+                    for i in 1..n {
+                        assert!(s.read(i - 1, i - 1).re <= s.read(i, i).re);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_graded() {
+        for n in [2, 5, 10] {
+            // a graded positive definite matrix, whose eigenvalues span many orders of magnitude
+            // and are determined to high relative accuracy by its entries
+            let scale = |i: usize| 1e-4f64.powi(i as i32);
+            let b = Mat::from_fn(n, n, |_, _| rand::random::<f64>() * 0.1);
+            let mat = Mat::from_fn(n, n, |i, j| {
+                let id = if i == j { 1.0 } else { 0.0 };
+                (id + b.read(i, j) * b.read(j, i)) * scale(i) * scale(j)
+            });
 
-            compute_hermitian_evd(
-                mat.as_ref(),
-                s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
-                Some(u.as_mut()),
-                Parallelism::None,
-                make_stack!(compute_hermitian_evd_req::<c64>(
-                    n,
-                    ComputeVectors::Yes,
+            let mut eigenvalues = Vec::new();
+            for algorithm in [
+                SymmetricEvdAlgorithm::Jacobi,
+                SymmetricEvdAlgorithm::PositiveDefiniteJacobi,
+            ] {
+                let mut params = SymmetricEvdParams::default();
+                params.algorithm = algorithm;
+
+                let mut s = Mat::zeros(n, 1);
+                let mut u = Mat::zeros(n, n);
+                compute_hermitian_evd(
+                    mat.as_ref(),
+                    s.as_mut(),
+                    Some(u.as_mut()),
                     Parallelism::None,
-                    Default::default(),
-                )),
-                Default::default(),
-            );
+                    make_stack!(compute_hermitian_evd_req::<f64>(
+                        n,
+                        ComputeVectors::Yes,
+                        Parallelism::None,
+                        params,
+                    )),
+                    params,
+                );
 
-            let reconstructed = &u * &s * u.adjoint();
-            dbgf::dbgf!("6.2?", &u, &reconstructed, &mat);
+                let utu = u.transpose() * &u;
+                for j in 0..n {
+                    assert!(s.read(j, 0) > 0.0);
+                    for i in 0..n {
+                        let expected = if i == j { 1.0 } else { 0.0 };
+                        assert_approx_eq!(utu.read(i, j), expected, 1e-10);
+                    }
+                }
+                eigenvalues.push(s);
+            }
 
             for j in 0..n {
-                for i in j..n {
-                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
-                }
+                let lhs = eigenvalues[0].read(j, 0);
+                let rhs = eigenvalues[1].read(j, 0);
+                assert!((lhs - rhs).abs() <= 1e-12 * rhs);
             }
         }
     }
@@ -1768,6 +2917,376 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cplx_balanced() {
+        for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |i, j| {
+                // the first row and the last column isolate eigenvalues
+                if (i == 0 && j != 0) || (j == n - 1 && i != n - 1) {
+                    c64::faer_zero()
+                } else {
+                    let scale = 2.0f64.powi(i as i32 - j as i32);
+                    c64::new(rand::random::<f64>() * scale, rand::random::<f64>() * scale)
+                }
+            });
+
+            let mut s = Mat::zeros(n, n);
+            let mut u = Mat::zeros(n, n);
+
+            let params = EvdParams {
+                balance: Balance::Both,
+                ..Default::default()
+            };
+
+            compute_evd_complex(
+                mat.as_ref(),
+                s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                Some(u.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_evd_req::<c64>(
+                    n,
+                    ComputeVectors::Yes,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let left = &mat * &u;
+            let right = &u * &s;
+
+            let tol = 1e-10 * mat.norm_max() * u.norm_max();
+            for j in 0..n {
+                for i in 0..n {
+                    assert!((left.read(i, j) - right.read(i, j)).faer_abs() < tol);
+                }
+            }
+
+            // the isolated eigenvalues are also reported when the eigenvectors are not computed
+            let mut s_only = Mat::zeros(n, 1);
+            compute_evd_complex(
+                mat.as_ref(),
+                s_only.as_mut(),
+                None,
+                Parallelism::None,
+                make_stack!(compute_evd_req::<c64>(
+                    n,
+                    ComputeVectors::No,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+            for i in 0..n {
+                let value = s_only.read(i, 0);
+                assert!((0..n).any(|j| (value - s.read(j, j)).faer_abs() < 1e-8 * mat.norm_max()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_real_balanced() {
+        for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |i, j| {
+                if (i == 0 && j != 0) || (j == n - 1 && i != n - 1) {
+                    0.0
+                } else {
+                    rand::random::<f64>() * 2.0f64.powi(i as i32 - j as i32)
+                }
+            });
+
+            let mut s_re = Mat::zeros(n, n);
+            let mut s_im = Mat::zeros(n, n);
+            let mut u_re = Mat::zeros(n, n);
+            let mut u_im = Mat::zeros(n, n);
+
+            let params = EvdParams {
+                balance: Balance::Both,
+                ..Default::default()
+            };
+
+            compute_evd_real(
+                mat.as_ref(),
+                s_re.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                s_im.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                Some(u_re.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_evd_req::<f64>(
+                    n,
+                    ComputeVectors::Yes,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let mut j = 0;
+            while j < n {
+                if s_im.read(j, j) != 0.0 {
+                    for i in 0..n {
+                        u_im.write(i, j, u_re.read(i, j + 1));
+                        u_im.write(i, j + 1, -u_re.read(i, j + 1));
+                        u_re.write(i, j + 1, u_re.read(i, j));
+                    }
+                    j += 1;
+                }
+                j += 1;
+            }
+
+            let u = Mat::from_fn(n, n, |i, j| Complex::new(u_re.read(i, j), u_im.read(i, j)));
+            let s = Mat::from_fn(n, n, |i, j| Complex::new(s_re.read(i, j), s_im.read(i, j)));
+            let mat = Mat::from_fn(n, n, |i, j| Complex::new(mat.read(i, j), 0.0));
+
+            let left = &mat * &u;
+            let right = &u * &s;
+
+            let tol = 1e-10 * mat.norm_max() * u.norm_max();
+            for j in 0..n {
+                for i in 0..n {
+                    assert!((left.read(i, j) - right.read(i, j)).norm() < tol);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cplx_left_eigenvectors() {
+        for n in [1, 2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+
+            let mut s = Mat::zeros(n, n);
+            let mut u = Mat::zeros(n, n);
+            let mut v = Mat::zeros(n, n);
+
+            let params = EvdParams {
+                balance: Balance::Both,
+                ..Default::default()
+            };
+
+            compute_evd_complex_with_left_eigenvectors(
+                mat.as_ref(),
+                s.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                u.as_mut(),
+                v.as_mut(),
+                Parallelism::None,
+                make_stack!(compute_evd_req::<c64>(
+                    n,
+                    ComputeVectors::Yes,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let left = v.adjoint() * &mat;
+            let right = &s * v.adjoint();
+            let vhu = v.adjoint() * &u;
+
+            for j in 0..n {
+                assert_approx_eq!(vhu.read(j, j), c64::new(1.0, 0.0), 1e-8);
+                for i in 0..n {
+                    assert_approx_eq!(left.read(i, j), right.read(i, j), 1e-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_real_left_eigenvectors() {
+        for n in [1, 2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let mat = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+
+            let mut s_re = Mat::zeros(n, n);
+            let mut s_im = Mat::zeros(n, n);
+            let mut u_real = Mat::zeros(n, n);
+            let mut v_real = Mat::zeros(n, n);
+
+            let params = EvdParams {
+                balance: Balance::Both,
+                ..Default::default()
+            };
+
+            compute_evd_real_with_left_eigenvectors(
+                mat.as_ref(),
+                s_re.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                s_im.as_mut().diagonal_mut().column_vector_mut().as_2d_mut(),
+                u_real.as_mut(),
+                v_real.as_mut(),
+                Parallelism::None,
+                make_stack!(compute_evd_req::<f64>(
+                    n,
+                    ComputeVectors::Yes,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            let to_complex = |x: &Mat<f64>| {
+                Mat::from_fn(n, n, |i, j| {
+                    if s_im.read(j, j) == 0.0 {
+                        Complex::new(x.read(i, j), 0.0)
+                    } else if j + 1 < n && s_im.read(j, j) > 0.0 {
+                        Complex::new(x.read(i, j), x.read(i, j + 1))
+                    } else {
+                        Complex::new(x.read(i, j - 1), -x.read(i, j))
+                    }
+                })
+            };
+
+            let u = to_complex(&u_real);
+            let v = to_complex(&v_real);
+            let s = Mat::from_fn(n, n, |i, j| Complex::new(s_re.read(i, j), s_im.read(i, j)));
+            let mat = Mat::from_fn(n, n, |i, j| Complex::new(mat.read(i, j), 0.0));
+
+            let left = v.adjoint() * &mat;
+            let right = &s * v.adjoint();
+            let vhu = v.adjoint() * &u;
+
+            for j in 0..n {
+                assert!((vhu.read(j, j) - Complex::new(1.0, 0.0)).norm() < 1e-8);
+                for i in 0..n {
+                    assert!((left.read(i, j) - right.read(i, j)).norm() < 1e-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        // covers both the unblocked and the multishift qr algorithms
+        for n in [10, 100] {
+            let mat = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+            let mut s_re = Mat::zeros(n, 1);
+            let mut s_im = Mat::zeros(n, 1);
+
+            for max_iterations in [None, Some(0)] {
+                let params = EvdParams {
+                    max_iterations,
+                    ..Default::default()
+                };
+                let info = compute_evd_real(
+                    mat.as_ref(),
+                    s_re.as_mut(),
+                    s_im.as_mut(),
+                    None,
+                    Parallelism::None,
+                    make_stack!(compute_evd_req::<f64>(
+                        n,
+                        ComputeVectors::No,
+                        Parallelism::None,
+                        params,
+                    )),
+                    params,
+                );
+                assert!(info.converged == max_iterations.is_none());
+                if max_iterations.is_none() {
+                    assert!(info.iteration_count > 0);
+                }
+            }
+
+            let mat = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+            let mut s = Mat::zeros(n, 1);
+
+            for max_iterations in [None, Some(0)] {
+                let params = EvdParams {
+                    max_iterations,
+                    ..Default::default()
+                };
+                let info = compute_evd_complex(
+                    mat.as_ref(),
+                    s.as_mut(),
+                    None,
+                    Parallelism::None,
+                    make_stack!(compute_evd_req::<c64>(
+                        n,
+                        ComputeVectors::No,
+                        Parallelism::None,
+                        params,
+                    )),
+                    params,
+                );
+                assert!(info.converged == max_iterations.is_none());
+            }
+        }
+
+        let mut nan = Mat::<f64>::zeros(4, 4);
+        nan.write(1, 2, f64::NAN);
+        let info = compute_evd_real(
+            nan.as_ref(),
+            Mat::zeros(4, 1).as_mut(),
+            Mat::zeros(4, 1).as_mut(),
+            None,
+            Parallelism::None,
+            make_stack!(compute_evd_req::<f64>(
+                4,
+                ComputeVectors::No,
+                Parallelism::None,
+                Default::default(),
+            )),
+            Default::default(),
+        );
+        assert!(!info.converged);
+    }
+
+    #[test]
+    fn test_hermitian_max_iterations() {
+        // covers both the tridiagonal qr algorithm and the divide and conquer algorithm
+        for n in [10, 100] {
+            let a = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+            let mat = Mat::from_fn(n, n, |i, j| a.read(i, j) + a.read(j, i));
+            let mut s = Mat::zeros(n, 1);
+            let mut u = Mat::zeros(n, n);
+
+            for compute_vectors in [ComputeVectors::No, ComputeVectors::Yes] {
+                for max_iterations in [None, Some(0)] {
+                    let params = SymmetricEvdParams {
+                        max_iterations,
+                        ..Default::default()
+                    };
+                    let info = compute_hermitian_evd(
+                        mat.as_ref(),
+                        s.as_mut(),
+                        if compute_vectors == ComputeVectors::Yes {
+                            Some(u.as_mut())
+                        } else {
+                            None
+                        },
+                        Parallelism::None,
+                        make_stack!(compute_hermitian_evd_req::<f64>(
+                            n,
+                            compute_vectors,
+                            Parallelism::None,
+                            params,
+                        )),
+                        params,
+                    );
+                    assert!(info.converged == max_iterations.is_none());
+                    if max_iterations.is_none() {
+                        assert!(info.iteration_count > 0);
+                    }
+                }
+            }
+        }
+
+        let mut nan = Mat::<f64>::zeros(4, 4);
+        nan.write(2, 1, f64::NAN);
+        let info = compute_hermitian_evd(
+            nan.as_ref(),
+            Mat::zeros(4, 1).as_mut(),
+            None,
+            Parallelism::None,
+            make_stack!(compute_hermitian_evd_req::<f64>(
+                4,
+                ComputeVectors::No,
+                Parallelism::None,
+                Default::default(),
+            )),
+            Default::default(),
+        );
+        assert!(!info.converged);
+    }
+
     #[test]
     fn test_cplx_identity() {
         for n in [2, 3, 4, 5, 6, 7, 10, 15, 25] {
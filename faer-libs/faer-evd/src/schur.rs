@@ -0,0 +1,801 @@
+//! The complex Schur decomposition of a square matrix $M$ of shape $(n, n)$ is a decomposition
+//! into two components $Z$, $T$:
+//!
+//! - $Z$ has shape $(n, n)$ and is unitary,
+//! - $T$ has shape $(n, n)$ and is upper triangular, with the eigenvalues of $M$ on its diagonal,
+//! - and finally:
+//!
+//! $$M = Z T Z^H.$$
+//!
+//! The complex generalized Schur decomposition of a square pencil $(A, B)$ is a decomposition into
+//! two unitary factors $Q$, $Z$ and two upper triangular factors $S$, $T$, such that
+//! $A = Q S Z^H$ and $B = Q T Z^H$.
+
+use crate::{hessenberg, hessenberg_cplx_evd, EvdParams};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    assert,
+    householder::{
+        apply_block_householder_sequence_on_the_right_in_place_req,
+        apply_block_householder_sequence_on_the_right_in_place_with_conj,
+    },
+    temp_mat_req, temp_mat_uninit, ComplexField, Conj, MatMut, MatRef, Parallelism, RealField,
+};
+use faer_qr::no_pivoting::compute::recommended_blocksize;
+use reborrow::*;
+
+/// Computes the size and alignment of required workspace for computing the complex Schur
+/// decomposition of a square matrix with dimension `n`.
+pub fn compute_schur_complex_req<E: ComplexField>(
+    n: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    if n == 0 {
+        return Ok(StackReq::empty());
+    }
+    let householder_blocksize = recommended_blocksize::<E>(n - 1, n - 1);
+    StackReq::try_all_of([
+        // eigenvalues
+        temp_mat_req::<E>(n, 1)?,
+        StackReq::try_any_of([
+            StackReq::try_all_of([
+                temp_mat_req::<E>(n - 1, householder_blocksize)?,
+                StackReq::try_any_of([
+                    hessenberg::make_hessenberg_in_place_req::<E>(
+                        n,
+                        householder_blocksize,
+                        parallelism,
+                    )?,
+                    apply_block_householder_sequence_on_the_right_in_place_req::<E>(
+                        n - 1,
+                        householder_blocksize,
+                        n,
+                    )?,
+                ])?,
+            ])?,
+            hessenberg_cplx_evd::multishift_qr_req::<E>(n, n, true, true, parallelism, params)?,
+        ])?,
+    ])
+}
+
+/// Computes the complex Schur decomposition of a square complex `matrix`, storing the upper
+/// triangular factor in `t` and the unitary factor in `z`.
+///
+/// The `balance` field of `params` is ignored, since balancing doesn't preserve the unitarity of
+/// the Schur vectors.
+///
+/// # Panics
+/// Panics if `matrix`, `t` and `z` don't all have the same square shape, or if the type `E` is
+/// real-valued or doesn't have a fixed precision at compile time, e.g. a dynamic multiprecision
+/// floating point type.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`compute_schur_complex_req`]).
+#[track_caller]
+pub fn compute_schur_complex<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    t: MatMut<'_, E>,
+    z: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) {
+    assert!(!coe::is_same::<E, E::Real>());
+    let n = matrix.nrows();
+    assert!(all(
+        matrix.ncols() == n,
+        t.nrows() == n,
+        t.ncols() == n,
+        z.nrows() == n,
+        z.ncols() == n,
+    ));
+
+    if n == 0 {
+        return;
+    }
+
+    let epsilon = E::Real::faer_epsilon().unwrap();
+    let zero_threshold = E::Real::faer_zero_threshold().unwrap();
+
+    let mut t = t;
+    let mut z = z;
+
+    if !matrix.is_all_finite() {
+        t.fill(E::faer_nan());
+        z.fill(E::faer_nan());
+        return;
+    }
+
+    let householder_blocksize = recommended_blocksize::<E>(n - 1, n - 1);
+
+    let (mut s, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let mut s = s.as_mut();
+
+    t.copy_from(matrix);
+    z.fill_zero();
+    z.rb_mut()
+        .diagonal_mut()
+        .column_vector_mut()
+        .fill(E::faer_one());
+
+    {
+        let (mut householder, mut stack) =
+            temp_mat_uninit(n - 1, householder_blocksize, stack.rb_mut());
+        let mut householder = householder.as_mut();
+
+        hessenberg::make_hessenberg_in_place(
+            t.rb_mut(),
+            householder.rb_mut(),
+            parallelism,
+            stack.rb_mut(),
+        );
+        apply_block_householder_sequence_on_the_right_in_place_with_conj(
+            t.rb().submatrix(1, 0, n - 1, n - 1),
+            householder.rb().transpose(),
+            Conj::No,
+            z.rb_mut().submatrix_mut(1, 1, n - 1, n - 1),
+            parallelism,
+            stack,
+        );
+
+        for j in 0..n {
+            for i in j + 2..n {
+                t.write(i, j, E::faer_zero());
+            }
+        }
+    }
+
+    hessenberg_cplx_evd::multishift_qr(
+        true,
+        t.rb_mut(),
+        Some(z.rb_mut()),
+        s.rb_mut(),
+        0,
+        n,
+        epsilon,
+        zero_threshold,
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    for j in 0..n {
+        for i in j + 1..n {
+            t.write(i, j, E::faer_zero());
+        }
+    }
+}
+
+/// Swaps the adjacent diagonal entries `k` and `k + 1` of the upper triangular factor `t` of a
+/// complex Schur decomposition, updating the Schur vectors `z` accordingly.
+fn swap_schur_complex<E: ComplexField>(t: MatMut<'_, E>, z: Option<MatMut<'_, E>>, k: usize) {
+    let n = t.nrows();
+    let mut t = t;
+
+    let a = t.read(k, k);
+    let b = t.read(k, k + 1);
+    let d = t.read(k + 1, k + 1);
+
+    // the first column of the rotation is the normalized eigenvector associated with `d`
+    let x = b;
+    let y = d.faer_sub(a);
+    let r = x.faer_abs2().faer_add(y.faer_abs2()).faer_sqrt();
+    if r == E::Real::faer_zero() {
+        return;
+    }
+    let r_inv = r.faer_inv();
+    let x = x.faer_scale_real(r_inv);
+    let y = y.faer_scale_real(r_inv);
+
+    // rows: Q^H T
+    for j in k..n {
+        let t0 = t.read(k, j);
+        let t1 = t.read(k + 1, j);
+        t.write(
+            k,
+            j,
+            x.faer_conj()
+                .faer_mul(t0)
+                .faer_add(y.faer_conj().faer_mul(t1)),
+        );
+        t.write(k + 1, j, x.faer_mul(t1).faer_sub(y.faer_mul(t0)));
+    }
+    // columns: T Q
+    for i in 0..k + 2 {
+        let t0 = t.read(i, k);
+        let t1 = t.read(i, k + 1);
+        t.write(i, k, x.faer_mul(t0).faer_add(y.faer_mul(t1)));
+        t.write(
+            i,
+            k + 1,
+            x.faer_conj()
+                .faer_mul(t1)
+                .faer_sub(y.faer_conj().faer_mul(t0)),
+        );
+    }
+    if let Some(mut z) = z {
+        for i in 0..z.nrows() {
+            let z0 = z.read(i, k);
+            let z1 = z.read(i, k + 1);
+            z.write(i, k, x.faer_mul(z0).faer_add(y.faer_mul(z1)));
+            z.write(
+                i,
+                k + 1,
+                x.faer_conj()
+                    .faer_mul(z1)
+                    .faer_sub(y.faer_conj().faer_mul(z0)),
+            );
+        }
+    }
+
+    t.write(k, k, d);
+    t.write(k + 1, k + 1, a);
+    t.write(k + 1, k, E::faer_zero());
+}
+
+/// Reorders the complex Schur decomposition $M = Z T Z^H$, so that the eigenvalues for which
+/// `select` returns `true` appear in the leading diagonal block of `t`, while preserving their
+/// relative order. The first columns of `z` then span the corresponding invariant subspace.
+///
+/// Returns the number of selected eigenvalues.
+///
+/// # Panics
+/// Panics if `t` is not square, or if `z` is `Some(..)` and doesn't have the same number of
+/// columns as `t`.
+#[track_caller]
+pub fn reorder_schur_complex<E: ComplexField>(
+    t: MatMut<'_, E>,
+    z: Option<MatMut<'_, E>>,
+    select: impl FnMut(E) -> bool,
+) -> usize {
+    let n = t.nrows();
+    assert!(t.ncols() == n);
+    if let Some(z) = z.rb() {
+        assert!(z.ncols() == n);
+    }
+
+    let mut t = t;
+    let mut z = z;
+    let mut select = select;
+
+    let mut count = 0usize;
+    for k in 0..n {
+        if select(t.read(k, k)) {
+            for i in (count..k).rev() {
+                swap_schur_complex(t.rb_mut(), z.rb_mut(), i);
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+fn max<E: RealField>(a: E, b: E) -> E {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Applies the plane rotation $\begin{bmatrix} c & s \\ -\bar s & c \end{bmatrix}$ to the rows
+/// `i0` and `i1` of `m`.
+fn rotate_rows<E: ComplexField>(m: MatMut<'_, E>, i0: usize, i1: usize, c: E::Real, s: E) {
+    let mut m = m;
+    for j in 0..m.ncols() {
+        let x = m.read(i0, j);
+        let y = m.read(i1, j);
+        m.write(i0, j, x.faer_scale_real(c).faer_add(s.faer_mul(y)));
+        m.write(
+            i1,
+            j,
+            y.faer_scale_real(c).faer_sub(s.faer_conj().faer_mul(x)),
+        );
+    }
+}
+
+/// Applies the plane rotation $\begin{bmatrix} c & s \\ -\bar s & c \end{bmatrix}$ to the
+/// columns `j0` and `j1` of `m`.
+fn rotate_cols<E: ComplexField>(m: MatMut<'_, E>, j0: usize, j1: usize, c: E::Real, s: E) {
+    rotate_rows(m.transpose_mut(), j0, j1, c, s);
+}
+
+/// Computes `c` and `s` such that the plane rotation maps `(a, b)` to `(r, 0)`.
+fn rotation<E: ComplexField>(
+    a: E,
+    b: E,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+) -> (E::Real, E) {
+    let (c, s, _) = hessenberg_cplx_evd::rotg(a, b, epsilon, zero_threshold);
+    (c, s)
+}
+
+/// Computes the complex generalized Schur decomposition of the square complex pencil $(A, B)$
+/// with the QZ algorithm, storing the upper triangular factors in `s` and `t` and the unitary
+/// factors in `q` and `z`, such that
+///
+/// $$A = Q S Z^H, \quad B = Q T Z^H.$$
+///
+/// The generalized eigenvalues of the pencil are the ratios $S_{kk} / T_{kk}$, where a zero
+/// $T_{kk}$ corresponds to an infinite eigenvalue, so $B$ is allowed to be singular.
+///
+/// # Output
+/// Returns `true` if the QZ algorithm converged within `30 * max(10, n)` sweeps. If it did not,
+/// the decomposition is not accurate.
+///
+/// # Panics
+/// Panics if `a`, `b`, `s`, `t`, `q` and `z` don't all have the same square shape, or if the type
+/// `E` is real-valued or doesn't have a fixed precision at compile time, e.g. a dynamic
+/// multiprecision floating point type.
+#[track_caller]
+pub fn compute_generalized_schur_complex<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    t: MatMut<'_, E>,
+    q: MatMut<'_, E>,
+    z: MatMut<'_, E>,
+) -> bool {
+    assert!(!coe::is_same::<E, E::Real>());
+    let n = a.nrows();
+    assert!(all(
+        a.ncols() == n,
+        b.nrows() == n,
+        b.ncols() == n,
+        s.nrows() == n,
+        s.ncols() == n,
+        t.nrows() == n,
+        t.ncols() == n,
+        q.nrows() == n,
+        q.ncols() == n,
+        z.nrows() == n,
+        z.ncols() == n,
+    ));
+
+    let mut s = s;
+    let mut t = t;
+    let mut q = q;
+    let mut z = z;
+
+    if n == 0 {
+        return true;
+    }
+
+    if !a.is_all_finite() || !b.is_all_finite() {
+        s.fill(E::faer_nan());
+        t.fill(E::faer_nan());
+        q.fill(E::faer_nan());
+        z.fill(E::faer_nan());
+        return false;
+    }
+
+    let epsilon = E::Real::faer_epsilon().unwrap();
+    let zero_threshold = E::Real::faer_zero_threshold().unwrap();
+
+    s.copy_from(a);
+    t.copy_from(b);
+    for x in [q.rb_mut(), z.rb_mut()] {
+        let mut x = x;
+        x.fill_zero();
+        x.diagonal_mut().column_vector_mut().fill(E::faer_one());
+    }
+
+    // rotating the rows i0 and i1 of the pencil is applied to the columns of q, and rotating its
+    // columns is applied to the columns of z
+    macro_rules! rotate_pencil_rows {
+        ($i0: expr, $i1: expr, $c: expr, $sn: expr) => {{
+            let (i0, i1, c, sn) = ($i0, $i1, $c, $sn);
+            rotate_rows(s.rb_mut(), i0, i1, c, sn);
+            rotate_rows(t.rb_mut(), i0, i1, c, sn);
+            rotate_cols(q.rb_mut(), i0, i1, c, sn.faer_conj());
+        }};
+    }
+    macro_rules! rotate_pencil_cols {
+        ($j0: expr, $j1: expr, $c: expr, $sn: expr) => {{
+            let (j0, j1, c, sn) = ($j0, $j1, $c, $sn);
+            rotate_cols(s.rb_mut(), j0, j1, c, sn);
+            rotate_cols(t.rb_mut(), j0, j1, c, sn);
+            rotate_cols(z.rb_mut(), j0, j1, c, sn);
+        }};
+    }
+
+    // reduce b to upper triangular form
+    for j in 0..n {
+        for i in (j + 1..n).rev() {
+            let (c, sn) = rotation(t.read(i - 1, j), t.read(i, j), epsilon, zero_threshold);
+            rotate_pencil_rows!(i - 1, i, c, sn);
+            t.write(i, j, E::faer_zero());
+        }
+    }
+
+    // reduce a to upper hessenberg form, while keeping b upper triangular
+    for j in 0..n.saturating_sub(2) {
+        for i in (j + 2..n).rev() {
+            let (c, sn) = rotation(s.read(i - 1, j), s.read(i, j), epsilon, zero_threshold);
+            rotate_pencil_rows!(i - 1, i, c, sn);
+            s.write(i, j, E::faer_zero());
+
+            let (c, sn) = rotation(t.read(i, i), t.read(i, i - 1), epsilon, zero_threshold);
+            rotate_pencil_cols!(i, i - 1, c, sn);
+            t.write(i, i - 1, E::faer_zero());
+        }
+    }
+
+    let btol = max(zero_threshold, epsilon.faer_mul(t.rb().norm_max()));
+
+    let max_iterations = 30 * Ord::max(10, n);
+    let mut iter = 0usize;
+    let mut since_deflation = 0usize;
+
+    let mut hi = n;
+    while hi > 0 {
+        // look for a negligible subdiagonal entry, which splits off the active block lo..hi
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let tol = max(
+                zero_threshold,
+                epsilon.faer_mul(
+                    s.read(lo - 1, lo - 1)
+                        .faer_abs()
+                        .faer_add(s.read(lo, lo).faer_abs()),
+                ),
+            );
+            if s.read(lo, lo - 1).faer_abs() <= tol {
+                s.write(lo, lo - 1, E::faer_zero());
+                break;
+            }
+            lo -= 1;
+        }
+
+        // a negligible diagonal entry of t is chased down to the bottom of the active block,
+        // where it is deflated as an infinite eigenvalue
+        let zero_diag = (lo..hi).find(|&j| t.read(j, j).faer_abs() <= btol);
+        if let Some(j) = zero_diag {
+            t.write(j, j, E::faer_zero());
+            for k in j..hi - 1 {
+                let (c, sn) = rotation(
+                    t.read(k, k + 1),
+                    t.read(k + 1, k + 1),
+                    epsilon,
+                    zero_threshold,
+                );
+                rotate_pencil_rows!(k, k + 1, c, sn);
+                t.write(k + 1, k + 1, E::faer_zero());
+
+                if k > lo {
+                    let (c, sn) = rotation(
+                        s.read(k + 1, k),
+                        s.read(k + 1, k - 1),
+                        epsilon,
+                        zero_threshold,
+                    );
+                    rotate_pencil_cols!(k, k - 1, c, sn);
+                    s.write(k + 1, k - 1, E::faer_zero());
+                }
+            }
+
+            if hi - 1 > lo {
+                let (c, sn) = rotation(
+                    s.read(hi - 1, hi - 1),
+                    s.read(hi - 1, hi - 2),
+                    epsilon,
+                    zero_threshold,
+                );
+                rotate_pencil_cols!(hi - 1, hi - 2, c, sn);
+                s.write(hi - 1, hi - 2, E::faer_zero());
+            }
+
+            hi -= 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        if hi - lo == 1 {
+            hi -= 1;
+            since_deflation = 0;
+            continue;
+        }
+
+        if iter == max_iterations {
+            return false;
+        }
+        iter += 1;
+        since_deflation += 1;
+
+        // shift
+        let (k0, k1) = (hi - 2, hi - 1);
+        let shift = if since_deflation % 10 == 0 {
+            // exceptional shift
+            s.read(k1, k1)
+                .faer_mul(t.read(k1, k1).faer_inv())
+                .faer_add(s.read(k1, k0).faer_mul(t.read(k0, k0).faer_inv()))
+        } else {
+            // wilkinson shift, from the eigenvalue of the trailing 2x2 block of t^-1 s that is
+            // closest to its last diagonal entry
+            let t00_inv = t.read(k0, k0).faer_inv();
+            let t11_inv = t.read(k1, k1).faer_inv();
+            let t01 = t.read(k0, k1).faer_mul(t00_inv).faer_mul(t11_inv);
+
+            let s10 = s.read(k1, k0);
+            let s11 = s.read(k1, k1);
+            let m00 = s.read(k0, k0).faer_mul(t00_inv).faer_sub(t01.faer_mul(s10));
+            let m01 = s.read(k0, k1).faer_mul(t00_inv).faer_sub(t01.faer_mul(s11));
+            let m10 = s10.faer_mul(t11_inv);
+            let m11 = s11.faer_mul(t11_inv);
+
+            let (e0, e1) = hessenberg_cplx_evd::lahqr_eig22(m00, m01, m10, m11);
+            if e0.faer_sub(m11).faer_abs() <= e1.faer_sub(m11).faer_abs() {
+                e0
+            } else {
+                e1
+            }
+        };
+
+        // implicit single shift qz sweep
+        let (c, sn) = rotation(
+            s.read(lo, lo).faer_sub(shift.faer_mul(t.read(lo, lo))),
+            s.read(lo + 1, lo),
+            epsilon,
+            zero_threshold,
+        );
+        rotate_pencil_rows!(lo, lo + 1, c, sn);
+
+        for k in lo..hi - 1 {
+            let (c, sn) = rotation(
+                t.read(k + 1, k + 1),
+                t.read(k + 1, k),
+                epsilon,
+                zero_threshold,
+            );
+            rotate_pencil_cols!(k + 1, k, c, sn);
+            t.write(k + 1, k, E::faer_zero());
+
+            if k + 2 < hi {
+                let (c, sn) = rotation(s.read(k + 1, k), s.read(k + 2, k), epsilon, zero_threshold);
+                rotate_pencil_rows!(k + 1, k + 2, c, sn);
+                s.write(k + 2, k, E::faer_zero());
+            }
+        }
+    }
+
+    true
+}
+
+/// Swaps the adjacent diagonal entries `k` and `k + 1` of the upper triangular factors `s` and `t`
+/// of a complex generalized Schur decomposition, updating the unitary factors `q` and `z`
+/// accordingly.
+fn swap_generalized_schur_complex<E: ComplexField>(
+    s: MatMut<'_, E>,
+    t: MatMut<'_, E>,
+    q: Option<MatMut<'_, E>>,
+    z: Option<MatMut<'_, E>>,
+    k: usize,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+) {
+    let mut s = s;
+    let mut t = t;
+
+    let s00 = s.read(k, k);
+    let s01 = s.read(k, k + 1);
+    let s11 = s.read(k + 1, k + 1);
+    let t00 = t.read(k, k);
+    let t01 = t.read(k, k + 1);
+    let t11 = t.read(k + 1, k + 1);
+
+    // the right rotation maps the first column to the generalized eigenvector associated with
+    // (s11, t11)
+    let f = s11.faer_mul(t00).faer_sub(t11.faer_mul(s00));
+    let g = s11.faer_mul(t01).faer_sub(t11.faer_mul(s01));
+    let (cz, sz) = rotation(g, f, epsilon, zero_threshold);
+    let sz = sz.faer_neg().faer_conj();
+
+    rotate_cols(s.rb_mut(), k, k + 1, cz, sz);
+    rotate_cols(t.rb_mut(), k, k + 1, cz, sz);
+    if let Some(z) = z {
+        rotate_cols(z, k, k + 1, cz, sz);
+    }
+
+    // the left rotation restores the triangular structure, using the better conditioned factor
+    let (cq, sq) = if s11.faer_abs() >= t11.faer_abs() {
+        rotation(s.read(k, k), s.read(k + 1, k), epsilon, zero_threshold)
+    } else {
+        rotation(t.read(k, k), t.read(k + 1, k), epsilon, zero_threshold)
+    };
+
+    rotate_rows(s.rb_mut(), k, k + 1, cq, sq);
+    rotate_rows(t.rb_mut(), k, k + 1, cq, sq);
+    if let Some(q) = q {
+        rotate_cols(q, k, k + 1, cq, sq.faer_conj());
+    }
+
+    s.write(k + 1, k, E::faer_zero());
+    t.write(k + 1, k, E::faer_zero());
+}
+
+/// Reorders the complex generalized Schur decomposition $(A, B) = (Q S Z^H, Q T Z^H)$, so that the
+/// generalized eigenvalues $(\alpha, \beta) = (S_{kk}, T_{kk})$ for which `select` returns `true`
+/// appear in the leading diagonal blocks of `s` and `t`, while preserving their relative order.
+/// The first columns of `z` then span the corresponding right deflating subspace.
+///
+/// Returns the number of selected eigenvalues.
+///
+/// # Panics
+/// Panics if `s` and `t` don't have the same square shape, or if `q` or `z` is `Some(..)` and
+/// doesn't have the same number of columns as `s`.
+#[track_caller]
+pub fn reorder_generalized_schur_complex<E: ComplexField>(
+    s: MatMut<'_, E>,
+    t: MatMut<'_, E>,
+    q: Option<MatMut<'_, E>>,
+    z: Option<MatMut<'_, E>>,
+    select: impl FnMut(E, E) -> bool,
+) -> usize {
+    let n = s.nrows();
+    assert!(all(s.ncols() == n, t.nrows() == n, t.ncols() == n));
+    if let Some(q) = q.rb() {
+        assert!(q.ncols() == n);
+    }
+    if let Some(z) = z.rb() {
+        assert!(z.ncols() == n);
+    }
+
+    let epsilon = E::Real::faer_epsilon().unwrap();
+    let zero_threshold = E::Real::faer_zero_threshold().unwrap();
+
+    let mut s = s;
+    let mut t = t;
+    let mut q = q;
+    let mut z = z;
+    let mut select = select;
+
+    let mut count = 0usize;
+    for k in 0..n {
+        if select(s.read(k, k), t.read(k, k)) {
+            for i in (count..k).rev() {
+                swap_generalized_schur_complex(
+                    s.rb_mut(),
+                    t.rb_mut(),
+                    q.rb_mut(),
+                    z.rb_mut(),
+                    i,
+                    epsilon,
+                    zero_threshold,
+                );
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use faer_core::{assert, c64, Mat};
+
+    macro_rules! make_stack {
+        ($req: expr) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_schur() {
+        for n in [1, 2, 5, 10, 40] {
+            let mat = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+
+            let mut t = Mat::zeros(n, n);
+            let mut z = Mat::zeros(n, n);
+            compute_schur_complex(
+                mat.as_ref(),
+                t.as_mut(),
+                z.as_mut(),
+                Parallelism::None,
+                make_stack!(compute_schur_complex_req::<c64>(
+                    n,
+                    Parallelism::None,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+
+            let reconstructed = &z * &t * z.adjoint();
+            let zz = z.adjoint() * &z;
+            for j in 0..n {
+                for i in 0..n {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                    let id = if i == j { 1.0 } else { 0.0 };
+                    assert_approx_eq!(zz.read(i, j), c64::new(id, 0.0), 1e-10);
+                    if i > j {
+                        assert!(t.read(i, j) == c64::new(0.0, 0.0));
+                    }
+                }
+            }
+
+            let count = reorder_schur_complex(t.as_mut(), Some(z.as_mut()), |x: c64| x.re < 0.5);
+            for k in 0..n {
+                assert!((t.read(k, k).re < 0.5) == (k < count));
+            }
+            let reconstructed = &z * &t * z.adjoint();
+            for j in 0..n {
+                for i in 0..n {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                    if i > j {
+                        assert!(t.read(i, j) == c64::new(0.0, 0.0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generalized_schur() {
+        for n in [1, 2, 5, 10, 40] {
+            for singular in [false, true] {
+                let a = Mat::from_fn(n, n, |_, _| c64::new(rand::random(), rand::random()));
+                let b = Mat::from_fn(n, n, |_, j| {
+                    if singular && j == n / 2 {
+                        c64::new(0.0, 0.0)
+                    } else {
+                        c64::new(rand::random(), rand::random())
+                    }
+                });
+
+                let mut s = Mat::zeros(n, n);
+                let mut t = Mat::zeros(n, n);
+                let mut q = Mat::zeros(n, n);
+                let mut z = Mat::zeros(n, n);
+                assert!(compute_generalized_schur_complex(
+                    a.as_ref(),
+                    b.as_ref(),
+                    s.as_mut(),
+                    t.as_mut(),
+                    q.as_mut(),
+                    z.as_mut(),
+                ));
+
+                let check = |s: &Mat<c64>, t: &Mat<c64>, q: &Mat<c64>, z: &Mat<c64>| {
+                    let a_reconstructed = q * s * z.adjoint();
+                    let b_reconstructed = q * t * z.adjoint();
+                    let qq = q.adjoint() * q;
+                    let zz = z.adjoint() * z;
+                    for j in 0..n {
+                        for i in 0..n {
+                            assert_approx_eq!(a_reconstructed.read(i, j), a.read(i, j), 1e-10);
+                            assert_approx_eq!(b_reconstructed.read(i, j), b.read(i, j), 1e-10);
+                            let id = if i == j { 1.0 } else { 0.0 };
+                            assert_approx_eq!(qq.read(i, j), c64::new(id, 0.0), 1e-10);
+                            assert_approx_eq!(zz.read(i, j), c64::new(id, 0.0), 1e-10);
+                            if i > j {
+                                assert!(s.read(i, j) == c64::new(0.0, 0.0));
+                                assert!(t.read(i, j) == c64::new(0.0, 0.0));
+                            }
+                        }
+                    }
+                };
+                check(&s, &t, &q, &z);
+
+                if singular {
+                    assert!((0..n).any(|k| t.read(k, k).faer_abs() < 1e-10));
+                }
+
+                let is_stable = |alpha: c64, beta: c64| alpha.faer_abs() < beta.faer_abs();
+                let count = reorder_generalized_schur_complex(
+                    s.as_mut(),
+                    t.as_mut(),
+                    Some(q.as_mut()),
+                    Some(z.as_mut()),
+                    is_stable,
+                );
+                for k in 0..n {
+                    assert!(is_stable(s.read(k, k), t.read(k, k)) == (k < count));
+                }
+                check(&s, &t, &q, &z);
+            }
+        }
+    }
+}
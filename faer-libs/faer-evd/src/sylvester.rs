@@ -0,0 +1,1153 @@
+//! Solvers for the Sylvester equation
+//! $$AX + XB = C,$$
+//! the continuous Lyapunov equation
+//! $$AX + XA^H + Q = 0,$$
+//! and the discrete Lyapunov equation
+//! $$AXA^H - X + Q = 0.$$
+//!
+//! The equations are solved with the Bartels-Stewart algorithm: the coefficient matrices are
+//! reduced to complex Schur form (see [`crate::schur`]), the resulting triangular equation is
+//! solved by substitution, and the solution is transformed back. Real-valued equations are
+//! converted to complex ones, and the real part of the complex solution is returned.
+//!
+//! When the right-hand side of the continuous Lyapunov equation is given in factored form
+//! $Q = BB^H$, and $A$ is stable, a factor $L$ of the solution $X = LL^H$ can be computed
+//! directly with Hammarling's method, without forming $X$.
+//!
+//! The solution of the Sylvester equation is unique if and only if $A$ and $-B$ have no common
+//! eigenvalues. The solution of the continuous (resp. discrete) Lyapunov equation is unique if and
+//! only if no two eigenvalues $\lambda_i$, $\lambda_j$ of $A$ satisfy
+//! $\lambda_i + \bar\lambda_j = 0$ (resp. $\lambda_i \bar\lambda_j = 1$). If this condition is
+//! violated, the near-singular pivots are perturbed to avoid division by zero, and the computed
+//! solution may be inaccurate. The number of perturbed pivots is reported in the returned
+//! [`SylvesterInfo`].
+
+use crate::{
+    schur::{compute_schur_complex, compute_schur_complex_req},
+    EvdParams,
+};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    assert, mul::matmul, temp_mat_req, temp_mat_uninit, temp_mat_zeroed, ComplexField, MatMut,
+    MatRef, Parallelism, RealField,
+};
+use num_complex::Complex;
+use reborrow::*;
+
+/// Information about the solution of a Sylvester or Lyapunov equation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SylvesterInfo {
+    /// Number of near-singular pivots that were perturbed during the triangular solves. A nonzero
+    /// value means that the equation is singular or close to singular, in which case the computed
+    /// solution may be inaccurate.
+    pub perturbed_pivot_count: usize,
+}
+
+/// Number of columns below which the triangular Sylvester solver is no longer split.
+const SYLVESTER_BLOCKSIZE: usize = 32;
+
+fn max<E: RealField>(a: E, b: E) -> E {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Solves $(\alpha T + \beta I) x = \text{rhs}$ in place, where $T$ is upper triangular.
+///
+/// Pivots with a magnitude smaller than `small` are replaced with `small`, and the number of
+/// replaced pivots is returned.
+fn solve_shifted_upper_triangular_in_place<E: ComplexField>(
+    t: MatRef<'_, E>,
+    alpha: E,
+    beta: E,
+    x: MatMut<'_, E>,
+    small: E::Real,
+) -> usize {
+    let n = t.nrows();
+    let mut x = x;
+    let mut perturbed = 0usize;
+
+    for i in (0..n).rev() {
+        let mut pivot = alpha.faer_mul(t.read(i, i)).faer_add(beta);
+        if pivot.faer_abs() < small {
+            pivot = E::faer_from_real(small);
+            perturbed += 1;
+        }
+        let xi = x.read(i, 0).faer_mul(pivot.faer_inv());
+        x.write(i, 0, xi);
+
+        let alpha_xi = alpha.faer_mul(xi);
+        for k in 0..i {
+            x.write(k, 0, x.read(k, 0).faer_sub(t.read(k, i).faer_mul(alpha_xi)));
+        }
+    }
+    perturbed
+}
+
+/// Solves $AY + YB = C$ in place, where $A$ and $B$ are upper triangular, and returns the number
+/// of perturbed pivots.
+fn triangular_sylvester<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    c: MatMut<'_, E>,
+    small: E::Real,
+    parallelism: Parallelism,
+) -> usize {
+    let n = b.nrows();
+    let mut c = c;
+
+    if n <= SYLVESTER_BLOCKSIZE {
+        let mut perturbed = 0usize;
+        for k in 0..n {
+            let (solved, mut rest) = c.rb_mut().split_at_col_mut(k);
+            let mut ck = rest.rb_mut().subcols_mut(0, 1);
+            if k > 0 {
+                matmul(
+                    ck.rb_mut(),
+                    solved.rb(),
+                    b.submatrix(0, k, k, 1),
+                    Some(E::faer_one()),
+                    E::faer_one().faer_neg(),
+                    parallelism,
+                );
+            }
+            perturbed +=
+                solve_shifted_upper_triangular_in_place(a, E::faer_one(), b.read(k, k), ck, small);
+        }
+        perturbed
+    } else {
+        let n1 = n / 2;
+        let n2 = n - n1;
+        let (mut c1, mut c2) = c.split_at_col_mut(n1);
+
+        let perturbed = triangular_sylvester(
+            a,
+            b.submatrix(0, 0, n1, n1),
+            c1.rb_mut(),
+            small,
+            parallelism,
+        );
+        matmul(
+            c2.rb_mut(),
+            c1.rb(),
+            b.submatrix(0, n1, n1, n2),
+            Some(E::faer_one()),
+            E::faer_one().faer_neg(),
+            parallelism,
+        );
+        perturbed + triangular_sylvester(a, b.submatrix(n1, n1, n2, n2), c2, small, parallelism)
+    }
+}
+
+/// Solves $TYT^H - Y + F = 0$ in place, where $T$ is upper triangular, and returns the number of
+/// perturbed pivots.
+fn triangular_stein<E: ComplexField>(
+    t: MatRef<'_, E>,
+    f: MatMut<'_, E>,
+    small: E::Real,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> usize {
+    let n = t.nrows();
+    let mut y = f;
+    let mut perturbed = 0usize;
+
+    let (mut r, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut w, _) = temp_mat_uninit::<E>(n, 1, stack);
+    let mut r = r.as_mut();
+    let mut w = w.as_mut();
+
+    // column j of TYT^H is T (conj(t_jj) y_j + r_j), with r_j = sum_{l > j} conj(t_jl) y_l, so
+    // (conj(t_jj) T - I) y_j = -f_j - T r_j
+    for j in (0..n).rev() {
+        let (left, right) = y.rb_mut().split_at_col_mut(j + 1);
+        let mut yj = left.subcols_mut(j, 1);
+
+        matmul(
+            r.rb_mut(),
+            right.rb(),
+            t.submatrix(j, j + 1, 1, n - j - 1).adjoint(),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        matmul(w.rb_mut(), t, r.rb(), None, E::faer_one(), parallelism);
+        for i in 0..n {
+            yj.write(i, 0, yj.read(i, 0).faer_add(w.read(i, 0)).faer_neg());
+        }
+
+        perturbed += solve_shifted_upper_triangular_in_place(
+            t,
+            t.read(j, j).faer_conj(),
+            E::faer_one().faer_neg(),
+            yj,
+            small,
+        );
+    }
+    perturbed
+}
+
+fn pivot_threshold<E: ComplexField>(a: MatRef<'_, E>, b: MatRef<'_, E>) -> E::Real {
+    let epsilon = E::Real::faer_epsilon().unwrap();
+    let zero_threshold = E::Real::faer_zero_threshold().unwrap();
+    max(
+        epsilon.faer_mul(max(a.norm_max(), b.norm_max())),
+        zero_threshold,
+    )
+}
+
+/// Copies the real-valued matrix `src` to the complex matrix `dst`.
+fn real_to_complex<E: ComplexField>(dst: MatMut<'_, Complex<E::Real>>, src: MatRef<'_, E>) {
+    let mut dst = dst;
+    for j in 0..src.ncols() {
+        for i in 0..src.nrows() {
+            dst.write(
+                i,
+                j,
+                Complex::new(src.read(i, j).faer_real(), E::Real::faer_zero()),
+            );
+        }
+    }
+}
+
+/// Copies the real part of the complex matrix `src` to `dst`.
+fn complex_to_real<E: ComplexField>(dst: MatMut<'_, E>, src: MatRef<'_, Complex<E::Real>>) {
+    let mut dst = dst;
+    for j in 0..src.ncols() {
+        for i in 0..src.nrows() {
+            dst.write(i, j, E::faer_from_real(src.read(i, j).re));
+        }
+    }
+}
+
+fn solve_sylvester_complex_req<E: ComplexField>(
+    m: usize,
+    n: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<E>(m, m)?,
+        temp_mat_req::<E>(m, m)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(m, n)?,
+        StackReq::try_any_of([
+            compute_schur_complex_req::<E>(m, parallelism, params)?,
+            compute_schur_complex_req::<E>(n, parallelism, params)?,
+        ])?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for solving the Sylvester equation, with
+/// $A$ of shape `(m, m)` and $B$ of shape `(n, n)`.
+pub fn solve_sylvester_req<E: ComplexField>(
+    m: usize,
+    n: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    if coe::is_same::<E, E::Real>() {
+        StackReq::try_all_of([
+            temp_mat_req::<Complex<E::Real>>(m, m)?,
+            temp_mat_req::<Complex<E::Real>>(n, n)?,
+            temp_mat_req::<Complex<E::Real>>(m, n)?,
+            solve_sylvester_complex_req::<Complex<E::Real>>(m, n, parallelism, params)?,
+        ])
+    } else {
+        solve_sylvester_complex_req::<E>(m, n, parallelism, params)
+    }
+}
+
+/// Solves the Sylvester equation $AX + XB = C$, where `c` contains $C$ on input, and is
+/// overwritten with the solution $X$.
+///
+/// # Panics
+/// Panics if `a` and `b` are not square, or if `c` doesn't have shape `(a.nrows(), b.nrows())`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`solve_sylvester_req`]).
+#[track_caller]
+pub fn solve_sylvester<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    c: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> SylvesterInfo {
+    let m = a.nrows();
+    let n = b.nrows();
+    assert!(all(
+        a.ncols() == m,
+        b.ncols() == n,
+        c.nrows() == m,
+        c.ncols() == n,
+    ));
+
+    let perturbed_pivot_count = if coe::is_same::<E, E::Real>() {
+        let (mut a_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(m, m, stack);
+        let (mut b_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(n, n, stack);
+        let (mut c_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(m, n, stack);
+        let mut a_cplx = a_cplx.as_mut();
+        let mut b_cplx = b_cplx.as_mut();
+        let mut c_cplx = c_cplx.as_mut();
+
+        real_to_complex(a_cplx.rb_mut(), a);
+        real_to_complex(b_cplx.rb_mut(), b);
+        real_to_complex(c_cplx.rb_mut(), c.rb());
+        let perturbed = solve_sylvester_complex(
+            a_cplx.rb(),
+            b_cplx.rb(),
+            c_cplx.rb_mut(),
+            parallelism,
+            stack,
+            params,
+        );
+        complex_to_real(c, c_cplx.rb());
+        perturbed
+    } else {
+        solve_sylvester_complex(a, b, c, parallelism, stack, params)
+    };
+
+    SylvesterInfo {
+        perturbed_pivot_count,
+    }
+}
+
+fn solve_sylvester_complex<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    c: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> usize {
+    let m = a.nrows();
+    let n = b.nrows();
+
+    if m == 0 || n == 0 {
+        return 0;
+    }
+
+    let mut c = c;
+
+    let (mut ta, stack) = temp_mat_uninit::<E>(m, m, stack);
+    let (mut za, stack) = temp_mat_uninit::<E>(m, m, stack);
+    let (mut tb, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut zb, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut tmp, mut stack) = temp_mat_uninit::<E>(m, n, stack);
+    let mut ta = ta.as_mut();
+    let mut za = za.as_mut();
+    let mut tb = tb.as_mut();
+    let mut zb = zb.as_mut();
+    let mut tmp = tmp.as_mut();
+
+    compute_schur_complex(
+        a,
+        ta.rb_mut(),
+        za.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+    compute_schur_complex(
+        b,
+        tb.rb_mut(),
+        zb.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    // F = Za^H C Zb
+    matmul(
+        tmp.rb_mut(),
+        za.rb().adjoint(),
+        c.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    matmul(
+        c.rb_mut(),
+        tmp.rb(),
+        zb.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+
+    let small = pivot_threshold(ta.rb(), tb.rb());
+    let perturbed = triangular_sylvester(ta.rb(), tb.rb(), c.rb_mut(), small, parallelism);
+
+    // X = Za Y Zb^H
+    matmul(
+        tmp.rb_mut(),
+        za.rb(),
+        c.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    matmul(
+        c,
+        tmp.rb(),
+        zb.rb().adjoint(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    perturbed
+}
+
+/// Computes the size and alignment of required workspace for solving the continuous or discrete
+/// Lyapunov equation, with $A$ of shape `(n, n)`.
+pub fn solve_lyapunov_req<E: ComplexField>(
+    n: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    if coe::is_same::<E, E::Real>() {
+        StackReq::try_all_of([
+            temp_mat_req::<Complex<E::Real>>(n, n)?,
+            temp_mat_req::<Complex<E::Real>>(n, n)?,
+            solve_lyapunov_complex_req::<Complex<E::Real>>(n, parallelism, params)?,
+        ])
+    } else {
+        solve_lyapunov_complex_req::<E>(n, parallelism, params)
+    }
+}
+
+fn solve_lyapunov_complex_req<E: ComplexField>(
+    n: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        StackReq::try_any_of([
+            compute_schur_complex_req::<E>(n, parallelism, params)?,
+            // triangular_stein
+            StackReq::try_all_of([temp_mat_req::<E>(n, 1)?, temp_mat_req::<E>(n, 1)?])?,
+        ])?,
+    ])
+}
+
+/// Shared implementation of the continuous and discrete Lyapunov solvers.
+#[track_caller]
+fn solve_lyapunov_impl<E: ComplexField>(
+    a: MatRef<'_, E>,
+    q: MatMut<'_, E>,
+    discrete: bool,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> SylvesterInfo {
+    let n = a.nrows();
+    assert!(all(a.ncols() == n, q.nrows() == n, q.ncols() == n));
+
+    let perturbed_pivot_count = if coe::is_same::<E, E::Real>() {
+        let (mut a_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(n, n, stack);
+        let (mut q_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(n, n, stack);
+        let mut a_cplx = a_cplx.as_mut();
+        let mut q_cplx = q_cplx.as_mut();
+
+        real_to_complex(a_cplx.rb_mut(), a);
+        real_to_complex(q_cplx.rb_mut(), q.rb());
+        let perturbed = solve_lyapunov_complex(
+            a_cplx.rb(),
+            q_cplx.rb_mut(),
+            discrete,
+            parallelism,
+            stack,
+            params,
+        );
+        complex_to_real(q, q_cplx.rb());
+        perturbed
+    } else {
+        solve_lyapunov_complex(a, q, discrete, parallelism, stack, params)
+    };
+
+    SylvesterInfo {
+        perturbed_pivot_count,
+    }
+}
+
+fn solve_lyapunov_complex<E: ComplexField>(
+    a: MatRef<'_, E>,
+    q: MatMut<'_, E>,
+    discrete: bool,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> usize {
+    let n = a.nrows();
+
+    if n == 0 {
+        return 0;
+    }
+
+    let mut q = q;
+
+    let (mut t, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut z, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut tmp, mut stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut t = t.as_mut();
+    let mut z = z.as_mut();
+    let mut tmp = tmp.as_mut();
+
+    compute_schur_complex(
+        a,
+        t.rb_mut(),
+        z.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    // F = Z^H Q Z
+    matmul(
+        tmp.rb_mut(),
+        z.rb().adjoint(),
+        q.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    matmul(
+        q.rb_mut(),
+        tmp.rb(),
+        z.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+
+    let small = pivot_threshold(t.rb(), t.rb());
+    let perturbed = if discrete {
+        triangular_stein(t.rb(), q.rb_mut(), small, parallelism, stack.rb_mut())
+    } else {
+        // TY + YT^H = -F. T^H is lower triangular, so we reverse the order of its rows and
+        // columns to make it upper triangular, and reverse the columns of Y accordingly
+        for j in 0..n {
+            for i in 0..n {
+                q.write(i, j, q.read(i, j).faer_neg());
+                tmp.write(i, j, t.read(j, i).faer_conj());
+            }
+        }
+        triangular_sylvester(
+            t.rb(),
+            tmp.rb().reverse_rows_and_cols(),
+            q.rb_mut().reverse_cols_mut(),
+            small,
+            parallelism,
+        )
+    };
+
+    // X = Z Y Z^H
+    matmul(
+        tmp.rb_mut(),
+        z.rb(),
+        q.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    matmul(
+        q,
+        tmp.rb(),
+        z.rb().adjoint(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    perturbed
+}
+
+/// Solves the continuous Lyapunov equation $AX + XA^H + Q = 0$, where `q` contains $Q$ on input,
+/// and is overwritten with the solution $X$.
+///
+/// # Panics
+/// Panics if `a` and `q` are not square matrices with the same dimension.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`solve_lyapunov_req`]).
+#[track_caller]
+pub fn solve_continuous_lyapunov<E: ComplexField>(
+    a: MatRef<'_, E>,
+    q: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> SylvesterInfo {
+    solve_lyapunov_impl(a, q, false, parallelism, stack, params)
+}
+
+/// Solves the discrete Lyapunov equation $AXA^H - X + Q = 0$, where `q` contains $Q$ on input, and
+/// is overwritten with the solution $X$.
+///
+/// # Panics
+/// Panics if `a` and `q` are not square matrices with the same dimension.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`solve_lyapunov_req`]).
+#[track_caller]
+pub fn solve_discrete_lyapunov<E: ComplexField>(
+    a: MatRef<'_, E>,
+    q: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> SylvesterInfo {
+    solve_lyapunov_impl(a, q, true, parallelism, stack, params)
+}
+
+/// Computes the size and alignment of required workspace for computing the factored solution of
+/// the continuous Lyapunov equation, with $A$ of shape `(n, n)` and $B$ of shape `(n, p)`.
+pub fn solve_continuous_lyapunov_factored_req<E: ComplexField>(
+    n: usize,
+    p: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    if coe::is_same::<E, E::Real>() {
+        let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(2 * n, n);
+        StackReq::try_all_of([
+            temp_mat_req::<Complex<E::Real>>(n, n)?,
+            temp_mat_req::<Complex<E::Real>>(n, p)?,
+            temp_mat_req::<Complex<E::Real>>(n, n)?,
+            StackReq::try_any_of([
+                solve_continuous_lyapunov_factored_complex_req::<Complex<E::Real>>(
+                    n,
+                    p,
+                    parallelism,
+                    params,
+                )?,
+                StackReq::try_all_of([
+                    temp_mat_req::<E>(2 * n, n)?,
+                    temp_mat_req::<E>(blocksize, n)?,
+                    faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
+                        2 * n,
+                        n,
+                        blocksize,
+                        parallelism,
+                        Default::default(),
+                    )?,
+                ])?,
+            ])?,
+        ])
+    } else {
+        solve_continuous_lyapunov_factored_complex_req::<E>(n, p, parallelism, params)
+    }
+}
+
+fn solve_continuous_lyapunov_factored_complex_req<E: ComplexField>(
+    n: usize,
+    p: usize,
+    parallelism: Parallelism,
+    params: EvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    let size = Ord::min(n, p);
+    let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(p, n);
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(p, n)?,
+        temp_mat_req::<E>(n, 1)?,
+        StackReq::try_any_of([
+            compute_schur_complex_req::<E>(n, parallelism, params)?,
+            StackReq::try_all_of([
+                temp_mat_req::<E>(blocksize, size)?,
+                faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
+                    p,
+                    n,
+                    blocksize,
+                    parallelism,
+                    Default::default(),
+                )?,
+            ])?,
+        ])?,
+    ])
+}
+
+/// Computes a factor $L$ of the solution $X = LL^H$ of the continuous Lyapunov equation
+/// $AX + XA^H + BB^H = 0$ using Hammarling's method, and stores it in `l`.
+///
+/// All the eigenvalues of $A$ must have a negative real part, in which case $X$ is self-adjoint
+/// positive semidefinite. If `E` is real-valued, the computed factor $L$ is real and lower
+/// triangular.
+///
+/// # Panics
+/// Panics if `a` and `l` are not square matrices with the same dimension, or if `b` doesn't have
+/// the same number of rows as `a`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`solve_continuous_lyapunov_factored_req`]).
+#[track_caller]
+pub fn solve_continuous_lyapunov_factored<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    l: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> SylvesterInfo {
+    let n = a.nrows();
+    let p = b.ncols();
+    assert!(all(
+        a.ncols() == n,
+        b.nrows() == n,
+        l.nrows() == n,
+        l.ncols() == n,
+    ));
+
+    let perturbed_pivot_count = if coe::is_same::<E, E::Real>() {
+        let mut l = l;
+
+        let (mut a_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(n, n, stack);
+        let (mut b_cplx, stack) = temp_mat_uninit::<Complex<E::Real>>(n, p, stack);
+        let (mut l_cplx, mut stack) = temp_mat_uninit::<Complex<E::Real>>(n, n, stack);
+        let mut a_cplx = a_cplx.as_mut();
+        let mut b_cplx = b_cplx.as_mut();
+        let mut l_cplx = l_cplx.as_mut();
+
+        real_to_complex(a_cplx.rb_mut(), a);
+        real_to_complex(b_cplx.rb_mut(), b);
+        let perturbed = solve_continuous_lyapunov_factored_complex(
+            a_cplx.rb(),
+            b_cplx.rb(),
+            l_cplx.rb_mut(),
+            parallelism,
+            stack.rb_mut(),
+            params,
+        );
+
+        // X = Re(L L^H) = Re(L) Re(L)^T + Im(L) Im(L)^T = W^T W, with W = [Re(L)^T; Im(L)^T].
+        // if W = QR, then X = R^T R, so R^T is a real factor of X
+        let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(2 * n, n);
+        let (mut w, mut stack) = temp_mat_uninit::<E>(2 * n, n, stack);
+        let mut w = w.as_mut();
+        for j in 0..n {
+            for i in 0..n {
+                let x = l_cplx.read(i, j);
+                w.write(j, i, E::faer_from_real(x.re));
+                w.write(n + j, i, E::faer_from_real(x.im));
+            }
+        }
+        if n > 0 {
+            let (mut householder, mut stack) = temp_mat_uninit::<E>(blocksize, n, stack.rb_mut());
+            faer_qr::no_pivoting::compute::qr_in_place(
+                w.rb_mut(),
+                householder.as_mut(),
+                parallelism,
+                stack.rb_mut(),
+                Default::default(),
+            );
+        }
+        for j in 0..n {
+            for i in 0..n {
+                l.write(i, j, if j <= i { w.read(j, i) } else { E::faer_zero() });
+            }
+        }
+        perturbed
+    } else {
+        solve_continuous_lyapunov_factored_complex(a, b, l, parallelism, stack, params)
+    };
+
+    SylvesterInfo {
+        perturbed_pivot_count,
+    }
+}
+
+fn solve_continuous_lyapunov_factored_complex<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    l: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: EvdParams,
+) -> usize {
+    let n = a.nrows();
+    let p = b.ncols();
+
+    if n == 0 {
+        return 0;
+    }
+
+    let mut l = l;
+    let size = Ord::min(n, p);
+
+    let (mut t, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut z, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut g, stack) = temp_mat_zeroed::<E>(n, n, stack);
+    let (mut u, stack) = temp_mat_zeroed::<E>(n, n, stack);
+    let (mut w, stack) = temp_mat_uninit::<E>(p, n, stack);
+    let (mut y, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let mut t = t.as_mut();
+    let mut z = z.as_mut();
+    let mut g = g.as_mut();
+    let mut u = u.as_mut();
+    let mut w = w.as_mut();
+    let mut y = y.as_mut();
+
+    compute_schur_complex(
+        a,
+        t.rb_mut(),
+        z.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    // W = (Z^H B)^H P, where P reverses the order of the columns.
+    // if W = QR, then Z^H B B^H Z = P R^H R P = G G^H, with G = P R^H P upper triangular
+    matmul(
+        w.rb_mut().reverse_cols_mut(),
+        b.adjoint(),
+        z.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    if size > 0 {
+        let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(p, n);
+        let (mut householder, mut stack) = temp_mat_uninit::<E>(blocksize, size, stack.rb_mut());
+        faer_qr::no_pivoting::compute::qr_in_place(
+            w.rb_mut(),
+            householder.as_mut(),
+            parallelism,
+            stack.rb_mut(),
+            Default::default(),
+        );
+    }
+    for j in 0..n {
+        for i in 0..=j {
+            let (ri, rj) = (n - 1 - j, n - 1 - i);
+            if ri < size {
+                g.write(i, j, w.read(ri, rj).faer_conj());
+            }
+        }
+    }
+
+    let small = pivot_threshold(t.rb(), t.rb());
+    let mut perturbed = 0usize;
+
+    // process the trailing diagonal entry of the remaining leading block, then reduce the
+    // problem to the leading block of size k
+    for k in (0..n).rev() {
+        let tau = t.read(k, k);
+        let gamma = g.read(k, k);
+
+        let minus_two_re_tau = tau.faer_real().faer_add(tau.faer_real()).faer_neg();
+        let nu = gamma
+            .faer_abs()
+            .faer_mul(minus_two_re_tau.faer_sqrt().faer_inv());
+        u.write(k, k, E::faer_from_real(nu));
+
+        let mut y = y.rb_mut().subrows_mut(0, k);
+        y.copy_from(g.rb().submatrix(0, k, k, 1));
+
+        if nu > E::Real::faer_zero() {
+            // (T1 + conj(tau) I) u = -nu t - conj(gamma) / nu g
+            let nu_inv = nu.faer_inv();
+            let mut uk = u.rb_mut().submatrix_mut(0, k, k, 1);
+            let coeff = gamma.faer_conj().faer_scale_real(nu_inv);
+            for i in 0..k {
+                uk.write(
+                    i,
+                    0,
+                    t.read(i, k)
+                        .faer_scale_real(nu)
+                        .faer_add(coeff.faer_mul(g.read(i, k)))
+                        .faer_neg(),
+                );
+            }
+            perturbed += solve_shifted_upper_triangular_in_place(
+                t.rb().submatrix(0, 0, k, k),
+                E::faer_one(),
+                tau.faer_conj(),
+                uk.rb_mut(),
+                small,
+            );
+
+            // y = g - gamma / nu u
+            let coeff = gamma.faer_scale_real(nu_inv);
+            for i in 0..k {
+                y.write(i, 0, y.read(i, 0).faer_sub(coeff.faer_mul(uk.read(i, 0))));
+            }
+        }
+
+        // G1 G1^H + y y^H = G1' G1'^H, where G1' is upper triangular, computed by annihilating
+        // the entries of y from the bottom with Givens rotations
+        for i in (0..k).rev() {
+            let a = g.read(i, i);
+            let b = y.read(i, 0);
+            let r = a.faer_abs2().faer_add(b.faer_abs2()).faer_sqrt();
+            if r == E::Real::faer_zero() {
+                continue;
+            }
+            let r_inv = r.faer_inv();
+            let a_ = a.faer_conj().faer_scale_real(r_inv);
+            let b_ = b.faer_conj().faer_scale_real(r_inv);
+            let a = a.faer_scale_real(r_inv);
+            let b = b.faer_scale_real(r_inv);
+            for row in 0..=i {
+                let gi = g.read(row, i);
+                let yi = y.read(row, 0);
+                g.write(row, i, a_.faer_mul(gi).faer_add(b_.faer_mul(yi)));
+                y.write(row, 0, a.faer_mul(yi).faer_sub(b.faer_mul(gi)));
+            }
+        }
+    }
+
+    // L = Z U
+    matmul(l.rb_mut(), z.rb(), u.rb(), None, E::faer_one(), parallelism);
+    perturbed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use faer_core::{assert, c64, Mat};
+
+    macro_rules! make_stack {
+        ($req: expr) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    fn random_mat(m: usize, n: usize) -> Mat<c64> {
+        Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()))
+    }
+
+    // shifts the diagonal of a random matrix to make it stable
+    fn stable_mat(n: usize) -> Mat<c64> {
+        let mut a = random_mat(n, n);
+        for i in 0..n {
+            a.write(i, i, a.read(i, i) - c64::new(n as f64, 0.0));
+        }
+        a
+    }
+
+    fn assert_mat_approx_eq(a: &Mat<c64>, b: &Mat<c64>) {
+        for j in 0..a.ncols() {
+            for i in 0..a.nrows() {
+                assert_approx_eq!(a.read(i, j), b.read(i, j), 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sylvester() {
+        for (m, n) in [(1, 1), (3, 5), (10, 4), (50, 70)] {
+            let a = random_mat(m, m);
+            let b = stable_mat(n);
+            let c = random_mat(m, n);
+
+            // shift A so that A and -B have no common eigenvalues
+            let mut a = a;
+            for i in 0..m {
+                a.write(i, i, a.read(i, i) + c64::new(m as f64, 0.0));
+            }
+
+            let mut x = c.clone();
+            solve_sylvester(
+                a.as_ref(),
+                b.as_ref(),
+                x.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_sylvester_req::<c64>(
+                    m,
+                    n,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            assert_mat_approx_eq(&(&a * &x + &x * &b), &c);
+        }
+    }
+
+    #[test]
+    fn test_lyapunov() {
+        for n in [1, 4, 10, 45] {
+            let a = stable_mat(n);
+            let q = random_mat(n, n);
+
+            let mut x = q.clone();
+            solve_continuous_lyapunov(
+                a.as_ref(),
+                x.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_lyapunov_req::<c64>(
+                    n,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            let zero = Mat::<c64>::zeros(n, n);
+            assert_mat_approx_eq(&(&a * &x + &x * a.adjoint() + &q), &zero);
+
+            // scale A so that its spectral radius is smaller than one
+            let a = Mat::from_fn(n, n, |i, j| {
+                let x = a.read(i, j);
+                c64::new(x.re / (3.0 * n as f64), x.im / (3.0 * n as f64))
+            });
+            let mut x = q.clone();
+            solve_discrete_lyapunov(
+                a.as_ref(),
+                x.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_lyapunov_req::<c64>(
+                    n,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            assert_mat_approx_eq(&(&a * &x * a.adjoint() - &x + &q), &zero);
+        }
+    }
+
+    #[test]
+    fn test_lyapunov_factored() {
+        for (n, p) in [(1, 1), (5, 2), (5, 8), (30, 3)] {
+            let a = stable_mat(n);
+            let b = random_mat(n, p);
+
+            let mut l = Mat::zeros(n, n);
+            solve_continuous_lyapunov_factored(
+                a.as_ref(),
+                b.as_ref(),
+                l.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_continuous_lyapunov_factored_req::<c64>(
+                    n,
+                    p,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            let x = &l * l.adjoint();
+            let zero = Mat::<c64>::zeros(n, n);
+            assert_mat_approx_eq(&(&a * &x + &x * a.adjoint() + &b * b.adjoint()), &zero);
+        }
+    }
+
+    fn assert_mat_approx_eq_real(a: &Mat<f64>, b: &Mat<f64>) {
+        for j in 0..a.ncols() {
+            for i in 0..a.nrows() {
+                assert_approx_eq!(a.read(i, j), b.read(i, j), 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_real() {
+        for n in [1, 4, 10, 45] {
+            let a = Mat::from_fn(n, n, |i, j| {
+                let x = rand::random::<f64>();
+                if i == j {
+                    x - n as f64
+                } else {
+                    x
+                }
+            });
+            let b = Mat::from_fn(n, 3, |_, _| rand::random::<f64>());
+            let q = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+            let zero = Mat::<f64>::zeros(n, n);
+
+            let mut x = q.clone();
+            let info = solve_sylvester(
+                a.as_ref(),
+                a.as_ref(),
+                x.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_sylvester_req::<f64>(
+                    n,
+                    n,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            assert!(info.perturbed_pivot_count == 0);
+            assert_mat_approx_eq_real(&(&a * &x + &x * &a), &q);
+
+            let mut x = q.clone();
+            let info = solve_continuous_lyapunov(
+                a.as_ref(),
+                x.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_lyapunov_req::<f64>(
+                    n,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            assert!(info.perturbed_pivot_count == 0);
+            assert_mat_approx_eq_real(&(&a * &x + &x * a.transpose() + &q), &zero);
+
+            let mut l = Mat::zeros(n, n);
+            let info = solve_continuous_lyapunov_factored(
+                a.as_ref(),
+                b.as_ref(),
+                l.as_mut(),
+                Parallelism::None,
+                make_stack!(solve_continuous_lyapunov_factored_req::<f64>(
+                    n,
+                    3,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+            assert!(info.perturbed_pivot_count == 0);
+            let x = &l * l.transpose();
+            assert_mat_approx_eq_real(&(&a * &x + &x * a.transpose() + &b * b.transpose()), &zero);
+        }
+    }
+
+    #[test]
+    fn test_singular() {
+        let n = 4;
+        // A and -B share all their eigenvalues
+        let a = Mat::<c64>::identity(n, n);
+        let b = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                c64::new(-1.0, 0.0)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        });
+        let mut x = random_mat(n, n);
+        let info = solve_sylvester(
+            a.as_ref(),
+            b.as_ref(),
+            x.as_mut(),
+            Parallelism::None,
+            make_stack!(solve_sylvester_req::<c64>(
+                n,
+                n,
+                Parallelism::None,
+                Default::default(),
+            )),
+            Default::default(),
+        );
+        assert!(info.perturbed_pivot_count > 0);
+    }
+}
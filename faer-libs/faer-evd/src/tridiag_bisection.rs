@@ -0,0 +1,444 @@
+use core::cmp::Ordering;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{MatMut, RealField};
+
+fn max<T: PartialOrd>(x: T, y: T) -> T {
+    if x > y {
+        x
+    } else {
+        y
+    }
+}
+
+fn min<T: PartialOrd>(x: T, y: T) -> T {
+    if x < y {
+        x
+    } else {
+        y
+    }
+}
+
+/// Returns the smallest allowed magnitude of a pivot in the Sturm sequence.
+fn pivot_min<E: RealField>(offdiag: &[E], zero_threshold: E) -> E {
+    let mut max_e2 = E::faer_one();
+    for &e in offdiag {
+        max_e2 = max(max_e2, e.faer_mul(e));
+    }
+    zero_threshold.faer_mul(max_e2)
+}
+
+/// Returns an interval containing all the eigenvalues of the tridiagonal matrix, as well as its
+/// norm.
+fn gershgorin_bounds<E: RealField>(diag: &[E], offdiag: &[E], epsilon: E, pivmin: E) -> (E, E, E) {
+    let n = diag.len();
+
+    let mut lo = diag[0];
+    let mut hi = diag[0];
+    for i in 0..n {
+        let mut radius = E::faer_zero();
+        if i > 0 {
+            radius = radius.faer_add(offdiag[i - 1].faer_abs());
+        }
+        if i + 1 < n {
+            radius = radius.faer_add(offdiag[i].faer_abs());
+        }
+        lo = min(lo, diag[i].faer_sub(radius));
+        hi = max(hi, diag[i].faer_add(radius));
+    }
+
+    let norm = max(lo.faer_abs(), hi.faer_abs());
+    let widen = epsilon
+        .faer_mul(norm)
+        .faer_mul(E::faer_from_f64(2.0 * n as f64))
+        .faer_add(pivmin.faer_scale_power_of_two(E::faer_from_f64(4.0)));
+
+    (lo.faer_sub(widen), hi.faer_add(widen), norm)
+}
+
+/// Returns the number of eigenvalues of the tridiagonal matrix that are strictly smaller than `x`.
+pub fn sturm_count<E: RealField>(diag: &[E], offdiag: &[E], x: E, pivmin: E) -> usize {
+    let n = diag.len();
+    let mut count = 0usize;
+
+    let mut q = diag[0].faer_sub(x);
+    if q.faer_abs() <= pivmin {
+        q = pivmin.faer_neg();
+    }
+    if q < E::faer_zero() {
+        count += 1;
+    }
+
+    for i in 1..n {
+        let e = offdiag[i - 1];
+        q = diag[i].faer_sub(x).faer_sub(e.faer_mul(e).faer_div(q));
+        if q.faer_abs() <= pivmin {
+            q = pivmin.faer_neg();
+        }
+        if q < E::faer_zero() {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Computes the size and alignment of required workspace for computing a subset of the
+/// eigenvectors of a real symmetric tridiagonal matrix.
+pub fn compute_tridiag_subset_req<E: RealField>(n: usize) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        // factors of the shifted matrix
+        StackReq::try_new::<E>(n)?,
+        StackReq::try_new::<E>(n)?,
+        StackReq::try_new::<E>(n)?,
+        StackReq::try_new::<E>(n)?,
+        // pivoting information
+        StackReq::try_new::<usize>(n)?,
+        // iteration vector
+        StackReq::try_new::<E>(n)?,
+    ])
+}
+
+/// Returns the range of eigenvalue indices, in nondecreasing order of eigenvalues, for the
+/// eigenvalues that lie in the half-open interval `[lo, hi)`.
+pub fn tridiag_index_range<E: RealField>(
+    diag: &[E],
+    offdiag: &[E],
+    lo: E,
+    hi: E,
+    zero_threshold: E,
+) -> (usize, usize) {
+    if diag.is_empty() || lo.partial_cmp(&hi) != Some(Ordering::Less) {
+        return (0, 0);
+    }
+    let pivmin = pivot_min(offdiag, zero_threshold);
+    (
+        sturm_count(diag, offdiag, lo, pivmin),
+        sturm_count(diag, offdiag, hi, pivmin),
+    )
+}
+
+/// Computes the eigenvalues of the real symmetric tridiagonal matrix with indices `lo..hi`, in
+/// nondecreasing order, using bisection on the Sturm sequence. The results are stored in
+/// `eigenvalues`, which must have length `hi - lo`.
+pub fn compute_tridiag_eigenvalues_bisection<E: RealField>(
+    diag: &[E],
+    offdiag: &[E],
+    lo: usize,
+    hi: usize,
+    eigenvalues: &mut [E],
+    epsilon: E,
+    zero_threshold: E,
+) {
+    let n = diag.len();
+    assert!(lo <= hi);
+    assert!(hi <= n);
+    assert!(eigenvalues.len() == hi - lo);
+    if n == 0 {
+        return;
+    }
+
+    let pivmin = pivot_min(offdiag, zero_threshold);
+    let (global_lo, global_hi, _) = gershgorin_bounds(diag, offdiag, epsilon, pivmin);
+
+    let half = E::faer_from_f64(0.5);
+    let two_eps = epsilon.faer_scale_power_of_two(E::faer_from_f64(2.0));
+
+    // use size_of::<E> as a proxy for the max precision of E
+    let nbits = core::mem::size_of::<E>() * 8;
+    let max_iter = 2 * nbits + 16;
+
+    let mut left = global_lo;
+    for (k, eigenvalue) in eigenvalues.iter_mut().enumerate() {
+        let index = lo + k;
+
+        // the eigenvalues are computed in nondecreasing order, so the previous one is a valid
+        // lower bound for the current one
+        let mut a = left;
+        let mut b = global_hi;
+
+        for _ in 0..max_iter {
+            let tol = two_eps
+                .faer_mul(max(a.faer_abs(), b.faer_abs()))
+                .faer_add(pivmin);
+            if b.faer_sub(a) <= tol {
+                break;
+            }
+
+            let mid = a.faer_add(b).faer_mul(half);
+            if !(mid > a && mid < b) {
+                break;
+            }
+
+            if sturm_count(diag, offdiag, mid, pivmin) > index {
+                b = mid;
+            } else {
+                a = mid;
+            }
+        }
+
+        *eigenvalue = a.faer_add(b).faer_mul(half);
+        left = a;
+    }
+}
+
+/// Computes the eigenvectors of the real symmetric tridiagonal matrix corresponding to the given
+/// eigenvalues, which must be sorted in nondecreasing order, using inverse iteration. Eigenvectors
+/// corresponding to close eigenvalues are reorthogonalized against each other.
+///
+/// The eigenvectors are stored in the columns of `u`, which must have `diag.len()` rows and
+/// `eigenvalues.len()` columns.
+///
+/// Returns `true` if the iteration converged for every eigenvector.
+pub fn compute_tridiag_eigenvectors_inverse_iteration<E: RealField>(
+    diag: &[E],
+    offdiag: &[E],
+    eigenvalues: &[E],
+    u: MatMut<'_, E>,
+    epsilon: E,
+    zero_threshold: E,
+    stack: PodStack<'_>,
+) -> bool {
+    let n = diag.len();
+    let k = eigenvalues.len();
+    let mut u = u;
+    assert!(u.nrows() == n);
+    assert!(u.ncols() == k);
+
+    if n == 0 || k == 0 {
+        return true;
+    }
+    if n == 1 {
+        u.fill(E::faer_one());
+        return true;
+    }
+
+    let pivmin = pivot_min(offdiag, zero_threshold);
+    let (_, _, norm) = gershgorin_bounds(diag, offdiag, epsilon, pivmin);
+    let norm = max(norm, zero_threshold);
+
+    // eigenvalues that are closer than this are treated as a cluster, and their eigenvectors are
+    // reorthogonalized
+    let ortho_tol = norm.faer_mul(E::faer_from_f64(1e-3));
+    // minimum separation between eigenvalues used as shifts
+    let sep = epsilon.faer_mul(E::faer_from_f64(10.0));
+    // smallest pivot allowed in the shifted factorization
+    let tiny = max(epsilon.faer_mul(norm), zero_threshold);
+    // the solution grows by roughly 1 / |shift - eigenvalue| when the iteration converges
+    let growth_tol = epsilon.faer_sqrt().faer_mul(norm).faer_inv();
+
+    let (d, stack) = stack.make_with(n, |_| E::faer_zero());
+    let (dl, stack) = stack.make_with(n, |_| E::faer_zero());
+    let (du, stack) = stack.make_with(n, |_| E::faer_zero());
+    let (du2, stack) = stack.make_with(n, |_| E::faer_zero());
+    let (swapped, stack) = stack.make_with(n, |_| 0usize);
+    let (x, _) = stack.make_with(n, |_| E::faer_zero());
+
+    const MAX_ITER: usize = 5;
+
+    let mut all_converged = true;
+    let mut cluster_start = 0usize;
+    let mut prev_shift = E::faer_zero();
+    let mut rng_state = 0x2545_f491u32;
+
+    for j in 0..k {
+        let mut shift = eigenvalues[j];
+        if j > 0 {
+            if eigenvalues[j].faer_sub(eigenvalues[j - 1]) > ortho_tol {
+                cluster_start = j;
+            }
+
+            // perturb identical shifts so that the iteration produces distinct vectors
+            let min_shift =
+                prev_shift.faer_add(sep.faer_mul(max(shift.faer_abs(), zero_threshold)));
+            if shift < min_shift {
+                shift = min_shift;
+            }
+        }
+        prev_shift = shift;
+
+        // factorize T - shift * I = P L U, where U has two superdiagonals
+        for (d, &diag) in d.iter_mut().zip(diag) {
+            *d = diag.faer_sub(shift);
+        }
+        for ((dl, du), &offdiag) in dl.iter_mut().zip(du.iter_mut()).zip(offdiag) {
+            *dl = offdiag;
+            *du = offdiag;
+        }
+        for i in 0..n - 1 {
+            if d[i].faer_abs() >= dl[i].faer_abs() {
+                swapped[i] = 0;
+                if d[i] != E::faer_zero() {
+                    let fact = dl[i].faer_div(d[i]);
+                    dl[i] = fact;
+                    d[i + 1] = d[i + 1].faer_sub(fact.faer_mul(du[i]));
+                } else {
+                    dl[i] = E::faer_zero();
+                }
+                du2[i] = E::faer_zero();
+            } else {
+                swapped[i] = 1;
+                let fact = d[i].faer_div(dl[i]);
+                d[i] = dl[i];
+                dl[i] = fact;
+                let temp = du[i];
+                du[i] = d[i + 1];
+                d[i + 1] = temp.faer_sub(fact.faer_mul(d[i + 1]));
+                if i + 2 < n {
+                    du2[i] = du[i + 1];
+                    du[i + 1] = fact.faer_neg().faer_mul(du[i + 1]);
+                } else {
+                    du2[i] = E::faer_zero();
+                }
+            }
+        }
+        for d in d.iter_mut() {
+            if d.faer_abs() < tiny {
+                *d = if *d < E::faer_zero() {
+                    tiny.faer_neg()
+                } else {
+                    tiny
+                };
+            }
+        }
+
+        // deterministic pseudo-random starting vector
+        for x in x.iter_mut() {
+            rng_state ^= rng_state << 13;
+            rng_state ^= rng_state >> 17;
+            rng_state ^= rng_state << 5;
+            *x = E::faer_from_f64((rng_state as f64) / (u32::MAX as f64) - 0.5);
+        }
+
+        let mut converged = false;
+        for _ in 0..MAX_ITER {
+            // forward substitution
+            for i in 0..n - 1 {
+                if swapped[i] == 0 {
+                    x[i + 1] = x[i + 1].faer_sub(dl[i].faer_mul(x[i]));
+                } else {
+                    let temp = x[i];
+                    x[i] = x[i + 1];
+                    x[i + 1] = temp.faer_sub(dl[i].faer_mul(x[i]));
+                }
+            }
+
+            // back substitution
+            x[n - 1] = x[n - 1].faer_div(d[n - 1]);
+            x[n - 2] = x[n - 2]
+                .faer_sub(du[n - 2].faer_mul(x[n - 1]))
+                .faer_div(d[n - 2]);
+            for i in (0..n - 2).rev() {
+                x[i] = x[i]
+                    .faer_sub(du[i].faer_mul(x[i + 1]))
+                    .faer_sub(du2[i].faer_mul(x[i + 2]))
+                    .faer_div(d[i]);
+            }
+
+            // reorthogonalize against the previous vectors of the cluster
+            for p in cluster_start..j {
+                let mut dot = E::faer_zero();
+                for (i, &x) in x.iter().enumerate() {
+                    dot = dot.faer_add(u.read(i, p).faer_mul(x));
+                }
+                for (i, x) in x.iter_mut().enumerate() {
+                    *x = x.faer_sub(dot.faer_mul(u.read(i, p)));
+                }
+            }
+
+            let mut norm2 = E::faer_zero();
+            for &x in x.iter() {
+                norm2 = norm2.faer_add(x.faer_mul(x));
+            }
+            let x_norm = norm2.faer_sqrt();
+            if x_norm.partial_cmp(&E::faer_zero()) != Some(Ordering::Greater) {
+                break;
+            }
+
+            let inv = x_norm.faer_inv();
+            for x in x.iter_mut() {
+                *x = x.faer_mul(inv);
+            }
+
+            if converged {
+                break;
+            }
+            converged = x_norm >= growth_tol;
+        }
+        all_converged &= converged;
+
+        for (i, &x) in x.iter().enumerate() {
+            u.write(i, j, x);
+        }
+    }
+
+    all_converged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use faer_core::Mat;
+
+    macro_rules! make_stack {
+        ($req: expr) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_bisection_inverse_iteration() {
+        for n in [1usize, 2, 3, 4, 5, 6, 7, 10, 15, 25] {
+            let diag = (0..n).map(|_| rand::random::<f64>()).collect::<Vec<_>>();
+            let offdiag = (0..n.saturating_sub(1))
+                .map(|_| rand::random::<f64>())
+                .collect::<Vec<_>>();
+
+            let mut eigenvalues = vec![0.0; n];
+            compute_tridiag_eigenvalues_bisection(
+                &diag,
+                &offdiag,
+                0,
+                n,
+                &mut eigenvalues,
+                f64::EPSILON,
+                f64::MIN_POSITIVE,
+            );
+
+            let mut u = Mat::<f64>::zeros(n, n);
+            let converged = compute_tridiag_eigenvectors_inverse_iteration(
+                &diag,
+                &offdiag,
+                &eigenvalues,
+                u.as_mut(),
+                f64::EPSILON,
+                f64::MIN_POSITIVE,
+                make_stack!(compute_tridiag_subset_req::<f64>(n)),
+            );
+            assert!(converged);
+
+            for j in 0..n {
+                if j > 0 {
+                    assert!(eigenvalues[j - 1] <= eigenvalues[j]);
+                }
+                for i in 0..n {
+                    let mut tu = diag[i] * u.read(i, j);
+                    if i > 0 {
+                        tu += offdiag[i - 1] * u.read(i - 1, j);
+                    }
+                    if i + 1 < n {
+                        tu += offdiag[i] * u.read(i + 1, j);
+                    }
+                    assert_approx_eq!(tu, eigenvalues[j] * u.read(i, j), 1e-10);
+                }
+                for k in 0..n {
+                    let mut dot = 0.0;
+                    for i in 0..n {
+                        dot += u.read(i, j) * u.read(i, k);
+                    }
+                    assert_approx_eq!(dot, if j == k { 1.0 } else { 0.0 }, 1e-10);
+                }
+            }
+        }
+    }
+}
@@ -13,16 +13,27 @@ use faer_core::{
 };
 use reborrow::*;
 
+use crate::EvdInfo;
+
+/// Computes the eigenvalue decomposition of a real symmetric tridiagonal matrix using the
+/// implicit QR algorithm with Wilkinson shifts, stopping after `max_iterations` QR sweeps.
+///
+/// If `max_iterations` is `None`, a default limit depending on the dimension and the precision of
+/// `E` is used. If the limit is reached, the eigenvalues are still sorted, but are not accurate.
 pub fn compute_tridiag_real_evd_qr_algorithm<E: RealField>(
     diag: &mut [E],
     offdiag: &mut [E],
     u: Option<MatMut<'_, E>>,
     epsilon: E,
     consider_zero_threshold: E,
-) {
+    max_iterations: Option<usize>,
+) -> EvdInfo {
     let n = diag.len();
     if n <= 1 {
-        return;
+        return EvdInfo {
+            iteration_count: 0,
+            converged: true,
+        };
     }
 
     let mut end = n - 1;
@@ -30,8 +41,9 @@ pub fn compute_tridiag_real_evd_qr_algorithm<E: RealField>(
 
     // use size_of::<E> as a proxy for the max precision of E
     let nbits = core::mem::size_of::<E>() * 8;
-    let max_iter = n.saturating_mul(Ord::max(30, nbits / 2));
+    let max_iter = max_iterations.unwrap_or_else(|| n.saturating_mul(Ord::max(30, nbits / 2)));
     let mut iter = 0;
+    let mut converged = true;
 
     let mut u = u;
 
@@ -63,10 +75,11 @@ pub fn compute_tridiag_real_evd_qr_algorithm<E: RealField>(
             break;
         }
 
-        iter += 1;
         if iter >= max_iter {
-            return;
+            converged = false;
+            break;
         }
+        iter += 1;
 
         start = end - 1;
         while start > 0 && offdiag[start - 1] != E::faer_zero() {
@@ -163,6 +176,11 @@ pub fn compute_tridiag_real_evd_qr_algorithm<E: RealField>(
             }
         }
     }
+
+    EvdInfo {
+        iteration_count: iter,
+        converged,
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +204,7 @@ mod tests {
                 Some(u.as_mut()),
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
             );
 
             Mat::from_fn(n, n, |i, j| if i == j { diag[i] } else { 0.0 })
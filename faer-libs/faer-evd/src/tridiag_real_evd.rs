@@ -7,6 +7,8 @@ use faer_core::{
 };
 use reborrow::*;
 
+use crate::EvdInfo;
+
 pub fn norm2<E: ComplexField>(v: MatRef<'_, E>) -> E::Real {
     inner_prod_with_conj(v, Conj::Yes, v, Conj::No).faer_real()
 }
@@ -470,15 +472,22 @@ fn compute_eigenvalues<E: RealField>(
     }
 }
 
+/// Computes the eigenvalue decomposition of a real symmetric tridiagonal matrix using the divide
+/// and conquer algorithm.
+///
+/// The small subproblems are solved with the QR algorithm, each of which is stopped after
+/// `max_iterations` QR sweeps, or a default limit if `max_iterations` is `None`. The returned
+/// [`EvdInfo`] reports the total number of sweeps, and whether every subproblem converged.
 pub fn compute_tridiag_real_evd<E: RealField>(
     diag: &mut [E],
     offdiag: &mut [E],
     u: MatMut<'_, E>,
     epsilon: E,
     consider_zero_threshold: E,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
-) {
+) -> EvdInfo {
     let n = diag.len();
     let (pl_before, stack) = stack.make_with(n, |_| 0usize);
     let (pl_after, stack) = stack.make_with(n, |_| 0usize);
@@ -499,6 +508,7 @@ pub fn compute_tridiag_real_evd<E: RealField>(
         u,
         epsilon,
         consider_zero_threshold,
+        max_iterations,
         parallelism,
         pl_before,
         pl_after,
@@ -512,7 +522,7 @@ pub fn compute_tridiag_real_evd<E: RealField>(
         shifts,
         repaired_u,
         tmp,
-    );
+    )
 }
 
 fn compute_tridiag_real_evd_impl<E: RealField>(
@@ -521,6 +531,7 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
     mut u: MatMut<'_, E>,
     epsilon: E,
     consider_zero_threshold: E,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     pl_before: &mut [usize],
     pl_after: &mut [usize],
@@ -534,13 +545,17 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
     mut shifts: MatMut<'_, E>,
     mut repaired_u: MatMut<'_, E>,
     mut tmp: MatMut<'_, E>,
-) {
+) -> EvdInfo {
     let n = diag.len();
+    let converged = EvdInfo {
+        iteration_count: 0,
+        converged: true,
+    };
 
     if n <= 1 {
         zipped!(u.rb_mut().diagonal_mut().column_vector_mut().as_2d_mut())
             .for_each(|unzipped!(mut x)| x.write(E::faer_one()));
-        return;
+        return converged;
     }
 
     if n == 2 {
@@ -613,17 +628,17 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
 
         diag[0] = r0;
         diag[1] = r1;
-        return;
+        return converged;
     }
     if n <= 32 {
-        crate::tridiag_qr_algorithm::compute_tridiag_real_evd_qr_algorithm(
+        return crate::tridiag_qr_algorithm::compute_tridiag_real_evd_qr_algorithm(
             diag,
             offdiag,
             Some(u),
             epsilon,
             consider_zero_threshold,
+            max_iterations,
         );
-        return;
     }
 
     let n1 = n / 2;
@@ -637,6 +652,8 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
     diag1[0] = diag1[0].faer_sub(rho.faer_abs());
 
     let (mut u0, _, _, mut u1) = u.rb_mut().split_at_mut(n1, n1);
+    let mut info0 = converged;
+    let mut info1 = converged;
     {
         let (pl_before0, pl_before1) = pl_before.split_at_mut(n1);
         let (pl_after0, pl_after1) = pl_after.split_at_mut(n1);
@@ -653,12 +670,13 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
 
         faer_core::join_raw(
             |parallelism| {
-                compute_tridiag_real_evd_impl(
+                info0 = compute_tridiag_real_evd_impl(
                     diag0,
                     offdiag0,
                     u0.rb_mut(),
                     epsilon,
                     consider_zero_threshold,
+                    max_iterations,
                     parallelism,
                     pl_before0,
                     pl_after0,
@@ -672,15 +690,16 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
                     shift0,
                     repaired_u0,
                     tmp0,
-                )
+                );
             },
             |parallelism| {
-                compute_tridiag_real_evd_impl(
+                info1 = compute_tridiag_real_evd_impl(
                     diag1,
                     offdiag1,
                     u1.rb_mut(),
                     epsilon,
                     consider_zero_threshold,
+                    max_iterations,
                     parallelism,
                     pl_before1,
                     pl_after1,
@@ -694,11 +713,15 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
                     shift1,
                     repaired_u1,
                     tmp1,
-                )
+                );
             },
             parallelism,
         );
     }
+    let info = EvdInfo {
+        iteration_count: info0.iteration_count + info1.iteration_count,
+        converged: info0.converged && info1.converged,
+    };
     let mut repaired_u = repaired_u.subrows_mut(0, n);
     let mut tmp = tmp.subrows_mut(0, n);
 
@@ -808,7 +831,7 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
             *diag = permuted_diag.read(j, 0);
         }
 
-        return;
+        return info;
     }
 
     for i in 0..n {
@@ -1082,6 +1105,8 @@ fn compute_tridiag_real_evd_impl<E: RealField>(
         let shift_i = shifts.read(pr[i], 0);
         diag[i] = mu_i.faer_add(shift_i);
     }
+
+    info
 }
 
 pub fn compute_tridiag_real_evd_req<E: Entity>(
@@ -1132,6 +1157,7 @@ mod tests {
                 u.as_mut(),
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_tridiag_real_evd_req::<f64>(n, Parallelism::None)),
             );
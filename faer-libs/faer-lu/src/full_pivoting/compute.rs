@@ -1400,8 +1400,21 @@ fn default_disable_parallelism(m: usize, n: usize) -> bool {
 }
 
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct FullPivLuInfo {
+    /// Number of transpositions that constitute the row and column permutations combined.
     pub transposition_count: usize,
+    /// Index of the first pivot that is zero, or whose reciprocal overflows, if any, in which case
+    /// the matrix is singular to working precision and solving a system with the factors produces
+    /// non-finite values. The pivot itself is the corresponding diagonal entry of $U$.
+    pub first_zero_pivot: Option<usize>,
+    /// Whether the factors contain NaN values, which indicates that the input contained
+    /// non-finite values, or that an overflow occurred.
+    pub nan_encountered: bool,
+    /// Ratio of the largest entry of $U$ to the largest entry of the input matrix, in absolute
+    /// value. Large values indicate that the factorization may be numerically unstable. This is
+    /// NaN if the scalar type cannot be converted to `f64`.
+    pub pivot_growth: f64,
 }
 
 /// Computes the LU decomposition of the given matrix with partial pivoting, replacing the matrix
@@ -1478,9 +1491,12 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
     let (row_transpositions, stack) = stack.make_with(size, |_| I::from_signed(truncate(0)));
     let (col_transpositions, _) = stack.make_with(size, |_| I::from_signed(truncate(0)));
 
+    let mut matrix = matrix;
+    let max_input = crate::max_abs(matrix.rb());
+
     let n_transpositions = if matrix.row_stride().abs() < matrix.col_stride().abs() {
         lu_in_place_unblocked(
-            matrix,
+            matrix.rb_mut(),
             row_transpositions,
             col_transpositions,
             parallelism,
@@ -1489,7 +1505,7 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
         )
     } else {
         lu_in_place_unblocked(
-            matrix.transpose_mut(),
+            matrix.rb_mut().transpose_mut(),
             col_transpositions,
             row_transpositions,
             parallelism,
@@ -1521,10 +1537,16 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
         col_perm_inv[p.to_signed().zx()] = I::from_signed(truncate(i));
     }
 
+    let (first_zero_pivot, nan_encountered) = crate::pivot_diagnostics(matrix.rb());
+    let pivot_growth = faer_core::real_to_f64(crate::pivot_growth(matrix.rb(), max_input));
+
     unsafe {
         (
             FullPivLuInfo {
                 transposition_count: n_transpositions,
+                first_zero_pivot,
+                nan_encountered,
+                pivot_growth,
             },
             PermutationMut::new_unchecked(row_perm, row_perm_inv),
             PermutationMut::new_unchecked(col_perm, col_perm_inv),
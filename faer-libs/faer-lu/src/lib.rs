@@ -4,3 +4,63 @@
 
 pub mod full_pivoting;
 pub mod partial_pivoting;
+
+use faer_core::{ComplexField, MatRef, RealField};
+
+/// Returns the largest absolute value of the entries of `matrix`.
+pub(crate) fn max_abs<E: ComplexField>(matrix: MatRef<'_, E>) -> E::Real {
+    let mut max = E::Real::faer_zero();
+    for j in 0..matrix.ncols() {
+        for i in 0..matrix.nrows() {
+            let abs = matrix.read(i, j).faer_abs();
+            if abs > max {
+                max = abs;
+            }
+        }
+    }
+    max
+}
+
+/// Returns the ratio of the largest absolute value of the entries of the upper triangular factor
+/// stored in `factors` to `max_input`, or one if `max_input` is zero.
+pub(crate) fn pivot_growth<E: ComplexField>(factors: MatRef<'_, E>, max_input: E::Real) -> E::Real {
+    let mut max_u = E::Real::faer_zero();
+    for j in 0..factors.ncols() {
+        for i in 0..Ord::min(j + 1, factors.nrows()) {
+            let abs = factors.read(i, j).faer_abs();
+            if abs > max_u {
+                max_u = abs;
+            }
+        }
+    }
+    if max_input == E::Real::faer_zero() {
+        E::Real::faer_one()
+    } else {
+        max_u.faer_div(max_input)
+    }
+}
+
+/// Scans the factors of an LU decomposition, and returns the index of the first pivot that is zero
+/// or whose reciprocal overflows, if any, as well as whether any of the factors is NaN.
+pub(crate) fn pivot_diagnostics<E: ComplexField>(factors: MatRef<'_, E>) -> (Option<usize>, bool) {
+    let size = Ord::min(factors.nrows(), factors.ncols());
+    let zero = E::faer_zero();
+
+    let mut first_zero_pivot = None;
+    for i in 0..size {
+        let pivot = factors.read(i, i);
+        if pivot == zero || (!pivot.faer_is_nan() && !pivot.faer_inv().faer_is_finite()) {
+            first_zero_pivot = Some(i);
+            break;
+        }
+    }
+
+    let mut nan_encountered = false;
+    for j in 0..factors.ncols() {
+        for i in 0..factors.nrows() {
+            nan_encountered |= factors.read(i, j).faer_is_nan();
+        }
+    }
+
+    (first_zero_pivot, nan_encountered)
+}
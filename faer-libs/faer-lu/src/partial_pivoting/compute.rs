@@ -5,7 +5,7 @@ use faer_core::{
     mul::matmul,
     permutation::{Index, PermutationMut, SignedIndex},
     solve::solve_unit_lower_triangular_in_place,
-    unzipped, zipped, ComplexField, Entity, MatMut, Parallelism, SimdCtx,
+    unzipped, zipped, ComplexField, DivCeil, Entity, MatMut, Parallelism, SimdCtx,
 };
 use faer_entity::*;
 use reborrow::*;
@@ -208,11 +208,11 @@ fn recursion_threshold<E: Entity>(_m: usize) -> usize {
 fn blocksize<E: Entity>(n: usize) -> usize {
     let base_rem = n / 2;
     n - if n >= 32 {
-        (base_rem + 15) / 16 * 16
+        base_rem.msrv_next_multiple_of(16)
     } else if n >= 16 {
-        (base_rem + 7) / 8 * 8
+        base_rem.msrv_next_multiple_of(8)
     } else if n >= 8 {
-        (base_rem + 3) / 4 * 4
+        base_rem.msrv_next_multiple_of(4)
     } else {
         base_rem
     }
@@ -335,8 +335,21 @@ pub fn lu_in_place_impl<I: Index, E: ComplexField>(
 pub struct PartialPivLuComputeParams {}
 
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 pub struct PartialPivLuInfo {
+    /// Number of transpositions that constitute the row permutation.
     pub transposition_count: usize,
+    /// Index of the first pivot that is zero, or whose reciprocal overflows, if any, in which case
+    /// the matrix is singular to working precision and solving a system with the factors produces
+    /// non-finite values. The pivot itself is the corresponding diagonal entry of $U$.
+    pub first_zero_pivot: Option<usize>,
+    /// Whether the factors contain NaN values, which indicates that the input contained
+    /// non-finite values, or that an overflow occurred.
+    pub nan_encountered: bool,
+    /// Ratio of the largest entry of $U$ to the largest entry of the input matrix, in absolute
+    /// value. Large values indicate that the factorization may be numerically unstable. This is
+    /// NaN if the scalar type cannot be converted to `f64`.
+    pub pivot_growth: f64,
 }
 
 /// Computes the size and alignment of required workspace for performing an LU
@@ -411,6 +424,8 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
         *p = I::from_signed(truncate(i));
     }
 
+    let max_input = crate::max_abs(matrix.rb());
+
     let (transpositions, _) = stack
         .rb_mut()
         .make_with(size, |_| I::from_signed(truncate(0)));
@@ -420,7 +435,7 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
         perm.swap(idx, idx + t.to_signed().zx());
     }
 
-    let (_, _, left, right) = matrix.split_at_mut(0, size);
+    let (_, _, left, right) = matrix.rb_mut().split_at_mut(0, size);
 
     if m < n {
         solve_unit_lower_triangular_in_place(left.rb(), right, parallelism);
@@ -430,9 +445,15 @@ pub fn lu_in_place<'out, I: Index, E: ComplexField>(
         perm_inv[p.to_signed().zx()] = I::from_signed(truncate(i));
     }
 
+    let (first_zero_pivot, nan_encountered) = crate::pivot_diagnostics(matrix.rb());
+    let pivot_growth = faer_core::real_to_f64(crate::pivot_growth(matrix.rb(), max_input));
+
     (
         PartialPivLuInfo {
             transposition_count: n_transpositions,
+            first_zero_pivot,
+            nan_encountered,
+            pivot_growth,
         },
         unsafe { PermutationMut::new_unchecked(perm, perm_inv) },
     )
@@ -620,4 +641,54 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn compute_lu_diagnostics() {
+        let n = 4;
+        for (zero_col, nan) in [(None, false), (Some(n - 1), false), (None, true)] {
+            let mut mat = Mat::from_fn(n, n, |_, _| random::<f64>());
+            if let Some(j) = zero_col {
+                mat.as_mut().col_mut(j).fill_zero();
+            }
+            let mut max_a = 0.0f64;
+            for j in 0..n {
+                for i in 0..n {
+                    max_a = max_a.max(mat.read(i, j).abs());
+                }
+            }
+            if nan {
+                mat.write(1, 1, f64::NAN);
+            }
+            let mut perm = vec![0usize; n];
+            let mut perm_inv = vec![0; n];
+
+            let (info, _) = lu_in_place(
+                mat.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                Parallelism::None,
+                make_stack!(lu_in_place_req::<usize, f64>(
+                    n,
+                    n,
+                    Parallelism::None,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+            assert!(info.first_zero_pivot == zero_col);
+            if let Some(j) = zero_col {
+                assert!(mat.read(j, j) == 0.0);
+            }
+            assert!(info.nan_encountered == nan);
+            if !nan {
+                let mut max_u = 0.0f64;
+                for j in 0..n {
+                    for i in 0..j + 1 {
+                        max_u = max_u.max(mat.read(i, j).abs());
+                    }
+                }
+                assert_approx_eq!(info.pivot_growth, max_u / max_a);
+            }
+        }
+    }
 }
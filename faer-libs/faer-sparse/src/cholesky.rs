@@ -380,8 +380,8 @@ pub mod simplicial {
 
                             match kind {
                                 FactorizationKind::Llt => {
-                                    if d <= E::Real::faer_zero() {
-                                        return Err(CholeskyError);
+                                    if d.faer_is_nan() || d <= E::Real::faer_zero() {
+                                        return Err(CholeskyError::new(*k, d));
                                     }
                                     L_values.write(k_start, E::faer_from_real(d.faer_sqrt()));
                                 }
@@ -548,8 +548,8 @@ pub mod simplicial {
 
                             match kind {
                                 FactorizationKind::Llt => {
-                                    if d <= E::Real::faer_zero() {
-                                        return Err(CholeskyError);
+                                    if d.faer_is_nan() || d <= E::Real::faer_zero() {
+                                        return Err(CholeskyError::new(*k, d));
                                     }
                                     L_values.write(k_start, E::faer_from_real(d.faer_sqrt()));
                                 }
@@ -2456,7 +2456,8 @@ pub mod supernodal {
                 parallelism,
                 stack.rb_mut(),
                 params,
-            )?
+            )
+            .map_err(|err| err.offset(s_start))?
             .dynamic_regularization_count;
             faer_core::solve::solve_lower_triangular_in_place(
                 Ls_top.rb().conjugate(),
@@ -0,0 +1,1226 @@
+//! Krylov subspace methods for the iterative solution of linear systems.
+//!
+//! The solvers only access the matrix and the preconditioner through the [`LinearOperator`]
+//! trait, which is implemented for dense and sparse matrices, and can be implemented by the user
+//! for matrix-free operators. The preconditioner is applied as an approximation of the inverse of
+//! the matrix. [`IdentityPrecond`] can be used when no preconditioning is needed.
+//!
+//! Each call solves a system with a single right-hand side, given as a column. Systems with
+//! several right-hand sides must be solved one column at a time.
+//!
+//! All the solvers take the memory they need from the provided [`PodStack`], whose size can be
+//! queried with the corresponding `_req` function, and don't allocate otherwise.
+
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    assert,
+    jacobi::JacobiRotation,
+    mul::{inner_prod::inner_prod_with_conj, matmul},
+    solve::solve_upper_triangular_in_place,
+    temp_mat_req, temp_mat_uninit, unzipped, zipped, ColMut, ColRef, Conj, MatMut, MatRef,
+    Parallelism,
+};
+use faer_entity::{ComplexField, RealField};
+use reborrow::*;
+
+pub use faer_core::linop::LinearOperator;
+
+/// Identity operator, used as a trivial preconditioner.
+#[derive(Copy, Clone, Debug)]
+pub struct IdentityPrecond {
+    /// Dimension of the operator.
+    pub dim: usize,
+}
+
+impl<E: ComplexField> LinearOperator<E> for IdentityPrecond {
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.dim
+    }
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.dim
+    }
+
+    #[inline]
+    fn apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[inline]
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = (rhs_ncols, parallelism);
+        Ok(StackReq::empty())
+    }
+
+    #[track_caller]
+    fn apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let _ = (conj, parallelism, stack);
+        let mut out = out;
+        out.copy_from(rhs);
+    }
+    #[track_caller]
+    fn transpose_apply_with_conj(
+        &self,
+        out: MatMut<'_, E>,
+        rhs: MatRef<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        self.apply_with_conj(out, rhs, conj, parallelism, stack)
+    }
+}
+
+/// Stopping criteria of the iterative solvers.
+///
+/// The iteration stops once the norm of the residual `rhs - A * out` is smaller than
+/// `max(abs_tolerance, rel_tolerance * norm(rhs))`, or after `max_iters` iterations.
+#[derive(Copy, Clone, Debug)]
+pub struct KrylovParams<E: ComplexField> {
+    /// Absolute tolerance on the norm of the residual.
+    pub abs_tolerance: E::Real,
+    /// Tolerance on the norm of the residual, relative to the norm of the right-hand side.
+    pub rel_tolerance: E::Real,
+    /// Maximum number of iterations.
+    pub max_iters: usize,
+    /// Whether `out` contains an initial guess on entry. Otherwise, the initial guess is zero.
+    pub initial_guess: bool,
+}
+
+impl<E: ComplexField> Default for KrylovParams<E> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            abs_tolerance: E::Real::faer_zero(),
+            rel_tolerance: E::Real::faer_epsilon().unwrap().faer_sqrt(),
+            max_iters: 1000,
+            initial_guess: false,
+        }
+    }
+}
+
+/// Information about a successful iterative solve.
+#[derive(Copy, Clone, Debug)]
+pub struct KrylovInfo<E: ComplexField> {
+    /// Norm of the final residual.
+    pub abs_residual: E::Real,
+    /// Norm of the final residual, relative to the norm of the right-hand side.
+    pub rel_residual: E::Real,
+    /// Number of iterations that were performed.
+    pub iter_count: usize,
+}
+
+/// Iterative solver error.
+///
+/// In both cases, `out` contains the last computed iterate.
+#[derive(Copy, Clone, Debug)]
+pub enum KrylovError<E: ComplexField> {
+    /// The stopping criterion was not met within the maximum number of iterations.
+    NoConvergence {
+        /// Norm of the final residual.
+        abs_residual: E::Real,
+        /// Norm of the final residual, relative to the norm of the right-hand side.
+        rel_residual: E::Real,
+    },
+    /// The method broke down, which can happen if the matrix or the preconditioner don't satisfy
+    /// the requirements of the method, or if a value became non-finite.
+    Breakdown {
+        /// Number of iterations that were performed before the breakdown.
+        iter_count: usize,
+    },
+}
+
+impl<E: ComplexField> core::fmt::Display for KrylovError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: ComplexField> std::error::Error for KrylovError<E> {}
+
+/// Keeps track of the residual norms and of the stopping criterion.
+struct Monitor<'a, E: ComplexField> {
+    history: &'a mut [E::Real],
+    rhs_norm: E::Real,
+    tolerance: E::Real,
+    max_iters: usize,
+}
+
+impl<'a, E: ComplexField> Monitor<'a, E> {
+    fn new(params: KrylovParams<E>, rhs_norm: E::Real, history: &'a mut [E::Real]) -> Self {
+        let rel_tolerance = params.rel_tolerance.faer_mul(rhs_norm);
+        Self {
+            history,
+            rhs_norm,
+            tolerance: if rel_tolerance > params.abs_tolerance {
+                rel_tolerance
+            } else {
+                params.abs_tolerance
+            },
+            max_iters: params.max_iters,
+        }
+    }
+
+    #[inline]
+    fn is_converged(&self, abs_residual: E::Real) -> bool {
+        abs_residual <= self.tolerance
+    }
+
+    /// Records the norm of the residual after `iter` iterations, and returns the result of the
+    /// solve if the iteration should stop.
+    fn check(
+        &mut self,
+        iter: usize,
+        abs_residual: E::Real,
+    ) -> Option<Result<KrylovInfo<E>, KrylovError<E>>> {
+        let rel_residual = if self.rhs_norm == E::Real::faer_zero() {
+            abs_residual
+        } else {
+            abs_residual.faer_div(self.rhs_norm)
+        };
+        if let Some(h) = self.history.get_mut(iter) {
+            *h = rel_residual;
+        }
+
+        if !abs_residual.faer_is_finite() {
+            Some(Err(KrylovError::Breakdown { iter_count: iter }))
+        } else if self.is_converged(abs_residual) {
+            Some(Ok(KrylovInfo {
+                abs_residual,
+                rel_residual,
+                iter_count: iter,
+            }))
+        } else if iter >= self.max_iters {
+            Some(Err(KrylovError::NoConvergence {
+                abs_residual,
+                rel_residual,
+            }))
+        } else {
+            None
+        }
+    }
+}
+
+#[inline]
+fn dot<E: ComplexField>(lhs: MatRef<'_, E>, rhs: MatRef<'_, E>) -> E {
+    inner_prod_with_conj(lhs, Conj::Yes, rhs, Conj::No)
+}
+
+/// Computes `dst += alpha * src`.
+#[inline]
+fn axpy<E: ComplexField>(dst: MatMut<'_, E>, alpha: E, src: MatRef<'_, E>) {
+    zipped!(dst, src).for_each(|unzipped!(mut dst, src)| {
+        dst.write(dst.read().faer_add(alpha.faer_mul(src.read())))
+    });
+}
+
+/// Computes `r = b - A * x`, or sets `x` to zero and `r` to `b` if there is no initial guess.
+fn init_residual<E: ComplexField>(
+    r: MatMut<'_, E>,
+    A: &dyn LinearOperator<E>,
+    x: MatMut<'_, E>,
+    b: MatRef<'_, E>,
+    initial_guess: bool,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    let mut r = r;
+    let mut x = x;
+    if initial_guess {
+        A.apply(r.rb_mut(), x.rb(), parallelism, stack);
+        zipped!(r, b).for_each(|unzipped!(mut r, b)| r.write(b.read().faer_sub(r.read())));
+    } else {
+        x.fill_zero();
+        r.copy_from(b);
+    }
+}
+
+#[track_caller]
+fn check_dims<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    out: ColRef<'_, E>,
+    rhs: ColRef<'_, E>,
+) {
+    let n = A.nrows();
+    assert!(all(
+        A.ncols() == n,
+        precond.nrows() == n,
+        precond.ncols() == n,
+        out.nrows() == n,
+        rhs.nrows() == n,
+    ));
+}
+
+fn op_req<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_any_of([
+        A.apply_req(1, parallelism)?,
+        precond.apply_req(1, parallelism)?,
+    ])
+}
+
+/// Computes the size and alignment of the workspace required to solve a linear system with
+/// [`conjugate_gradient`].
+pub fn conjugate_gradient_req<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let vec = temp_mat_req::<E>(A.nrows(), 1)?;
+    StackReq::try_all_of([vec, vec, vec, vec, op_req(A, precond, parallelism)?])
+}
+
+/// Solves `A * out = rhs` with the preconditioned conjugate gradient method.
+///
+/// `A` and `precond` must be self-adjoint and positive definite.
+///
+/// The relative residual norm at iteration `k` is stored in `history[k]`, if `history` is long
+/// enough.
+#[track_caller]
+pub fn conjugate_gradient<E: ComplexField>(
+    out: ColMut<'_, E>,
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    rhs: ColRef<'_, E>,
+    params: KrylovParams<E>,
+    history: &mut [E::Real],
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> Result<KrylovInfo<E>, KrylovError<E>> {
+    check_dims(A, precond, out.rb(), rhs);
+    let n = A.nrows();
+    let zero = E::Real::faer_zero();
+
+    let mut x = out.as_2d_mut();
+    let b = rhs.as_2d();
+
+    let (mut r, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut z, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut p, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut q, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+
+    init_residual(
+        r.rb_mut(),
+        A,
+        x.rb_mut(),
+        b,
+        params.initial_guess,
+        parallelism,
+        stack.rb_mut(),
+    );
+    let mut monitor = Monitor::new(params, b.norm_l2(), history);
+
+    precond.apply(z.rb_mut(), r.rb(), parallelism, stack.rb_mut());
+    p.copy_from(z.rb());
+    let mut rz = dot(r.rb(), z.rb()).faer_real();
+
+    let mut iter = 0;
+    loop {
+        if let Some(result) = monitor.check(iter, r.norm_l2()) {
+            return result;
+        }
+        if rz <= zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+
+        A.apply(q.rb_mut(), p.rb(), parallelism, stack.rb_mut());
+        let pq = dot(p.rb(), q.rb()).faer_real();
+        if pq <= zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+
+        let alpha = rz.faer_div(pq);
+        axpy(x.rb_mut(), E::faer_from_real(alpha), p.rb());
+        axpy(r.rb_mut(), E::faer_from_real(alpha.faer_neg()), q.rb());
+
+        precond.apply(z.rb_mut(), r.rb(), parallelism, stack.rb_mut());
+        let rz_new = dot(r.rb(), z.rb()).faer_real();
+        let beta = rz_new.faer_div(rz);
+        rz = rz_new;
+        zipped!(p.rb_mut(), z.rb()).for_each(|unzipped!(mut p, z)| {
+            p.write(z.read().faer_add(p.read().faer_scale_real(beta)))
+        });
+
+        iter += 1;
+    }
+}
+
+/// Computes the size and alignment of the workspace required to solve a linear system with
+/// [`minres`].
+pub fn minres_req<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let vec = temp_mat_req::<E>(A.nrows(), 1)?;
+    StackReq::try_all_of([
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        op_req(A, precond, parallelism)?,
+    ])
+}
+
+/// Solves `A * out = rhs` with the preconditioned minimum residual method.
+///
+/// `A` must be self-adjoint, and `precond` must be self-adjoint and positive definite. `A` may be
+/// indefinite.
+///
+/// The residual `r` is measured in the norm induced by the preconditioner, `sqrt(r^H * precond *
+/// r)`, and the right-hand side is measured in the same norm. Both reduce to the euclidean norm
+/// when no preconditioning is used.
+///
+/// The relative residual norm at iteration `k` is stored in `history[k]`, if `history` is long
+/// enough. It is the estimate obtained from the Lanczos recurrence rather than the norm of the
+/// recomputed residual, and the two may drift apart in floating point arithmetic. The same
+/// estimate is used for the stopping criterion and reported in the result.
+#[track_caller]
+pub fn minres<E: ComplexField>(
+    out: ColMut<'_, E>,
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    rhs: ColRef<'_, E>,
+    params: KrylovParams<E>,
+    history: &mut [E::Real],
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> Result<KrylovInfo<E>, KrylovError<E>> {
+    check_dims(A, precond, out.rb(), rhs);
+    let n = A.nrows();
+    let zero = E::Real::faer_zero();
+    let one = E::Real::faer_one();
+
+    let mut x = out.as_2d_mut();
+    let b = rhs.as_2d();
+
+    let (mut r1, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut r2, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut y, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut v, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut w, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut w1, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut w2, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+
+    precond.apply(y.rb_mut(), b, parallelism, stack.rb_mut());
+    let b_norm2 = dot(b, y.rb()).faer_real();
+    if b_norm2 < zero {
+        return Err(KrylovError::Breakdown { iter_count: 0 });
+    }
+
+    init_residual(
+        r1.rb_mut(),
+        A,
+        x.rb_mut(),
+        b,
+        params.initial_guess,
+        parallelism,
+        stack.rb_mut(),
+    );
+    let mut monitor = Monitor::new(params, b_norm2.faer_sqrt(), history);
+
+    precond.apply(y.rb_mut(), r1.rb(), parallelism, stack.rb_mut());
+    let beta1 = dot(r1.rb(), y.rb()).faer_real();
+    if beta1 < zero {
+        return Err(KrylovError::Breakdown { iter_count: 0 });
+    }
+    let beta1 = beta1.faer_sqrt();
+
+    r2.copy_from(r1.rb());
+    w.fill_zero();
+    w1.fill_zero();
+    w2.fill_zero();
+
+    let mut old_beta = zero;
+    let mut beta = beta1;
+    let mut dbar = zero;
+    let mut epsilon = zero;
+    let mut phibar = beta1;
+    let mut cs = one.faer_neg();
+    let mut sn = zero;
+
+    let mut iter = 0;
+    loop {
+        if let Some(result) = monitor.check(iter, phibar) {
+            return result;
+        }
+        iter += 1;
+
+        // lanczos step
+        let beta_inv = beta.faer_inv();
+        zipped!(v.rb_mut(), y.rb())
+            .for_each(|unzipped!(mut v, y)| v.write(y.read().faer_scale_real(beta_inv)));
+        A.apply(y.rb_mut(), v.rb(), parallelism, stack.rb_mut());
+        if iter >= 2 {
+            axpy(
+                y.rb_mut(),
+                E::faer_from_real(beta.faer_div(old_beta).faer_neg()),
+                r1.rb(),
+            );
+        }
+        let alpha = dot(v.rb(), y.rb()).faer_real();
+        axpy(
+            y.rb_mut(),
+            E::faer_from_real(alpha.faer_div(beta).faer_neg()),
+            r2.rb(),
+        );
+
+        // r1 <- r2, r2 <- y, y <- precond * r2
+        core::mem::swap(&mut r1, &mut r2);
+        core::mem::swap(&mut r2, &mut y);
+        precond.apply(y.rb_mut(), r2.rb(), parallelism, stack.rb_mut());
+
+        old_beta = beta;
+        let beta2 = dot(r2.rb(), y.rb()).faer_real();
+        if beta2 < zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+        beta = beta2.faer_sqrt();
+
+        // apply the previous rotation, then compute and apply the next one
+        let old_epsilon = epsilon;
+        let delta = cs.faer_mul(dbar).faer_add(sn.faer_mul(alpha));
+        let gbar = sn.faer_mul(dbar).faer_sub(cs.faer_mul(alpha));
+        epsilon = sn.faer_mul(beta);
+        dbar = cs.faer_mul(beta).faer_neg();
+
+        let gamma = gbar.faer_abs2().faer_add(beta.faer_abs2()).faer_sqrt();
+        if gamma <= zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+        let gamma_inv = gamma.faer_inv();
+        cs = gbar.faer_mul(gamma_inv);
+        sn = beta.faer_mul(gamma_inv);
+        let phi = cs.faer_mul(phibar);
+        phibar = sn.faer_mul(phibar);
+
+        // w1 <- w2, w2 <- w, w <- (v - old_epsilon * w1 - delta * w2) / gamma
+        core::mem::swap(&mut w1, &mut w2);
+        core::mem::swap(&mut w2, &mut w);
+        zipped!(w.rb_mut(), v.rb(), w1.rb(), w2.rb()).for_each(|unzipped!(mut w, v, w1, w2)| {
+            w.write(
+                v.read()
+                    .faer_sub(w1.read().faer_scale_real(old_epsilon))
+                    .faer_sub(w2.read().faer_scale_real(delta))
+                    .faer_scale_real(gamma_inv),
+            )
+        });
+        axpy(x.rb_mut(), E::faer_from_real(phi), w.rb());
+    }
+}
+
+/// Computes the size and alignment of the workspace required to solve a linear system with
+/// [`gmres`].
+pub fn gmres_req<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    restart: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let n = A.nrows();
+    let m = restart;
+    let m1 = m.checked_add(1).ok_or(SizeOverflow)?;
+    StackReq::try_all_of([
+        temp_mat_req::<E>(n, m1)?,
+        temp_mat_req::<E>(m1, m)?,
+        temp_mat_req::<E>(m1, 1)?,
+        temp_mat_req::<E>(m, 1)?,
+        StackReq::try_new::<JacobiRotation<E::Real>>(m)?,
+        temp_mat_req::<E>(n, 1)?,
+        op_req(A, precond, parallelism)?,
+    ])
+}
+
+/// Computes a unit phase and a real Givens rotation that together map `(a, b)` to `(r, 0)`.
+#[inline]
+fn make_givens<E: ComplexField>(a: E, b: E::Real) -> (E, JacobiRotation<E::Real>) {
+    let a_abs = a.faer_abs();
+    let phase = if a_abs == E::Real::faer_zero() {
+        E::faer_one()
+    } else {
+        a.faer_scale_real(a_abs.faer_inv())
+    };
+    (phase, JacobiRotation::make_givens(a_abs, b))
+}
+
+/// Applies the rotation computed by [`make_givens`] to the pair `(x, y)`.
+#[inline]
+fn apply_givens<E: ComplexField>(phase: E, rot: JacobiRotation<E::Real>, x: E, y: E) -> (E, E) {
+    let JacobiRotation { c, s } = rot;
+    let x = phase.faer_conj().faer_mul(x);
+    (
+        x.faer_scale_real(c).faer_sub(y.faer_scale_real(s)),
+        x.faer_scale_real(s).faer_add(y.faer_scale_real(c)),
+    )
+}
+
+/// Solves `A * out = rhs` with the restarted generalized minimal residual method, GMRES(`restart`).
+///
+/// `precond` is applied on the right, so the residual that is minimized is the residual of the
+/// original system.
+///
+/// The relative residual norm at iteration `k` is stored in `history[k]`, if `history` is long
+/// enough. Within a restart cycle, the residual norm is the estimate from the least squares
+/// problem, and the true residual is recomputed at each restart.
+///
+/// # Panics
+/// Panics if `restart` is zero.
+#[track_caller]
+pub fn gmres<E: ComplexField>(
+    out: ColMut<'_, E>,
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    rhs: ColRef<'_, E>,
+    restart: usize,
+    params: KrylovParams<E>,
+    history: &mut [E::Real],
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> Result<KrylovInfo<E>, KrylovError<E>> {
+    check_dims(A, precond, out.rb(), rhs);
+    assert!(restart > 0);
+    let n = A.nrows();
+    let m = restart;
+
+    let mut x = out.as_2d_mut();
+    let b = rhs.as_2d();
+
+    let (mut V, stack) = temp_mat_uninit::<E>(n, m + 1, stack);
+    let (mut H, stack) = temp_mat_uninit::<E>(m + 1, m, stack);
+    let (mut g, stack) = temp_mat_uninit::<E>(m + 1, 1, stack);
+    let (mut phases, stack) = temp_mat_uninit::<E>(m, 1, stack);
+    let (rotations, stack) = stack.make_raw::<JacobiRotation<E::Real>>(m);
+    let (mut z, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+
+    let mut monitor = Monitor::new(params, b.norm_l2(), history);
+    let mut initial_guess = params.initial_guess;
+
+    let mut iter = 0;
+    loop {
+        init_residual(
+            V.rb_mut().col_mut(0).as_2d_mut(),
+            A,
+            x.rb_mut(),
+            b,
+            initial_guess,
+            parallelism,
+            stack.rb_mut(),
+        );
+        initial_guess = true;
+
+        let beta = V.rb().col(0).norm_l2();
+        if let Some(result) = monitor.check(iter, beta) {
+            return result;
+        }
+
+        let beta_inv = beta.faer_inv();
+        zipped!(V.rb_mut().col_mut(0).as_2d_mut())
+            .for_each(|unzipped!(mut v)| v.write(v.read().faer_scale_real(beta_inv)));
+        g.fill_zero();
+        g.write(0, 0, E::faer_from_real(beta));
+
+        let mut k = 0;
+        let mut stop = None;
+        while k < m {
+            let j = k;
+
+            // arnoldi step, with modified gram-schmidt orthogonalization
+            precond.apply(
+                z.rb_mut(),
+                V.rb().col(j).as_2d(),
+                parallelism,
+                stack.rb_mut(),
+            );
+            let (V_left, V_right) = V.rb_mut().split_at_col_mut(j + 1);
+            let mut w = V_right.col_mut(0).as_2d_mut();
+            A.apply(w.rb_mut(), z.rb(), parallelism, stack.rb_mut());
+            for i in 0..j + 1 {
+                let vi = V_left.rb().col(i).as_2d();
+                let h = dot(vi, w.rb());
+                axpy(w.rb_mut(), h.faer_neg(), vi);
+                H.write(i, j, h);
+            }
+            let h_next = w.norm_l2();
+
+            // reduce the hessenberg matrix to upper triangular form
+            for (i, &rot) in rotations[..j].iter().enumerate() {
+                let (hi, hi1) =
+                    apply_givens(phases.read(i, 0), rot, H.read(i, j), H.read(i + 1, j));
+                H.write(i, j, hi);
+                H.write(i + 1, j, hi1);
+            }
+            let (phase, rot) = make_givens(H.read(j, j), h_next);
+            phases.write(j, 0, phase);
+            rotations[j] = rot;
+            let (hj, _) = apply_givens(phase, rot, H.read(j, j), E::faer_from_real(h_next));
+            H.write(j, j, hj);
+            H.write(j + 1, j, E::faer_zero());
+            let (gj, gj1) = apply_givens(phase, rot, g.read(j, 0), E::faer_zero());
+            g.write(j, 0, gj);
+            g.write(j + 1, 0, gj1);
+
+            k += 1;
+            iter += 1;
+            stop = monitor.check(iter, gj1.faer_abs());
+            if stop.is_some() || h_next == E::Real::faer_zero() {
+                break;
+            }
+
+            let h_next_inv = h_next.faer_inv();
+            zipped!(w).for_each(|unzipped!(mut w)| w.write(w.read().faer_scale_real(h_next_inv)));
+        }
+
+        // x += precond * V * inv(H) * g
+        solve_upper_triangular_in_place(
+            H.rb().submatrix(0, 0, k, k),
+            g.rb_mut().subrows_mut(0, k),
+            parallelism,
+        );
+        matmul(
+            z.rb_mut(),
+            V.rb().subcols(0, k),
+            g.rb().subrows(0, k),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        let mut t = V.rb_mut().col_mut(0).as_2d_mut();
+        precond.apply(t.rb_mut(), z.rb(), parallelism, stack.rb_mut());
+        axpy(x.rb_mut(), E::faer_one(), t.rb());
+
+        if let Some(result) = stop {
+            return result;
+        }
+    }
+}
+
+/// Computes the size and alignment of the workspace required to solve a linear system with
+/// [`bicgstab`].
+pub fn bicgstab_req<E: ComplexField>(
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let vec = temp_mat_req::<E>(A.nrows(), 1)?;
+    StackReq::try_all_of([
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        vec,
+        op_req(A, precond, parallelism)?,
+    ])
+}
+
+/// Solves `A * out = rhs` with the biconjugate gradient stabilized method.
+///
+/// `precond` is applied on the right, so the residual that is measured is the residual of the
+/// original system.
+///
+/// The relative residual norm at iteration `k` is stored in `history[k]`, if `history` is long
+/// enough.
+#[track_caller]
+pub fn bicgstab<E: ComplexField>(
+    out: ColMut<'_, E>,
+    A: &dyn LinearOperator<E>,
+    precond: &dyn LinearOperator<E>,
+    rhs: ColRef<'_, E>,
+    params: KrylovParams<E>,
+    history: &mut [E::Real],
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> Result<KrylovInfo<E>, KrylovError<E>> {
+    check_dims(A, precond, out.rb(), rhs);
+    let n = A.nrows();
+    let zero = E::faer_zero();
+
+    let mut x = out.as_2d_mut();
+    let b = rhs.as_2d();
+
+    let (mut r, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut r_hat, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut p, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut v, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut p_hat, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut s_hat, stack) = temp_mat_uninit::<E>(n, 1, stack);
+    let (mut t, mut stack) = temp_mat_uninit::<E>(n, 1, stack);
+
+    init_residual(
+        r.rb_mut(),
+        A,
+        x.rb_mut(),
+        b,
+        params.initial_guess,
+        parallelism,
+        stack.rb_mut(),
+    );
+    let mut monitor = Monitor::new(params, b.norm_l2(), history);
+    r_hat.copy_from(r.rb());
+
+    let mut rho = E::faer_one();
+    let mut alpha = E::faer_one();
+    let mut omega = E::faer_one();
+
+    let mut iter = 0;
+    loop {
+        if let Some(result) = monitor.check(iter, r.norm_l2()) {
+            return result;
+        }
+
+        let rho_new = dot(r_hat.rb(), r.rb());
+        if rho_new == zero || omega == zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+        if iter == 0 {
+            p.copy_from(r.rb());
+        } else {
+            let beta = rho_new
+                .faer_mul(rho.faer_inv())
+                .faer_mul(alpha.faer_mul(omega.faer_inv()));
+            zipped!(p.rb_mut(), r.rb(), v.rb()).for_each(|unzipped!(mut p, r, v)| {
+                p.write(
+                    r.read()
+                        .faer_add(beta.faer_mul(p.read().faer_sub(omega.faer_mul(v.read())))),
+                )
+            });
+        }
+        rho = rho_new;
+
+        precond.apply(p_hat.rb_mut(), p.rb(), parallelism, stack.rb_mut());
+        A.apply(v.rb_mut(), p_hat.rb(), parallelism, stack.rb_mut());
+        let r_hat_v = dot(r_hat.rb(), v.rb());
+        if r_hat_v == zero {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+        alpha = rho.faer_mul(r_hat_v.faer_inv());
+
+        // r now holds s = r - alpha * v
+        axpy(r.rb_mut(), alpha.faer_neg(), v.rb());
+        axpy(x.rb_mut(), alpha, p_hat.rb());
+
+        let s_norm = r.norm_l2();
+        if monitor.is_converged(s_norm) {
+            iter += 1;
+            return monitor.check(iter, s_norm).unwrap();
+        }
+
+        precond.apply(s_hat.rb_mut(), r.rb(), parallelism, stack.rb_mut());
+        A.apply(t.rb_mut(), s_hat.rb(), parallelism, stack.rb_mut());
+        let tt = dot(t.rb(), t.rb()).faer_real();
+        if tt == E::Real::faer_zero() {
+            return Err(KrylovError::Breakdown { iter_count: iter });
+        }
+        omega = dot(t.rb(), r.rb()).faer_scale_real(tt.faer_inv());
+
+        axpy(x.rb_mut(), omega, s_hat.rb());
+        axpy(r.rb_mut(), omega.faer_neg(), t.rb());
+
+        iter += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dyn_stack::GlobalPodBuffer;
+    use faer_core::{
+        assert, c64,
+        sparse::{SparseColMatRef, SymbolicSparseColMatRef},
+        Col, Mat,
+    };
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    const TOL: f64 = 1e-10;
+
+    /// Five-point stencil on a `k * k` grid, with a convection term `c` along the first axis and
+    /// a diagonal shift.
+    fn stencil(k: usize, c: f64, shift: f64) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+        let mut col_ptrs = vec![0usize];
+        let mut row_indices = vec![];
+        let mut values = vec![];
+        for j in 0..k * k {
+            let (x, y) = (j % k, j / k);
+            let mut push = |i: usize, v: f64| {
+                row_indices.push(i);
+                values.push(v);
+            };
+            if y > 0 {
+                push(j - k, -1.0);
+            }
+            if x > 0 {
+                push(j - 1, -1.0 - c);
+            }
+            push(j, 4.0 + (j % 3) as f64 - shift);
+            if x + 1 < k {
+                push(j + 1, -1.0 + c);
+            }
+            if y + 1 < k {
+                push(j + k, -1.0);
+            }
+            col_ptrs.push(row_indices.len());
+        }
+        (col_ptrs, row_indices, values)
+    }
+
+    /// User-defined diagonal preconditioner.
+    struct Jacobi {
+        inv_diag: Vec<f64>,
+    }
+
+    impl LinearOperator<f64> for Jacobi {
+        fn nrows(&self) -> usize {
+            self.inv_diag.len()
+        }
+        fn ncols(&self) -> usize {
+            self.inv_diag.len()
+        }
+        fn apply_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, SizeOverflow> {
+            let _ = (rhs_ncols, parallelism);
+            Ok(StackReq::empty())
+        }
+        fn transpose_apply_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, SizeOverflow> {
+            let _ = (rhs_ncols, parallelism);
+            Ok(StackReq::empty())
+        }
+        fn apply_with_conj(
+            &self,
+            out: MatMut<'_, f64>,
+            rhs: MatRef<'_, f64>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            let _ = (conj, parallelism, stack);
+            let mut out = out;
+            for j in 0..rhs.ncols() {
+                for (i, &d) in self.inv_diag.iter().enumerate() {
+                    out.write(i, j, rhs.read(i, j) * d);
+                }
+            }
+        }
+        fn transpose_apply_with_conj(
+            &self,
+            out: MatMut<'_, f64>,
+            rhs: MatRef<'_, f64>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.apply_with_conj(out, rhs, conj, parallelism, stack)
+        }
+    }
+
+    fn rel_residual<E: ComplexField>(
+        A: &dyn LinearOperator<E>,
+        x: ColRef<'_, E>,
+        b: ColRef<'_, E>,
+    ) -> E::Real {
+        let mut r = Col::<E>::zeros(b.nrows());
+        A.apply(
+            r.as_mut().as_2d_mut(),
+            x.as_2d(),
+            Parallelism::None,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                A.apply_req(1, Parallelism::None).unwrap(),
+            )),
+        );
+        zipped!(r.as_mut().as_2d_mut(), b.as_2d())
+            .for_each(|unzipped!(mut r, b)| r.write(b.read().faer_sub(r.read())));
+        r.norm_l2().faer_div(b.norm_l2())
+    }
+
+    type Solver<E> = fn(
+        ColMut<'_, E>,
+        &dyn LinearOperator<E>,
+        &dyn LinearOperator<E>,
+        ColRef<'_, E>,
+        KrylovParams<E>,
+        &mut [<E as ComplexField>::Real],
+        Parallelism,
+        PodStack<'_>,
+    ) -> Result<KrylovInfo<E>, KrylovError<E>>;
+
+    type SolverReq<E> = fn(
+        &dyn LinearOperator<E>,
+        &dyn LinearOperator<E>,
+        Parallelism,
+    ) -> Result<StackReq, SizeOverflow>;
+
+    fn gmres_10<E: ComplexField>(
+        out: ColMut<'_, E>,
+        A: &dyn LinearOperator<E>,
+        precond: &dyn LinearOperator<E>,
+        rhs: ColRef<'_, E>,
+        params: KrylovParams<E>,
+        history: &mut [E::Real],
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) -> Result<KrylovInfo<E>, KrylovError<E>> {
+        gmres(
+            out,
+            A,
+            precond,
+            rhs,
+            10,
+            params,
+            history,
+            parallelism,
+            stack,
+        )
+    }
+
+    fn gmres_10_req<E: ComplexField>(
+        A: &dyn LinearOperator<E>,
+        precond: &dyn LinearOperator<E>,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        gmres_req(A, precond, 10, parallelism)
+    }
+
+    #[track_caller]
+    fn check_solver<E: ComplexField>(
+        solver: Solver<E>,
+        solver_req: SolverReq<E>,
+        A: &dyn LinearOperator<E>,
+        precond: &dyn LinearOperator<E>,
+        b: ColRef<'_, E>,
+    ) {
+        let n = A.nrows();
+        let params = KrylovParams {
+            rel_tolerance: E::Real::faer_from_f64(TOL),
+            ..Default::default()
+        };
+        let mut history = vec![E::Real::faer_zero(); params.max_iters + 1];
+        let mut x = Col::<E>::zeros(n);
+        let info = solver(
+            x.as_mut(),
+            A,
+            precond,
+            b,
+            params,
+            &mut history,
+            Parallelism::None,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                solver_req(A, precond, Parallelism::None).unwrap(),
+            )),
+        )
+        .unwrap();
+
+        assert!(info.iter_count > 0);
+        assert!(history[0] == E::Real::faer_one());
+        assert!(history[info.iter_count] == info.rel_residual);
+        assert!(info.rel_residual <= E::Real::faer_from_f64(TOL));
+        assert!(rel_residual(A, x.as_ref(), b) <= E::Real::faer_from_f64(100.0 * TOL));
+
+        // restarting from the solution converges immediately
+        let info = solver(
+            x.as_mut(),
+            A,
+            precond,
+            b,
+            KrylovParams {
+                initial_guess: true,
+                rel_tolerance: E::Real::faer_from_f64(100.0 * TOL),
+                ..params
+            },
+            &mut [],
+            Parallelism::None,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                solver_req(A, precond, Parallelism::None).unwrap(),
+            )),
+        )
+        .unwrap();
+        assert!(info.iter_count == 0);
+    }
+
+    #[test]
+    fn test_krylov_sparse() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let k = 10;
+        let n = k * k;
+
+        let b = Col::<f64>::from_fn(n, |_| rng.gen());
+        let identity = IdentityPrecond { dim: n };
+
+        let spd = stencil(k, 0.0, 0.0);
+        let spd = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n, &spd.0, None, &spd.1),
+            &spd.2,
+        );
+        let jacobi = Jacobi {
+            inv_diag: (0..n).map(|j| 1.0 / (4.0 + (j % 3) as f64)).collect(),
+        };
+
+        let indefinite = stencil(k, 0.0, 3.0);
+        let indefinite = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n, &indefinite.0, None, &indefinite.1),
+            &indefinite.2,
+        );
+
+        let unsymmetric = stencil(k, 0.5, 0.0);
+        let unsymmetric = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n, &unsymmetric.0, None, &unsymmetric.1),
+            &unsymmetric.2,
+        );
+
+        for precond in [&identity as &dyn LinearOperator<f64>, &jacobi] {
+            check_solver(
+                conjugate_gradient,
+                conjugate_gradient_req,
+                &spd,
+                precond,
+                b.as_ref(),
+            );
+            check_solver(minres, minres_req, &spd, precond, b.as_ref());
+            for A in [&spd, &unsymmetric] {
+                check_solver(gmres_10, gmres_10_req, A, precond, b.as_ref());
+                check_solver(bicgstab, bicgstab_req, A, precond, b.as_ref());
+            }
+        }
+        check_solver(minres, minres_req, &indefinite, &identity, b.as_ref());
+    }
+
+    #[test]
+    fn test_krylov_dense_complex() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let n = 30;
+
+        let random = |rng: &mut StdRng| c64::new(rng.gen(), rng.gen());
+        let A = Mat::<c64>::from_fn(n, n, |i, j| {
+            let r = random(rng);
+            if i == j {
+                r + c64::new(2.0 * n as f64, 0.0)
+            } else {
+                r
+            }
+        });
+        let H = Mat::<c64>::from_fn(n, n, |i, j| A.read(i, j) + A.read(j, i).faer_conj());
+        let b = Col::<c64>::from_fn(n, |_| random(rng));
+        let identity = IdentityPrecond { dim: n };
+
+        check_solver(
+            conjugate_gradient,
+            conjugate_gradient_req,
+            &H.as_ref(),
+            &identity,
+            b.as_ref(),
+        );
+        check_solver(minres, minres_req, &H.as_ref(), &identity, b.as_ref());
+        check_solver(gmres_10, gmres_10_req, &A.as_ref(), &identity, b.as_ref());
+        check_solver(bicgstab, bicgstab_req, &A.as_ref(), &identity, b.as_ref());
+    }
+
+    #[test]
+    fn test_krylov_non_finite() {
+        let n = 10;
+        let A = Mat::<f64>::identity(n, n);
+        let b = Col::<f64>::from_fn(n, |i| if i == 3 { f64::INFINITY } else { 1.0 });
+        let identity = IdentityPrecond { dim: n };
+
+        for (solver, solver_req) in [
+            (
+                conjugate_gradient as Solver<f64>,
+                conjugate_gradient_req as SolverReq<f64>,
+            ),
+            (minres, minres_req),
+            (gmres_10, gmres_10_req),
+            (bicgstab, bicgstab_req),
+        ] {
+            let mut x = Col::<f64>::zeros(n);
+            let err = solver(
+                x.as_mut(),
+                &A.as_ref(),
+                &identity,
+                b.as_ref(),
+                Default::default(),
+                &mut [],
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    solver_req(&A.as_ref(), &identity, Parallelism::None).unwrap(),
+                )),
+            )
+            .err()
+            .unwrap();
+            assert!(matches!(err, KrylovError::Breakdown { .. }));
+        }
+    }
+
+    #[test]
+    fn test_krylov_no_convergence() {
+        let k = 10;
+        let n = k * k;
+        let A = stencil(k, 0.5, 0.0);
+        let A = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n, &A.0, None, &A.1),
+            &A.2,
+        );
+        let b = Col::<f64>::from_fn(n, |i| i as f64);
+        let identity = IdentityPrecond { dim: n };
+
+        let mut x = Col::<f64>::zeros(n);
+        let err = gmres(
+            x.as_mut(),
+            &A,
+            &identity,
+            b.as_ref(),
+            4,
+            KrylovParams {
+                max_iters: 2,
+                ..Default::default()
+            },
+            &mut [],
+            Parallelism::None,
+            PodStack::new(&mut GlobalPodBuffer::new(
+                gmres_req::<f64>(&A, &identity, 4, Parallelism::None).unwrap(),
+            )),
+        )
+        .err()
+        .unwrap();
+        match err {
+            KrylovError::NoConvergence {
+                rel_residual: estimate,
+                ..
+            } => {
+                assert!(estimate < 1.0);
+                assert!((estimate - rel_residual(&A, x.as_ref(), b.as_ref())).abs() < 1e-12);
+            }
+            KrylovError::Breakdown { .. } => panic!(),
+        }
+    }
+}
@@ -27,7 +27,7 @@ use faer_core::{
     permutation::{PermutationRef, SignedIndex},
     solve,
     sparse::SparseColMatRef,
-    temp_mat_req, temp_mat_uninit, Conj, MatMut, Parallelism,
+    temp_mat_req, temp_mat_uninit, ColMut, Conj, MatMut, Parallelism,
 };
 use faer_entity::*;
 use reborrow::*;
@@ -192,6 +192,36 @@ pub mod supernodal {
             self.nsupernodes
         }
 
+        /// Writes the diagonal of the factor $U$ to `diag`.
+        #[track_caller]
+        pub fn u_diagonal(&self, diag: ColMut<'_, E>) {
+            assert!(diag.nrows() == self.ncols());
+            let mut diag = diag;
+            let supernode_ptr = &*self.supernode_ptr;
+
+            for s in 0..self.nsupernodes {
+                let s_begin = supernode_ptr[s].zx();
+                let s_end = supernode_ptr[s + 1].zx();
+                let s_size = s_end - s_begin;
+                let s_row_index_count =
+                    (self.l_col_ptr_for_row_ind[s + 1] - self.l_col_ptr_for_row_ind[s]).zx();
+
+                // the diagonal block of U is stored in the upper part of the top of L
+                let L = self
+                    .l_val
+                    .as_slice()
+                    .subslice(self.l_col_ptr_for_val[s].zx()..self.l_col_ptr_for_val[s + 1].zx());
+                let L = faer_core::mat::from_column_major_slice::<'_, E>(
+                    L.into_inner(),
+                    s_row_index_count,
+                    s_size,
+                );
+                for j in 0..s_size {
+                    diag.write(s_begin + j, L.read(j, j));
+                }
+            }
+        }
+
         #[track_caller]
         pub fn solve_in_place_with_conj(
             &self,
@@ -1431,6 +1461,29 @@ pub mod simplicial {
             )
         }
 
+        /// Writes the diagonal of the factor $U$ to `diag`.
+        #[track_caller]
+        pub fn u_diagonal(&self, diag: ColMut<'_, E>)
+        where
+            E: ComplexField,
+        {
+            assert!(diag.nrows() == self.ncols());
+            let mut diag = diag;
+            let u = self.u_factor_unsorted();
+            for j in 0..self.ncols() {
+                let mut value = E::faer_zero();
+                for (i, v) in zip(
+                    u.row_indices_of_col(j),
+                    SliceGroup::<'_, E>::new(u.values_of_col(j)).into_ref_iter(),
+                ) {
+                    if i == j {
+                        value = v.read();
+                    }
+                }
+                diag.write(j, value);
+            }
+        }
+
         #[track_caller]
         pub fn solve_in_place_with_conj(
             &self,
@@ -1939,6 +1992,24 @@ impl<'a, I: Index, E: Entity> LuRef<'a, I, E> {
             _ => unreachable!(),
         }
     }
+
+    /// Writes the diagonal of the factor $U$ to `diag`. The factorization satisfies
+    /// $P_r A P_c^\top = LU$, where $L$ has a unit diagonal.
+    #[track_caller]
+    pub fn u_diagonal(self, diag: ColMut<'_, E>)
+    where
+        E: ComplexField,
+    {
+        match (&self.symbolic.raw, &self.numeric.raw) {
+            (SymbolicLuRaw::Simplicial { .. }, NumericLuRaw::Simplicial(numeric)) => {
+                numeric.u_diagonal(diag)
+            }
+            (SymbolicLuRaw::Supernodal(_), NumericLuRaw::Supernodal(numeric)) => {
+                numeric.u_diagonal(diag)
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl<I: Index> SymbolicLu<I> {
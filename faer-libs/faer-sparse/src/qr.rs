@@ -26,11 +26,36 @@ use faer_core::{
     householder::apply_block_householder_sequence_transpose_on_the_left_in_place_with_conj,
     permutation::{PermutationRef, SignedIndex},
     sparse::{SparseColMatRef, SymbolicSparseColMatRef},
-    temp_mat_req, temp_mat_uninit, unzipped, zipped, Conj, MatMut, Parallelism,
+    temp_mat_req, temp_mat_uninit, unzipped, zipped, ColMut, Conj, MatMut, Parallelism,
 };
 use faer_entity::*;
 use reborrow::*;
 
+/// Returns whether the permutation that maps `i` to `fwd[i]` is odd. Indices that are out of
+/// bounds or repeated are treated as fixed points.
+fn permutation_is_odd<I: Index>(fwd: &[I]) -> bool {
+    let n = fwd.len();
+    let mut visited = alloc::vec![false; n];
+    let mut odd = false;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        // a cycle of length k is the product of k - 1 transpositions
+        let mut i = start;
+        let mut len = 0usize;
+        while i < n && !visited[i] {
+            visited[i] = true;
+            i = fwd[i].zx();
+            len += 1;
+        }
+        if len % 2 == 0 {
+            odd = !odd;
+        }
+    }
+    odd
+}
+
 #[inline]
 pub(crate) fn ghost_col_etree<'m, 'n, I: Index>(
     A: ghost::SymbolicSparseColMatRef<'m, 'n, '_, I>,
@@ -1974,6 +1999,187 @@ impl<'a, I: Index, E: Entity> QrRef<'a, I, E> {
             }
         }
     }
+
+    /// Writes the diagonal of the factor $R$ to `diag`. The factorization satisfies
+    /// $A P^\top = QR$, where $P$ is the column permutation of the symbolic factorization.
+    #[track_caller]
+    pub fn r_diagonal(self, diag: ColMut<'_, E>)
+    where
+        E: ComplexField,
+    {
+        let n = self.symbolic.ncols();
+        assert!(diag.nrows() == n);
+        let mut diag = diag;
+
+        let indices = self.indices;
+        let values = self.values;
+
+        match &self.symbolic.raw {
+            SymbolicQrRaw::Simplicial(symbolic) => {
+                let (r_col_ptrs, indices) = indices.split_at(n + 1);
+                let (r_row_indices, _) = indices.split_at(symbolic.len_r());
+                let (r_values, _) = values.rb().split_at(symbolic.len_r());
+
+                let r = SparseColMatRef::<'_, I, E>::new(
+                    unsafe {
+                        SymbolicSparseColMatRef::new_unchecked(
+                            n,
+                            n,
+                            r_col_ptrs,
+                            None,
+                            r_row_indices,
+                        )
+                    },
+                    r_values.into_inner(),
+                );
+                for j in 0..n {
+                    let mut value = E::faer_zero();
+                    for (i, v) in zip(
+                        r.row_indices_of_col(j),
+                        SliceGroup::<'_, E>::new(r.values_of_col(j)).into_ref_iter(),
+                    ) {
+                        if i == j {
+                            value = v.read();
+                        }
+                    }
+                    diag.write(j, value);
+                }
+            }
+            SymbolicQrRaw::Supernodal(symbolic) => {
+                let (r_values, _) = values.rb().split_at(symbolic.r_adjoint().len_values());
+                // R^H is stored as a supernodal lower triangular factor
+                let l =
+                    SupernodalLltRef::<'_, I, E>::new(symbolic.r_adjoint(), r_values.into_inner());
+                for s in 0..symbolic.r_adjoint().n_supernodes() {
+                    let s = l.supernode(s);
+                    let start = s.start();
+                    let s_l = s.matrix();
+                    for j in 0..s_l.ncols() {
+                        diag.write(start + j, s_l.read(j, j).faer_conj());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the determinant of $QP$ is $-1$, and `false` if it is $1$, where the
+    /// factorization satisfies $A P^\top = QR$. Combined with the diagonal of $R$ computed by
+    /// [`Self::r_diagonal`], this gives the determinant of $A$.
+    ///
+    /// The result is unspecified if $R$ has a zero diagonal element, since $A$ is then singular.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    #[track_caller]
+    pub fn qp_determinant_is_negative(self) -> bool
+    where
+        E: ComplexField,
+    {
+        let m = self.symbolic.nrows();
+        let n = self.symbolic.ncols();
+        assert!(m == n);
+
+        // each nontrivial householder reflection has a determinant of -1. the head of each
+        // reflection is moved to the corresponding row of $R$, which is a row permutation
+        let mut negative = permutation_is_odd(self.symbolic.col_perm().into_arrays().0);
+        let mut r_rows = alloc::vec![I::truncate(usize::MAX); n];
+
+        let indices = self.indices;
+        let values = self.values;
+
+        match &self.symbolic.raw {
+            SymbolicQrRaw::Simplicial(symbolic) => {
+                let (_, indices) = indices.split_at(n + 1);
+                let (_, indices) = indices.split_at(symbolic.len_r());
+                let (householder_col_ptrs, indices) = indices.split_at(n + 1);
+                let (householder_row_indices, _) = indices.split_at(symbolic.len_householder());
+
+                let (_, values) = values.rb().split_at(symbolic.len_r());
+                let (householder_values, _) = values.split_at(symbolic.len_householder());
+
+                for j in 0..n {
+                    let begin = householder_col_ptrs[j].zx();
+                    let end = householder_col_ptrs[j + 1].zx();
+                    if begin == end {
+                        continue;
+                    }
+                    r_rows[j] = householder_row_indices[begin];
+                    if householder_values
+                        .subslice(begin + 1..end)
+                        .into_ref_iter()
+                        .any(|v| v.read() != E::faer_zero())
+                    {
+                        negative = !negative;
+                    }
+                }
+            }
+            SymbolicQrRaw::Supernodal(symbolic) => {
+                let L_symbolic = symbolic.r_adjoint();
+                let H_symbolic = symbolic.householder();
+
+                let (householder_row_indices, indices) =
+                    indices.split_at(H_symbolic.len_householder_row_indices());
+                let (_, indices) = indices.split_at(n);
+                let (householder_nrows, indices) = indices.split_at(n);
+                let (householder_ncols, _) = indices.split_at(n);
+
+                let (_, values) = values.rb().split_at(L_symbolic.len_values());
+                let (householder_values, _) = values.split_at(H_symbolic.len_householder_values());
+
+                let mut block_count = 0usize;
+                for s in 0..L_symbolic.n_supernodes() {
+                    let s_col_begin = L_symbolic.supernode_begin()[s].zx();
+                    let s_col_end = L_symbolic.supernode_end()[s].zx();
+                    let s_ncols = s_col_end - s_col_begin;
+
+                    let s_row_indices_in_panel = &householder_row_indices[H_symbolic
+                        .col_ptrs_for_householder_row_indices()[s]
+                        .zx()
+                        ..H_symbolic.col_ptrs_for_householder_row_indices()[s + 1].zx()];
+                    for (idx, &i) in s_row_indices_in_panel.iter().take(s_ncols).enumerate() {
+                        r_rows[s_col_begin + idx] = i;
+                    }
+
+                    let s_H = householder_values.subslice(
+                        H_symbolic.col_ptrs_for_householder_values()[s].zx()
+                            ..H_symbolic.col_ptrs_for_householder_values()[s + 1].zx(),
+                    );
+                    let s_H = faer_core::mat::from_column_major_slice::<'_, E>(
+                        s_H.into_inner(),
+                        s_row_indices_in_panel.len(),
+                        s_ncols
+                            + (L_symbolic.col_ptrs_for_row_indices()[s + 1].zx()
+                                - L_symbolic.col_ptrs_for_row_indices()[s].zx()),
+                    );
+
+                    let mut start = 0;
+                    let end = s_H.ncols();
+                    while start < end {
+                        let nrows = householder_nrows[block_count].zx();
+                        let ncols = householder_ncols[block_count].zx();
+                        let b_H = s_H.submatrix(start, start, nrows, ncols);
+                        for j in 0..Ord::min(nrows, ncols) {
+                            if (j + 1..nrows).any(|i| b_H.read(i, j) != E::faer_zero()) {
+                                negative = !negative;
+                            }
+                        }
+
+                        start += ncols;
+                        block_count += 1;
+
+                        if start >= s_H.nrows() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        if r_rows.iter().all(|&i| i.zx() < m) {
+            negative ^= permutation_is_odd(&r_rows);
+        }
+        negative
+    }
 }
 
 impl<I: Index> SymbolicQr<I> {
@@ -114,6 +114,7 @@ fn bidiag_svd(c: &mut Criterion) {
                         128,
                         f64::EPSILON,
                         f64::MIN_POSITIVE,
+                        None,
                         Parallelism::None,
                         stack.rb_mut(),
                     );
@@ -151,6 +152,7 @@ fn bidiag_svd(c: &mut Criterion) {
                         128,
                         f64::EPSILON,
                         f64::MIN_POSITIVE,
+                        None,
                         Parallelism::Rayon(0),
                         stack.rb_mut(),
                     );
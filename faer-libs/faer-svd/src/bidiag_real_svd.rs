@@ -12,7 +12,10 @@
 // Public License v. 2.0. If a copy of the MPL was not distributed
 // with this file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::jacobi::{jacobi_svd, Skip};
+use crate::{
+    jacobi::{jacobi_svd, Skip},
+    SvdInfo,
+};
 use coe::Coerce;
 use core::{iter::zip, mem::swap};
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
@@ -1159,9 +1162,10 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
     mut v: Option<MatMut<'_, E>>,
     epsilon: E,
     consider_zero_threshold: E,
-) {
+    max_iterations: Option<usize>,
+) -> SvdInfo {
     let n = diag.len();
-    let max_iter = 30usize.saturating_mul(n).saturating_mul(n);
+    let max_iter = max_iterations.unwrap_or_else(|| 30usize.saturating_mul(n).saturating_mul(n));
 
     let epsilon = epsilon.faer_scale_real(E::faer_from_f64(128.0));
 
@@ -1195,7 +1199,7 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
     let max_val = E::faer_one();
 
     if max_val == E::faer_zero() {
-        return;
+        return SvdInfo::converged();
     }
 
     for x in &mut *diag {
@@ -1216,7 +1220,7 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
     }
 
     impl<E: RealField> pulp::WithSimd for Impl<'_, E> {
-        type Output = ();
+        type Output = SvdInfo;
 
         #[inline(always)]
         fn with_simd<S: pulp::Simd>(self, simd: S) -> Self::Output {
@@ -1232,8 +1236,12 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
             let n = diag.len();
             let arch = E::Simd::default();
 
+            let mut info = SvdInfo {
+                iteration_count: max_iter,
+                converged: false,
+            };
+
             for iter in 0..max_iter {
-                let _ = iter;
                 for i in 0..n - 1 {
                     if subdiag[i].faer_abs()
                         <= epsilon.faer_mul(diag[i].faer_abs().faer_add(diag[i + 1].faer_abs()))
@@ -1255,6 +1263,10 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
                 }
 
                 if end == 1 {
+                    info = SvdInfo {
+                        iteration_count: iter,
+                        converged: true,
+                    };
                     break;
                 }
 
@@ -1443,11 +1455,13 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
                     }
                 }
             }
+
+            info
         }
     }
 
     use faer_entity::SimdCtx;
-    E::Simd::default().dispatch(Impl {
+    let info = E::Simd::default().dispatch(Impl {
         epsilon,
         consider_zero_threshold,
         max_iter,
@@ -1492,6 +1506,8 @@ fn bidiag_svd_qr_algorithm_impl<E: RealField>(
     for x in &mut *diag {
         *x = (*x).faer_mul(max_val);
     }
+
+    info
 }
 
 /// svd of bidiagonal lower matrix of shape (n + 1, n), with the last row being all zeros
@@ -1504,9 +1520,10 @@ pub fn compute_bidiag_real_svd<E: RealField>(
     bidiag_qr_fallback_threshold: usize,
     epsilon: E,
     consider_zero_threshold: E,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
-) {
+) -> SvdInfo {
     let n = diag.len();
 
     if n <= jacobi_fallback_threshold {
@@ -1520,11 +1537,12 @@ pub fn compute_bidiag_real_svd<E: RealField>(
             }
         }
 
-        jacobi_svd(
+        let (_, info) = jacobi_svd(
             s.rb_mut(),
             u.rb_mut().map(|u| u.submatrix_mut(0, 0, n, n)),
             v,
             Skip::None,
+            max_iterations,
             epsilon,
             consider_zero_threshold,
         );
@@ -1539,8 +1557,17 @@ pub fn compute_bidiag_real_svd<E: RealField>(
                 .for_each(|unzipped!(mut x)| x.write(E::faer_zero()));
             u.write(n, n, E::faer_one());
         }
+        info
     } else if n <= bidiag_qr_fallback_threshold {
-        bidiag_svd_qr_algorithm_impl(diag, subdiag, u, v, epsilon, consider_zero_threshold);
+        bidiag_svd_qr_algorithm_impl(
+            diag,
+            subdiag,
+            u,
+            v,
+            epsilon,
+            consider_zero_threshold,
+            max_iterations,
+        )
     } else {
         match u {
             Some(u) => bidiag_svd_impl(
@@ -1552,6 +1579,7 @@ pub fn compute_bidiag_real_svd<E: RealField>(
                 jacobi_fallback_threshold,
                 epsilon,
                 consider_zero_threshold,
+                max_iterations,
                 parallelism,
                 stack,
             ),
@@ -1567,9 +1595,10 @@ pub fn compute_bidiag_real_svd<E: RealField>(
                     jacobi_fallback_threshold,
                     epsilon,
                     consider_zero_threshold,
+                    max_iterations,
                     parallelism,
                     stack,
-                );
+                )
             }
         }
     }
@@ -1585,9 +1614,10 @@ fn bidiag_svd_impl<E: RealField>(
     jacobi_fallback_threshold: usize,
     epsilon: E,
     consider_zero_threshold: E,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     mut stack: PodStack<'_>,
-) {
+) -> SvdInfo {
     let n = diag.len();
 
     let mut max_val = E::faer_zero();
@@ -1617,7 +1647,7 @@ fn bidiag_svd_impl<E: RealField>(
             v.fill_zero();
             v.diagonal_mut().column_vector_mut().fill(E::faer_one());
         };
-        return;
+        return SvdInfo::converged();
     }
 
     for x in &mut *diag {
@@ -1642,6 +1672,7 @@ fn bidiag_svd_impl<E: RealField>(
 
     let compact_u = (u.nrows() != n + 1) as usize;
 
+    let info;
     if k <= jacobi_fallback_threshold || rem <= jacobi_fallback_threshold {
         let (mut u1_alloc, stack) =
             temp_mat_uninit::<E>(k + 1, compact_u * (k + 1), stack.rb_mut());
@@ -1690,11 +1721,12 @@ fn bidiag_svd_impl<E: RealField>(
             matrix2.write(j + 1, j + 1, sub_d2[j]);
         }
 
-        jacobi_svd(
+        let (_, info1) = jacobi_svd(
             matrix1.rb_mut(),
             Some(u1.rb_mut()),
             v1.rb_mut(),
             Skip::Last,
+            max_iterations,
             epsilon,
             consider_zero_threshold,
         );
@@ -1705,14 +1737,16 @@ fn bidiag_svd_impl<E: RealField>(
                 }
             }
         }
-        jacobi_svd(
+        let (_, info2) = jacobi_svd(
             matrix2.rb_mut(),
             Some(u2.rb_mut()),
             v2.rb_mut(),
             Skip::First,
+            max_iterations,
             epsilon,
             consider_zero_threshold,
         );
+        info = info1.merge(info2);
         for j in 0..matrix2.ncols() {
             for i in 0..matrix1.nrows() {
                 if i != j {
@@ -1809,9 +1843,11 @@ fn bidiag_svd_impl<E: RealField>(
         let (mem1, stack2) = stack.rb_mut().make_raw::<u8>(stack_bytes / 2);
         let stack1 = PodStack::new(mem1);
 
+        let mut info1 = SvdInfo::converged();
+        let mut info2 = SvdInfo::converged();
         join_raw(
             |parallelism| {
-                bidiag_svd_impl(
+                info1 = bidiag_svd_impl(
                     d1,
                     sub_d1,
                     u1.rb_mut(),
@@ -1820,12 +1856,13 @@ fn bidiag_svd_impl<E: RealField>(
                     jacobi_fallback_threshold,
                     epsilon,
                     consider_zero_threshold,
+                    max_iterations,
                     parallelism,
                     stack1,
                 );
             },
             |parallelism| {
-                bidiag_svd_impl(
+                info2 = bidiag_svd_impl(
                     d2,
                     sub_d2,
                     u2.rb_mut(),
@@ -1834,12 +1871,14 @@ fn bidiag_svd_impl<E: RealField>(
                     jacobi_fallback_threshold,
                     epsilon,
                     consider_zero_threshold,
+                    max_iterations,
                     parallelism,
                     stack2,
                 );
             },
             parallelism,
         );
+        info = info1.merge(info2);
 
         if compact_u == 1 {
             // handle rotation of Q1, q1
@@ -2201,6 +2240,8 @@ fn bidiag_svd_impl<E: RealField>(
     for x in &mut *diag {
         *x = (*x).faer_mul(max_val);
     }
+
+    info
 }
 
 pub fn bidiag_real_svd_req<E: Entity>(
@@ -2286,6 +2327,7 @@ mod tests {
                     0,
                     f64::EPSILON,
                     f64::MIN_POSITIVE,
+                    None,
                     Parallelism::None,
                     make_stack!(bidiag_real_svd_req::<f64>(
                         n,
@@ -2333,6 +2375,7 @@ mod tests {
                 Some(v.as_mut()),
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
             );
             Mat::from_fn(n, n, |i, j| if i == j { diag[i] } else { 0.0 })
         };
@@ -2503,6 +2546,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -2809,6 +2853,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -4907,6 +4952,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -5981,6 +6027,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -8079,6 +8126,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -10177,6 +10225,7 @@ mod tests {
                 0,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(bidiag_real_svd_req::<f64>(
                     n,
@@ -0,0 +1,381 @@
+//! The generalized singular value decomposition (GSVD) of a pair of matrices $A$ and $B$ with
+//! shapes $(m, n)$ and $(p, n)$ is a decomposition into the components $U$, $V$, $X$, $\alpha$
+//! and $\beta$, such that:
+//!
+//! - $U$ has shape $(m, n)$, its first $\min(m, n)$ columns are orthonormal, and the remaining
+//!   ones are zero, which implies that the corresponding values of $\alpha$ are zero,
+//! - $V$ has shape $(p, n)$, its last $\min(p, n)$ columns are orthonormal, and the remaining ones
+//!   are zero, which implies that the corresponding values of $\beta$ are zero,
+//! - $X$ has shape $(n, n)$ and is invertible,
+//! - $\alpha$ and $\beta$ are nonnegative, with $\alpha_i^2 + \beta_i^2 = 1$, and $\alpha$ is
+//!   sorted in nonincreasing order,
+//! - and finally:
+//!
+//! $$AX = U \operatorname{diag}(\alpha), \quad BX = V \operatorname{diag}(\beta).$$
+//!
+//! The generalized singular values of the pair are the ratios $\alpha_i / \beta_i$.
+//!
+//! The decomposition is computed from the QR decomposition of the stacked matrix
+//! $\begin{bmatrix}A \\ B\end{bmatrix} = \begin{bmatrix}Q_1 \\ Q_2\end{bmatrix} R$, followed by the
+//! CS decomposition of $Q_1$ and $Q_2$. The stacked matrix is required to have full column rank.
+
+use crate::{compute_svd, compute_svd_req, ComputeVectors, SvdParams};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    assert,
+    householder::{
+        apply_block_householder_sequence_on_the_left_in_place_req,
+        apply_block_householder_sequence_on_the_left_in_place_with_conj,
+    },
+    mul::matmul,
+    solve::solve_upper_triangular_in_place,
+    temp_mat_req, temp_mat_uninit, ComplexField, Conj, MatMut, MatRef, Parallelism,
+};
+use reborrow::*;
+
+fn thin_qr_req<E: ComplexField>(
+    nrows: usize,
+    ncols: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+    StackReq::try_all_of([
+        temp_mat_req::<E>(blocksize, ncols)?,
+        StackReq::try_any_of([
+            faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
+                nrows,
+                ncols,
+                blocksize,
+                parallelism,
+                Default::default(),
+            )?,
+            apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                nrows, blocksize, ncols,
+            )?,
+        ])?,
+    ])
+}
+
+/// Computes the QR decomposition of `matrix`, storing the thin $Q$ factor in `q`, and the $R$
+/// factor in the upper triangular part of `matrix`.
+fn thin_qr<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    q: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+    let mut matrix = matrix;
+    let mut q = q;
+
+    let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(m, n);
+    let (mut householder, mut stack) = temp_mat_uninit::<E>(blocksize, n, stack);
+    let mut householder = householder.as_mut();
+
+    faer_qr::no_pivoting::compute::qr_in_place(
+        matrix.rb_mut(),
+        householder.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        Default::default(),
+    );
+
+    q.fill_zero();
+    for j in 0..q.ncols() {
+        q.write(j, j, E::faer_one());
+    }
+
+    apply_block_householder_sequence_on_the_left_in_place_with_conj(
+        matrix.rb(),
+        householder.rb(),
+        Conj::No,
+        q,
+        parallelism,
+        stack,
+    );
+}
+
+/// Computes the size and alignment of required workspace for computing the generalized singular
+/// value decomposition of a pair of matrices with shapes `(m, n)` and `(p, n)`.
+pub fn compute_gsvd_req<E: ComplexField>(
+    m: usize,
+    p: usize,
+    n: usize,
+    parallelism: Parallelism,
+    params: SvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    let mp = m.checked_add(p).ok_or(SizeOverflow)?;
+    let k = Ord::min(m, n);
+    let r = Ord::min(p, n);
+
+    StackReq::try_all_of([
+        temp_mat_req::<E>(mp, n)?,
+        temp_mat_req::<E>(mp, n)?,
+        temp_mat_req::<E>(k, 1)?,
+        temp_mat_req::<E>(m, k)?,
+        temp_mat_req::<E>(n, n)?,
+        temp_mat_req::<E>(p, n)?,
+        temp_mat_req::<E>(p, r)?,
+        StackReq::try_any_of([
+            thin_qr_req::<E>(mp, n, parallelism)?,
+            compute_svd_req::<E>(
+                m,
+                n,
+                ComputeVectors::Thin,
+                ComputeVectors::Full,
+                parallelism,
+                params,
+            )?,
+            thin_qr_req::<E>(p, r, parallelism)?,
+        ])?,
+    ])
+}
+
+/// Computes the generalized singular value decomposition of the pair `(a, b)`.
+///
+/// `alpha` and `beta` must have shape `(n, 1)`, where `n` is the number of columns of `a` and
+/// `b`, and receive the diagonals of the decomposition.
+///
+/// If `u` is `Some(..)`, it must have shape `(m, n)`. If `v` is `Some(..)`, it must have shape
+/// `(p, n)`. If `x` is `Some(..)`, it must have shape `(n, n)`. See the module level
+/// documentation for a description of the computed factors.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated, or if `m + p < n`, in which
+/// case the stacked matrix cannot have full column rank.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`compute_gsvd_req`]).
+#[track_caller]
+pub fn compute_gsvd<E: ComplexField>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, E>,
+    alpha: MatMut<'_, E>,
+    beta: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+    x: Option<MatMut<'_, E>>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: SvdParams,
+) {
+    let m = a.nrows();
+    let p = b.nrows();
+    let n = a.ncols();
+    assert!(all(
+        b.ncols() == n,
+        m + p >= n,
+        alpha.nrows() == n,
+        alpha.ncols() == 1,
+        beta.nrows() == n,
+        beta.ncols() == 1,
+    ));
+    if let Some(u) = u.rb() {
+        assert!(all(u.nrows() == m, u.ncols() == n));
+    }
+    if let Some(v) = v.rb() {
+        assert!(all(v.nrows() == p, v.ncols() == n));
+    }
+    if let Some(x) = x.rb() {
+        assert!(all(x.nrows() == n, x.ncols() == n));
+    }
+
+    if n == 0 {
+        return;
+    }
+
+    let mut alpha = alpha;
+    let mut beta = beta;
+    let k = Ord::min(m, n);
+    let r = Ord::min(p, n);
+
+    let (mut stacked, stack) = temp_mat_uninit::<E>(m + p, n, stack);
+    let (mut q, stack) = temp_mat_uninit::<E>(m + p, n, stack);
+    let (mut c, stack) = temp_mat_uninit::<E>(k, 1, stack);
+    let (mut u1, stack) = temp_mat_uninit::<E>(m, k, stack);
+    let (mut w, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let (mut g, stack) = temp_mat_uninit::<E>(p, n, stack);
+    let (mut v2, mut stack) = temp_mat_uninit::<E>(p, r, stack);
+    let mut stacked = stacked.as_mut();
+    let mut q = q.as_mut();
+    let mut c = c.as_mut();
+    let mut u1 = u1.as_mut();
+    let mut w = w.as_mut();
+    let mut g = g.as_mut();
+    let mut v2 = v2.as_mut();
+
+    // [A; B] = [Q1; Q2] R
+    stacked.rb_mut().subrows_mut(0, m).copy_from(a);
+    stacked.rb_mut().subrows_mut(m, p).copy_from(b);
+    thin_qr(stacked.rb_mut(), q.rb_mut(), parallelism, stack.rb_mut());
+
+    // Q1 = U1 C W^H
+    compute_svd(
+        q.rb().subrows(0, m),
+        c.rb_mut(),
+        Some(u1.rb_mut()),
+        Some(w.rb_mut()),
+        parallelism,
+        stack.rb_mut(),
+        params,
+    );
+
+    // Q2 W = V2 S, with orthogonal columns. since the values of C are sorted in nonincreasing
+    // order, the nonzero columns are the last `r` ones.
+    matmul(
+        g.rb_mut(),
+        q.rb().subrows(m, p),
+        w.rb(),
+        None,
+        E::faer_one(),
+        parallelism,
+    );
+    // orthonormalize the nonzero columns to make sure the basis is accurate even for small
+    // values of S
+    thin_qr(
+        g.rb_mut().subcols_mut(n - r, r),
+        v2.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    for j in 0..n {
+        alpha.write(
+            j,
+            0,
+            if j < k {
+                E::faer_from_real(c.read(j, 0).faer_real())
+            } else {
+                E::faer_zero()
+            },
+        );
+    }
+
+    for j in 0..n {
+        beta.write(j, 0, E::faer_zero());
+    }
+    for j in 0..r {
+        let diag = g.read(j, n - r + j);
+        let abs = diag.faer_abs();
+        beta.write(n - r + j, 0, E::faer_from_real(abs));
+
+        // fix the phase of the basis vector so that Q2 W = V2 S
+        if abs > E::Real::faer_zero() {
+            let phase = diag.faer_scale_real(abs.faer_inv());
+            for i in 0..p {
+                v2.write(i, j, v2.read(i, j).faer_mul(phase));
+            }
+        }
+    }
+
+    if let Some(mut u) = u {
+        u.fill_zero();
+        u.rb_mut().subcols_mut(0, k).copy_from(u1.rb());
+    }
+    if let Some(mut v) = v {
+        v.fill_zero();
+        v.rb_mut().subcols_mut(n - r, r).copy_from(v2.rb());
+    }
+    if let Some(mut x) = x {
+        // X = R^-1 W
+        x.copy_from(w.rb());
+        solve_upper_triangular_in_place(stacked.rb().subrows(0, n), x, parallelism);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use faer_core::{assert, c64, Mat};
+
+    macro_rules! make_stack {
+        ($req: expr) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_gsvd() {
+        for (m, p, n) in [
+            (6, 5, 4),
+            (3, 5, 4),
+            (5, 3, 4),
+            (4, 4, 4),
+            (2, 7, 6),
+            (7, 2, 6),
+        ] {
+            let a = Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()));
+            let b = Mat::from_fn(p, n, |_, _| c64::new(rand::random(), rand::random()));
+
+            let mut alpha = Mat::zeros(n, 1);
+            let mut beta = Mat::zeros(n, 1);
+            let mut u = Mat::zeros(m, n);
+            let mut v = Mat::zeros(p, n);
+            let mut x = Mat::zeros(n, n);
+
+            compute_gsvd(
+                a.as_ref(),
+                b.as_ref(),
+                alpha.as_mut(),
+                beta.as_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Some(x.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_gsvd_req::<c64>(
+                    m,
+                    p,
+                    n,
+                    Parallelism::None,
+                    Default::default()
+                )),
+                Default::default(),
+            );
+
+            for j in 0..n {
+                let a = alpha.read(j, 0);
+                let b = beta.read(j, 0);
+                assert_approx_eq!(a * a + b * b, c64::new(1.0, 0.0), 1e-10);
+                if j > 0 {
+                    assert!(alpha.read(j - 1, 0).re >= alpha.read(j, 0).re);
+                }
+            }
+
+            let ax = &a * &x;
+            let bx = &b * &x;
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(ax.read(i, j), u.read(i, j) * alpha.read(j, 0), 1e-10);
+                }
+                for i in 0..p {
+                    assert_approx_eq!(bx.read(i, j), v.read(i, j) * beta.read(j, 0), 1e-10);
+                }
+            }
+
+            let uu = u.adjoint() * &u;
+            let vv = v.adjoint() * &v;
+            for j in 0..n {
+                for i in 0..n {
+                    let expected_u = if i == j && alpha.read(j, 0).re > 1e-10 {
+                        1.0
+                    } else if i == j {
+                        uu.read(i, j).re
+                    } else {
+                        0.0
+                    };
+                    let expected_v = if i == j && beta.read(j, 0).re > 1e-10 {
+                        1.0
+                    } else if i == j {
+                        vv.read(i, j).re
+                    } else {
+                        0.0
+                    };
+                    assert_approx_eq!(uu.read(i, j), c64::new(expected_u, 0.0), 1e-10);
+                    assert_approx_eq!(vv.read(i, j), c64::new(expected_v, 0.0), 1e-10);
+                }
+            }
+        }
+    }
+}
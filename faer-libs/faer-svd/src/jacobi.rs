@@ -8,9 +8,12 @@
 // Public License v. 2.0. If a copy of the MPL was not distributed
 // with this file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use crate::SvdInfo;
 use faer_core::{assert, jacobi::JacobiRotation, permutation::swap_cols, MatMut, RealField};
 use reborrow::*;
 
+const MAX_SWEEPS: usize = 64;
+
 fn compute_2x2<E: RealField>(
     m00: E,
     m01: E,
@@ -60,9 +63,10 @@ pub fn jacobi_svd<E: RealField>(
     u: Option<MatMut<'_, E>>,
     v: Option<MatMut<'_, E>>,
     skip: Skip,
+    max_sweeps: Option<usize>,
     epsilon: E,
     consider_zero_threshold: E,
-) -> usize {
+) -> (usize, SvdInfo) {
     assert!(matrix.nrows() == matrix.ncols());
     let n = matrix.nrows();
 
@@ -124,7 +128,12 @@ pub fn jacobi_svd<E: RealField>(
     }
 
     let precision = epsilon.faer_scale_power_of_two(E::faer_one().faer_add(E::faer_one()));
-    loop {
+    let max_sweeps = max_sweeps.unwrap_or(MAX_SWEEPS);
+    let mut info = SvdInfo {
+        iteration_count: max_sweeps,
+        converged: false,
+    };
+    for sweep in 0..max_sweeps {
         let mut failed = false;
         for p in 1..n {
             for q in 0..p {
@@ -180,6 +189,10 @@ pub fn jacobi_svd<E: RealField>(
             }
         }
         if !failed {
+            info = SvdInfo {
+                iteration_count: sweep,
+                converged: true,
+            };
             break;
         }
     }
@@ -242,7 +255,7 @@ pub fn jacobi_svd<E: RealField>(
             }
         }
     }
-    nnz_count
+    (nnz_count, info)
 }
 
 #[cfg(test)]
@@ -304,6 +317,7 @@ mod tests {
                 Some(u.as_mut()),
                 Some(v.as_mut()),
                 Skip::None,
+                None,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
             );
@@ -329,6 +343,7 @@ mod tests {
                 Some(u.as_mut()),
                 Some(v.as_mut()),
                 Skip::First,
+                None,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
             );
@@ -373,6 +388,7 @@ mod tests {
                 Some(u.as_mut()),
                 Some(v.as_mut()),
                 Skip::Last,
+                None,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
             );
@@ -402,6 +418,7 @@ mod tests {
             Some(u.as_mut()),
             Some(v.as_mut()),
             Skip::None,
+            None,
             f64::EPSILON,
             f64::MIN_POSITIVE,
         );
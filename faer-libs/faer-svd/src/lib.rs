@@ -38,6 +38,11 @@ pub mod bidiag;
 pub mod bidiag_real_svd;
 #[doc(hidden)]
 pub mod jacobi;
+#[doc(hidden)]
+pub mod one_sided_jacobi;
+
+pub mod gsvd;
+pub mod randomized;
 
 const JACOBI_FALLBACK_THRESHOLD: usize = 4;
 const BIDIAG_QR_FALLBACK_THRESHOLD: usize = 128;
@@ -178,9 +183,10 @@ fn compute_real_svd_small<E: RealField>(
     v: Option<MatMut<'_, E>>,
     epsilon: E,
     zero_threshold: E,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
-) {
+) -> SvdInfo {
     let mut u = u;
     let mut v = v;
 
@@ -196,17 +202,18 @@ fn compute_real_svd_small<E: RealField>(
         zipped!(jacobi_mat.rb_mut(), matrix)
             .for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
 
-        jacobi::jacobi_svd(
+        let (_, info) = jacobi::jacobi_svd(
             jacobi_mat.rb_mut(),
             u,
             v,
             jacobi::Skip::None,
+            max_iterations,
             epsilon,
             zero_threshold,
         );
         zipped!(s, jacobi_mat.rb().diagonal().column_vector().as_2d())
             .for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
-        return;
+        return info;
     }
 
     let householder_blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(m, n);
@@ -216,7 +223,7 @@ fn compute_real_svd_small<E: RealField>(
     let mut qr = qr.as_mut();
     let mut householder = householder.as_mut();
 
-    {
+    let info = {
         let (mut r, mut stack) = temp_mat_uninit::<E>(n, n, stack.rb_mut());
         let mut r = r.as_mut();
 
@@ -238,17 +245,19 @@ fn compute_real_svd_small<E: RealField>(
             });
 
         // r = u s v
-        jacobi::jacobi_svd(
+        let (_, info) = jacobi::jacobi_svd(
             r.rb_mut(),
             u.rb_mut().map(|u| u.submatrix_mut(0, 0, n, n)),
             v.rb_mut(),
             jacobi::Skip::None,
+            max_iterations,
             epsilon,
             zero_threshold,
         );
         zipped!(s, r.rb().diagonal().column_vector().as_2d())
             .for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
-    }
+        info
+    };
 
     // matrix = q u s v
     if let Some(mut u) = u.rb_mut() {
@@ -276,6 +285,8 @@ fn compute_real_svd_small<E: RealField>(
             stack.rb_mut(),
         );
     }
+
+    info
 }
 
 fn compute_bidiag_cplx_svd<E: ComplexField>(
@@ -287,9 +298,10 @@ fn compute_bidiag_cplx_svd<E: ComplexField>(
     bidiag_qr_fallback_threshold: usize,
     epsilon: E::Real,
     consider_zero_threshold: E::Real,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
-) {
+) -> SvdInfo {
     let n = diag.len();
     let (mut u_real, stack) =
         temp_mat_uninit::<E::Real>(n + 1, if u.is_some() { n + 1 } else { 0 }, stack);
@@ -323,7 +335,7 @@ fn compute_bidiag_cplx_svd<E: ComplexField>(
         col_mul[i] = col_normalized;
     }
 
-    compute_bidiag_real_svd::<E::Real>(
+    let info = compute_bidiag_real_svd::<E::Real>(
         diag_real,
         subdiag_real,
         u.is_some().then_some(u_real.rb_mut()),
@@ -332,6 +344,7 @@ fn compute_bidiag_cplx_svd<E: ComplexField>(
         bidiag_qr_fallback_threshold,
         epsilon,
         consider_zero_threshold,
+        max_iterations,
         parallelism,
         stack,
     );
@@ -374,6 +387,8 @@ fn compute_bidiag_cplx_svd<E: ComplexField>(
             }
         }
     }
+
+    info
 }
 
 fn bidiag_cplx_svd_req<E: Entity>(
@@ -415,14 +430,16 @@ fn compute_svd_big<E: ComplexField>(
         bidiag_qr_fallback_threshold: usize,
         epsilon: E::Real,
         consider_zero_threshold: E::Real,
+        max_iterations: Option<usize>,
         parallelism: Parallelism,
         stack: PodStack<'_>,
-    ),
+    ) -> SvdInfo,
     epsilon: E::Real,
     zero_threshold: E::Real,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack<'_>,
-) {
+) -> SvdInfo {
     let mut stack = stack;
 
     assert!(matrix.nrows() >= matrix.ncols());
@@ -497,7 +514,7 @@ fn compute_svd_big<E: ComplexField>(
     let (mut v_b, mut stack) = temp_mat_uninit::<E>(n, if u.is_some() { n } else { 0 }, stack);
     let mut v_b = v_b.as_mut();
 
-    bidiag_svd(
+    let info = bidiag_svd(
         diag,
         subdiag,
         v.is_some().then_some(u_b.rb_mut()),
@@ -506,6 +523,7 @@ fn compute_svd_big<E: ComplexField>(
         BIDIAG_QR_FALLBACK_THRESHOLD,
         epsilon,
         zero_threshold,
+        max_iterations,
         parallelism,
         stack.rb_mut(),
     );
@@ -570,11 +588,63 @@ fn compute_svd_big<E: ComplexField>(
             stack.rb_mut(),
         );
     }
+
+    info
+}
+
+/// Algorithm used for computing the singular value decomposition.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SvdAlgorithm {
+    /// Reduction to bidiagonal form, followed by a divide and conquer bidiagonal SVD.
+    #[default]
+    Bidiagonal,
+    /// One-sided Jacobi SVD, preconditioned by a QR decomposition with column pivoting.
+    ///
+    /// This is slower than [`SvdAlgorithm::Bidiagonal`], but computes the singular values of
+    /// graded or badly scaled matrices to high relative accuracy, including the tiny ones.
+    Jacobi,
 }
 
 #[derive(Default, Copy, Clone)]
 #[non_exhaustive]
-pub struct SvdParams {}
+pub struct SvdParams {
+    /// Algorithm used for computing the decomposition.
+    pub algorithm: SvdAlgorithm,
+    /// Maximum number of iterations allowed for each iterative stage of the algorithm, i.e., the
+    /// number of sweeps of the Jacobi stages, or the number of QR iterations of the bidiagonal QR
+    /// algorithm. If `None`, a default limit depending on the algorithm and the dimension of the
+    /// matrix is used.
+    pub max_iterations: Option<usize>,
+}
+
+/// Information about the convergence of the singular value decomposition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SvdInfo {
+    /// Total number of iterations performed by the iterative stages of the algorithm.
+    pub iteration_count: usize,
+    /// Whether every iterative stage converged before reaching the maximum number of iterations.
+    /// This is also `false` if the input matrix contained non-finite values.
+    pub converged: bool,
+}
+
+impl SvdInfo {
+    #[inline]
+    pub(crate) fn converged() -> Self {
+        Self {
+            iteration_count: 0,
+            converged: true,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn merge(self, other: Self) -> Self {
+        Self {
+            iteration_count: self.iteration_count + other.iteration_count,
+            converged: self.converged && other.converged,
+        }
+    }
+}
 
 /// Computes the size and alignment of required workspace for performing a singular value
 /// decomposition. $U$ and $V$ may be computed fully, partially, or not computed at all.
@@ -608,7 +678,10 @@ pub fn compute_svd_req<E: ComplexField>(
         (size, size)
     };
 
-    let _ = params;
+    if params.algorithm == SvdAlgorithm::Jacobi {
+        return one_sided_jacobi::compute_jacobi_svd_req::<E>(nrows, ncols, parallelism);
+    }
+
     let squareish_svd = if coe::is_same::<E, E::Real>() {
         if size <= JACOBI_FALLBACK_THRESHOLD {
             compute_real_svd_small_req::<E>(svd_nrows, svd_ncols, compute_u, compute_v, parallelism)
@@ -684,6 +757,11 @@ pub fn compute_svd_req<E: ComplexField>(
 /// fixed precision at compile time, e.g. a dynamic multiprecision floating point type.
 ///
 /// This can also panic if the provided memory in `stack` is insufficient (see [`compute_svd_req`]).
+///
+/// # Output
+///
+/// The number of iterations that were performed, and whether the algorithm converged within the
+/// limit set by `params.max_iterations`. If it did not, the output is not accurate.
 #[track_caller]
 pub fn compute_svd<E: ComplexField>(
     matrix: MatRef<'_, E>,
@@ -693,7 +771,7 @@ pub fn compute_svd<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: SvdParams,
-) {
+) -> SvdInfo {
     compute_svd_custom_epsilon(
         matrix,
         s,
@@ -704,7 +782,7 @@ pub fn compute_svd<E: ComplexField>(
         parallelism,
         stack,
         params,
-    );
+    )
 }
 
 /// See [`compute_svd`].
@@ -726,7 +804,7 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
     parallelism: Parallelism,
     stack: PodStack<'_>,
     params: SvdParams,
-) {
+) -> SvdInfo {
     let size = Ord::min(matrix.nrows(), matrix.ncols());
     assert!(all(s.nrows() == size, s.ncols() == 1));
     if let Some(u) = u.rb() {
@@ -760,7 +838,10 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         if let Some(mut v) = v {
             v.fill(E::faer_nan());
         }
-        return;
+        return SvdInfo {
+            iteration_count: 0,
+            converged: false,
+        };
     }
 
     let mut u = u;
@@ -786,12 +867,23 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
             .for_each(|unzipped!(mut dst)| dst.write(E::faer_one()));
         }
 
-        return;
+        return SvdInfo::converged();
     }
 
-    let _ = params;
-
-    if m as f64 / n as f64 <= 11.0 / 6.0 {
+    let max_iterations = params.max_iterations;
+    let info = if params.algorithm == SvdAlgorithm::Jacobi {
+        one_sided_jacobi::compute_jacobi_svd(
+            matrix,
+            s,
+            u.rb_mut(),
+            v.rb_mut(),
+            epsilon,
+            zero_threshold,
+            max_iterations,
+            parallelism,
+            stack,
+        )
+    } else if m as f64 / n as f64 <= 11.0 / 6.0 {
         squareish_svd(
             matrix,
             s,
@@ -799,9 +891,10 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
             v.rb_mut(),
             epsilon,
             zero_threshold,
+            max_iterations,
             parallelism,
             stack,
-        );
+        )
     } else {
         // do a qr first, then do the svd
         let householder_blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(m, n);
@@ -811,7 +904,7 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
         let (mut householder, mut stack) = temp_mat_uninit::<E>(householder_blocksize, n, stack);
         let mut householder = householder.as_mut();
 
-        {
+        let info = {
             let (mut r, mut stack) = temp_mat_uninit::<E>(n, n, stack.rb_mut());
             let mut r = r.as_mut();
 
@@ -841,10 +934,11 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
                 v.rb_mut(),
                 epsilon,
                 zero_threshold,
+                max_iterations,
                 parallelism,
                 stack,
-            );
-        }
+            )
+        };
 
         // matrix = q u s v
         if let Some(mut u) = u.rb_mut() {
@@ -872,7 +966,9 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
                 stack.rb_mut(),
             );
         }
-    }
+
+        info
+    };
 
     if do_transpose {
         // conjugate u and v
@@ -883,6 +979,8 @@ pub fn compute_svd_custom_epsilon<E: ComplexField>(
             zipped!(v).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()))
         }
     }
+
+    info
 }
 
 fn squareish_svd<E: ComplexField>(
@@ -892,9 +990,10 @@ fn squareish_svd<E: ComplexField>(
     mut v: Option<MatMut<E>>,
     epsilon: E::Real,
     zero_threshold: E::Real,
+    max_iterations: Option<usize>,
     parallelism: Parallelism,
     stack: PodStack,
-) {
+) -> SvdInfo {
     let size = matrix.ncols();
     if coe::is_same::<E, E::Real>() {
         if size <= JACOBI_FALLBACK_THRESHOLD {
@@ -905,9 +1004,10 @@ fn squareish_svd<E: ComplexField>(
                 v.rb_mut().map(coe::Coerce::coerce),
                 coe::coerce_static(epsilon),
                 coe::coerce_static(zero_threshold),
+                max_iterations,
                 parallelism,
                 stack,
-            );
+            )
         } else {
             compute_svd_big::<E::Real>(
                 matrix.coerce(),
@@ -917,9 +1017,10 @@ fn squareish_svd<E: ComplexField>(
                 compute_bidiag_real_svd::<E::Real>,
                 coe::coerce_static(epsilon),
                 coe::coerce_static(zero_threshold),
+                max_iterations,
                 parallelism,
                 stack,
-            );
+            )
         }
     } else {
         compute_svd_big::<E>(
@@ -930,9 +1031,10 @@ fn squareish_svd<E: ComplexField>(
             compute_bidiag_cplx_svd::<E>,
             coe::coerce_static(epsilon),
             coe::coerce_static(zero_threshold),
+            max_iterations,
             parallelism,
             stack,
-        );
+        )
     }
 }
 
@@ -970,6 +1072,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1016,6 +1119,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1059,6 +1163,7 @@ mod tests {
                 compute_bidiag_real_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<f64>(
                     m,
@@ -1101,6 +1206,7 @@ mod tests {
                 Some(v.as_mut()),
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_real_svd_small_req::<f64>(
                     m,
@@ -1529,6 +1635,7 @@ mod tests {
                 compute_bidiag_cplx_svd::<c64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<c64>(
                     m,
@@ -1572,6 +1679,7 @@ mod tests {
                 compute_bidiag_cplx_svd::<f64>,
                 f64::EPSILON,
                 f64::MIN_POSITIVE,
+                None,
                 Parallelism::None,
                 make_stack!(compute_svd_big_req::<c64>(
                     m,
@@ -1667,4 +1775,163 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_jacobi_cplx() {
+        let mut params = SvdParams::default();
+        params.algorithm = SvdAlgorithm::Jacobi;
+
+        for (m, n) in [(1, 1), (3, 2), (2, 3), (4, 4), (15, 10), (10, 15), (20, 20)] {
+            for rank_deficient in [false, true] {
+                let mat = if rank_deficient {
+                    let a = Mat::from_fn(m, 1, |_, _| c64::new(rand::random(), rand::random()));
+                    let b = Mat::from_fn(1, n, |_, _| c64::new(rand::random(), rand::random()));
+                    &a * &b
+                } else {
+                    Mat::from_fn(m, n, |_, _| c64::new(rand::random(), rand::random()))
+                };
+                let size = m.min(n);
+
+                let mut s = Mat::zeros(m, n);
+                let mut u = Mat::zeros(m, m);
+                let mut v = Mat::zeros(n, n);
+
+                compute_svd(
+                    mat.as_ref(),
+                    s.as_mut()
+                        .submatrix_mut(0, 0, size, size)
+                        .diagonal_mut()
+                        .column_vector_mut()
+                        .as_2d_mut(),
+                    Some(u.as_mut()),
+                    Some(v.as_mut()),
+                    Parallelism::None,
+                    make_stack!(compute_svd_req::<c64>(
+                        m,
+                        n,
+                        ComputeVectors::Full,
+                        ComputeVectors::Full,
+                        Parallelism::None,
+                        params,
+                    )),
+                    params,
+                );
+
+                let reconstructed = &u * &s * v.adjoint();
+                for j in 0..n {
+                    for i in 0..m {
+                        assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                    }
+                }
+
+                let uu = u.adjoint() * &u;
+                for j in 0..m {
+                    for i in 0..m {
+                        let target = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+                        assert_approx_eq!(uu.read(i, j), target, 1e-10);
+                    }
+                }
+                let vv = v.adjoint() * &v;
+                for j in 0..n {
+                    for i in 0..n {
+                        let target = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+                        assert_approx_eq!(vv.read(i, j), target, 1e-10);
+                    }
+                }
+                for i in 1..size {
+                    assert!(s.read(i - 1, i - 1).re >= s.read(i, i).re);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_graded() {
+        let mut params = SvdParams::default();
+        params.algorithm = SvdAlgorithm::Jacobi;
+
+        for n in [2, 5, 10] {
+            // a householder reflector with geometrically graded columns, whose singular values are
+            // exactly the column scaling factors
+            let w = Mat::from_fn(n, 1, |_, _| rand::random::<f64>() + 0.5);
+            let ww = w.norm_l2() * w.norm_l2();
+            let scale = |j: usize| 1e-3f64.powi(j as i32);
+            let mat = Mat::from_fn(n, n, |i, j| {
+                let id = if i == j { 1.0 } else { 0.0 };
+                (id - 2.0 * w.read(i, 0) * w.read(j, 0) / ww) * scale(n - 1 - j)
+            });
+
+            let mut s = Mat::zeros(n, 1);
+            let mut u = Mat::zeros(n, n);
+            let mut v = Mat::zeros(n, n);
+
+            compute_svd(
+                mat.as_ref(),
+                s.as_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Parallelism::None,
+                make_stack!(compute_svd_req::<f64>(
+                    n,
+                    n,
+                    ComputeVectors::Full,
+                    ComputeVectors::Full,
+                    Parallelism::None,
+                    params,
+                )),
+                params,
+            );
+
+            for j in 0..n {
+                let expected = scale(j);
+                assert!((s.read(j, 0) - expected).abs() <= 1e-12 * expected);
+            }
+
+            let reconstructed = &u
+                * Mat::from_fn(n, n, |i, j| if i == j { s.read(i, 0) } else { 0.0 })
+                * v.transpose();
+            for j in 0..n {
+                for i in 0..n {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        let n = 30;
+        let mat = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+
+        for algorithm in [SvdAlgorithm::Bidiagonal, SvdAlgorithm::Jacobi] {
+            for max_iterations in [None, Some(0)] {
+                let mut params = SvdParams::default();
+                params.algorithm = algorithm;
+                params.max_iterations = max_iterations;
+
+                let mut s = Mat::zeros(n, 1);
+                let info = compute_svd(
+                    mat.as_ref(),
+                    s.as_mut(),
+                    None,
+                    None,
+                    Parallelism::None,
+                    make_stack!(compute_svd_req::<f64>(
+                        n,
+                        n,
+                        ComputeVectors::No,
+                        ComputeVectors::No,
+                        Parallelism::None,
+                        params,
+                    )),
+                    params,
+                );
+
+                assert!(info.converged == max_iterations.is_none());
+                if max_iterations.is_none() {
+                    assert!(info.iteration_count > 0);
+                }
+            }
+        }
+    }
 }
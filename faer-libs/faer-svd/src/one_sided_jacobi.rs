@@ -0,0 +1,338 @@
+//! Preconditioned one-sided Jacobi SVD, following the approach of Drmač and Veselić.
+//!
+//! The matrix is first reduced with a QR decomposition with column pivoting, $AP^\top = QR$, after
+//! which the one-sided (Hestenes) Jacobi method is applied to $R^H$. The column pivoting makes the
+//! result insensitive to the column scaling of $A$, so that graded matrices get all their singular
+//! values computed to high relative accuracy, including the tiny ones.
+
+use crate::SvdInfo;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    householder::{
+        apply_block_householder_sequence_on_the_left_in_place_req,
+        apply_block_householder_sequence_on_the_left_in_place_with_conj,
+    },
+    permutation::swap_cols,
+    temp_mat_req, temp_mat_uninit, unzipped, zipped, ComplexField, Conj, Entity, MatMut, MatRef,
+    Parallelism, RealField,
+};
+use reborrow::*;
+
+const MAX_SWEEPS: usize = 64;
+
+/// Computes the size and alignment of required workspace for computing the SVD of a matrix with
+/// `nrows >= ncols` using the preconditioned one-sided Jacobi method.
+pub fn compute_jacobi_svd_req<E: Entity>(
+    nrows: usize,
+    ncols: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let householder_blocksize =
+        faer_qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+    StackReq::try_all_of([
+        temp_mat_req::<E>(nrows, ncols)?,
+        temp_mat_req::<E>(householder_blocksize, ncols)?,
+        StackReq::try_new::<usize>(ncols)?,
+        StackReq::try_new::<usize>(ncols)?,
+        temp_mat_req::<E>(ncols, ncols)?,
+        temp_mat_req::<E>(ncols, ncols)?,
+        StackReq::try_any_of([
+            faer_qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
+                nrows,
+                ncols,
+                householder_blocksize,
+                parallelism,
+                Default::default(),
+            )?,
+            apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                nrows,
+                householder_blocksize,
+                nrows,
+            )?,
+        ])?,
+    ])
+}
+
+/// Orthogonalizes the columns of `x` with one-sided Jacobi rotations, accumulating the rotations
+/// in `w` if it is provided. The iteration stops after `max_sweeps` sweeps over all the pairs of
+/// columns.
+pub fn one_sided_jacobi<E: ComplexField>(
+    x: MatMut<'_, E>,
+    w: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    max_sweeps: usize,
+) -> SvdInfo {
+    let n = x.ncols();
+    let mut x = x;
+    let mut w = w;
+
+    let tol = epsilon.faer_mul(E::Real::faer_from_f64(x.nrows() as f64).faer_sqrt());
+    let one = E::Real::faer_one();
+    let two = E::Real::faer_from_f64(2.0);
+
+    for sweep in 0..max_sweeps {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let norm_p = x.rb().col(p).norm_l2();
+                let norm_q = x.rb().col(q).norm_l2();
+                if norm_p == E::Real::faer_zero() || norm_q == E::Real::faer_zero() {
+                    continue;
+                }
+
+                // cosine of the angle between the two columns, computed on the normalized
+                // columns to avoid underflow for tiny columns
+                let inv_p = norm_p.faer_inv();
+                let inv_q = norm_q.faer_inv();
+                let mut cos = E::faer_zero();
+                for i in 0..x.nrows() {
+                    cos = cos.faer_add(
+                        x.read(i, p)
+                            .faer_scale_real(inv_p)
+                            .faer_conj()
+                            .faer_mul(x.read(i, q).faer_scale_real(inv_q)),
+                    );
+                }
+                let abs_cos = cos.faer_abs();
+                if abs_cos <= tol {
+                    continue;
+                }
+                rotated = true;
+
+                let phase_conj = cos.faer_scale_real(abs_cos.faer_inv()).faer_conj();
+                let zeta = (norm_q.faer_mul(inv_p).faer_sub(norm_p.faer_mul(inv_q)))
+                    .faer_div(two.faer_mul(abs_cos));
+                let t = zeta
+                    .faer_abs()
+                    .faer_add(one.faer_add(zeta.faer_mul(zeta)).faer_sqrt())
+                    .faer_inv();
+                let t = if zeta < E::Real::faer_zero() {
+                    t.faer_neg()
+                } else {
+                    t
+                };
+                let c = one.faer_add(t.faer_mul(t)).faer_sqrt().faer_inv();
+                let s = c.faer_mul(t);
+
+                let s_phase = phase_conj.faer_scale_real(s);
+                let c_phase = phase_conj.faer_scale_real(c);
+                let c = E::faer_from_real(c);
+                let s = E::faer_from_real(s);
+
+                let rotate = |mat: MatMut<'_, E>| {
+                    let mut mat = mat;
+                    for i in 0..mat.nrows() {
+                        let xp = mat.read(i, p);
+                        let xq = mat.read(i, q);
+                        mat.write(i, p, c.faer_mul(xp).faer_sub(s_phase.faer_mul(xq)));
+                        mat.write(i, q, s.faer_mul(xp).faer_add(c_phase.faer_mul(xq)));
+                    }
+                };
+                rotate(x.rb_mut());
+                if let Some(w) = w.rb_mut() {
+                    rotate(w);
+                }
+            }
+        }
+
+        if !rotated {
+            return SvdInfo {
+                iteration_count: sweep,
+                converged: true,
+            };
+        }
+    }
+
+    SvdInfo {
+        iteration_count: max_sweeps,
+        converged: false,
+    }
+}
+
+/// Computes the SVD of `matrix`, which must have at least as many rows as columns, using the
+/// preconditioned one-sided Jacobi method.
+///
+/// The singular values are stored in `s` in nonincreasing order. `u` and `v` follow the same
+/// conventions as in [`crate::compute_svd`].
+pub fn compute_jacobi_svd<E: ComplexField>(
+    matrix: MatRef<'_, E>,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+    epsilon: E::Real,
+    zero_threshold: E::Real,
+    max_sweeps: Option<usize>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) -> SvdInfo {
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+    let mut s = s;
+
+    let householder_blocksize = faer_qr::col_pivoting::compute::recommended_blocksize::<E>(m, n);
+
+    let (mut qr, stack) = temp_mat_uninit::<E>(m, n, stack);
+    let mut qr = qr.as_mut();
+    let (mut householder, stack) = temp_mat_uninit::<E>(householder_blocksize, n, stack);
+    let mut householder = householder.as_mut();
+    let (col_perm, stack) = stack.make_raw::<usize>(n);
+    let (col_perm_inv, stack) = stack.make_raw::<usize>(n);
+    let (mut x, stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut x = x.as_mut();
+    let (mut w, mut stack) = temp_mat_uninit::<E>(n, n, stack);
+    let mut w = w.as_mut();
+
+    // matrix * P^T = q * r
+    zipped!(qr.rb_mut(), matrix).for_each(|unzipped!(mut dst, src)| dst.write(src.read()));
+    faer_qr::col_pivoting::compute::qr_in_place(
+        qr.rb_mut(),
+        householder.rb_mut(),
+        col_perm,
+        col_perm_inv,
+        parallelism,
+        stack.rb_mut(),
+        Default::default(),
+    );
+
+    // x = r^H
+    for j in 0..n {
+        for i in 0..n {
+            x.write(
+                i,
+                j,
+                if i >= j {
+                    qr.read(j, i).faer_conj()
+                } else {
+                    E::faer_zero()
+                },
+            );
+        }
+    }
+
+    let compute_u = u.is_some();
+    if compute_u {
+        w.fill_zero();
+        for i in 0..n {
+            w.write(i, i, E::faer_one());
+        }
+    }
+
+    // x * w = u_x * s, so that r = w * s * u_x^H
+    let info = one_sided_jacobi(
+        x.rb_mut(),
+        if compute_u { Some(w.rb_mut()) } else { None },
+        epsilon,
+        max_sweeps.unwrap_or(MAX_SWEEPS),
+    );
+
+    for j in 0..n {
+        s.write(j, 0, E::faer_from_real(x.rb().col(j).norm_l2()));
+    }
+
+    // sort the singular values in nonincreasing order
+    for j in 0..n {
+        let mut max_idx = j;
+        let mut max_val = s.read(j, 0).faer_real();
+        for k in j + 1..n {
+            let val = s.read(k, 0).faer_real();
+            if val > max_val {
+                max_idx = k;
+                max_val = val;
+            }
+        }
+        if max_idx != j {
+            let tmp = s.read(j, 0);
+            s.write(j, 0, s.read(max_idx, 0));
+            s.write(max_idx, 0, tmp);
+            swap_cols(x.rb_mut(), j, max_idx);
+            if compute_u {
+                swap_cols(w.rb_mut(), j, max_idx);
+            }
+        }
+    }
+
+    if let Some(mut v) = v {
+        for j in 0..n {
+            let sigma = s.read(j, 0).faer_real();
+            if sigma > zero_threshold {
+                let inv = sigma.faer_inv();
+                for i in 0..n {
+                    x.write(i, j, x.read(i, j).faer_scale_real(inv));
+                }
+            } else {
+                // the column is numerically zero, so we complete the previous columns to an
+                // orthonormal basis with the unit vector that is the least aligned with them
+                let mut best = 0usize;
+                let mut best_norm = E::Real::faer_zero();
+                for k in 0..n {
+                    for i in 0..n {
+                        x.write(i, j, E::faer_zero());
+                    }
+                    x.write(k, j, E::faer_one());
+                    orthogonalize_against_previous(x.rb_mut(), j);
+                    let norm = x.rb().col(j).norm_l2();
+                    if norm > best_norm {
+                        best = k;
+                        best_norm = norm;
+                    }
+                }
+                for i in 0..n {
+                    x.write(i, j, E::faer_zero());
+                }
+                x.write(best, j, E::faer_one());
+                orthogonalize_against_previous(x.rb_mut(), j);
+                let inv = x.rb().col(j).norm_l2().faer_inv();
+                for i in 0..n {
+                    x.write(i, j, x.read(i, j).faer_scale_real(inv));
+                }
+            }
+        }
+
+        // matrix = q * w * s * u_x^H * P, so that v = P^T * u_x
+        v.fill_zero();
+        for j in 0..n {
+            for i in 0..n {
+                v.write(col_perm[i], j, x.read(i, j));
+            }
+        }
+    }
+
+    if let Some(mut u) = u {
+        let ncols = u.ncols();
+        u.fill_zero();
+        u.rb_mut().submatrix_mut(0, 0, n, n).copy_from(w.rb());
+        for i in n..ncols {
+            u.write(i, i, E::faer_one());
+        }
+
+        apply_block_householder_sequence_on_the_left_in_place_with_conj(
+            qr.rb(),
+            householder.rb(),
+            Conj::No,
+            u,
+            parallelism,
+            stack.rb_mut(),
+        );
+    }
+
+    info
+}
+
+/// Orthogonalizes column `j` of `x` against its first `j` columns, which are assumed to be
+/// orthonormal.
+fn orthogonalize_against_previous<E: ComplexField>(x: MatMut<'_, E>, j: usize) {
+    let mut x = x;
+    // two passes of classical gram-schmidt for numerical stability
+    for _ in 0..2 {
+        for k in 0..j {
+            let mut dot = E::faer_zero();
+            for i in 0..x.nrows() {
+                dot = dot.faer_add(x.read(i, k).faer_conj().faer_mul(x.read(i, j)));
+            }
+            for i in 0..x.nrows() {
+                x.write(i, j, x.read(i, j).faer_sub(dot.faer_mul(x.read(i, k))));
+            }
+        }
+    }
+}
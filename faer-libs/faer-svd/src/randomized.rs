@@ -0,0 +1,459 @@
+//! Randomized algorithms for computing a low rank approximation of a matrix, following the
+//! framework of Halko, Martinsson and Tropp.
+//!
+//! Given a target rank $k$, the range finder computes a matrix $Q$ with $k + p$ orthonormal
+//! columns, where $p$ is the oversampling parameter, such that
+//! $$A \approx Q Q^H A.$$
+//! The truncated SVD of $A$ is then obtained from the SVD of the small matrix $Q^H A$.
+//!
+//! The input matrix is only accessed through products with $A$ and $A^H$, which allows using
+//! sparse matrices, or matrix-free operators through the [`LinearOperator`] trait.
+
+use crate::{compute_svd, compute_svd_req, ComputeVectors, SvdParams};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use faer_core::{
+    assert,
+    householder::{
+        apply_block_householder_sequence_on_the_left_in_place_req,
+        apply_block_householder_sequence_on_the_left_in_place_with_conj,
+    },
+    linop::LinearOperator,
+    mul::matmul,
+    temp_mat_req, temp_mat_uninit, ComplexField, Conj, MatMut, Parallelism,
+};
+use reborrow::*;
+
+/// Parameters of the randomized algorithms.
+#[derive(Default, Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct RandomizedSvdParams {
+    /// Number of additional samples used on top of the target rank. Defaults to `10`.
+    pub oversampling: Option<usize>,
+    /// Number of power iterations, which improve the accuracy for matrices whose singular values
+    /// decay slowly. Defaults to `2`.
+    pub power_iterations: Option<usize>,
+    /// Seed of the pseudo-random generator used for the test matrix.
+    pub seed: Option<u64>,
+}
+
+const DEFAULT_OVERSAMPLING: usize = 10;
+const DEFAULT_POWER_ITERATIONS: usize = 2;
+const DEFAULT_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Small xorshift generator, used to fill the gaussian test matrix.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        let x = self.state.wrapping_mul(0x2545_f491_4f6c_dd1d);
+        // uniform in [0, 1)
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_gaussian(&mut self) -> f64 {
+        // Irwin-Hall approximation of a standard normal variable, which is accurate enough for
+        // sampling the range of the matrix
+        let mut acc = 0.0;
+        for _ in 0..12 {
+            acc += self.next_f64();
+        }
+        acc - 6.0
+    }
+}
+
+fn orthonormalize_req<E: ComplexField>(
+    nrows: usize,
+    ncols: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+    StackReq::try_all_of([
+        temp_mat_req::<E>(blocksize, ncols)?,
+        StackReq::try_any_of([
+            faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
+                nrows,
+                ncols,
+                blocksize,
+                parallelism,
+                Default::default(),
+            )?,
+            apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                nrows, blocksize, ncols,
+            )?,
+        ])?,
+    ])
+}
+
+/// Computes an orthonormal basis of the column space of `matrix` in `q`, overwriting `matrix`.
+fn orthonormalize<E: ComplexField>(
+    matrix: MatMut<'_, E>,
+    q: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+) {
+    let m = matrix.nrows();
+    let n = matrix.ncols();
+    let mut matrix = matrix;
+    let mut q = q;
+
+    let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(m, n);
+    let (mut householder, mut stack) = temp_mat_uninit::<E>(blocksize, n, stack);
+    let mut householder = householder.as_mut();
+
+    faer_qr::no_pivoting::compute::qr_in_place(
+        matrix.rb_mut(),
+        householder.rb_mut(),
+        parallelism,
+        stack.rb_mut(),
+        Default::default(),
+    );
+
+    q.fill_zero();
+    for j in 0..q.ncols() {
+        q.write(j, j, E::faer_one());
+    }
+
+    apply_block_householder_sequence_on_the_left_in_place_with_conj(
+        matrix.rb(),
+        householder.rb(),
+        Conj::No,
+        q,
+        parallelism,
+        stack,
+    );
+}
+
+/// Computes the size and alignment of required workspace for computing a basis with `size`
+/// columns of the approximate range of `operator`.
+pub fn randomized_range_finder_req<E: ComplexField, Op: ?Sized + LinearOperator<E>>(
+    operator: &Op,
+    size: usize,
+    parallelism: Parallelism,
+    params: RandomizedSvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    let _ = params;
+    let m = operator.nrows();
+    let n = operator.ncols();
+
+    StackReq::try_all_of([
+        temp_mat_req::<E>(m, size)?,
+        temp_mat_req::<E>(n, size)?,
+        temp_mat_req::<E>(n, size)?,
+        StackReq::try_any_of([
+            operator.apply_req(size, parallelism)?,
+            operator.transpose_apply_req(size, parallelism)?,
+            orthonormalize_req::<E>(m, size, parallelism)?,
+            orthonormalize_req::<E>(n, size, parallelism)?,
+        ])?,
+    ])
+}
+
+/// Computes a matrix `q` with orthonormal columns such that `q * q.adjoint() * operator` is an
+/// approximation of `operator`, using a gaussian test matrix with `q.ncols()` columns.
+///
+/// The number of power iterations and the seed of the test matrix are taken from `params`.
+///
+/// # Panics
+/// Panics if `q` doesn't have the same number of rows as `operator`, or if it has more columns
+/// than the minimum of the number of rows and columns of `operator`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`randomized_range_finder_req`]).
+#[track_caller]
+pub fn randomized_range_finder<E: ComplexField, Op: ?Sized + LinearOperator<E>>(
+    operator: &Op,
+    q: MatMut<'_, E>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: RandomizedSvdParams,
+) {
+    let m = operator.nrows();
+    let n = operator.ncols();
+    let size = q.ncols();
+    assert!(all(q.nrows() == m, size <= Ord::min(m, n)));
+
+    if size == 0 {
+        return;
+    }
+
+    let mut q = q;
+    let mut rng = Rng::new(params.seed.unwrap_or(DEFAULT_SEED));
+    let power_iterations = params.power_iterations.unwrap_or(DEFAULT_POWER_ITERATIONS);
+
+    let (mut y, stack) = temp_mat_uninit::<E>(m, size, stack);
+    let (mut z, stack) = temp_mat_uninit::<E>(n, size, stack);
+    let (mut z_basis, mut stack) = temp_mat_uninit::<E>(n, size, stack);
+    let mut y = y.as_mut();
+    let mut z = z.as_mut();
+    let mut z_basis = z_basis.as_mut();
+
+    for j in 0..size {
+        for i in 0..n {
+            z_basis.write(i, j, E::faer_from_f64(rng.next_gaussian()));
+        }
+    }
+
+    operator.apply(y.rb_mut(), z_basis.rb(), parallelism, stack.rb_mut());
+    orthonormalize(y.rb_mut(), q.rb_mut(), parallelism, stack.rb_mut());
+
+    for _ in 0..power_iterations {
+        operator.adjoint_apply(z.rb_mut(), q.rb(), parallelism, stack.rb_mut());
+        orthonormalize(z.rb_mut(), z_basis.rb_mut(), parallelism, stack.rb_mut());
+
+        operator.apply(y.rb_mut(), z_basis.rb(), parallelism, stack.rb_mut());
+        orthonormalize(y.rb_mut(), q.rb_mut(), parallelism, stack.rb_mut());
+    }
+}
+
+fn sample_size(m: usize, n: usize, rank: usize, params: RandomizedSvdParams) -> usize {
+    let oversampling = params.oversampling.unwrap_or(DEFAULT_OVERSAMPLING);
+    Ord::min(rank.saturating_add(oversampling), Ord::min(m, n))
+}
+
+/// Computes the size and alignment of required workspace for computing a randomized truncated
+/// singular value decomposition of `operator` with the given `rank`.
+pub fn randomized_svd_req<E: ComplexField, Op: ?Sized + LinearOperator<E>>(
+    operator: &Op,
+    rank: usize,
+    parallelism: Parallelism,
+    params: RandomizedSvdParams,
+) -> Result<StackReq, SizeOverflow> {
+    let m = operator.nrows();
+    let n = operator.ncols();
+    let size = sample_size(m, n, rank, params);
+
+    StackReq::try_all_of([
+        temp_mat_req::<E>(m, size)?,
+        temp_mat_req::<E>(n, size)?,
+        temp_mat_req::<E>(size, 1)?,
+        temp_mat_req::<E>(n, size)?,
+        temp_mat_req::<E>(size, size)?,
+        StackReq::try_any_of([
+            randomized_range_finder_req::<E, Op>(operator, size, parallelism, params)?,
+            operator.transpose_apply_req(size, parallelism)?,
+            compute_svd_req::<E>(
+                n,
+                size,
+                ComputeVectors::Thin,
+                ComputeVectors::Full,
+                parallelism,
+                SvdParams::default(),
+            )?,
+        ])?,
+    ])
+}
+
+/// Computes an approximation of the `rank` largest singular values of `operator`, along with the
+/// corresponding singular vectors, using a randomized algorithm, where `rank` is the number of
+/// rows of `s`.
+///
+/// `s` represents the diagonal of the truncated matrix $S$, in nonincreasing order.
+///
+/// If `u` is `Some(..)`, then it must have shape `(operator.nrows(), rank)`, and the
+/// approximate left singular vectors are stored in it. If `v` is `Some(..)`, then it must have
+/// shape `(operator.ncols(), rank)`, and the approximate right singular vectors are stored in it.
+///
+/// # Panics
+/// Panics if any of the conditions described above is violated, or if `rank` is larger than the
+/// minimum of the number of rows and columns of `operator`.
+///
+/// This can also panic if the provided memory in `stack` is insufficient (see
+/// [`randomized_svd_req`]).
+#[track_caller]
+pub fn randomized_svd<E: ComplexField, Op: ?Sized + LinearOperator<E>>(
+    operator: &Op,
+    s: MatMut<'_, E>,
+    u: Option<MatMut<'_, E>>,
+    v: Option<MatMut<'_, E>>,
+    parallelism: Parallelism,
+    stack: PodStack<'_>,
+    params: RandomizedSvdParams,
+) {
+    let m = operator.nrows();
+    let n = operator.ncols();
+    let rank = s.nrows();
+    assert!(all(s.ncols() == 1, rank <= Ord::min(m, n)));
+    if let Some(u) = u.rb() {
+        assert!(all(u.nrows() == m, u.ncols() == rank));
+    }
+    if let Some(v) = v.rb() {
+        assert!(all(v.nrows() == n, v.ncols() == rank));
+    }
+
+    if rank == 0 {
+        return;
+    }
+
+    let size = sample_size(m, n, rank, params);
+    let mut s = s;
+
+    let (mut q, stack) = temp_mat_uninit::<E>(m, size, stack);
+    let (mut w, stack) = temp_mat_uninit::<E>(n, size, stack);
+    let (mut s_w, stack) = temp_mat_uninit::<E>(size, 1, stack);
+    let (mut u_w, stack) = temp_mat_uninit::<E>(n, size, stack);
+    let (mut v_w, mut stack) = temp_mat_uninit::<E>(size, size, stack);
+    let mut q = q.as_mut();
+    let mut w = w.as_mut();
+    let mut s_w = s_w.as_mut();
+    let mut u_w = u_w.as_mut();
+    let mut v_w = v_w.as_mut();
+
+    randomized_range_finder(operator, q.rb_mut(), parallelism, stack.rb_mut(), params);
+
+    // B = Q^H A = W^H, where W = A^H Q
+    // if W = U_w S V_w^H, then B = V_w S U_w^H
+    operator.adjoint_apply(w.rb_mut(), q.rb(), parallelism, stack.rb_mut());
+    compute_svd(
+        w.rb(),
+        s_w.rb_mut(),
+        Some(u_w.rb_mut()),
+        Some(v_w.rb_mut()),
+        parallelism,
+        stack.rb_mut(),
+        SvdParams::default(),
+    );
+
+    s.copy_from(s_w.rb().subrows(0, rank));
+    if let Some(u) = u {
+        matmul(
+            u,
+            q.rb(),
+            v_w.rb().subcols(0, rank),
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+    }
+    if let Some(mut v) = v {
+        v.copy_from(u_w.rb().subcols(0, rank));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use faer_core::{assert, c64, sparse::SparseColMat, Mat};
+
+    macro_rules! make_stack {
+        ($req: expr) => {
+            ::dyn_stack::PodStack::new(&mut ::dyn_stack::GlobalPodBuffer::new($req.unwrap()))
+        };
+    }
+
+    #[test]
+    fn test_randomized_svd_low_rank() {
+        for (m, n, k) in [(50, 30, 5), (30, 50, 5), (100, 100, 10)] {
+            let a = Mat::from_fn(m, k, |_, _| c64::new(rand::random(), rand::random()));
+            let b = Mat::from_fn(k, n, |_, _| c64::new(rand::random(), rand::random()));
+            let mat = &a * &b;
+
+            let mut s_full = Mat::zeros(Ord::min(m, n), 1);
+            compute_svd(
+                mat.as_ref(),
+                s_full.as_mut(),
+                None,
+                None,
+                Parallelism::None,
+                make_stack!(compute_svd_req::<c64>(
+                    m,
+                    n,
+                    ComputeVectors::No,
+                    ComputeVectors::No,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            let operator = mat.as_ref();
+            let mut s = Mat::zeros(k, 1);
+            let mut u = Mat::zeros(m, k);
+            let mut v = Mat::zeros(n, k);
+            randomized_svd(
+                &operator,
+                s.as_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Parallelism::None,
+                make_stack!(randomized_svd_req::<c64, _>(
+                    &operator,
+                    k,
+                    Parallelism::None,
+                    Default::default(),
+                )),
+                Default::default(),
+            );
+
+            for i in 0..k {
+                assert_approx_eq!(s.read(i, 0), s_full.read(i, 0), 1e-8);
+            }
+
+            let mut us = u.clone();
+            for j in 0..k {
+                for i in 0..m {
+                    us.write(i, j, u.read(i, j) * s.read(j, 0));
+                }
+            }
+            let reconstructed = &us * v.adjoint();
+            for j in 0..n {
+                for i in 0..m {
+                    assert_approx_eq!(reconstructed.read(i, j), mat.read(i, j), 1e-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_randomized_range_finder_sparse() {
+        let n = 40;
+        let k = 4;
+
+        // diagonal matrix with exactly k nonzero entries
+        let triplets = (0..k)
+            .map(|i| (i * 7, i * 5, (i + 1) as f64))
+            .collect::<Vec<_>>();
+        let mat = SparseColMat::<usize, f64>::try_new_from_triplets(n, n, &triplets).unwrap();
+        let operator = mat.as_ref();
+
+        let mut q = Mat::zeros(n, k);
+        randomized_range_finder(
+            &operator,
+            q.as_mut(),
+            Parallelism::None,
+            make_stack!(randomized_range_finder_req::<f64, _>(
+                &operator,
+                k,
+                Parallelism::None,
+                Default::default(),
+            )),
+            Default::default(),
+        );
+
+        let dense = Mat::from_fn(n, n, |i, j| {
+            triplets
+                .iter()
+                .find(|&&(row, col, _)| row == i && col == j)
+                .map(|&(_, _, value)| value)
+                .unwrap_or(0.0)
+        });
+
+        let projected = &q * (q.adjoint() * &dense);
+        for j in 0..n {
+            for i in 0..n {
+                assert_approx_eq!(projected.read(i, j), dense.read(i, j), 1e-10);
+            }
+        }
+    }
+}
@@ -66,12 +66,39 @@
 //!
 //! [An implementation for sparse matrices is also available.](sparse::solvers::Cholesky)
 //!
+//! ## LDLT decomposition
+//! [`FaerMat::ldlt`] decomposes a self-adjoint matrix $A$ such that
+//! $$A = LDL^H,$$
+//! where $L$ is a unit lower triangular matrix, and $D$ is a real diagonal matrix. This
+//! decomposition requires no pivoting, which makes it efficient for positive definite and
+//! quasi-definite matrices, but it may be unstable for general indefinite matrices.
+//! [`solvers::Ldlt::new_with_regularization`] additionally allows dynamically regularizing the
+//! pivots when their expected signs are known.
+//!
+//! ## Pivoted Cholesky decomposition
+//! [`FaerMat::pivoted_cholesky`] decomposes a self-adjoint positive semidefinite matrix $A$ such
+//! that
+//! $$P A P^\top = LL^H,$$
+//! where $P$ is a permutation matrix chosen with diagonal pivoting, and $L$ is a lower triangular
+//! matrix. The factorization stops once the remaining diagonal entries are below a tolerance, which
+//! reveals the numerical rank of $A$, and makes it suitable for low-rank approximations of kernel
+//! matrices.
+//!
 //! ## Bunch-Kaufman decomposition
 //! [`FaerMat::lblt`] decomposes a self-adjoint (possibly indefinite) matrix $A$ such that
 //! $$P A P^\top = LBL^H,$$
 //! where $P$ is a permutation matrix, $L$ is a lower triangular matrix, and $B$ is a block
 //! diagonal matrix, with $1 \times 1$ or $2 \times 2$ diagonal blocks.
 //! This decomposition is efficient and has good stability properties.
+//! [`solvers::Lblt::new_with_pivoting`] additionally supports rook and bounded Bunch-Kaufman
+//! pivoting, which keep the entries of $L$ bounded.
+//!
+//! ## Aasen decomposition
+//! [`FaerMat::ltlt`] decomposes a self-adjoint (possibly indefinite) matrix $A$ such that
+//! $$P A P^\top = LTL^H,$$
+//! where $P$ is a permutation matrix, $L$ is a unit lower triangular matrix, and $T$ is a
+//! self-adjoint tridiagonal matrix. The entries of $L$ are bounded by one in magnitude.
+//!
 //! ## LU decomposition with partial pivoting
 //! [`FaerMat::partial_piv_lu`] decomposes a square invertible matrix $A$ into a lower triangular
 //! matrix $L$, a unit upper triangular matrix $U$, and a permutation matrix $P$, such that
@@ -128,7 +155,8 @@
 //!
 //! ## Eigendecomposition
 //! **Note**: The order of the eigenvalues is currently unspecified and may be changed in a future
-//! release.
+//! release. A deterministic order can be obtained with [`solvers::Eigendecomposition::sort`] or
+//! [`solvers::Eigendecomposition::sort_by`].
 //!
 //! The eigendecomposition of a square matrix $M$ of shape $(n, n)$ is a decomposition into
 //! two components $U$, $S$:
@@ -152,10 +180,73 @@
 //!   output
 //! having the same type.
 //!
+//! For the non self-adjoint case, the left eigenvectors and the reciprocal condition numbers of
+//! the eigenvalues can also be obtained, if the decomposition was computed with
+//! [`solvers::Eigendecomposition::new_from_real_with_left_eigenvectors`] or
+//! [`solvers::Eigendecomposition::new_from_complex_with_left_eigenvectors`], using
+//! [`solvers::Eigendecomposition::compute_left_eigenvectors`] and
+//! [`solvers::Eigendecomposition::reciprocal_condition_numbers`].
+//!
 //! If only the eigenvalues (elements of $S$) are desired, they can be obtained in
 //! nonincreasing order using [`FaerMat::selfadjoint_eigenvalues`], [`FaerMat::eigenvalues`], or
 //! [`FaerMat::complex_eigenvalues`], with the same conditions described above.
 //!
+//! If $M$ is self-adjoint and only a few of its eigenpairs are needed, they can be selected by
+//! index or by value using [`FaerMat::selfadjoint_eigendecomposition_range`], which avoids
+//! computing the full set of eigenvectors.
+//!
+//! ## Self-adjoint generalized eigendecomposition
+//! [`FaerMat::selfadjoint_generalized_eigendecomposition`] solves the problem
+//! $$AU = BUS,$$
+//! where $A$ is self-adjoint, $B$ is self-adjoint positive definite, and $S$ is a real diagonal
+//! matrix. The eigenvectors are normalized so that $U^H B U = I$.
+//!
+//! The problems $ABx = \lambda x$ and $BAx = \lambda x$ are also supported through
+//! [`solvers::SelfAdjointGeneralizedEigendecomposition::try_new`].
+//!
+//! ## Generalized singular value decomposition
+//! The GSVD of a pair of matrices $A$ and $B$ with shapes $(m, n)$ and $(p, n)$, computed using
+//! [`FaerMat::gsvd`], is a decomposition into the components $U$, $V$, $X$, $\alpha$ and
+//! $\beta$, such that:
+//!
+//! - $U$ has shape $(m, n)$, its first $\min(m, n)$ columns are orthonormal, and the remaining
+//!   ones are zero,
+//! - $V$ has shape $(p, n)$, its last $\min(p, n)$ columns are orthonormal, and the remaining
+//!   ones are zero,
+//! - $X$ has shape $(n, n)$ and is invertible,
+//! - $\alpha$ and $\beta$ are nonnegative, with $\alpha_i^2 + \beta_i^2 = 1$, and $\alpha$ is
+//!   sorted in nonincreasing order,
+//! - and finally:
+//!
+//! $$AX = U \operatorname{diag}(\alpha), \quad BX = V \operatorname{diag}(\beta).$$
+//!
+//! The stacked matrix $\begin{bmatrix}A \\ B\end{bmatrix}$ is required to have full column rank.
+//!
+//! ## Algebraic Riccati equations
+//! The stabilizing solutions of the continuous and discrete algebraic Riccati equations
+//! $$A^HX + XA - XBR^{-1}B^HX + Q = 0,$$
+//! $$A^HXA - X - A^HXB(R + B^HXB)^{-1}B^HXA + Q = 0,$$
+//! can be computed using [`solvers::Riccati::try_new_continuous`] and
+//! [`solvers::Riccati::try_new_discrete`]. The residual norm of the equation at the computed
+//! solution, and the reciprocal condition number of the basis of the stable invariant (or
+//! deflating) subspace it was computed from, are also returned.
+//!
+//! ## Polar decomposition
+//! The polar decomposition of a matrix $M$ of shape $(m, n)$, computed using [`FaerMat::polar`],
+//! is a decomposition into two components $U$ and $H$, such that:
+//!
+//! - $U$ has shape $(m, n)$, with orthonormal columns if $m \geq n$, or orthonormal rows
+//!   otherwise,
+//! - $H$ has shape $(n, n)$ and is self-adjoint positive semidefinite,
+//! - and finally:
+//!
+//! $$M = UH.$$
+//!
+//! The unitary factor of $A^H B$ solves the orthogonal Procrustes problem
+//! $\min_\Omega \|A\Omega - B\|_F$, which is available as [`FaerMat::procrustes`]. The solution
+//! can additionally be constrained to have a unit determinant, which corresponds to the Kabsch
+//! algorithm for real matrices.
+//!
 //! # Crate features
 //!
 //! - `std`: enabled by default. Links with the standard library to enable additional features such
@@ -174,8 +265,8 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use dyn_stack::{GlobalPodBuffer, PodStack};
-use faer_core::{AsMatMut, AsMatRef, ComplexField, Conj, Conjugate, Entity};
+use dyn_stack::{GlobalPodBuffer, PodStack, StackReq};
+use faer_core::{AsMatMut, AsMatRef, ComplexField, Conj, Conjugate, Entity, RealField};
 use prelude::*;
 use solvers::*;
 
@@ -185,6 +276,330 @@ use solvers::*;
 pub use dbgf::dbgf;
 pub use faer_cholesky::llt::CholeskyError;
 
+/// Error that can occur in the non-panicking variants of the decompositions and solvers, such as
+/// [`FaerMat::try_partial_piv_lu`] or [`sparse::solvers::SpSolver::try_solve`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SolverError {
+    /// Generic error, such as a failed memory allocation.
+    Generic(faer_core::FaerError),
+    /// A dimension of one of the operands doesn't match the one that is expected.
+    DimensionMismatch { expected: usize, found: usize },
+    /// The input matrix is required to be square.
+    NonSquare { nrows: usize, ncols: usize },
+    /// The input matrix is required to have at least as many rows as columns.
+    Underdetermined { nrows: usize, ncols: usize },
+    /// No nonzero pivot could be found in the given column of the sparse input matrix.
+    StructurallySingular { column: usize },
+    /// The input contains infinite or NaN values.
+    NonFinite,
+    /// The input matrix is required to be positive definite.
+    NotPositiveDefinite,
+    /// An iterative algorithm failed to converge.
+    NoConvergence,
+    /// The requested scalar type can't hold the result, e.g., the complex eigendecomposition of a
+    /// matrix was requested with a real-valued scalar type.
+    ScalarTypeMismatch,
+}
+
+impl core::fmt::Display for SolverError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SolverError {}
+
+impl From<faer_core::FaerError> for SolverError {
+    #[inline]
+    fn from(value: faer_core::FaerError) -> Self {
+        Self::Generic(value)
+    }
+}
+
+impl From<faer_sparse::lu::LuError> for SolverError {
+    #[inline]
+    fn from(value: faer_sparse::lu::LuError) -> Self {
+        match value {
+            faer_sparse::lu::LuError::Generic(err) => Self::Generic(err),
+            faer_sparse::lu::LuError::SymbolicSingular(column) => {
+                Self::StructurallySingular { column }
+            }
+        }
+    }
+}
+
+impl From<CholeskyError> for SolverError {
+    #[inline]
+    fn from(_: CholeskyError) -> Self {
+        Self::NotPositiveDefinite
+    }
+}
+
+#[track_caller]
+fn check_square<E: Entity>(matrix: MatRef<'_, E>) -> Result<(), SolverError> {
+    if matrix.nrows() == matrix.ncols() {
+        Ok(())
+    } else {
+        Err(SolverError::NonSquare {
+            nrows: matrix.nrows(),
+            ncols: matrix.ncols(),
+        })
+    }
+}
+
+#[track_caller]
+fn check_dim(expected: usize, found: usize) -> Result<(), SolverError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(SolverError::DimensionMismatch { expected, found })
+    }
+}
+
+/// Checks that the values of `matrix` are all finite. If `side` is provided, only that triangular
+/// half is checked.
+#[track_caller]
+fn check_finite<E: Conjugate>(matrix: MatRef<'_, E>, side: Option<Side>) -> Result<(), SolverError>
+where
+    E::Canonical: ComplexField,
+{
+    for j in 0..matrix.ncols() {
+        let (start, end) = match side {
+            None => (0, matrix.nrows()),
+            Some(Side::Lower) => (Ord::min(j, matrix.nrows()), matrix.nrows()),
+            Some(Side::Upper) => (0, Ord::min(j + 1, matrix.nrows())),
+        };
+        for i in start..end {
+            if !matrix.read(i, j).canonicalize().faer_is_finite() {
+                return Err(SolverError::NonFinite);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `converged` is set, i.e., that the iterative algorithm that computed the result
+/// converged within its iteration limit.
+fn check_converged(converged: bool) -> Result<(), SolverError> {
+    if converged {
+        Ok(())
+    } else {
+        Err(SolverError::NoConvergence)
+    }
+}
+
+/// Checks that the eigenvalues of a matrix with entries of type `E` can be stored in `ComplexE`,
+/// which must either be `E::Canonical` if it is complex-valued, or a complex-valued type if `E` is
+/// real-valued.
+fn check_complex_scalar<E: Conjugate, ComplexE: ComplexField>() -> Result<(), SolverError>
+where
+    E::Canonical: ComplexField,
+{
+    let complex = !coe::is_same::<ComplexE, ComplexE::Real>();
+    let real_input = coe::is_same::<E, <E::Canonical as ComplexField>::Real>();
+    if complex && (real_input || coe::is_same::<E::Canonical, ComplexE>()) {
+        Ok(())
+    } else {
+        Err(SolverError::ScalarTypeMismatch)
+    }
+}
+
+/// Accumulates a product of scalars, such as the determinant of a triangular factor, as a phase
+/// and the logarithm of its absolute value, which avoids overflow and underflow.
+struct LogDeterminant<E: ComplexField> {
+    phase: E,
+    log_abs: E::Real,
+}
+
+impl<E: ComplexField> LogDeterminant<E> {
+    fn new() -> Self {
+        Self {
+            phase: E::faer_one(),
+            log_abs: E::Real::faer_zero(),
+        }
+    }
+
+    fn mul(&mut self, value: E) {
+        // zero is absorbing, so that values computed after a zero pivot, which may be non-finite,
+        // don't affect the result
+        if self.phase == E::faer_zero() {
+            return;
+        }
+        let abs = value.faer_abs();
+        if abs == E::Real::faer_zero() {
+            self.phase = E::faer_zero();
+            self.log_abs = E::Real::faer_zero().faer_ln();
+        } else {
+            self.phase = self.phase.faer_mul(value.faer_scale_real(abs.faer_inv()));
+            self.log_abs = self.log_abs.faer_add(abs.faer_ln());
+        }
+    }
+
+    fn negate_if(&mut self, cond: bool) {
+        if cond {
+            self.phase = self.phase.faer_neg();
+        }
+    }
+
+    fn finish(self) -> (E, E::Real) {
+        (self.phase, self.log_abs)
+    }
+}
+
+/// Returns whether the permutation that maps `i` to `fwd[i]` is odd.
+fn permutation_is_odd<I: faer_core::permutation::Index>(fwd: &[I]) -> bool {
+    let n = fwd.len();
+    let mut visited = alloc::vec![false; n];
+    let mut n_transpositions = 0usize;
+    for start in 0..n {
+        if visited[start] {
+            continue;
+        }
+        let mut i = start;
+        let mut len = 0usize;
+        while !visited[i] {
+            visited[i] = true;
+            i = fwd[i].zx();
+            len += 1;
+        }
+        n_transpositions += len - 1;
+    }
+    n_transpositions % 2 == 1
+}
+
+/// Workspace memory that is kept alive by a decomposition between refactorizations and solves, so
+/// that repeatedly factorizing matrices of the same size, or solving systems with them, doesn't go
+/// through the allocator.
+///
+/// The factorizations also reserve the memory needed for solving a system with a single right-hand
+/// side, and the buffer grows as needed when solving with more right-hand sides.
+///
+/// The buffer never shrinks on its own: it keeps the size of the largest request it served until
+/// the decomposition is dropped, or until it is freed with the `release_workspace` method of the
+/// decomposition. If the buffer is already in use when a solve starts, e.g. by a solve running on
+/// another thread, that solve allocates a temporary buffer instead of waiting for it.
+///
+/// Applying a decomposition through [`faer_core::linop::LinearOperator`] uses the stack provided
+/// by the caller instead, and doesn't touch the buffer.
+#[derive(Default)]
+struct Workspace {
+    buffer: std::sync::Mutex<WorkspaceBuffer>,
+}
+
+#[derive(Default)]
+struct WorkspaceBuffer {
+    buffer: Option<GlobalPodBuffer>,
+    size: usize,
+    align: usize,
+}
+
+/// Exclusive access to the memory of a [`Workspace`].
+enum WorkspaceGuard<'a> {
+    Shared(std::sync::MutexGuard<'a, WorkspaceBuffer>),
+    // the workspace is already in use, e.g. by a solve running on another thread, so a temporary
+    // buffer is used instead of waiting for it
+    Temporary(WorkspaceBuffer),
+}
+
+impl WorkspaceBuffer {
+    /// Grows the buffer so that it can hold `req`, as well as the previously reserved
+    /// requirements.
+    #[track_caller]
+    fn reserve(&mut self, req: StackReq) {
+        if self.buffer.is_none() || self.size < req.size_bytes() || self.align < req.align_bytes() {
+            let req = if self.buffer.is_none() {
+                req
+            } else {
+                StackReq::any_of([req, StackReq::new_aligned::<u8>(self.size, self.align)])
+            };
+            self.size = req.size_bytes();
+            self.align = req.align_bytes();
+            // free the old buffer before allocating the new one
+            self.buffer = None;
+            self.buffer = Some(GlobalPodBuffer::new(req));
+        }
+    }
+
+    /// Returns a stack that can hold `req`, reallocating the buffer only if it is too small.
+    #[track_caller]
+    fn stack(&mut self, req: StackReq) -> PodStack<'_> {
+        self.reserve(req);
+        PodStack::new(self.buffer.as_mut().unwrap())
+    }
+}
+
+impl Workspace {
+    fn buffer_mut(&mut self) -> &mut WorkspaceBuffer {
+        // the buffer only holds scratch memory, so it's still usable after a panic
+        self.buffer
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Grows the buffer so that it can hold `req`, as well as the previously reserved
+    /// requirements.
+    #[track_caller]
+    fn reserve(&mut self, req: StackReq) {
+        self.buffer_mut().reserve(req)
+    }
+
+    /// Returns a stack that can hold `req`, reallocating the buffer only if it is too small.
+    #[track_caller]
+    fn stack(&mut self, req: StackReq) -> PodStack<'_> {
+        self.buffer_mut().stack(req)
+    }
+
+    /// Frees the buffer. The next factorization or solve allocates a new one.
+    fn release(&mut self) {
+        *self.buffer_mut() = WorkspaceBuffer::default();
+    }
+
+    /// Locks the workspace, so that it can be used from a shared reference.
+    fn lock(&self) -> WorkspaceGuard<'_> {
+        match self.buffer.try_lock() {
+            Ok(buffer) => WorkspaceGuard::Shared(buffer),
+            Err(std::sync::TryLockError::Poisoned(buffer)) => {
+                WorkspaceGuard::Shared(buffer.into_inner())
+            }
+            Err(std::sync::TryLockError::WouldBlock) => {
+                WorkspaceGuard::Temporary(WorkspaceBuffer::default())
+            }
+        }
+    }
+}
+
+impl WorkspaceGuard<'_> {
+    /// Returns a stack that can hold `req`, reallocating the buffer only if it is too small.
+    #[track_caller]
+    fn stack(&mut self, req: StackReq) -> PodStack<'_> {
+        match self {
+            WorkspaceGuard::Shared(buffer) => buffer.stack(req),
+            WorkspaceGuard::Temporary(buffer) => buffer.stack(req),
+        }
+    }
+}
+
+// the workspace only holds scratch memory, so a clone starts out with an empty one
+impl Clone for Workspace {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl core::fmt::Debug for Workspace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut f = f.debug_struct("Workspace");
+        if let WorkspaceGuard::Shared(buffer) = self.lock() {
+            f.field("size", &buffer.size).field("align", &buffer.align);
+        }
+        f.finish_non_exhaustive()
+    }
+}
+
 /// Re-exports.
 pub mod modules {
     pub use faer_cholesky as cholesky;
@@ -217,12 +632,152 @@ pub use matrixcompare::assert_matrix_eq;
 extern crate alloc;
 use alloc::{vec, vec::Vec};
 
+/// Solves linear systems with a decomposition, using the provided parallelism and workspace.
+pub(crate) trait SolveWithStack<E: Entity> {
+    /// Computes the size and alignment of required workspace for
+    /// [`Self::solve_in_place_with_stack`].
+    fn solve_in_place_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, dyn_stack::SizeOverflow>;
+    /// Computes the size and alignment of required workspace for
+    /// [`Self::solve_transpose_in_place_with_stack`].
+    fn solve_transpose_in_place_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, dyn_stack::SizeOverflow>;
+
+    /// Solves the equation `Op(self) * X = rhs`, where `Op` is either the identity or the
+    /// conjugation depending on `conj`, and stores the result in `rhs`.
+    fn solve_in_place_with_stack(
+        &self,
+        rhs: MatMut<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    );
+    /// Solves the equation `Op(self)^T * X = rhs`, where `Op` is either the identity or the
+    /// conjugation depending on `conj`, and stores the result in `rhs`.
+    fn solve_transpose_in_place_with_stack(
+        &self,
+        rhs: MatMut<'_, E>,
+        conj: Conj,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    );
+}
+
+/// Implements `release_workspace` for decompositions that hold a [`Workspace`] in their
+/// `workspace` field.
+macro_rules! impl_release_workspace {
+    ($({$($generics: tt)*} $ty: ty;)*) => {$(
+        impl<$($generics)*> $ty {
+            /// Frees the workspace memory that is kept by the decomposition between
+            /// refactorizations and solves.
+            ///
+            /// The memory is allocated again by the next refactorization or solve that needs it.
+            pub fn release_workspace(&mut self) {
+                self.workspace.release();
+            }
+        }
+    )*};
+}
+
+/// Implements [`faer_core::linop::LinearOperator`] for decompositions of square matrices, as the
+/// inverse operator `inverse(A)`.
+///
+/// This must only be used with decompositions that can't be computed for rectangular matrices,
+/// such as those of self-adjoint matrices or the LU decompositions, since the inverse of a
+/// rectangular matrix isn't defined.
+macro_rules! impl_inverse_linop {
+    ($({$($generics: tt)*} $ty: ty;)*) => {$(
+        impl<$($generics)*> faer_core::linop::LinearOperator<E> for $ty {
+            #[inline]
+            fn nrows(&self) -> usize {
+                <Self as $crate::sparse::solvers::SpSolverCore<E>>::ncols(self)
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                <Self as $crate::sparse::solvers::SpSolverCore<E>>::nrows(self)
+            }
+
+            #[inline]
+            fn apply_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: faer_core::Parallelism,
+            ) -> Result<dyn_stack::StackReq, dyn_stack::SizeOverflow> {
+                <Self as $crate::SolveWithStack<E>>::solve_in_place_req(self, rhs_ncols, parallelism)
+            }
+            #[inline]
+            fn transpose_apply_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: faer_core::Parallelism,
+            ) -> Result<dyn_stack::StackReq, dyn_stack::SizeOverflow> {
+                <Self as $crate::SolveWithStack<E>>::solve_transpose_in_place_req(
+                    self,
+                    rhs_ncols,
+                    parallelism,
+                )
+            }
+
+            #[track_caller]
+            fn apply_with_conj(
+                &self,
+                out: faer_core::MatMut<'_, E>,
+                rhs: faer_core::MatRef<'_, E>,
+                conj: faer_core::Conj,
+                parallelism: faer_core::Parallelism,
+                stack: dyn_stack::PodStack<'_>,
+            ) {
+                let mut out = out;
+                out.copy_from(rhs);
+                <Self as $crate::SolveWithStack<E>>::solve_in_place_with_stack(
+                    self,
+                    out,
+                    conj,
+                    parallelism,
+                    stack,
+                );
+            }
+            #[track_caller]
+            fn transpose_apply_with_conj(
+                &self,
+                out: faer_core::MatMut<'_, E>,
+                rhs: faer_core::MatRef<'_, E>,
+                conj: faer_core::Conj,
+                parallelism: faer_core::Parallelism,
+                stack: dyn_stack::PodStack<'_>,
+            ) {
+                let mut out = out;
+                out.copy_from(rhs);
+                <Self as $crate::SolveWithStack<E>>::solve_transpose_in_place_with_stack(
+                    self,
+                    out,
+                    conj,
+                    parallelism,
+                    stack,
+                );
+            }
+        }
+    )*};
+}
+
 /// Matrix solvers and decompositions.
 pub mod solvers {
     use super::*;
-    use faer_core::{assert, permutation::PermutationRef, zipped};
+    use faer_core::{assert, permutation::PermutationRef, zipped, RealField};
     use sparse::solvers::{SpSolverCore, SpSolverLstsqCore};
 
+    pub use faer_cholesky::{
+        bunch_kaufman::compute::PivotingStrategy, ldlt_diagonal::compute::LdltRegularization,
+    };
+    pub use faer_evd::{EigenRange, EvdParams, SymmetricEvdAlgorithm, SymmetricEvdParams};
+    pub use faer_svd::{SvdAlgorithm, SvdParams};
+
     pub trait SolverCore<E: Entity>: SpSolverCore<E> {
         /// Reconstructs the original matrix using the decomposition.
         fn reconstruct(&self) -> Mat<E>;
@@ -251,6 +806,25 @@ pub mod solvers {
     /// Cholesky decomposition.
     pub struct Cholesky<E: Entity> {
         factors: Mat<E>,
+        workspace: Workspace,
+    }
+
+    /// $LDL^H$ decomposition, without pivoting.
+    pub struct Ldlt<E: Entity> {
+        factors: Mat<E>,
+        dynamic_regularization_count: usize,
+        first_zero_pivot: Option<(usize, f64)>,
+        nan_encountered: bool,
+        workspace: Workspace,
+    }
+
+    /// Cholesky decomposition with diagonal pivoting.
+    pub struct PivotedCholesky<E: Entity> {
+        factors: Mat<E>,
+        perm: Vec<usize>,
+        perm_inv: Vec<usize>,
+        rank: usize,
+        workspace: Workspace,
     }
 
     /// Bunch-Kaufman decomposition.
@@ -259,6 +833,16 @@ pub mod solvers {
         subdiag: Mat<E>,
         perm: Vec<usize>,
         perm_inv: Vec<usize>,
+        pivoting: PivotingStrategy,
+        workspace: Workspace,
+    }
+
+    /// Aasen decomposition.
+    pub struct Ltlt<E: Entity> {
+        factors: Mat<E>,
+        perm: Vec<usize>,
+        perm_inv: Vec<usize>,
+        workspace: Workspace,
     }
 
     /// LU decomposition with partial pivoting.
@@ -267,6 +851,10 @@ pub mod solvers {
         row_perm: Vec<usize>,
         row_perm_inv: Vec<usize>,
         n_transpositions: usize,
+        first_zero_pivot: Option<usize>,
+        nan_encountered: bool,
+        pivot_growth: f64,
+        workspace: Workspace,
     }
     /// LU decomposition with full pivoting.
     pub struct FullPivLu<E: Entity> {
@@ -276,12 +864,17 @@ pub mod solvers {
         col_perm: Vec<usize>,
         col_perm_inv: Vec<usize>,
         n_transpositions: usize,
+        first_zero_pivot: Option<usize>,
+        nan_encountered: bool,
+        pivot_growth: f64,
+        workspace: Workspace,
     }
 
     /// QR decomposition.
     pub struct Qr<E: Entity> {
         factors: Mat<E>,
         householder: Mat<E>,
+        workspace: Workspace,
     }
     /// QR decomposition with column pivoting.
     pub struct ColPivQr<E: Entity> {
@@ -289,6 +882,7 @@ pub mod solvers {
         householder: Mat<E>,
         col_perm: Vec<usize>,
         col_perm_inv: Vec<usize>,
+        workspace: Workspace,
     }
 
     /// Singular value decomposition.
@@ -296,6 +890,9 @@ pub mod solvers {
         s: Mat<E>,
         u: Mat<E>,
         v: Mat<E>,
+        converged: bool,
+        params: SvdParams,
+        workspace: Workspace,
     }
     /// Thin singular value decomposition.
     pub struct ThinSvd<E: Entity> {
@@ -306,12 +903,126 @@ pub mod solvers {
     pub struct SelfAdjointEigendecomposition<E: Entity> {
         s: Mat<E>,
         u: Mat<E>,
+        converged: bool,
+        params: SymmetricEvdParams,
+        workspace: Workspace,
+    }
+
+    /// Partial self-adjoint eigendecomposition, holding a subset of the eigenpairs.
+    pub struct PartialSelfAdjointEigendecomposition<E: Entity> {
+        s: Mat<E>,
+        u: Mat<E>,
+        converged: bool,
     }
 
     /// Complex eigendecomposition.
     pub struct Eigendecomposition<E: Entity> {
         s: Col<E>,
         u: Mat<E>,
+        v: Option<Mat<E>>,
+        converged: bool,
+    }
+
+    /// Ordering of the eigenvalues of an [`Eigendecomposition`].
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum EigenvalueOrder {
+        /// Nondecreasing real part, with ties broken by nondecreasing imaginary part.
+        RealPart,
+        /// Nondecreasing magnitude, with ties broken by nondecreasing real part, then imaginary
+        /// part.
+        Magnitude,
+    }
+
+    /// Type of a self-adjoint generalized eigenvalue problem, where $A$ is self-adjoint and $B$ is
+    /// self-adjoint positive definite.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum GeneralizedEigenProblem {
+        /// $Ax = \lambda Bx$.
+        AxLambdaBx,
+        /// $ABx = \lambda x$.
+        ABxLambdaX,
+        /// $BAx = \lambda x$.
+        BAxLambdaX,
+    }
+
+    /// Self-adjoint generalized eigendecomposition.
+    pub struct SelfAdjointGeneralizedEigendecomposition<E: Entity> {
+        s: Mat<E>,
+        u: Mat<E>,
+        problem: GeneralizedEigenProblem,
+        converged: bool,
+    }
+
+    /// Generalized singular value decomposition.
+    pub struct Gsvd<E: Entity> {
+        alpha: Mat<E>,
+        beta: Mat<E>,
+        u: Mat<E>,
+        v: Mat<E>,
+        x: Mat<E>,
+    }
+
+    /// Polar decomposition.
+    pub struct Polar<E: Entity> {
+        u: Mat<E>,
+        h: Mat<E>,
+        converged: bool,
+    }
+
+    /// Constraint on the solution of the orthogonal Procrustes problem, computed by
+    /// [`FaerMat::procrustes`].
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum ProcrustesConstraint {
+        /// The solution is unitary.
+        Unitary,
+        /// The solution is unitary, with a determinant equal to one. For real matrices, this
+        /// restricts the solution to rotations, as in the Kabsch algorithm.
+        SpecialUnitary,
+    }
+
+    /// Method that was used by [`FaerMat::solve_auto`] to solve a linear system, depending on the
+    /// structure of the matrix.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum SolvePath {
+        /// The matrix is lower triangular, and the system was solved by forward substitution.
+        LowerTriangular,
+        /// The matrix is upper triangular, and the system was solved by back substitution.
+        UpperTriangular,
+        /// The matrix is self-adjoint positive definite, and the system was solved with the
+        /// Cholesky decomposition.
+        Cholesky,
+        /// The matrix is self-adjoint but not positive definite, and the system was solved with
+        /// the Bunch-Kaufman decomposition.
+        Lblt,
+        /// The matrix is square, and the system was solved with the LU decomposition with partial
+        /// pivoting.
+        PartialPivLu,
+        /// The matrix has more rows than columns, and the system was solved in the sense of least
+        /// squares with the QR decomposition.
+        Qr,
+        /// The matrix has more columns than rows, and the minimum norm solution was computed with
+        /// the QR decomposition of its adjoint.
+        QrMinimumNorm,
+    }
+
+    /// Stabilizing solution of an algebraic Riccati equation, along with diagnostics about its
+    /// accuracy.
+    pub struct Riccati<E: ComplexField> {
+        x: Mat<E>,
+        residual: E::Real,
+        rcond: E::Real,
+    }
+
+    /// Error that can occur when solving an algebraic Riccati equation.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum RiccatiError {
+        /// The weight matrix $R$ is not positive definite.
+        NonPositiveDefiniteWeight,
+        /// The equation has no stabilizing solution, either because the Hamiltonian matrix (or
+        /// symplectic pencil) has eigenvalues on the stability boundary, or because the basis of
+        /// its stable invariant (or deflating) subspace is singular.
+        NoStabilizingSolution,
     }
 
     impl<E: ComplexField> Cholesky<E> {
@@ -323,18 +1034,57 @@ pub mod solvers {
             assert!(matrix.nrows() == matrix.ncols());
 
             let dim = matrix.nrows();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, side, &mut workspace)?;
+            this.workspace = workspace;
+            Ok(this)
+        }
+
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        ///
+        /// If the matrix is not positive definite, the error is returned and the contents of
+        /// `self` are unspecified until the next successful refactorization.
+        #[track_caller]
+        pub fn try_refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+        ) -> Result<(), CholeskyError> {
+            assert!(all(
+                matrix.nrows() == self.dim(),
+                matrix.ncols() == self.dim()
+            ));
+
+            let mut workspace = core::mem::take(&mut self.workspace);
+            let result = self.__factorize(matrix, side, &mut workspace);
+            self.workspace = workspace;
+            result
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            workspace: &mut Workspace,
+        ) -> Result<(), CholeskyError> {
+            let dim = self.dim();
             let parallelism = get_global_parallelism();
 
-            let mut factors = Mat::<E>::zeros(dim, dim);
             match side {
                 Side::Lower => {
-                    zipped!(factors.as_mut(), matrix).for_each_triangular_lower(
+                    zipped!(self.factors.as_mut(), matrix).for_each_triangular_lower(
                         faer_core::zip::Diag::Include,
                         |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
                     );
                 }
                 Side::Upper => {
-                    zipped!(factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                    zipped!(self.factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
                         faer_core::zip::Diag::Include,
                         |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
                     );
@@ -343,21 +1093,25 @@ pub mod solvers {
 
             let params = Default::default();
 
+            workspace.reserve(
+                faer_cholesky::llt::solve::solve_in_place_req::<E>(dim, 1, parallelism).unwrap(),
+            );
+
             faer_cholesky::llt::compute::cholesky_in_place(
-                factors.as_mut(),
+                self.factors.as_mut(),
                 Default::default(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                workspace.stack(
                     faer_cholesky::llt::compute::cholesky_in_place_req::<E>(
                         dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             )?;
-            Ok(Self { factors })
+            Ok(())
         }
 
         fn dim(&self) -> usize {
@@ -372,32 +1126,89 @@ pub mod solvers {
                 });
             factor
         }
+
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`, such that
+        /// the determinant is equal to `phase * exp(log_abs)`.
+        ///
+        /// Since the matrix is positive definite, the phase is always equal to one.
+        pub fn log_determinant(&self) -> (E, E::Real) {
+            let mut log_abs = E::Real::faer_zero();
+            for i in 0..self.dim() {
+                log_abs = log_abs.faer_add(self.factors.read(i, i).faer_real().faer_ln());
+            }
+            (E::faer_one(), log_abs.faer_add(log_abs))
+        }
     }
-    impl<E: ComplexField> SpSolverCore<E> for Cholesky<E> {
-        #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
+    impl<E: ComplexField> SolveWithStack<E> for Cholesky<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_cholesky::llt::solve::solve_in_place_req::<E>(self.dim(), rhs_ncols, parallelism)
+        }
+
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
+        }
 
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
             faer_cholesky::llt::solve::solve_in_place_with_conj(
                 self.factors.as_ref(),
                 conj,
                 rhs,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_cholesky::llt::solve::solve_in_place_req::<E>(
-                        self.dim(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
+        }
+    }
+    impl<E: ComplexField> SpSolverCore<E> for Cholesky<E> {
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
             );
         }
 
         #[track_caller]
         fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            self.solve_in_place_with_conj_impl(rhs, conj.compose(Conj::Yes))
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
         fn nrows(&self) -> usize {
@@ -417,10 +1228,10 @@ pub mod solvers {
                 inv.as_mut(),
                 self.factors.as_ref(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                self.workspace.lock().stack(
                     faer_cholesky::llt::inverse::invert_lower_req::<E>(self.dim(), parallelism)
                         .unwrap(),
-                )),
+                ),
             );
 
             for j in 0..self.dim() {
@@ -440,10 +1251,10 @@ pub mod solvers {
                 rec.as_mut(),
                 self.factors.as_ref(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
+                self.workspace.lock().stack(
                     faer_cholesky::llt::reconstruct::reconstruct_lower_req::<E>(self.dim())
                         .unwrap(),
-                )),
+                ),
             );
 
             for j in 0..self.dim() {
@@ -456,28 +1267,96 @@ pub mod solvers {
         }
     }
 
-    impl<E: ComplexField> Lblt<E> {
+    impl<E: ComplexField> Ldlt<E> {
+        /// Computes the $LDL^H$ decomposition of `matrix`, without pivoting.
+        ///
+        /// The decomposition exists for strongly regular matrices, such as positive definite or
+        /// quasi-definite ones, but may be unstable for general indefinite matrices, in which
+        /// case [`Lblt`] should be preferred.
         #[track_caller]
         pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
+            Self::new_with_regularization(matrix, side, Default::default())
+        }
+
+        /// Computes the $LDL^H$ decomposition of `matrix`, without pivoting, and with dynamic
+        /// regularization of the pivots.
+        ///
+        /// If `regularization.dynamic_regularization_signs` is provided, each pivot whose sign
+        /// does not match the expected one, or whose magnitude is less than or equal to
+        /// `regularization.dynamic_regularization_epsilon`, is replaced by
+        /// `regularization.dynamic_regularization_delta` with the expected sign.
+        #[track_caller]
+        pub fn new_with_regularization<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            regularization: LdltRegularization<'_, E>,
+        ) -> Self {
             assert!(matrix.nrows() == matrix.ncols());
 
             let dim = matrix.nrows();
-            let parallelism = get_global_parallelism();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                dynamic_regularization_count: 0,
+                first_zero_pivot: None,
+                nan_encountered: false,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, side, regularization, &mut workspace);
+            this.workspace = workspace;
+            this
+        }
+
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+        ) {
+            self.refactor_with_regularization(matrix, side, Default::default())
+        }
+
+        /// Recomputes the decomposition for `matrix` with dynamic regularization of the pivots.
+        /// See [`Self::refactor`] and [`Self::new_with_regularization`].
+        #[track_caller]
+        pub fn refactor_with_regularization<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            regularization: LdltRegularization<'_, E>,
+        ) {
+            assert!(all(
+                matrix.nrows() == self.dim(),
+                matrix.ncols() == self.dim()
+            ));
 
-            let mut factors = Mat::<E>::zeros(dim, dim);
-            let mut subdiag = Mat::<E>::zeros(dim, 1);
-            let mut perm = vec![0; dim];
-            let mut perm_inv = vec![0; dim];
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, side, regularization, &mut workspace);
+            self.workspace = workspace;
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            regularization: LdltRegularization<'_, E>,
+            workspace: &mut Workspace,
+        ) {
+            let dim = self.dim();
+            let parallelism = get_global_parallelism();
 
             match side {
                 Side::Lower => {
-                    zipped!(factors.as_mut(), matrix).for_each_triangular_lower(
+                    zipped!(self.factors.as_mut(), matrix).for_each_triangular_lower(
                         faer_core::zip::Diag::Include,
                         |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
                     );
                 }
                 Side::Upper => {
-                    zipped!(factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                    zipped!(self.factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
                         faer_core::zip::Diag::Include,
                         |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
                     );
@@ -486,63 +1365,169 @@ pub mod solvers {
 
             let params = Default::default();
 
-            faer_cholesky::bunch_kaufman::compute::cholesky_in_place(
-                factors.as_mut(),
-                subdiag.as_mut(),
-                Default::default(),
-                &mut perm,
-                &mut perm_inv,
+            workspace.reserve(
+                faer_cholesky::ldlt_diagonal::solve::solve_in_place_req::<E>(dim, 1, parallelism)
+                    .unwrap(),
+            );
+
+            let info = faer_cholesky::ldlt_diagonal::compute::raw_cholesky_in_place(
+                self.factors.as_mut(),
+                regularization,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_cholesky::bunch_kaufman::compute::cholesky_in_place_req::<usize, E>(
+                workspace.stack(
+                    faer_cholesky::ldlt_diagonal::compute::raw_cholesky_in_place_req::<E>(
                         dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
-            Self {
-                factors,
-                subdiag,
-                perm,
-                perm_inv,
-            }
+            self.dynamic_regularization_count = info.dynamic_regularization_count;
+            self.first_zero_pivot = info.first_zero_pivot;
+            self.nan_encountered = info.nan_encountered;
         }
 
         fn dim(&self) -> usize {
             self.factors.nrows()
         }
+
+        /// Returns the number of pivots that were replaced by the dynamic regularization.
+        pub fn dynamic_regularization_count(&self) -> usize {
+            self.dynamic_regularization_count
+        }
+
+        /// Returns the index and the value of the first pivot of $D$ that is zero, or whose
+        /// reciprocal overflows, if any.
+        pub fn first_zero_pivot(&self) -> Option<(usize, E::Real)> {
+            self.first_zero_pivot
+                .map(|(idx, pivot)| (idx, E::Real::faer_from_f64(pivot)))
+        }
+
+        /// Returns whether NaN values were encountered during the factorization.
+        pub fn nan_encountered(&self) -> bool {
+            self.nan_encountered
+        }
+
+        /// Returns the unit lower triangular factor $L$.
+        pub fn compute_l(&self) -> Mat<E> {
+            let n = self.dim();
+            Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    E::faer_one()
+                } else if i > j {
+                    self.factors.read(i, j)
+                } else {
+                    E::faer_zero()
+                }
+            })
+        }
+
+        /// Returns the diagonal of the factor $D$, as a column vector.
+        pub fn compute_d(&self) -> Mat<E> {
+            Mat::from_fn(self.dim(), 1, |i, _| {
+                E::faer_from_real(self.factors.read(i, i).faer_real().faer_inv())
+            })
+        }
+
+        /// Returns the inertia of the matrix, i.e., the number of positive, negative and zero
+        /// entries of $D$, in that order.
+        ///
+        /// By Sylvester's law of inertia, these are also the numbers of positive, negative and zero
+        /// eigenvalues of the decomposed matrix.
+        pub fn inertia(&self) -> (usize, usize, usize) {
+            let zero = E::Real::faer_zero();
+            let mut positive = 0;
+            let mut negative = 0;
+            let mut null = 0;
+            for i in 0..self.dim() {
+                let d = self.factors.read(i, i).faer_real().faer_inv();
+                if d > zero {
+                    positive += 1;
+                } else if d < zero {
+                    negative += 1;
+                } else {
+                    null += 1;
+                }
+            }
+            (positive, negative, null)
+        }
     }
+    impl<E: ComplexField> SolveWithStack<E> for Ldlt<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_cholesky::ldlt_diagonal::solve::solve_in_place_req::<E>(
+                self.dim(),
+                rhs_ncols,
+                parallelism,
+            )
+        }
 
-    impl<E: ComplexField> SpSolverCore<E> for Lblt<E> {
-        #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
+        }
 
-            faer_cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            faer_cholesky::ldlt_diagonal::solve::solve_in_place_with_conj(
                 self.factors.as_ref(),
-                self.subdiag.as_ref(),
                 conj,
-                unsafe { PermutationRef::new_unchecked(&self.perm, &self.perm_inv) },
                 rhs,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_cholesky::bunch_kaufman::solve::solve_in_place_req::<usize, E>(
-                        self.dim(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
+        }
+    }
+    impl<E: ComplexField> SpSolverCore<E> for Ldlt<E> {
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
             );
         }
 
         #[track_caller]
         fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            self.solve_in_place_with_conj_impl(rhs, conj.compose(Conj::Yes))
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
         fn nrows(&self) -> usize {
@@ -553,7 +1538,7 @@ pub mod solvers {
             self.factors.ncols()
         }
     }
-    impl<E: ComplexField> SolverCore<E> for Lblt<E> {
+    impl<E: ComplexField> SolverCore<E> for Ldlt<E> {
         fn inverse(&self) -> Mat<E> {
             let n = self.dim();
             let mut inv = Mat::identity(n, n);
@@ -564,406 +1549,395 @@ pub mod solvers {
         fn reconstruct(&self) -> Mat<E> {
             let parallelism = get_global_parallelism();
             let n = self.dim();
-            let lbl = self.factors.as_ref();
-            let subdiag = self.subdiag.as_ref();
-            let mut mat = Mat::<E>::identity(n, n);
-            let mut mat2 = Mat::<E>::identity(n, n);
-            zipped!(mat.as_mut(), lbl).for_each_triangular_lower(
-                faer_core::zip::Diag::Skip,
-                |unzipped!(mut dst, src)| dst.write(src.read()),
-            );
-
-            let mut j = 0;
-            while j < n {
-                if subdiag.read(j, 0) == E::faer_zero() {
-                    let d = lbl.read(j, j).faer_real().faer_inv();
-                    for i in 0..n {
-                        mat.write(i, j, mat.read(i, j).faer_scale_real(d));
-                    }
-                    j += 1;
-                } else {
-                    let akp1k = subdiag.read(j, 0).faer_inv();
-                    let ak = akp1k.faer_scale_real(lbl.read(j, j).faer_real());
-                    let akp1 = akp1k
-                        .faer_conj()
-                        .faer_scale_real(lbl.read(j + 1, j + 1).faer_real());
-                    let denom = ak
-                        .faer_mul(akp1)
-                        .faer_sub(E::faer_one())
-                        .faer_real()
-                        .faer_inv();
-
-                    for i in 0..n {
-                        let xk = mat.read(i, j).faer_mul(akp1k);
-                        let xkp1 = mat.read(i, j + 1).faer_mul(akp1k.faer_conj());
-
-                        mat.write(
-                            i,
-                            j,
-                            (akp1.faer_mul(xk).faer_sub(xkp1)).faer_scale_real(denom),
-                        );
-                        mat.write(
-                            i,
-                            j + 1,
-                            (ak.faer_mul(xkp1).faer_sub(xk)).faer_scale_real(denom),
-                        );
-                    }
-                    j += 2;
+            let l = self.compute_l();
+            let mut ld = l.clone();
+            for j in 0..n {
+                let d = self.factors.read(j, j).faer_real().faer_inv();
+                for i in j..n {
+                    ld.write(i, j, ld.read(i, j).faer_scale_real(d));
                 }
             }
+
+            let mut rec = Mat::<E>::zeros(n, n);
             faer_core::mul::triangular::matmul(
-                mat2.as_mut(),
-                faer_core::mul::triangular::BlockStructure::TriangularLower,
-                lbl,
-                faer_core::mul::triangular::BlockStructure::UnitTriangularLower,
-                mat.as_ref().adjoint(),
+                rec.as_mut(),
                 faer_core::mul::triangular::BlockStructure::Rectangular,
+                ld.as_ref(),
+                faer_core::mul::triangular::BlockStructure::TriangularLower,
+                l.as_ref().adjoint(),
+                faer_core::mul::triangular::BlockStructure::UnitTriangularUpper,
                 None,
                 E::faer_one(),
                 parallelism,
             );
-
-            for j in 0..n {
-                let pj = self.perm_inv[j];
-                for i in j..n {
-                    let pi = self.perm_inv[i];
-
-                    mat.write(
-                        i,
-                        j,
-                        if pi >= pj {
-                            mat2.read(pi, pj)
-                        } else {
-                            mat2.read(pj, pi).faer_conj()
-                        },
-                    );
-                }
-            }
-
-            for j in 0..n {
-                mat.write(j, j, E::faer_from_real(mat.read(j, j).faer_real()));
-                for i in 0..j {
-                    mat.write(i, j, mat.read(j, i).faer_conj());
-                }
-            }
-
-            mat
+            rec
         }
     }
 
-    impl<E: ComplexField> PartialPivLu<E> {
+    impl<E: ComplexField> PivotedCholesky<E> {
+        /// Computes the pivoted Cholesky decomposition of `matrix`, stopping once the largest
+        /// remaining diagonal entry is less than or equal to `tolerance`. If `tolerance` is
+        /// negative, a default tolerance proportional to the largest diagonal entry is used.
         #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        pub fn new<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            tolerance: E::Real,
+        ) -> Self {
             assert!(matrix.nrows() == matrix.ncols());
 
             let dim = matrix.nrows();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                perm: vec![0; dim],
+                perm_inv: vec![0; dim],
+                rank: 0,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, side, tolerance, &mut workspace);
+            this.workspace = workspace;
+            this
+        }
+
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            tolerance: E::Real,
+        ) {
+            assert!(all(
+                matrix.nrows() == self.factors.nrows(),
+                matrix.ncols() == self.factors.nrows(),
+            ));
+
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, side, tolerance, &mut workspace);
+            self.workspace = workspace;
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            tolerance: E::Real,
+            workspace: &mut Workspace,
+        ) {
+            let dim = self.factors.nrows();
             let parallelism = get_global_parallelism();
 
-            let mut factors = matrix.to_owned();
+            match side {
+                Side::Lower => {
+                    zipped!(self.factors.as_mut(), matrix).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+                Side::Upper => {
+                    zipped!(self.factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+            }
 
             let params = Default::default();
 
-            let mut row_perm = vec![0usize; dim];
-            let mut row_perm_inv = vec![0usize; dim];
-
-            let (n_transpositions, _) = faer_lu::partial_pivoting::compute::lu_in_place(
-                factors.as_mut(),
-                &mut row_perm,
-                &mut row_perm_inv,
+            let (info, _) = faer_cholesky::piv_llt::compute::cholesky_in_place(
+                self.factors.as_mut(),
+                tolerance,
+                &mut self.perm,
+                &mut self.perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::partial_pivoting::compute::lu_in_place_req::<usize, E>(
-                        dim,
+                workspace.stack(
+                    faer_cholesky::piv_llt::compute::cholesky_in_place_req::<usize, E>(
                         dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
 
-            Self {
-                n_transpositions: n_transpositions.transposition_count,
-                factors,
-                row_perm,
-                row_perm_inv,
-            }
+            self.rank = info.rank;
         }
 
-        fn dim(&self) -> usize {
-            self.factors.nrows()
+        /// Returns the numerical rank of the matrix.
+        pub fn rank(&self) -> usize {
+            self.rank
         }
 
+        /// Returns the permutation $P$.
         pub fn row_permutation(&self) -> PermutationRef<'_, usize, E> {
-            unsafe { PermutationRef::new_unchecked(&self.row_perm, &self.row_perm_inv) }
-        }
-
-        pub fn transposition_count(&self) -> usize {
-            self.n_transpositions
+            unsafe { PermutationRef::new_unchecked(&self.perm, &self.perm_inv) }
         }
 
+        /// Returns the factor $L$, truncated to its first `self.rank()` columns.
         pub fn compute_l(&self) -> Mat<E> {
-            let mut factor = self.factors.to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_upper(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
-        }
-        pub fn compute_u(&self) -> Mat<E> {
-            let mut factor = self.factors.to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
-                .as_mut()
-                .diagonal_mut()
-                .column_vector_mut()
-                .fill(E::faer_one());
-            factor
+            let n = self.factors.nrows();
+            Mat::from_fn(n, self.rank, |i, j| {
+                if i >= j {
+                    self.factors.read(i, j)
+                } else {
+                    E::faer_zero()
+                }
+            })
         }
     }
-    impl<E: ComplexField> SpSolverCore<E> for PartialPivLu<E> {
+
+    impl<E: ComplexField> Lblt<E> {
         #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_lu::partial_pivoting::solve::solve_in_place(
-                self.factors.as_ref(),
-                conj,
-                self.row_permutation(),
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::partial_pivoting::solve::solve_in_place_req::<usize, E>(
-                        self.dim(),
-                        self.dim(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
-            );
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
+            Self::new_with_pivoting(matrix, side, PivotingStrategy::Diagonal)
         }
 
+        /// Computes the Bunch-Kaufman decomposition of `matrix`, using the provided pivoting
+        /// strategy.
         #[track_caller]
-        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_lu::partial_pivoting::solve::solve_transpose_in_place(
-                self.factors.as_ref(),
-                conj,
-                self.row_permutation(),
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::partial_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
-                        self.dim(),
-                        self.dim(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
-            );
-        }
-
-        fn nrows(&self) -> usize {
-            self.factors.nrows()
-        }
-
-        fn ncols(&self) -> usize {
-            self.factors.ncols()
-        }
-    }
-    impl<E: ComplexField> SolverCore<E> for PartialPivLu<E> {
-        fn inverse(&self) -> Mat<E> {
-            let mut inv = Mat::<E>::zeros(self.dim(), self.dim());
-            let parallelism = get_global_parallelism();
-
-            faer_lu::partial_pivoting::inverse::invert(
-                inv.as_mut(),
-                self.factors.as_ref(),
-                self.row_permutation(),
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::partial_pivoting::inverse::invert_req::<usize, E>(
-                        self.dim(),
-                        self.dim(),
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
-            );
+        pub fn new_with_pivoting<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            pivoting: PivotingStrategy,
+        ) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
 
-            inv
+            let dim = matrix.nrows();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                subdiag: Mat::zeros(dim, 1),
+                perm: vec![0; dim],
+                perm_inv: vec![0; dim],
+                pivoting,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, side, &mut workspace);
+            this.workspace = workspace;
+            this
         }
 
-        fn reconstruct(&self) -> Mat<E> {
-            let mut rec = Mat::<E>::zeros(self.dim(), self.dim());
-            let parallelism = get_global_parallelism();
-
-            faer_lu::partial_pivoting::reconstruct::reconstruct(
-                rec.as_mut(),
-                self.factors.as_ref(),
-                self.row_permutation(),
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::partial_pivoting::reconstruct::reconstruct_req::<usize, E>(
-                        self.dim(),
-                        self.dim(),
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
-            );
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        ///
+        /// The same pivoting strategy as the original decomposition is used.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+        ) {
+            assert!(all(
+                matrix.nrows() == self.dim(),
+                matrix.ncols() == self.dim()
+            ));
 
-            rec
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, side, &mut workspace);
+            self.workspace = workspace;
         }
-    }
 
-    impl<E: ComplexField> FullPivLu<E> {
         #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
-            let m = matrix.nrows();
-            let n = matrix.ncols();
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            workspace: &mut Workspace,
+        ) {
+            let dim = self.dim();
             let parallelism = get_global_parallelism();
 
-            let mut factors = matrix.to_owned();
+            match side {
+                Side::Lower => {
+                    zipped!(self.factors.as_mut(), matrix).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+                Side::Upper => {
+                    zipped!(self.factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+            }
+
+            let mut params = faer_cholesky::bunch_kaufman::compute::BunchKaufmanParams::default();
+            params.pivoting = self.pivoting;
 
-            let params = Default::default();
+            workspace.reserve(
+                faer_cholesky::bunch_kaufman::solve::solve_in_place_req::<usize, E>(
+                    dim,
+                    1,
+                    parallelism,
+                )
+                .unwrap(),
+            );
 
-            let mut row_perm = vec![0usize; m];
-            let mut row_perm_inv = vec![0usize; m];
-            let mut col_perm = vec![0usize; n];
-            let mut col_perm_inv = vec![0usize; n];
-
-            let (n_transpositions, _, _) = faer_lu::full_pivoting::compute::lu_in_place(
-                factors.as_mut(),
-                &mut row_perm,
-                &mut row_perm_inv,
-                &mut col_perm,
-                &mut col_perm_inv,
+            faer_cholesky::bunch_kaufman::compute::cholesky_in_place(
+                self.factors.as_mut(),
+                self.subdiag.as_mut(),
+                Default::default(),
+                &mut self.perm,
+                &mut self.perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::full_pivoting::compute::lu_in_place_req::<usize, E>(
-                        m,
-                        n,
+                workspace.stack(
+                    faer_cholesky::bunch_kaufman::compute::cholesky_in_place_req::<usize, E>(
+                        dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
+        }
 
-            Self {
-                factors,
-                row_perm,
-                row_perm_inv,
-                col_perm,
-                col_perm_inv,
-                n_transpositions: n_transpositions.transposition_count,
-            }
+        fn dim(&self) -> usize {
+            self.factors.nrows()
         }
 
+        /// Returns the permutation $P$ that was chosen by the pivoting strategy.
         pub fn row_permutation(&self) -> PermutationRef<'_, usize, E> {
-            unsafe { PermutationRef::new_unchecked(&self.row_perm, &self.row_perm_inv) }
+            unsafe { PermutationRef::new_unchecked(&self.perm, &self.perm_inv) }
         }
-        pub fn col_permutation(&self) -> PermutationRef<'_, usize, E> {
-            unsafe { PermutationRef::new_unchecked(&self.col_perm, &self.col_perm_inv) }
+
+        /// Returns the sizes of the diagonal blocks of $B$ that were chosen by the pivoting
+        /// strategy, each of which is either `1` or `2`.
+        pub fn block_sizes(&self) -> Vec<usize> {
+            let n = self.dim();
+            let mut sizes = Vec::new();
+            let mut i = 0;
+            while i < n {
+                if self.subdiag.read(i, 0) == E::faer_zero() {
+                    sizes.push(1);
+                    i += 1;
+                } else {
+                    sizes.push(2);
+                    i += 2;
+                }
+            }
+            sizes
         }
 
-        pub fn transposition_count(&self) -> usize {
-            self.n_transpositions
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`, such that
+        /// the determinant is equal to `phase * exp(log_abs)`.
+        ///
+        /// The determinant is computed from the diagonal blocks of $B$. Since the matrix is
+        /// self-adjoint, the phase is either one or minus one, or zero if the matrix is singular.
+        pub fn log_determinant(&self) -> (E, E::Real) {
+            // the factorization stores the inverses of the diagonal blocks of $B$, so we compute
+            // the determinant of inverse(B), whose phase is the same as that of det(B)
+            let n = self.dim();
+            let mut det = LogDeterminant::<E>::new();
+            let mut i = 0;
+            while i < n {
+                let subdiag = self.subdiag.read(i, 0);
+                if subdiag == E::faer_zero() {
+                    det.mul(E::faer_from_real(self.factors.read(i, i).faer_real()));
+                    i += 1;
+                } else {
+                    // det = a * b - |s|^2 = |s|^2 * (a / |s| * b / |s| - 1)
+                    let abs = subdiag.faer_abs();
+                    let abs_inv = abs.faer_inv();
+                    let a = self.factors.read(i, i).faer_real().faer_mul(abs_inv);
+                    let b = self
+                        .factors
+                        .read(i + 1, i + 1)
+                        .faer_real()
+                        .faer_mul(abs_inv);
+                    det.mul(E::faer_from_real(abs));
+                    det.mul(E::faer_from_real(abs));
+                    det.mul(E::faer_from_real(
+                        a.faer_mul(b).faer_sub(E::Real::faer_one()),
+                    ));
+                    i += 2;
+                }
+            }
+            let (phase, log_abs_inv) = det.finish();
+            (phase, log_abs_inv.faer_neg())
         }
+    }
 
-        pub fn compute_l(&self) -> Mat<E> {
-            let size = Ord::min(self.nrows(), self.ncols());
-            let mut factor = self
-                .factors
-                .as_ref()
-                .submatrix(0, 0, self.nrows(), size)
-                .to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_upper(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
+    impl<E: ComplexField> SolveWithStack<E> for Lblt<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_cholesky::bunch_kaufman::solve::solve_in_place_req::<usize, E>(
+                self.dim(),
+                rhs_ncols,
+                parallelism,
+            )
         }
-        pub fn compute_u(&self) -> Mat<E> {
-            let size = Ord::min(self.nrows(), self.ncols());
-            let mut factor = self
-                .factors
-                .as_ref()
-                .submatrix(0, 0, size, self.ncols())
-                .to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
-                .as_mut()
-                .diagonal_mut()
-                .column_vector_mut()
-                .fill(E::faer_one());
-            factor
+
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
+        }
+
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            faer_cholesky::bunch_kaufman::solve::solve_in_place_with_conj(
+                self.factors.as_ref(),
+                self.subdiag.as_ref(),
+                conj,
+                unsafe { PermutationRef::new_unchecked(&self.perm, &self.perm_inv) },
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
         }
     }
-    impl<E: ComplexField> SpSolverCore<E> for FullPivLu<E> {
+    impl<E: ComplexField> SpSolverCore<E> for Lblt<E> {
         #[track_caller]
         fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-
             let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_lu::full_pivoting::solve::solve_in_place(
-                self.factors.as_ref(),
-                conj,
-                self.row_permutation(),
-                self.col_permutation(),
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
                 rhs,
+                conj,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::full_pivoting::solve::solve_in_place_req::<usize, E>(
-                        self.nrows(),
-                        self.ncols(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
+                self.workspace.lock().stack(req),
             );
         }
 
         #[track_caller]
         fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-
             let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_lu::full_pivoting::solve::solve_transpose_in_place(
-                self.factors.as_ref(),
-                conj,
-                self.row_permutation(),
-                self.col_permutation(),
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
                 rhs,
+                conj,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::full_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
-                        self.nrows(),
-                        self.ncols(),
-                        rhs_ncols,
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
+                self.workspace.lock().stack(req),
             );
         }
 
@@ -975,186 +1949,303 @@ pub mod solvers {
             self.factors.ncols()
         }
     }
-    impl<E: ComplexField> SolverCore<E> for FullPivLu<E> {
-        #[track_caller]
+    impl<E: ComplexField> SolverCore<E> for Lblt<E> {
         fn inverse(&self) -> Mat<E> {
-            assert!(self.nrows() == self.ncols());
-
-            let dim = self.nrows();
+            let n = self.dim();
+            let mut inv = Mat::identity(n, n);
+            self.solve_in_place_with_conj_impl(inv.as_mut(), Conj::No);
+            inv
+        }
 
-            let mut inv = Mat::<E>::zeros(dim, dim);
+        fn reconstruct(&self) -> Mat<E> {
             let parallelism = get_global_parallelism();
-
-            faer_lu::full_pivoting::inverse::invert(
-                inv.as_mut(),
-                self.factors.as_ref(),
-                self.row_permutation(),
-                self.col_permutation(),
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::full_pivoting::inverse::invert_req::<usize, E>(dim, dim, parallelism)
-                        .unwrap(),
-                )),
+            let n = self.dim();
+            let lbl = self.factors.as_ref();
+            let subdiag = self.subdiag.as_ref();
+            let mut mat = Mat::<E>::identity(n, n);
+            let mut mat2 = Mat::<E>::identity(n, n);
+            zipped!(mat.as_mut(), lbl).for_each_triangular_lower(
+                faer_core::zip::Diag::Skip,
+                |unzipped!(mut dst, src)| dst.write(src.read()),
             );
 
-            inv
-        }
+            let mut j = 0;
+            while j < n {
+                if subdiag.read(j, 0) == E::faer_zero() {
+                    let d = lbl.read(j, j).faer_real().faer_inv();
+                    for i in 0..n {
+                        mat.write(i, j, mat.read(i, j).faer_scale_real(d));
+                    }
+                    j += 1;
+                } else {
+                    let akp1k = subdiag.read(j, 0).faer_inv();
+                    let ak = akp1k.faer_scale_real(lbl.read(j, j).faer_real());
+                    let akp1 = akp1k
+                        .faer_conj()
+                        .faer_scale_real(lbl.read(j + 1, j + 1).faer_real());
+                    let denom = ak
+                        .faer_mul(akp1)
+                        .faer_sub(E::faer_one())
+                        .faer_real()
+                        .faer_inv();
 
-        fn reconstruct(&self) -> Mat<E> {
-            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
-            let parallelism = get_global_parallelism();
+                    for i in 0..n {
+                        let xk = mat.read(i, j).faer_mul(akp1k);
+                        let xkp1 = mat.read(i, j + 1).faer_mul(akp1k.faer_conj());
 
-            faer_lu::full_pivoting::reconstruct::reconstruct(
-                rec.as_mut(),
-                self.factors.as_ref(),
-                self.row_permutation(),
-                self.col_permutation(),
+                        mat.write(
+                            i,
+                            j,
+                            (akp1.faer_mul(xk).faer_sub(xkp1)).faer_scale_real(denom),
+                        );
+                        mat.write(
+                            i,
+                            j + 1,
+                            (ak.faer_mul(xkp1).faer_sub(xk)).faer_scale_real(denom),
+                        );
+                    }
+                    j += 2;
+                }
+            }
+            faer_core::mul::triangular::matmul(
+                mat2.as_mut(),
+                faer_core::mul::triangular::BlockStructure::TriangularLower,
+                lbl,
+                faer_core::mul::triangular::BlockStructure::UnitTriangularLower,
+                mat.as_ref().adjoint(),
+                faer_core::mul::triangular::BlockStructure::Rectangular,
+                None,
+                E::faer_one(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_lu::full_pivoting::reconstruct::reconstruct_req::<usize, E>(
-                        self.nrows(),
-                        self.ncols(),
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
             );
 
-            rec
+            for j in 0..n {
+                let pj = self.perm_inv[j];
+                for i in j..n {
+                    let pi = self.perm_inv[i];
+
+                    mat.write(
+                        i,
+                        j,
+                        if pi >= pj {
+                            mat2.read(pi, pj)
+                        } else {
+                            mat2.read(pj, pi).faer_conj()
+                        },
+                    );
+                }
+            }
+
+            for j in 0..n {
+                mat.write(j, j, E::faer_from_real(mat.read(j, j).faer_real()));
+                for i in 0..j {
+                    mat.write(i, j, mat.read(j, i).faer_conj());
+                }
+            }
+
+            mat
         }
     }
 
-    impl<E: ComplexField> Qr<E> {
+    impl<E: ComplexField> Ltlt<E> {
         #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
+
+            let dim = matrix.nrows();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                perm: vec![0; dim],
+                perm_inv: vec![0; dim],
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, side, &mut workspace);
+            this.workspace = workspace;
+            this
+        }
+
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+        ) {
+            assert!(all(
+                matrix.nrows() == self.dim(),
+                matrix.ncols() == self.dim()
+            ));
+
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, side, &mut workspace);
+            self.workspace = workspace;
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            workspace: &mut Workspace,
+        ) {
+            let dim = self.dim();
             let parallelism = get_global_parallelism();
-            let nrows = matrix.nrows();
-            let ncols = matrix.ncols();
 
-            let mut factors = matrix.to_owned();
-            let size = Ord::min(nrows, ncols);
-            let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
-            let mut householder = Mat::<E>::zeros(blocksize, size);
+            match side {
+                Side::Lower => {
+                    zipped!(self.factors.as_mut(), matrix).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+                Side::Upper => {
+                    zipped!(self.factors.as_mut(), matrix.adjoint()).for_each_triangular_lower(
+                        faer_core::zip::Diag::Include,
+                        |unzipped!(mut dst, src)| dst.write(src.read().canonicalize()),
+                    );
+                }
+            }
 
             let params = Default::default();
 
-            faer_qr::no_pivoting::compute::qr_in_place(
-                factors.as_mut(),
-                householder.as_mut(),
+            workspace.reserve(
+                faer_cholesky::aasen::solve::solve_in_place_req::<usize, E>(dim, 1, parallelism)
+                    .unwrap(),
+            );
+
+            faer_cholesky::aasen::compute::cholesky_in_place(
+                self.factors.as_mut(),
+                &mut self.perm,
+                &mut self.perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
-                        nrows,
-                        ncols,
-                        blocksize,
+                workspace.stack(
+                    faer_cholesky::aasen::compute::cholesky_in_place_req::<usize, E>(
+                        dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
-
-            Self {
-                factors,
-                householder,
-            }
         }
 
-        fn blocksize(&self) -> usize {
-            self.householder.nrows()
+        fn dim(&self) -> usize {
+            self.factors.nrows()
         }
 
-        pub fn compute_r(&self) -> Mat<E> {
-            let mut factor = self.factors.to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
+        /// Returns the permutation $P$.
+        pub fn row_permutation(&self) -> PermutationRef<'_, usize, E> {
+            unsafe { PermutationRef::new_unchecked(&self.perm, &self.perm_inv) }
         }
 
-        pub fn compute_q(&self) -> Mat<E> {
-            Self::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
+        /// Returns the unit lower triangular factor $L$.
+        pub fn compute_l(&self) -> Mat<E> {
+            let n = self.dim();
+            Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    E::faer_one()
+                } else if j > 0 && i > j {
+                    self.factors.read(i, j - 1)
+                } else {
+                    E::faer_zero()
+                }
+            })
         }
 
-        pub fn compute_thin_r(&self) -> Mat<E> {
-            let m = self.nrows();
-            let n = self.ncols();
-            let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
-            zipped!(factor.as_mut())
-                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
-                    dst.write(E::faer_zero())
-                });
-            factor
+        /// Returns the hermitian tridiagonal factor $T$.
+        pub fn compute_t(&self) -> Mat<E> {
+            let n = self.dim();
+            Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    E::faer_from_real(self.factors.read(i, i).faer_real())
+                } else if i == j + 1 {
+                    self.factors.read(i, j)
+                } else if j == i + 1 {
+                    self.factors.read(j, i).faer_conj()
+                } else {
+                    E::faer_zero()
+                }
+            })
         }
+    }
 
-        pub fn compute_thin_q(&self) -> Mat<E> {
-            Self::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
+    impl<E: ComplexField> SolveWithStack<E> for Ltlt<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_cholesky::aasen::solve::solve_in_place_req::<usize, E>(
+                self.dim(),
+                rhs_ncols,
+                parallelism,
+            )
         }
 
-        fn __compute_q_impl(
-            factors: MatRef<'_, E>,
-            householder: MatRef<'_, E>,
-            thin: bool,
-        ) -> Mat<E> {
-            let parallelism = get_global_parallelism();
-            let m = factors.nrows();
-            let size = Ord::min(m, factors.ncols());
-
-            let mut q = Mat::<E>::zeros(m, if thin { size } else { m });
-            q.as_mut()
-                .diagonal_mut()
-                .column_vector_mut()
-                .fill(E::faer_one());
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
+        }
 
-            faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
-                factors,
-                householder,
-                Conj::No,
-                q.as_mut(),
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            faer_cholesky::aasen::solve::solve_in_place_with_conj(
+                self.factors.as_ref(),
+                conj,
+                self.row_permutation(),
+                rhs,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_req::<E>(
-                        m,
-                        householder.nrows(),
-                        m,
-                    )
-                    .unwrap(),
-                )),
+                stack,
             );
+        }
 
-            q
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
         }
     }
-    impl<E: ComplexField> SpSolverCore<E> for Qr<E> {
+    impl<E: ComplexField> SpSolverCore<E> for Ltlt<E> {
         #[track_caller]
         fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-            self.solve_lstsq_in_place_with_conj_impl(rhs, conj)
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
         #[track_caller]
         fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-
             let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_qr::no_pivoting::solve::solve_transpose_in_place(
-                self.factors.as_ref(),
-                self.householder.as_ref(),
-                conj,
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
                 rhs,
+                conj,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::no_pivoting::solve::solve_transpose_in_place_req::<E>(
-                        self.nrows(),
-                        self.blocksize(),
-                        rhs_ncols,
-                    )
-                    .unwrap(),
-                )),
+                self.workspace.lock().stack(req),
             );
         }
 
@@ -1166,191 +2257,293 @@ pub mod solvers {
             self.factors.ncols()
         }
     }
-    impl<E: ComplexField> SolverCore<E> for Qr<E> {
+    impl<E: ComplexField> SolverCore<E> for Ltlt<E> {
+        fn inverse(&self) -> Mat<E> {
+            let n = self.dim();
+            let mut inv = Mat::identity(n, n);
+            self.solve_in_place_with_conj_impl(inv.as_mut(), Conj::No);
+            inv
+        }
+
         fn reconstruct(&self) -> Mat<E> {
-            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
             let parallelism = get_global_parallelism();
-
-            faer_qr::no_pivoting::reconstruct::reconstruct(
-                rec.as_mut(),
-                self.factors.as_ref(),
-                self.householder.as_ref(),
+            let n = self.dim();
+            let l = self.compute_l();
+            let t = self.compute_t();
+
+            let mut lt = Mat::<E>::zeros(n, n);
+            faer_core::mul::matmul(
+                lt.as_mut(),
+                l.as_ref(),
+                t.as_ref(),
+                None,
+                E::faer_one(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::no_pivoting::reconstruct::reconstruct_req::<E>(
-                        self.nrows(),
-                        self.ncols(),
-                        self.blocksize(),
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
             );
-
-            rec
-        }
-
-        fn inverse(&self) -> Mat<E> {
-            assert!(self.nrows() == self.ncols());
-
-            let mut inv = Mat::<E>::zeros(self.nrows(), self.ncols());
-            let parallelism = get_global_parallelism();
-
-            faer_qr::no_pivoting::inverse::invert(
-                inv.as_mut(),
-                self.factors.as_ref(),
-                self.householder.as_ref(),
+            let mut ltl = Mat::<E>::zeros(n, n);
+            faer_core::mul::matmul(
+                ltl.as_mut(),
+                lt.as_ref(),
+                l.as_ref().adjoint(),
+                None,
+                E::faer_one(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::no_pivoting::inverse::invert_req::<E>(
-                        self.nrows(),
-                        self.ncols(),
-                        self.blocksize(),
-                        parallelism,
-                    )
-                    .unwrap(),
-                )),
             );
 
-            inv
+            Mat::from_fn(n, n, |i, j| ltl.read(self.perm_inv[i], self.perm_inv[j]))
         }
     }
 
-    impl<E: ComplexField> SpSolverLstsqCore<E> for Qr<E> {
+    impl<E: ComplexField> PartialPivLu<E> {
         #[track_caller]
-        fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
 
-            faer_qr::no_pivoting::solve::solve_in_place(
-                self.factors.as_ref(),
-                self.householder.as_ref(),
-                conj,
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::no_pivoting::solve::solve_in_place_req::<E>(
-                        self.nrows(),
-                        self.blocksize(),
-                        rhs_ncols,
-                    )
-                    .unwrap(),
-                )),
-            );
+            let dim = matrix.nrows();
+            let mut this = Self {
+                factors: Mat::zeros(dim, dim),
+                row_perm: vec![0usize; dim],
+                row_perm_inv: vec![0usize; dim],
+                n_transpositions: 0,
+                first_zero_pivot: None,
+                nan_encountered: false,
+                pivot_growth: 1.0,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, &mut workspace);
+            this.workspace = workspace;
+            this
         }
-    }
-    impl<E: ComplexField> SolverLstsqCore<E> for Qr<E> {}
 
-    impl<E: ComplexField> ColPivQr<E> {
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
         #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            assert!(all(
+                matrix.nrows() == self.dim(),
+                matrix.ncols() == self.dim()
+            ));
+
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, &mut workspace);
+            self.workspace = workspace;
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            workspace: &mut Workspace,
+        ) {
+            let dim = self.dim();
             let parallelism = get_global_parallelism();
-            let nrows = matrix.nrows();
-            let ncols = matrix.ncols();
 
-            let mut factors = matrix.to_owned();
-            let size = Ord::min(nrows, ncols);
-            let blocksize =
-                faer_qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
-            let mut householder = Mat::<E>::zeros(blocksize, size);
+            zipped!(self.factors.as_mut(), matrix)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
 
             let params = Default::default();
 
-            let mut col_perm = vec![0usize; ncols];
-            let mut col_perm_inv = vec![0usize; ncols];
+            workspace.reserve(StackReq::any_of([
+                faer_lu::partial_pivoting::solve::solve_in_place_req::<usize, E>(
+                    dim,
+                    dim,
+                    1,
+                    parallelism,
+                )
+                .unwrap(),
+                faer_lu::partial_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                    dim,
+                    dim,
+                    1,
+                    parallelism,
+                )
+                .unwrap(),
+            ]));
 
-            faer_qr::col_pivoting::compute::qr_in_place(
-                factors.as_mut(),
-                householder.as_mut(),
-                &mut col_perm,
-                &mut col_perm_inv,
+            let (info, _) = faer_lu::partial_pivoting::compute::lu_in_place(
+                self.factors.as_mut(),
+                &mut self.row_perm,
+                &mut self.row_perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
-                        nrows,
-                        ncols,
-                        blocksize,
+                workspace.stack(
+                    faer_lu::partial_pivoting::compute::lu_in_place_req::<usize, E>(
+                        dim,
+                        dim,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
 
-            Self {
-                factors,
-                householder,
-                col_perm,
-                col_perm_inv,
-            }
+            self.n_transpositions = info.transposition_count;
+            self.first_zero_pivot = info.first_zero_pivot;
+            self.nan_encountered = info.nan_encountered;
+            self.pivot_growth = info.pivot_growth;
         }
 
-        pub fn col_permutation(&self) -> PermutationRef<'_, usize, E> {
-            unsafe { PermutationRef::new_unchecked(&self.col_perm, &self.col_perm_inv) }
+        fn dim(&self) -> usize {
+            self.factors.nrows()
         }
 
-        fn blocksize(&self) -> usize {
-            self.householder.nrows()
+        pub fn row_permutation(&self) -> PermutationRef<'_, usize, E> {
+            unsafe { PermutationRef::new_unchecked(&self.row_perm, &self.row_perm_inv) }
         }
 
-        pub fn compute_r(&self) -> Mat<E> {
+        pub fn transposition_count(&self) -> usize {
+            self.n_transpositions
+        }
+
+        /// Returns the index and the value of the first pivot of $U$ that is zero, or whose
+        /// reciprocal overflows, if any, in which case the matrix is singular to working
+        /// precision.
+        pub fn first_zero_pivot(&self) -> Option<(usize, E)> {
+            self.first_zero_pivot
+                .map(|idx| (idx, self.factors.read(idx, idx)))
+        }
+
+        /// Returns whether NaN values were encountered during the factorization.
+        pub fn nan_encountered(&self) -> bool {
+            self.nan_encountered
+        }
+
+        /// Returns the pivot growth factor, i.e., the ratio of the largest entry of $U$ to the
+        /// largest entry of the input matrix, in absolute value. Large values indicate that the
+        /// factorization may be numerically unstable.
+        pub fn pivot_growth(&self) -> E::Real {
+            E::Real::faer_from_f64(self.pivot_growth)
+        }
+
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`, such that
+        /// the determinant is equal to `phase * exp(log_abs)`. The phase has a unit modulus, or is
+        /// zero if the matrix is singular, in which case `log_abs` is negative infinity.
+        ///
+        /// Unlike [`FaerMat::determinant`], this doesn't overflow or underflow for large matrices.
+        ///
+        /// # Panics
+        /// Panics if the matrix is not square.
+        #[track_caller]
+        pub fn log_determinant(&self) -> (E, E::Real) {
+            assert!(self.nrows() == self.ncols());
+            let mut det = LogDeterminant::<E>::new();
+            for i in 0..self.nrows() {
+                det.mul(self.factors.read(i, i));
+            }
+            det.negate_if(self.n_transpositions % 2 == 1);
+            det.finish()
+        }
+
+        pub fn compute_l(&self) -> Mat<E> {
             let mut factor = self.factors.to_owned();
             zipped!(factor.as_mut())
-                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                .for_each_triangular_upper(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
                     dst.write(E::faer_zero())
                 });
             factor
         }
-
-        pub fn compute_q(&self) -> Mat<E> {
-            Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
-        }
-
-        pub fn compute_thin_r(&self) -> Mat<E> {
-            let m = self.nrows();
-            let n = self.ncols();
-            let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
+        pub fn compute_u(&self) -> Mat<E> {
+            let mut factor = self.factors.to_owned();
             zipped!(factor.as_mut())
                 .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
                     dst.write(E::faer_zero())
                 });
             factor
+                .as_mut()
+                .diagonal_mut()
+                .column_vector_mut()
+                .fill(E::faer_one());
+            factor
+        }
+    }
+    impl<E: ComplexField> SolveWithStack<E> for PartialPivLu<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_lu::partial_pivoting::solve::solve_in_place_req::<usize, E>(
+                self.dim(),
+                self.dim(),
+                rhs_ncols,
+                parallelism,
+            )
         }
 
-        pub fn compute_thin_q(&self) -> Mat<E> {
-            Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_lu::partial_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                self.dim(),
+                self.dim(),
+                rhs_ncols,
+                parallelism,
+            )
+        }
+
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            faer_lu::partial_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                conj,
+                self.row_permutation(),
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            faer_lu::partial_pivoting::solve::solve_transpose_in_place(
+                self.factors.as_ref(),
+                conj,
+                self.row_permutation(),
+                rhs,
+                parallelism,
+                stack,
+            );
         }
     }
-    impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
+    impl<E: ComplexField> SpSolverCore<E> for PartialPivLu<E> {
         #[track_caller]
         fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-            self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
         #[track_caller]
         fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-
             let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
-
-            faer_qr::col_pivoting::solve::solve_transpose_in_place(
-                self.factors.as_ref(),
-                self.householder.as_ref(),
-                self.col_permutation(),
-                conj,
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
                 rhs,
+                conj,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::col_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
-                        self.nrows(),
-                        self.blocksize(),
-                        rhs_ncols,
-                    )
-                    .unwrap(),
-                )),
+                self.workspace.lock().stack(req),
             );
         }
 
@@ -1362,1720 +2555,5761 @@ pub mod solvers {
             self.factors.ncols()
         }
     }
-    impl<E: ComplexField> SolverCore<E> for ColPivQr<E> {
-        fn reconstruct(&self) -> Mat<E> {
-            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
+    impl<E: ComplexField> SolverCore<E> for PartialPivLu<E> {
+        fn inverse(&self) -> Mat<E> {
+            let mut inv = Mat::<E>::zeros(self.dim(), self.dim());
             let parallelism = get_global_parallelism();
 
-            faer_qr::col_pivoting::reconstruct::reconstruct(
-                rec.as_mut(),
+            faer_lu::partial_pivoting::inverse::invert(
+                inv.as_mut(),
                 self.factors.as_ref(),
-                self.householder.as_ref(),
-                self.col_permutation(),
+                self.row_permutation(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::col_pivoting::reconstruct::reconstruct_req::<usize, E>(
-                        self.nrows(),
-                        self.ncols(),
-                        self.blocksize(),
+                self.workspace.lock().stack(
+                    faer_lu::partial_pivoting::inverse::invert_req::<usize, E>(
+                        self.dim(),
+                        self.dim(),
                         parallelism,
                     )
                     .unwrap(),
-                )),
+                ),
             );
 
-            rec
+            inv
         }
 
-        fn inverse(&self) -> Mat<E> {
-            assert!(self.nrows() == self.ncols());
-
-            let mut inv = Mat::<E>::zeros(self.nrows(), self.ncols());
+        fn reconstruct(&self) -> Mat<E> {
+            let mut rec = Mat::<E>::zeros(self.dim(), self.dim());
             let parallelism = get_global_parallelism();
 
-            faer_qr::col_pivoting::inverse::invert(
-                inv.as_mut(),
+            faer_lu::partial_pivoting::reconstruct::reconstruct(
+                rec.as_mut(),
                 self.factors.as_ref(),
-                self.householder.as_ref(),
-                self.col_permutation(),
+                self.row_permutation(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::col_pivoting::inverse::invert_req::<usize, E>(
-                        self.nrows(),
-                        self.ncols(),
-                        self.blocksize(),
+                self.workspace.lock().stack(
+                    faer_lu::partial_pivoting::reconstruct::reconstruct_req::<usize, E>(
+                        self.dim(),
+                        self.dim(),
                         parallelism,
                     )
                     .unwrap(),
-                )),
+                ),
             );
 
-            inv
+            rec
         }
     }
 
-    impl<E: ComplexField> SpSolverLstsqCore<E> for ColPivQr<E> {
+    impl<E: ComplexField> FullPivLu<E> {
         #[track_caller]
-        fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            let parallelism = get_global_parallelism();
-            let rhs_ncols = rhs.ncols();
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            let m = matrix.nrows();
+            let n = matrix.ncols();
 
-            faer_qr::col_pivoting::solve::solve_in_place(
-                self.factors.as_ref(),
-                self.householder.as_ref(),
-                self.col_permutation(),
-                conj,
-                rhs,
-                parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_qr::col_pivoting::solve::solve_in_place_req::<usize, E>(
-                        self.nrows(),
-                        self.blocksize(),
-                        rhs_ncols,
-                    )
-                    .unwrap(),
-                )),
-            );
+            let mut this = Self {
+                factors: Mat::zeros(m, n),
+                row_perm: vec![0usize; m],
+                row_perm_inv: vec![0usize; m],
+                col_perm: vec![0usize; n],
+                col_perm_inv: vec![0usize; n],
+                n_transpositions: 0,
+                first_zero_pivot: None,
+                nan_encountered: false,
+                pivot_growth: 1.0,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, &mut workspace);
+            this.workspace = workspace;
+            this
+        }
+
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            assert!(all(
+                matrix.nrows() == self.factors.nrows(),
+                matrix.ncols() == self.factors.ncols(),
+            ));
+
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, &mut workspace);
+            self.workspace = workspace;
         }
-    }
-    impl<E: ComplexField> SolverLstsqCore<E> for ColPivQr<E> {}
 
-    impl<E: ComplexField> Svd<E> {
         #[track_caller]
-        fn __new_impl((matrix, conj): (MatRef<'_, E>, Conj), thin: bool) -> Self {
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            workspace: &mut Workspace,
+        ) {
+            let m = self.factors.nrows();
+            let n = self.factors.ncols();
             let parallelism = get_global_parallelism();
-            let m = matrix.nrows();
-            let n = matrix.ncols();
-            let size = Ord::min(m, n);
 
-            let mut s = Mat::<E>::zeros(size, 1);
-            let mut u = Mat::<E>::zeros(m, if thin { size } else { m });
-            let mut v = Mat::<E>::zeros(n, if thin { size } else { n });
+            zipped!(self.factors.as_mut(), matrix)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
 
             let params = Default::default();
 
-            let compute_vecs = if thin {
-                faer_svd::ComputeVectors::Thin
-            } else {
-                faer_svd::ComputeVectors::Full
-            };
-
-            faer_svd::compute_svd(
-                matrix,
-                s.as_mut(),
-                Some(u.as_mut()),
-                Some(v.as_mut()),
+            workspace.reserve(StackReq::any_of([
+                faer_lu::full_pivoting::solve::solve_in_place_req::<usize, E>(m, n, 1, parallelism)
+                    .unwrap(),
+                faer_lu::full_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                    m,
+                    n,
+                    1,
+                    parallelism,
+                )
+                .unwrap(),
+            ]));
+
+            let (info, _, _) = faer_lu::full_pivoting::compute::lu_in_place(
+                self.factors.as_mut(),
+                &mut self.row_perm,
+                &mut self.row_perm_inv,
+                &mut self.col_perm,
+                &mut self.col_perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_svd::compute_svd_req::<E>(
+                workspace.stack(
+                    faer_lu::full_pivoting::compute::lu_in_place_req::<usize, E>(
                         m,
                         n,
-                        compute_vecs,
-                        compute_vecs,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
 
-            if matches!(conj, Conj::Yes) {
-                zipped!(u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-                zipped!(v.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-            }
-
-            Self { s, u, v }
-        }
-
-        #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
-            Self::__new_impl(matrix.canonicalize(), false)
+            self.n_transpositions = info.transposition_count;
+            self.first_zero_pivot = info.first_zero_pivot;
+            self.nan_encountered = info.nan_encountered;
+            self.pivot_growth = info.pivot_growth;
         }
 
-        pub fn u(&self) -> MatRef<'_, E> {
-            self.u.as_ref()
+        pub fn row_permutation(&self) -> PermutationRef<'_, usize, E> {
+            unsafe { PermutationRef::new_unchecked(&self.row_perm, &self.row_perm_inv) }
         }
-        pub fn s_diagonal(&self) -> MatRef<'_, E> {
-            self.s.as_ref()
+        pub fn col_permutation(&self) -> PermutationRef<'_, usize, E> {
+            unsafe { PermutationRef::new_unchecked(&self.col_perm, &self.col_perm_inv) }
         }
-        pub fn v(&self) -> MatRef<'_, E> {
-            self.v.as_ref()
+
+        pub fn transposition_count(&self) -> usize {
+            self.n_transpositions
         }
-    }
-    fn div_by_s<E: ComplexField>(rhs: MatMut<'_, E>, s: MatRef<'_, E>) {
-        let mut rhs = rhs;
-        for j in 0..rhs.ncols() {
-            zipped!(rhs.rb_mut().col_mut(j).as_2d_mut(), s).for_each(|unzipped!(mut rhs, s)| {
-                rhs.write(rhs.read().faer_scale_real(s.read().faer_real().faer_inv()))
-            });
+
+        /// Returns the index and the value of the first pivot of $U$ that is zero, or whose
+        /// reciprocal overflows, if any, in which case the matrix is singular to working
+        /// precision.
+        pub fn first_zero_pivot(&self) -> Option<(usize, E)> {
+            self.first_zero_pivot
+                .map(|idx| (idx, self.factors.read(idx, idx)))
         }
-    }
-    impl<E: ComplexField> SpSolverCore<E> for Svd<E> {
-        fn nrows(&self) -> usize {
-            self.u.nrows()
+
+        /// Returns whether NaN values were encountered during the factorization.
+        pub fn nan_encountered(&self) -> bool {
+            self.nan_encountered
         }
 
-        fn ncols(&self) -> usize {
-            self.v.nrows()
+        /// Returns the pivot growth factor, i.e., the ratio of the largest entry of $U$ to the
+        /// largest entry of the input matrix, in absolute value. Large values indicate that the
+        /// factorization may be numerically unstable.
+        pub fn pivot_growth(&self) -> E::Real {
+            E::Real::faer_from_f64(self.pivot_growth)
         }
 
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`. See
+        /// [`PartialPivLu::log_determinant`].
+        ///
+        /// # Panics
+        /// Panics if the matrix is not square.
         #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+        pub fn log_determinant(&self) -> (E, E::Real) {
             assert!(self.nrows() == self.ncols());
-            let mut rhs = rhs;
-
-            let u = self.u.as_ref();
-            let v = self.v.as_ref();
-            let s = self.s.as_ref();
-
-            match conj {
-                Conj::Yes => {
-                    rhs.copy_from((u.transpose() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((v.conjugate() * rhs.rb()).as_ref());
-                }
-                Conj::No => {
-                    rhs.copy_from((u.adjoint() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((v * rhs.rb()).as_ref());
-                }
+            let mut det = LogDeterminant::<E>::new();
+            for i in 0..self.nrows() {
+                det.mul(self.factors.read(i, i));
             }
+            det.negate_if(self.n_transpositions % 2 == 1);
+            det.finish()
         }
 
-        #[track_caller]
-        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-            let mut rhs = rhs;
-
-            let u = self.u.as_ref();
-            let v = self.v.as_ref();
-            let s = self.s.as_ref();
-
-            match conj {
-                Conj::No => {
-                    rhs.copy_from((v.transpose() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u.conjugate() * rhs.rb()).as_ref());
-                }
-                Conj::Yes => {
-                    rhs.copy_from((v.adjoint() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u * rhs.rb()).as_ref());
-                }
-            }
+        pub fn compute_l(&self) -> Mat<E> {
+            let size = Ord::min(self.nrows(), self.ncols());
+            let mut factor = self
+                .factors
+                .as_ref()
+                .submatrix(0, 0, self.nrows(), size)
+                .to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_upper(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
+        }
+        pub fn compute_u(&self) -> Mat<E> {
+            let size = Ord::min(self.nrows(), self.ncols());
+            let mut factor = self
+                .factors
+                .as_ref()
+                .submatrix(0, 0, size, self.ncols())
+                .to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
+                .as_mut()
+                .diagonal_mut()
+                .column_vector_mut()
+                .fill(E::faer_one());
+            factor
         }
     }
-    impl<E: ComplexField> SolverCore<E> for Svd<E> {
-        fn reconstruct(&self) -> Mat<E> {
-            let m = self.nrows();
-            let n = self.ncols();
-            let size = Ord::min(m, n);
-
-            let thin_u = self.u.as_ref().submatrix(0, 0, m, size);
-            let s = self.s.as_ref();
-            let us = Mat::<E>::from_fn(m, size, |i, j| thin_u.read(i, j).faer_mul(s.read(j, 0)));
+    impl<E: ComplexField> SolveWithStack<E> for FullPivLu<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_lu::full_pivoting::solve::solve_in_place_req::<usize, E>(
+                self.nrows(),
+                self.ncols(),
+                rhs_ncols,
+                parallelism,
+            )
+        }
 
-            us * self.v.adjoint()
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            faer_lu::full_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                self.nrows(),
+                self.ncols(),
+                rhs_ncols,
+                parallelism,
+            )
         }
 
-        fn inverse(&self) -> Mat<E> {
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
             assert!(self.nrows() == self.ncols());
-            let dim = self.nrows();
-
-            let u = self.u.as_ref();
-            let v = self.v.as_ref();
-            let s = self.s.as_ref();
-
-            let vs_inv = Mat::<E>::from_fn(dim, dim, |i, j| {
-                v.read(i, j).faer_mul(s.read(j, 0).faer_inv())
-            });
+            faer_lu::full_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                conj,
+                self.row_permutation(),
+                self.col_permutation(),
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
 
-            vs_inv * u.adjoint()
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            faer_lu::full_pivoting::solve::solve_transpose_in_place(
+                self.factors.as_ref(),
+                conj,
+                self.row_permutation(),
+                self.col_permutation(),
+                rhs,
+                parallelism,
+                stack,
+            );
         }
     }
-
-    impl<E: ComplexField> ThinSvd<E> {
+    impl<E: ComplexField> SpSolverCore<E> for FullPivLu<E> {
         #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
-            Self {
-                inner: Svd::__new_impl(matrix.canonicalize(), true),
-            }
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
-        pub fn u(&self) -> MatRef<'_, E> {
-            self.inner.u.as_ref()
-        }
-        pub fn s_diagonal(&self) -> MatRef<'_, E> {
-            self.inner.s.as_ref()
-        }
-        pub fn v(&self) -> MatRef<'_, E> {
-            self.inner.v.as_ref()
+        #[track_caller]
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
-    }
-    impl<E: ComplexField> SpSolverCore<E> for ThinSvd<E> {
+
         fn nrows(&self) -> usize {
-            self.inner.nrows()
+            self.factors.nrows()
         }
 
         fn ncols(&self) -> usize {
-            self.inner.ncols()
+            self.factors.ncols()
         }
-
+    }
+    impl<E: ComplexField> SolverCore<E> for FullPivLu<E> {
         #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            self.inner.solve_in_place_with_conj_impl(rhs, conj)
-        }
+        fn inverse(&self) -> Mat<E> {
+            assert!(self.nrows() == self.ncols());
 
-        #[track_caller]
-        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            self.inner
-                .solve_transpose_in_place_with_conj_impl(rhs, conj)
-        }
-    }
-    impl<E: ComplexField> SolverCore<E> for ThinSvd<E> {
-        fn reconstruct(&self) -> Mat<E> {
-            self.inner.reconstruct()
-        }
-
-        fn inverse(&self) -> Mat<E> {
-            self.inner.inverse()
-        }
-    }
+            let dim = self.nrows();
 
-    impl<E: ComplexField> SelfAdjointEigendecomposition<E> {
-        #[track_caller]
-        fn __new_impl((matrix, conj): (MatRef<'_, E>, Conj), side: Side) -> Self {
-            assert!(matrix.nrows() == matrix.ncols());
+            let mut inv = Mat::<E>::zeros(dim, dim);
             let parallelism = get_global_parallelism();
 
-            let dim = matrix.nrows();
+            faer_lu::full_pivoting::inverse::invert(
+                inv.as_mut(),
+                self.factors.as_ref(),
+                self.row_permutation(),
+                self.col_permutation(),
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_lu::full_pivoting::inverse::invert_req::<usize, E>(dim, dim, parallelism)
+                        .unwrap(),
+                ),
+            );
 
-            let mut s = Mat::<E>::zeros(dim, 1);
-            let mut u = Mat::<E>::zeros(dim, dim);
+            inv
+        }
 
-            let matrix = match side {
-                Side::Lower => matrix,
-                Side::Upper => matrix.transpose(),
-            };
-            let conj = conj.compose(match side {
-                Side::Lower => Conj::No,
-                Side::Upper => Conj::Yes,
-            });
+        fn reconstruct(&self) -> Mat<E> {
+            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
+            let parallelism = get_global_parallelism();
 
-            let params = Default::default();
-            faer_evd::compute_hermitian_evd(
-                matrix,
-                s.as_mut(),
-                Some(u.as_mut()),
+            faer_lu::full_pivoting::reconstruct::reconstruct(
+                rec.as_mut(),
+                self.factors.as_ref(),
+                self.row_permutation(),
+                self.col_permutation(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_evd::compute_hermitian_evd_req::<E>(
-                        dim,
-                        faer_evd::ComputeVectors::Yes,
+                self.workspace.lock().stack(
+                    faer_lu::full_pivoting::reconstruct::reconstruct_req::<usize, E>(
+                        self.nrows(),
+                        self.ncols(),
                         parallelism,
-                        params,
                     )
                     .unwrap(),
-                )),
-                params,
+                ),
             );
 
-            if matches!(conj, Conj::Yes) {
-                zipped!(u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-            }
-
-            Self { s, u }
-        }
-
-        #[track_caller]
-        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
-            Self::__new_impl(matrix.canonicalize(), side)
-        }
-
-        pub fn u(&self) -> MatRef<'_, E> {
-            self.u.as_ref()
-        }
-        pub fn s_diagonal(&self) -> MatRef<'_, E> {
-            self.s.as_ref()
+            rec
         }
     }
-    impl<E: ComplexField> SpSolverCore<E> for SelfAdjointEigendecomposition<E> {
-        fn nrows(&self) -> usize {
-            self.u.nrows()
-        }
-
-        fn ncols(&self) -> usize {
-            self.u.nrows()
-        }
 
+    impl<E: ComplexField> Qr<E> {
         #[track_caller]
-        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-            let mut rhs = rhs;
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            let nrows = matrix.nrows();
+            let ncols = matrix.ncols();
 
-            let u = self.u.as_ref();
-            let s = self.s.as_ref();
+            let size = Ord::min(nrows, ncols);
+            let blocksize = faer_qr::no_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
 
-            match conj {
-                Conj::Yes => {
-                    rhs.copy_from((u.transpose() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u.conjugate() * rhs.rb()).as_ref());
-                }
-                Conj::No => {
-                    rhs.copy_from((u.adjoint() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u * rhs.rb()).as_ref());
-                }
-            }
+            let mut this = Self {
+                factors: Mat::zeros(nrows, ncols),
+                householder: Mat::zeros(blocksize, size),
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, &mut workspace);
+            this.workspace = workspace;
+            this
         }
 
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
         #[track_caller]
-        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-            assert!(self.nrows() == self.ncols());
-            let mut rhs = rhs;
-
-            let u = self.u.as_ref();
-            let s = self.s.as_ref();
-
-            match conj {
-                Conj::No => {
-                    rhs.copy_from((u.transpose() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u.conjugate() * rhs.rb()).as_ref());
-                }
-                Conj::Yes => {
-                    rhs.copy_from((u.adjoint() * rhs.rb()).as_ref());
-                    div_by_s(rhs.rb_mut(), s);
-                    rhs.copy_from((u * rhs.rb()).as_ref());
-                }
-            }
-        }
-    }
-    impl<E: ComplexField> SolverCore<E> for SelfAdjointEigendecomposition<E> {
-        fn reconstruct(&self) -> Mat<E> {
-            let size = self.nrows();
-
-            let u = self.u.as_ref();
-            let s = self.s.as_ref();
-            let us = Mat::<E>::from_fn(size, size, |i, j| u.read(i, j).faer_mul(s.read(j, 0)));
-
-            us * u.adjoint()
-        }
-
-        fn inverse(&self) -> Mat<E> {
-            let dim = self.nrows();
-
-            let u = self.u.as_ref();
-            let s = self.s.as_ref();
-
-            let us_inv = Mat::<E>::from_fn(dim, dim, |i, j| {
-                u.read(i, j).faer_mul(s.read(j, 0).faer_inv())
-            });
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            assert!(all(
+                matrix.nrows() == self.factors.nrows(),
+                matrix.ncols() == self.factors.ncols(),
+            ));
 
-            us_inv * u.adjoint()
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, &mut workspace);
+            self.workspace = workspace;
         }
-    }
 
-    impl<E: ComplexField> Eigendecomposition<E> {
         #[track_caller]
-        pub(crate) fn __values_from_real(matrix: MatRef<'_, E::Real>) -> Vec<E> {
-            assert!(matrix.nrows() == matrix.ncols());
-            if coe::is_same::<E, E::Real>() {
-                panic!(
-                    "The type E ({}) must not be real-valued.",
-                    core::any::type_name::<E>(),
-                );
-            }
-
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            workspace: &mut Workspace,
+        ) {
             let parallelism = get_global_parallelism();
+            let nrows = self.factors.nrows();
+            let ncols = self.factors.ncols();
+            let blocksize = self.blocksize();
 
-            let dim = matrix.nrows();
-            let mut s_re = Mat::<E::Real>::zeros(dim, 1);
-            let mut s_im = Mat::<E::Real>::zeros(dim, 1);
+            zipped!(self.factors.as_mut(), matrix)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
 
             let params = Default::default();
 
-            faer_evd::compute_evd_real(
-                matrix,
-                s_re.as_mut(),
-                s_im.as_mut(),
-                None,
+            workspace.reserve(StackReq::any_of([
+                faer_qr::no_pivoting::solve::solve_in_place_req::<E>(nrows, blocksize, 1).unwrap(),
+                faer_qr::no_pivoting::solve::solve_transpose_in_place_req::<E>(nrows, blocksize, 1)
+                    .unwrap(),
+            ]));
+
+            faer_qr::no_pivoting::compute::qr_in_place(
+                self.factors.as_mut(),
+                self.householder.as_mut(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_evd::compute_evd_req::<E::Real>(
-                        dim,
-                        faer_evd::ComputeVectors::Yes,
+                workspace.stack(
+                    faer_qr::no_pivoting::compute::qr_in_place_req::<E>(
+                        nrows,
+                        ncols,
+                        blocksize,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
+        }
 
-            let imag = E::faer_from_f64(-1.0).faer_sqrt();
-            let cplx = |re: E::Real, im: E::Real| -> E {
-                E::faer_from_real(re).faer_add(imag.faer_mul(E::faer_from_real(im)))
-            };
+        fn blocksize(&self) -> usize {
+            self.householder.nrows()
+        }
 
-            (0..dim)
-                .map(|i| cplx(s_re.read(i, 0), s_im.read(i, 0)))
-                .collect()
+        pub fn compute_r(&self) -> Mat<E> {
+            let mut factor = self.factors.to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
+        }
+
+        pub fn compute_q(&self) -> Mat<E> {
+            Self::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
+        }
+
+        pub fn compute_thin_r(&self) -> Mat<E> {
+            let m = self.nrows();
+            let n = self.ncols();
+            let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
+        }
+
+        pub fn compute_thin_q(&self) -> Mat<E> {
+            Self::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
         }
 
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`. See
+        /// [`PartialPivLu::log_determinant`].
+        ///
+        /// # Panics
+        /// Panics if the matrix is not square.
         #[track_caller]
-        pub(crate) fn __values_from_complex_impl((matrix, conj): (MatRef<'_, E>, Conj)) -> Vec<E> {
-            assert!(matrix.nrows() == matrix.ncols());
-            if coe::is_same::<E, E::Real>() {
-                panic!(
-                    "The type E ({}) must not be real-valued.",
-                    core::any::type_name::<E>(),
-                );
+        pub fn log_determinant(&self) -> (E, E::Real) {
+            assert!(self.nrows() == self.ncols());
+            let n = self.nrows();
+            let mut det = LogDeterminant::<E>::new();
+            let mut n_reflections = 0usize;
+            for j in 0..n {
+                det.mul(self.factors.read(j, j));
+                // the householder reflection is the identity if and only if its essential part
+                // is zero, otherwise its determinant is -1
+                if (j + 1..n).any(|i| self.factors.read(i, j) != E::faer_zero()) {
+                    n_reflections += 1;
+                }
             }
+            det.negate_if(n_reflections % 2 == 1);
+            det.finish()
+        }
 
+        fn __compute_q_impl(
+            factors: MatRef<'_, E>,
+            householder: MatRef<'_, E>,
+            thin: bool,
+        ) -> Mat<E> {
             let parallelism = get_global_parallelism();
-            let dim = matrix.nrows();
-
-            let mut s = Mat::<E>::zeros(dim, 1);
+            let m = factors.nrows();
+            let size = Ord::min(m, factors.ncols());
 
-            let params = Default::default();
+            let mut q = Mat::<E>::zeros(m, if thin { size } else { m });
+            q.as_mut()
+                .diagonal_mut()
+                .column_vector_mut()
+                .fill(E::faer_one());
 
-            faer_evd::compute_evd_complex(
-                matrix,
-                s.as_mut(),
-                None,
+            faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_with_conj(
+                factors,
+                householder,
+                Conj::No,
+                q.as_mut(),
                 parallelism,
                 PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_evd::compute_evd_req::<E>(
-                        dim,
-                        faer_evd::ComputeVectors::Yes,
-                        parallelism,
-                        params,
+                    faer_core::householder::apply_block_householder_sequence_on_the_left_in_place_req::<E>(
+                        m,
+                        householder.nrows(),
+                        m,
                     )
                     .unwrap(),
                 )),
-                params,
             );
 
-            if matches!(conj, Conj::Yes) {
-                zipped!(s.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-            }
+            q
+        }
+    }
+    impl<E: ComplexField> SolveWithStack<E> for Qr<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            faer_qr::no_pivoting::solve::solve_in_place_req::<E>(
+                self.nrows(),
+                self.blocksize(),
+                rhs_ncols,
+            )
+        }
 
-            (0..dim).map(|i| s.read(i, 0)).collect()
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            faer_qr::no_pivoting::solve::solve_transpose_in_place_req::<E>(
+                self.nrows(),
+                self.blocksize(),
+                rhs_ncols,
+            )
         }
 
         #[track_caller]
-        pub fn new_from_real(matrix: MatRef<'_, E::Real>) -> Self {
-            assert!(matrix.nrows() == matrix.ncols());
-            if coe::is_same::<E, E::Real>() {
-                panic!(
-                    "The type E ({}) must not be real-valued.",
-                    core::any::type_name::<E>(),
-                );
-            }
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            faer_qr::no_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                conj,
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            faer_qr::no_pivoting::solve::solve_transpose_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                conj,
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
+    }
+    impl<E: ComplexField> SpSolverCore<E> for Qr<E> {
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            assert!(self.nrows() == self.ncols());
+            self.solve_lstsq_in_place_with_conj_impl(rhs, conj)
+        }
 
+        #[track_caller]
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
             let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
+        }
 
-            let dim = matrix.nrows();
-            let mut s_re = Col::<E::Real>::zeros(dim);
-            let mut s_im = Col::<E::Real>::zeros(dim);
-            let mut u_real = Mat::<E::Real>::zeros(dim, dim);
+        fn nrows(&self) -> usize {
+            self.factors.nrows()
+        }
 
-            let params = Default::default();
+        fn ncols(&self) -> usize {
+            self.factors.ncols()
+        }
+    }
+    impl<E: ComplexField> SolverCore<E> for Qr<E> {
+        fn reconstruct(&self) -> Mat<E> {
+            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
+            let parallelism = get_global_parallelism();
 
-            faer_evd::compute_evd_real(
-                matrix,
-                s_re.as_mut().as_2d_mut(),
-                s_im.as_mut().as_2d_mut(),
-                Some(u_real.as_mut()),
+            faer_qr::no_pivoting::reconstruct::reconstruct(
+                rec.as_mut(),
+                self.factors.as_ref(),
+                self.householder.as_ref(),
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_evd::compute_evd_req::<E::Real>(
-                        dim,
-                        faer_evd::ComputeVectors::Yes,
+                self.workspace.lock().stack(
+                    faer_qr::no_pivoting::reconstruct::reconstruct_req::<E>(
+                        self.nrows(),
+                        self.ncols(),
+                        self.blocksize(),
                         parallelism,
-                        params,
                     )
                     .unwrap(),
-                )),
-                params,
+                ),
             );
 
-            let imag = E::faer_from_f64(-1.0).faer_sqrt();
-            let cplx = |re: E::Real, im: E::Real| -> E {
-                E::faer_from_real(re).faer_add(imag.faer_mul(E::faer_from_real(im)))
-            };
+            rec
+        }
 
-            let s = Col::<E>::from_fn(dim, |i| cplx(s_re.read(i), s_im.read(i)));
-            let mut u = Mat::<E>::zeros(dim, dim);
-            let u_real = u_real.as_ref();
+        fn inverse(&self) -> Mat<E> {
+            assert!(self.nrows() == self.ncols());
 
-            let mut j = 0usize;
-            while j < dim {
-                if s_im.read(j) == E::Real::faer_zero() {
-                    zipped!(u.as_mut().col_mut(j).as_2d_mut(), u_real.col(j).as_2d()).for_each(
-                        |unzipped!(mut dst, src)| dst.write(E::faer_from_real(src.read())),
-                    );
-                    j += 1;
-                } else {
-                    let (u_left, u_right) = u.as_mut().split_at_col_mut(j + 1);
+            let mut inv = Mat::<E>::zeros(self.nrows(), self.ncols());
+            let parallelism = get_global_parallelism();
 
-                    zipped!(
-                        u_left.col_mut(j).as_2d_mut(),
-                        u_right.col_mut(0).as_2d_mut(),
-                        u_real.col(j).as_2d(),
-                        u_real.col(j + 1).as_2d(),
+            faer_qr::no_pivoting::inverse::invert(
+                inv.as_mut(),
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_qr::no_pivoting::inverse::invert_req::<E>(
+                        self.nrows(),
+                        self.ncols(),
+                        self.blocksize(),
+                        parallelism,
                     )
-                    .for_each(|unzipped!(mut dst, mut dst_conj, re, im)| {
-                        let re = re.read();
-                        let im = im.read();
-                        dst_conj.write(cplx(re, im.faer_neg()));
-                        dst.write(cplx(re, im));
-                    });
+                    .unwrap(),
+                ),
+            );
 
-                    j += 2;
-                }
-            }
+            inv
+        }
+    }
+
+    impl<E: ComplexField> SpSolverLstsqCore<E> for Qr<E> {
+        #[track_caller]
+        fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let rhs_ncols = rhs.ncols();
+
+            faer_qr::no_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                conj,
+                rhs,
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_qr::no_pivoting::solve::solve_in_place_req::<E>(
+                        self.nrows(),
+                        self.blocksize(),
+                        rhs_ncols,
+                    )
+                    .unwrap(),
+                ),
+            );
+        }
+    }
+    impl<E: ComplexField> SolverLstsqCore<E> for Qr<E> {}
+
+    impl<E: ComplexField> ColPivQr<E> {
+        #[track_caller]
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            let nrows = matrix.nrows();
+            let ncols = matrix.ncols();
 
-            Self { s, u }
+            let size = Ord::min(nrows, ncols);
+            let blocksize =
+                faer_qr::col_pivoting::compute::recommended_blocksize::<E>(nrows, ncols);
+
+            let mut this = Self {
+                factors: Mat::zeros(nrows, ncols),
+                householder: Mat::zeros(blocksize, size),
+                col_perm: vec![0usize; ncols],
+                col_perm_inv: vec![0usize; ncols],
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize(matrix, &mut workspace);
+            this.workspace = workspace;
+            this
         }
 
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
         #[track_caller]
-        pub(crate) fn __new_from_complex_impl((matrix, conj): (MatRef<'_, E>, Conj)) -> Self {
-            assert!(matrix.nrows() == matrix.ncols());
-            if coe::is_same::<E, E::Real>() {
-                panic!(
-                    "The type E ({}) must not be real-valued.",
-                    core::any::type_name::<E>(),
-                );
-            }
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            assert!(all(
+                matrix.nrows() == self.factors.nrows(),
+                matrix.ncols() == self.factors.ncols(),
+            ));
 
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize(matrix, &mut workspace);
+            self.workspace = workspace;
+        }
+
+        #[track_caller]
+        fn __factorize<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            workspace: &mut Workspace,
+        ) {
             let parallelism = get_global_parallelism();
-            let dim = matrix.nrows();
+            let nrows = self.factors.nrows();
+            let ncols = self.factors.ncols();
+            let blocksize = self.blocksize();
 
-            let mut s = Col::<E>::zeros(dim);
-            let mut u = Mat::<E>::zeros(dim, dim);
+            zipped!(self.factors.as_mut(), matrix)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
 
             let params = Default::default();
 
-            faer_evd::compute_evd_complex(
-                matrix,
-                s.as_mut().as_2d_mut(),
-                Some(u.as_mut()),
+            workspace.reserve(StackReq::any_of([
+                faer_qr::col_pivoting::solve::solve_in_place_req::<usize, E>(nrows, blocksize, 1)
+                    .unwrap(),
+                faer_qr::col_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                    nrows, blocksize, 1,
+                )
+                .unwrap(),
+            ]));
+
+            faer_qr::col_pivoting::compute::qr_in_place(
+                self.factors.as_mut(),
+                self.householder.as_mut(),
+                &mut self.col_perm,
+                &mut self.col_perm_inv,
                 parallelism,
-                PodStack::new(&mut GlobalPodBuffer::new(
-                    faer_evd::compute_evd_req::<E>(
-                        dim,
-                        faer_evd::ComputeVectors::Yes,
+                workspace.stack(
+                    faer_qr::col_pivoting::compute::qr_in_place_req::<usize, E>(
+                        nrows,
+                        ncols,
+                        blocksize,
                         parallelism,
                         params,
                     )
                     .unwrap(),
-                )),
+                ),
                 params,
             );
+        }
 
-            if matches!(conj, Conj::Yes) {
-                zipped!(s.as_mut().as_2d_mut())
-                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-                zipped!(u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
-            }
+        pub fn col_permutation(&self) -> PermutationRef<'_, usize, E> {
+            unsafe { PermutationRef::new_unchecked(&self.col_perm, &self.col_perm_inv) }
+        }
 
-            Self { s, u }
+        fn blocksize(&self) -> usize {
+            self.householder.nrows()
         }
 
-        #[track_caller]
-        pub fn new_from_complex<ViewE: Conjugate<Canonical = E>>(
-            matrix: MatRef<'_, ViewE>,
-        ) -> Self {
-            Self::__new_from_complex_impl(matrix.canonicalize())
+        pub fn compute_r(&self) -> Mat<E> {
+            let mut factor = self.factors.to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
         }
 
-        pub fn u(&self) -> MatRef<'_, E> {
-            self.u.as_ref()
+        pub fn compute_q(&self) -> Mat<E> {
+            Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), false)
         }
-        pub fn s_diagonal(&self) -> ColRef<'_, E> {
-            self.s.as_ref()
+
+        pub fn compute_thin_r(&self) -> Mat<E> {
+            let m = self.nrows();
+            let n = self.ncols();
+            let mut factor = self.factors.as_ref().subrows(0, Ord::min(m, n)).to_owned();
+            zipped!(factor.as_mut())
+                .for_each_triangular_lower(faer_core::zip::Diag::Skip, |unzipped!(mut dst)| {
+                    dst.write(E::faer_zero())
+                });
+            factor
+        }
+
+        pub fn compute_thin_q(&self) -> Mat<E> {
+            Qr::<E>::__compute_q_impl(self.factors.as_ref(), self.householder.as_ref(), true)
         }
     }
-}
+    impl<E: ComplexField> SolveWithStack<E> for ColPivQr<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            faer_qr::col_pivoting::solve::solve_in_place_req::<usize, E>(
+                self.nrows(),
+                self.blocksize(),
+                rhs_ncols,
+            )
+        }
 
-/// Extension trait for `faer` types.
-pub trait FaerMat<E: ComplexField> {
-    /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
-    /// stores the result in `rhs`.
-    fn solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-    /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
-    /// stores the result in `rhs`.
-    fn solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-    /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
-    /// and stores the result in `rhs`.
-    fn solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-    /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
-    /// and stores the result in `rhs`.
-    fn solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            faer_qr::col_pivoting::solve::solve_transpose_in_place_req::<usize, E>(
+                self.nrows(),
+                self.blocksize(),
+                rhs_ncols,
+            )
+        }
 
-    /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
-    /// returns the result.
-    #[track_caller]
-    fn solve_lower_triangular<ViewE: Conjugate<Canonical = E>>(
-        &self,
-        rhs: impl AsMatRef<ViewE>,
-    ) -> Mat<E> {
-        let mut rhs = rhs.as_mat_ref().to_owned();
-        self.solve_lower_triangular_in_place(rhs.as_mut());
-        rhs
-    }
-    /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
-    /// returns the result.
-    #[track_caller]
-    fn solve_upper_triangular<ViewE: Conjugate<Canonical = E>>(
-        &self,
-        rhs: impl AsMatRef<ViewE>,
-    ) -> Mat<E> {
-        let mut rhs = rhs.as_mat_ref().to_owned();
-        self.solve_upper_triangular_in_place(rhs.as_mut());
-        rhs
-    }
-    /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`, and
-    /// returns the result.
-    #[track_caller]
-    fn solve_unit_lower_triangular<ViewE: Conjugate<Canonical = E>>(
-        &self,
-        rhs: impl AsMatRef<ViewE>,
-    ) -> Mat<E> {
-        let mut rhs = rhs.as_mat_ref().to_owned();
-        self.solve_unit_lower_triangular_in_place(rhs.as_mut());
-        rhs
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            faer_qr::col_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                self.col_permutation(),
+                conj,
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            faer_qr::col_pivoting::solve::solve_transpose_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                self.col_permutation(),
+                conj,
+                rhs,
+                parallelism,
+                stack,
+            );
+        }
     }
-    /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`, and
-    /// returns the result.
-    #[track_caller]
-    fn solve_unit_upper_triangular<ViewE: Conjugate<Canonical = E>>(
-        &self,
-        rhs: impl AsMatRef<ViewE>,
-    ) -> Mat<E> {
-        let mut rhs = rhs.as_mat_ref().to_owned();
-        self.solve_unit_upper_triangular_in_place(rhs.as_mut());
-        rhs
+    impl<E: ComplexField> SpSolverCore<E> for ColPivQr<E> {
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            assert!(self.nrows() == self.ncols());
+            self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
+        }
+
+        #[track_caller]
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
+        }
+
+        fn nrows(&self) -> usize {
+            self.factors.nrows()
+        }
+
+        fn ncols(&self) -> usize {
+            self.factors.ncols()
+        }
     }
+    impl<E: ComplexField> SolverCore<E> for ColPivQr<E> {
+        fn reconstruct(&self) -> Mat<E> {
+            let mut rec = Mat::<E>::zeros(self.nrows(), self.ncols());
+            let parallelism = get_global_parallelism();
 
-    /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
-    fn cholesky(&self, side: Side) -> Result<Cholesky<E>, CholeskyError>;
-    /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
-    fn lblt(&self, side: Side) -> Lblt<E>;
-    /// Returns the LU decomposition of `self` with partial (row) pivoting.
-    fn partial_piv_lu(&self) -> PartialPivLu<E>;
-    /// Returns the LU decomposition of `self` with full pivoting.
-    fn full_piv_lu(&self) -> FullPivLu<E>;
-    /// Returns the QR decomposition of `self`.
-    fn qr(&self) -> Qr<E>;
-    /// Returns the QR decomposition of `self`, with column pivoting.
-    fn col_piv_qr(&self) -> ColPivQr<E>;
-    /// Returns the SVD of `self`.
-    fn svd(&self) -> Svd<E>;
-    /// Returns the thin SVD of `self`.
-    fn thin_svd(&self) -> ThinSvd<E>;
-    /// Returns the eigendecomposition of `self`, assuming it is self-adjoint. Only the provided
-    /// side is accessed.
-    fn selfadjoint_eigendecomposition(&self, side: Side) -> SelfAdjointEigendecomposition<E>;
-    /// Returns the eigendecomposition of `self`, as a complex matrix.
-    fn eigendecomposition<ComplexE: ComplexField<Real = E::Real>>(
-        &self,
-    ) -> Eigendecomposition<ComplexE>;
-    /// Returns the eigendecomposition of `self`, when `E` is in the complex domain.
-    fn complex_eigendecomposition(&self) -> Eigendecomposition<E>;
+            faer_qr::col_pivoting::reconstruct::reconstruct(
+                rec.as_mut(),
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                self.col_permutation(),
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_qr::col_pivoting::reconstruct::reconstruct_req::<usize, E>(
+                        self.nrows(),
+                        self.ncols(),
+                        self.blocksize(),
+                        parallelism,
+                    )
+                    .unwrap(),
+                ),
+            );
 
-    /// Returns the determinant of `self`.
-    fn determinant(&self) -> E;
-    /// Returns the singular values of `self`, in nonincreasing order.
-    fn singular_values(&self) -> Vec<E::Real>;
-    /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
-    /// side is accessed. The order of the eigenvalues is currently unspecified.
-    fn selfadjoint_eigenvalues(&self, side: Side) -> Vec<E::Real>;
-    /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
-    /// currently unspecified.
-    fn eigenvalues<ComplexE: ComplexField<Real = E::Real>>(&self) -> Vec<ComplexE>;
-    /// Returns the eigenvalues of `self`, when `E` is in the complex domain. The order of the
-    /// eigenvalues is currently unspecified.
-    fn complex_eigenvalues(&self) -> Vec<E::Canonical>;
-}
+            rec
+        }
 
-/// Sparse solvers and traits.
-pub mod sparse {
-    use super::*;
-    use faer_core::group_helpers::VecGroup;
+        fn inverse(&self) -> Mat<E> {
+            assert!(self.nrows() == self.ncols());
 
-    pub use faer_core::{
-        permutation::Index,
-        sparse::{
-            SparseColMatRef, SparseRowMatRef, SymbolicSparseColMatRef, SymbolicSparseRowMatRef,
-        },
-    };
-    pub use faer_sparse::{lu::LuError, FaerError};
+            let mut inv = Mat::<E>::zeros(self.nrows(), self.ncols());
+            let parallelism = get_global_parallelism();
 
-    /// Sparse Cholesky error.
-    #[derive(Copy, Clone, Debug)]
-    pub enum CholeskyError {
-        Generic(FaerError),
-        SymbolicSingular,
-        NotPositiveDefinite,
-    }
+            faer_qr::col_pivoting::inverse::invert(
+                inv.as_mut(),
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                self.col_permutation(),
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_qr::col_pivoting::inverse::invert_req::<usize, E>(
+                        self.nrows(),
+                        self.ncols(),
+                        self.blocksize(),
+                        parallelism,
+                    )
+                    .unwrap(),
+                ),
+            );
 
-    impl core::fmt::Display for CholeskyError {
-        #[inline]
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            core::fmt::Debug::fmt(self, f)
+            inv
         }
     }
 
-    #[cfg(feature = "std")]
-    impl std::error::Error for CholeskyError {}
+    impl<E: ComplexField> SpSolverLstsqCore<E> for ColPivQr<E> {
+        #[track_caller]
+        fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let rhs_ncols = rhs.ncols();
 
-    impl From<FaerError> for CholeskyError {
-        #[inline]
-        fn from(value: FaerError) -> Self {
-            Self::Generic(value)
+            faer_qr::col_pivoting::solve::solve_in_place(
+                self.factors.as_ref(),
+                self.householder.as_ref(),
+                self.col_permutation(),
+                conj,
+                rhs,
+                parallelism,
+                self.workspace.lock().stack(
+                    faer_qr::col_pivoting::solve::solve_in_place_req::<usize, E>(
+                        self.nrows(),
+                        self.blocksize(),
+                        rhs_ncols,
+                    )
+                    .unwrap(),
+                ),
+            );
         }
     }
+    impl<E: ComplexField> SolverLstsqCore<E> for ColPivQr<E> {}
 
-    impl From<crate::CholeskyError> for CholeskyError {
-        #[inline]
-        fn from(_: crate::CholeskyError) -> Self {
-            Self::NotPositiveDefinite
-        }
-    }
+    impl<E: ComplexField> Svd<E> {
+        #[track_caller]
+        fn __new_impl(
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            thin: bool,
+            params: SvdParams,
+        ) -> Self {
+            let m = matrix.nrows();
+            let n = matrix.ncols();
+            let size = Ord::min(m, n);
 
-    /// Sparse solvers.
-    ///
-    /// Each solver satisfies the [`SpSolver`] and/or [`SpSolverLstsq`] traits, which can be used
-    /// to solve linear systems.
-    pub mod solvers {
-        use super::*;
+            let mut this = Self {
+                s: Mat::zeros(size, 1),
+                u: Mat::zeros(m, if thin { size } else { m }),
+                v: Mat::zeros(n, if thin { size } else { n }),
+                converged: false,
+                params,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize((matrix, conj), thin, &mut workspace);
+            this.workspace = workspace;
+            this
+        }
 
-        /// Object-safe base for [`SpSolver`]
-        pub trait SpSolverCore<E: Entity> {
-            /// Returns the number of rows of the matrix used to construct this decomposition.
-            fn nrows(&self) -> usize;
-            /// Returns the number of columns of the matrix used to construct this decomposition.
-            fn ncols(&self) -> usize;
+        #[track_caller]
+        fn __refactor_impl(&mut self, (matrix, conj): (MatRef<'_, E>, Conj), thin: bool) {
+            assert!(all(
+                matrix.nrows() == self.u.nrows(),
+                matrix.ncols() == self.v.nrows(),
+            ));
 
-            #[doc(hidden)]
-            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
-            #[doc(hidden)]
-            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize((matrix, conj), thin, &mut workspace);
+            self.workspace = workspace;
         }
 
-        pub trait SpSolverLstsqCore<E: Entity>: SpSolverCore<E> {
-            #[doc(hidden)]
-            fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
-        }
+        #[track_caller]
+        fn __factorize(
+            &mut self,
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            thin: bool,
+            workspace: &mut Workspace,
+        ) {
+            let parallelism = get_global_parallelism();
+            let m = matrix.nrows();
+            let n = matrix.ncols();
 
-        pub trait SpSolver<E: ComplexField>: SpSolverCore<E> {
-            /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
-            fn solve_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `conjugate(self) * X = rhs`, and stores the result in `rhs`.
-            fn solve_conj_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `transpose(self) * X = rhs`, and stores the result in `rhs`.
-            fn solve_transpose_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `adjoint(self) * X = rhs`, and stores the result in `rhs`.
-            fn solve_conj_transpose_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `self * X = rhs`, and returns the result.
-            fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: impl AsMatRef<ViewE>) -> Mat<E>;
-            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result.
-            fn solve_conj<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E>;
-            /// Solves the equation `transpose(self) * X = rhs`, and returns the result.
-            fn solve_transpose<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E>;
-            /// Solves the equation `adjoint(self) * X = rhs`, and returns the result.
-            fn solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E>;
-        }
+            let params = self.params;
 
-        pub trait SpSolverLstsq<E: Entity>: SpSolverLstsqCore<E> {
-            /// Solves the equation `self * X = rhs`, in the sense of least squares, and stores the
-            /// result in the top rows of `rhs`.
-            fn solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `conjugate(self) * X = rhs`, in the sense of least squares, and
-            /// stores the result in the top rows of `rhs`.
-            fn solve_lstsq_conj_in_place(&self, rhs: impl AsMatMut<E>);
-            /// Solves the equation `self * X = rhs`, and returns the result.
-            fn solve_lstsq<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E>;
-            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result.
-            fn solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E>;
+            let compute_vecs = if thin {
+                faer_svd::ComputeVectors::Thin
+            } else {
+                faer_svd::ComputeVectors::Full
+            };
+
+            let info = faer_svd::compute_svd(
+                matrix,
+                self.s.as_mut(),
+                Some(self.u.as_mut()),
+                Some(self.v.as_mut()),
+                parallelism,
+                workspace.stack(
+                    faer_svd::compute_svd_req::<E>(
+                        m,
+                        n,
+                        compute_vecs,
+                        compute_vecs,
+                        parallelism,
+                        params,
+                    )
+                    .unwrap(),
+                ),
+                params,
+            );
+
+            if matches!(conj, Conj::Yes) {
+                zipped!(self.u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+                zipped!(self.v.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+            }
+
+            self.converged = info.converged;
         }
 
         #[track_caller]
-        fn solve_with_conj_impl<
-            E: ComplexField,
-            D: ?Sized + SpSolverCore<E>,
-            ViewE: Conjugate<Canonical = E>,
-        >(
-            d: &D,
-            rhs: MatRef<'_, ViewE>,
-            conj: Conj,
-        ) -> Mat<E> {
-            let mut rhs = rhs.to_owned();
-            d.solve_in_place_with_conj_impl(rhs.as_mut(), conj);
-            rhs
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            Self::__new_impl(matrix.canonicalize(), false, Default::default())
         }
 
+        /// Computes the decomposition of `matrix` with the given parameters, which are also used
+        /// by subsequent calls to [`Self::refactor`].
+        ///
+        /// If the iteration limit set by `params.max_iterations` is reached, the decomposition is
+        /// not accurate, which is reported by [`Self::converged`].
         #[track_caller]
-        fn solve_transpose_with_conj_impl<
-            E: ComplexField,
-            D: ?Sized + SpSolverCore<E>,
-            ViewE: Conjugate<Canonical = E>,
-        >(
-            d: &D,
-            rhs: MatRef<'_, ViewE>,
-            conj: Conj,
-        ) -> Mat<E> {
-            let mut rhs = rhs.to_owned();
-            d.solve_transpose_in_place_with_conj_impl(rhs.as_mut(), conj);
-            rhs
+        pub fn new_with_params<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            params: SvdParams,
+        ) -> Self {
+            Self::__new_impl(matrix.canonicalize(), false, params)
         }
 
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
         #[track_caller]
-        fn solve_lstsq_with_conj_impl<
-            E: ComplexField,
-            D: ?Sized + SpSolverLstsqCore<E>,
-            ViewE: Conjugate<Canonical = E>,
-        >(
-            d: &D,
-            rhs: MatRef<'_, ViewE>,
-            conj: Conj,
-        ) -> Mat<E> {
-            let mut rhs = rhs.to_owned();
-            let k = rhs.ncols();
-            d.solve_lstsq_in_place_with_conj_impl(rhs.as_mut(), conj);
-            rhs.resize_with(d.ncols(), k, |_, _| unreachable!());
-            rhs
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            self.__refactor_impl(matrix.canonicalize(), false)
         }
 
-        impl<E: ComplexField, Dec: ?Sized + SpSolverCore<E>> SpSolver<E> for Dec {
-            #[track_caller]
-            fn solve_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
-            }
-
-            #[track_caller]
-            fn solve_conj_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
-            }
-
-            #[track_caller]
-            fn solve_transpose_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_transpose_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
-            }
-
-            #[track_caller]
-            fn solve_conj_transpose_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_transpose_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
-            }
-
-            #[track_caller]
-            fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: impl AsMatRef<ViewE>) -> Mat<E> {
-                solve_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
-            }
-
-            #[track_caller]
-            fn solve_conj<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E> {
-                solve_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
-            }
-
-            #[track_caller]
-            fn solve_transpose<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E> {
-                solve_transpose_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
-            }
-
-            #[track_caller]
-            fn solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E> {
-                solve_transpose_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
-            }
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> MatRef<'_, E> {
+            self.s.as_ref()
+        }
+        pub fn v(&self) -> MatRef<'_, E> {
+            self.v.as_ref()
+        }
+        /// Returns `true` if the iterative algorithm converged within its iteration limit. If it
+        /// did not, the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
         }
 
-        impl<E: ComplexField, Dec: ?Sized + SpSolverLstsqCore<E>> SpSolverLstsq<E> for Dec {
-            #[track_caller]
-            fn solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_lstsq_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
-            }
-
-            #[track_caller]
-            fn solve_lstsq_conj_in_place(&self, rhs: impl AsMatMut<E>) {
-                let mut rhs = rhs;
-                self.solve_lstsq_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
+        /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`. See
+        /// [`PartialPivLu::log_determinant`].
+        ///
+        /// The logarithm of the absolute value is the sum of the logarithms of the singular values,
+        /// while the phase is that of $\det(U) \overline{\det(V)}$, which is computed by factorizing
+        /// $U$ and $V$.
+        ///
+        /// # Panics
+        /// Panics if the matrix is not square.
+        #[track_caller]
+        pub fn log_determinant(&self) -> (E, E::Real) {
+            assert!(self.nrows() == self.ncols());
+            let mut det = LogDeterminant::<E>::new();
+            for i in 0..self.s.nrows() {
+                det.mul(self.s.read(i, 0));
             }
+            let (phase, log_abs) = det.finish();
+            let (phase_u, _) = PartialPivLu::<E>::new(self.u.as_ref()).log_determinant();
+            let (phase_v, _) = PartialPivLu::<E>::new(self.v.as_ref()).log_determinant();
+            (
+                phase.faer_mul(phase_u).faer_mul(phase_v.faer_conj()),
+                log_abs,
+            )
+        }
+    }
+    fn div_by_s<E: ComplexField>(rhs: MatMut<'_, E>, s: MatRef<'_, E>) {
+        let mut rhs = rhs;
+        for j in 0..rhs.ncols() {
+            zipped!(rhs.rb_mut().col_mut(j).as_2d_mut(), s).for_each(|unzipped!(mut rhs, s)| {
+                rhs.write(rhs.read().faer_scale_real(s.read().faer_real().faer_inv()))
+            });
+        }
+    }
 
-            #[track_caller]
-            fn solve_lstsq<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E> {
-                solve_lstsq_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
-            }
+    fn unitary_solve_in_place_req<E: Entity>(
+        dim: usize,
+        rhs_ncols: usize,
+    ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+        faer_core::temp_mat_req::<E>(dim, rhs_ncols)
+    }
 
-            #[track_caller]
-            fn solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
-                &self,
-                rhs: impl AsMatRef<ViewE>,
-            ) -> Mat<E> {
-                solve_lstsq_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
-            }
+    /// Solves the equation `Op(U S V^H) * X = rhs` in place, where `U` and `V` are unitary, `S` is
+    /// real and diagonal, and `Op` is either the identity or the conjugation depending on `conj`.
+    fn unitary_solve_in_place<E: ComplexField>(
+        u: MatRef<'_, E>,
+        s: MatRef<'_, E>,
+        v: MatRef<'_, E>,
+        conj: Conj,
+        rhs: MatMut<'_, E>,
+        parallelism: Parallelism,
+        stack: PodStack<'_>,
+    ) {
+        let mut rhs = rhs;
+        let (mut tmp, _) = faer_core::temp_mat_uninit::<E>(u.ncols(), rhs.ncols(), stack);
+        let mut tmp = tmp.as_mut();
+
+        faer_core::mul::matmul_with_conj(
+            tmp.rb_mut(),
+            u.transpose(),
+            conj.compose(Conj::Yes),
+            rhs.rb(),
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+        div_by_s(tmp.rb_mut(), s);
+        faer_core::mul::matmul_with_conj(
+            rhs.rb_mut(),
+            v,
+            conj,
+            tmp.rb(),
+            Conj::No,
+            None,
+            E::faer_one(),
+            parallelism,
+        );
+    }
+    impl<E: ComplexField> SolveWithStack<E> for Svd<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            unitary_solve_in_place_req::<E>(self.s.nrows(), rhs_ncols)
         }
 
-        #[derive(Debug)]
-        pub struct SymbolicCholesky<I> {
-            inner: alloc::sync::Arc<faer_sparse::cholesky::SymbolicCholesky<I>>,
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
         }
-        #[derive(Clone, Debug)]
-        pub struct Cholesky<I, E: Entity> {
-            symbolic: SymbolicCholesky<I>,
-            values: VecGroup<E>,
+
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            unitary_solve_in_place(
+                self.u.as_ref(),
+                self.s.as_ref(),
+                self.v.as_ref(),
+                conj,
+                rhs,
+                parallelism,
+                stack,
+            );
         }
 
-        #[derive(Debug)]
-        pub struct SymbolicQr<I> {
-            inner: alloc::sync::Arc<faer_sparse::qr::SymbolicQr<I>>,
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            assert!(self.nrows() == self.ncols());
+            // A^T = conj(V) S conj(U)^H
+            unitary_solve_in_place(
+                self.v.as_ref(),
+                self.s.as_ref(),
+                self.u.as_ref(),
+                conj.compose(Conj::Yes),
+                rhs,
+                parallelism,
+                stack,
+            );
         }
-        #[derive(Clone, Debug)]
-        pub struct Qr<I, E: Entity> {
-            symbolic: SymbolicQr<I>,
-            indices: alloc::vec::Vec<I>,
-            values: VecGroup<E>,
+    }
+    impl<E: ComplexField> SpSolverCore<E> for Svd<E> {
+        fn nrows(&self) -> usize {
+            self.u.nrows()
         }
 
-        #[derive(Debug)]
-        pub struct SymbolicLu<I> {
-            inner: alloc::sync::Arc<faer_sparse::lu::SymbolicLu<I>>,
+        fn ncols(&self) -> usize {
+            self.v.nrows()
         }
-        #[derive(Clone, Debug)]
-        pub struct Lu<I, E: Entity> {
-            symbolic: SymbolicLu<I>,
-            numeric: faer_sparse::lu::NumericLu<I, E>,
+
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
 
-        impl<I> Clone for SymbolicCholesky<I> {
-            #[inline]
-            fn clone(&self) -> Self {
-                Self {
-                    inner: self.inner.clone(),
-                }
-            }
-        }
-        impl<I> Clone for SymbolicQr<I> {
-            #[inline]
-            fn clone(&self) -> Self {
-                Self {
-                    inner: self.inner.clone(),
-                }
-            }
+        #[track_caller]
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
+            );
         }
-        impl<I> Clone for SymbolicLu<I> {
-            #[inline]
-            fn clone(&self) -> Self {
-                Self {
-                    inner: self.inner.clone(),
-                }
-            }
+    }
+    impl<E: ComplexField> SolverCore<E> for Svd<E> {
+        fn reconstruct(&self) -> Mat<E> {
+            let m = self.nrows();
+            let n = self.ncols();
+            let size = Ord::min(m, n);
+
+            let thin_u = self.u.as_ref().submatrix(0, 0, m, size);
+            let s = self.s.as_ref();
+            let us = Mat::<E>::from_fn(m, size, |i, j| thin_u.read(i, j).faer_mul(s.read(j, 0)));
+
+            us * self.v.adjoint()
         }
 
-        impl<I: Index> SymbolicCholesky<I> {
-            #[track_caller]
-            pub fn try_new(
-                mat: SymbolicSparseColMatRef<'_, I>,
-                side: Side,
-            ) -> Result<Self, FaerError> {
-                Ok(Self {
-                    inner: alloc::sync::Arc::new(
-                        faer_sparse::cholesky::factorize_symbolic_cholesky(
-                            mat,
-                            side,
-                            Default::default(),
-                        )?,
-                    ),
-                })
-            }
+        fn inverse(&self) -> Mat<E> {
+            assert!(self.nrows() == self.ncols());
+            let dim = self.nrows();
+
+            let u = self.u.as_ref();
+            let v = self.v.as_ref();
+            let s = self.s.as_ref();
+
+            let vs_inv = Mat::<E>::from_fn(dim, dim, |i, j| {
+                v.read(i, j).faer_mul(s.read(j, 0).faer_inv())
+            });
+
+            vs_inv * u.adjoint()
         }
-        impl<I: Index> SymbolicQr<I> {
-            #[track_caller]
-            pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
-                Ok(Self {
-                    inner: alloc::sync::Arc::new(faer_sparse::qr::factorize_symbolic_qr(
-                        mat,
-                        Default::default(),
-                    )?),
-                })
+    }
+
+    impl<E: ComplexField> ThinSvd<E> {
+        #[track_caller]
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            Self {
+                inner: Svd::__new_impl(matrix.canonicalize(), true, Default::default()),
             }
         }
-        impl<I: Index> SymbolicLu<I> {
-            #[track_caller]
-            pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
-                Ok(Self {
-                    inner: alloc::sync::Arc::new(faer_sparse::lu::factorize_symbolic_lu(
-                        mat,
-                        Default::default(),
-                    )?),
-                })
+
+        /// Computes the decomposition of `matrix` with the given parameters, which are also used
+        /// by subsequent calls to [`Self::refactor`].
+        ///
+        /// If the iteration limit set by `params.max_iterations` is reached, the decomposition is
+        /// not accurate, which is reported by [`Self::converged`].
+        #[track_caller]
+        pub fn new_with_params<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            params: SvdParams,
+        ) -> Self {
+            Self {
+                inner: Svd::__new_impl(matrix.canonicalize(), true, params),
             }
         }
 
-        impl<I: Index, E: ComplexField> Cholesky<I, E> {
-            #[track_caller]
-            pub fn try_new_with_symbolic(
-                symbolic: SymbolicCholesky<I>,
-                mat: SparseColMatRef<'_, I, E>,
-                side: Side,
-            ) -> Result<Self, sparse::CholeskyError> {
-                let len_values = symbolic.inner.len_values();
-                let mut values = VecGroup::new();
-                values
-                    .try_reserve_exact(len_values)
-                    .map_err(|_| FaerError::OutOfMemory)?;
-                values.resize(len_values, E::faer_zero().faer_into_units());
-                let parallelism = get_global_parallelism();
-                symbolic.inner.factorize_numeric_llt::<E>(
-                    values.as_slice_mut().into_inner(),
-                    mat,
-                    side,
-                    Default::default(),
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        symbolic
-                            .inner
-                            .factorize_numeric_llt_req::<E>(parallelism)
-                            .map_err(|_| FaerError::OutOfMemory)?,
-                    )),
-                )?;
-                Ok(Self { symbolic, values })
-            }
+        /// Recomputes the decomposition for `matrix`, which must have the same dimensions as the
+        /// previously decomposed matrix, reusing the storage and workspace of `self`.
+        #[track_caller]
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(&mut self, matrix: MatRef<'_, ViewE>) {
+            self.inner.__refactor_impl(matrix.canonicalize(), true)
         }
 
-        impl<I: Index, E: ComplexField> Qr<I, E> {
-            #[track_caller]
-            pub fn try_new_with_symbolic(
-                symbolic: SymbolicQr<I>,
-                mat: SparseColMatRef<'_, I, E>,
-            ) -> Result<Self, FaerError> {
-                let len_values = symbolic.inner.len_values();
-                let len_indices = symbolic.inner.len_indices();
-                let mut values = VecGroup::new();
-                let mut indices = alloc::vec::Vec::new();
-                values
-                    .try_reserve_exact(len_values)
-                    .map_err(|_| FaerError::OutOfMemory)?;
-                indices
-                    .try_reserve_exact(len_indices)
-                    .map_err(|_| FaerError::OutOfMemory)?;
-                values.resize(len_values, E::faer_zero().faer_into_units());
-                indices.resize(len_indices, I::truncate(0));
-                let parallelism = get_global_parallelism();
-                symbolic.inner.factorize_numeric_qr::<E>(
-                    &mut indices,
-                    values.as_slice_mut().into_inner(),
-                    mat,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        symbolic
-                            .inner
-                            .factorize_numeric_qr_req::<E>(parallelism)
-                            .map_err(|_| FaerError::OutOfMemory)?,
-                    )),
-                );
-                Ok(Self {
-                    symbolic,
-                    indices,
-                    values,
-                })
-            }
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.inner.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> MatRef<'_, E> {
+            self.inner.s.as_ref()
+        }
+        pub fn v(&self) -> MatRef<'_, E> {
+            self.inner.v.as_ref()
+        }
+        /// Returns `true` if the iterative algorithm converged within its iteration limit. If it
+        /// did not, the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.inner.converged
+        }
+    }
+    impl<E: ComplexField> SolveWithStack<E> for ThinSvd<E> {
+        fn solve_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.inner.solve_in_place_req(rhs_ncols, parallelism)
         }
 
-        impl<I: Index, E: ComplexField> Lu<I, E> {
-            #[track_caller]
-            pub fn try_new_with_symbolic(
-                symbolic: SymbolicLu<I>,
-                mat: SparseColMatRef<'_, I, E>,
-            ) -> Result<Self, LuError> {
-                let mut numeric = faer_sparse::lu::NumericLu::new();
-                let parallelism = get_global_parallelism();
-                symbolic.inner.factorize_numeric_lu::<E>(
-                    &mut numeric,
-                    mat,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        symbolic
-                            .inner
-                            .factorize_numeric_lu_req::<E>(parallelism)
-                            .map_err(|_| FaerError::OutOfMemory)?,
-                    )),
-                )?;
-                Ok(Self { symbolic, numeric })
-            }
+        fn solve_transpose_in_place_req(
+            &self,
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.inner
+                .solve_transpose_in_place_req(rhs_ncols, parallelism)
         }
 
-        impl<I: Index, E: ComplexField> SpSolverCore<E> for Cholesky<I, E> {
-            #[inline]
-            fn nrows(&self) -> usize {
-                self.symbolic.inner.nrows()
-            }
-            #[inline]
-            fn ncols(&self) -> usize {
-                self.symbolic.inner.ncols()
-            }
+        #[track_caller]
+        fn solve_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.inner
+                .solve_in_place_with_stack(rhs, conj, parallelism, stack)
+        }
 
-            #[track_caller]
-            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let parallelism = get_global_parallelism();
-                let rhs_ncols = rhs.ncols();
-                faer_sparse::cholesky::LltRef::<'_, I, E>::new(
-                    &self.symbolic.inner,
-                    self.values.as_slice().into_inner(),
-                )
-                .solve_in_place_with_conj(
-                    conj,
-                    rhs,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        self.symbolic
-                            .inner
-                            .solve_in_place_req::<E>(rhs_ncols)
-                            .unwrap(),
-                    )),
-                );
-            }
+        #[track_caller]
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            self.inner
+                .solve_transpose_in_place_with_stack(rhs, conj, parallelism, stack)
+        }
+    }
+    impl<E: ComplexField> SpSolverCore<E> for ThinSvd<E> {
+        fn nrows(&self) -> usize {
+            self.inner.nrows()
+        }
 
-            #[track_caller]
-            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let parallelism = get_global_parallelism();
-                let rhs_ncols = rhs.ncols();
-                faer_sparse::cholesky::LltRef::<'_, I, E>::new(
-                    &self.symbolic.inner,
-                    self.values.as_slice().into_inner(),
-                )
-                .solve_in_place_with_conj(
-                    conj.compose(Conj::Yes),
-                    rhs,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        self.symbolic
-                            .inner
-                            .solve_in_place_req::<E>(rhs_ncols)
-                            .unwrap(),
-                    )),
-                );
-            }
+        fn ncols(&self) -> usize {
+            self.inner.ncols()
         }
 
-        impl<I: Index, E: ComplexField> SpSolverCore<E> for Qr<I, E> {
-            #[inline]
-            fn nrows(&self) -> usize {
-                self.symbolic.inner.nrows()
-            }
-            #[inline]
-            fn ncols(&self) -> usize {
-                self.symbolic.inner.ncols()
-            }
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            self.inner.solve_in_place_with_conj_impl(rhs, conj)
+        }
 
-            #[track_caller]
-            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
-            }
+        #[track_caller]
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            self.inner
+                .solve_transpose_in_place_with_conj_impl(rhs, conj)
+        }
+    }
+    impl<E: ComplexField> SolverCore<E> for ThinSvd<E> {
+        fn reconstruct(&self) -> Mat<E> {
+            self.inner.reconstruct()
+        }
 
-            #[track_caller]
-            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let _ = (&rhs, &conj);
-                unimplemented!(
-                    "the sparse QR decomposition doesn't support solve_transpose.\n\
-                               consider using the sparse LU or Cholesky instead."
-                )
-            }
+        fn inverse(&self) -> Mat<E> {
+            self.inner.inverse()
         }
+    }
 
-        impl<I: Index, E: ComplexField> SpSolverLstsqCore<E> for Qr<I, E> {
-            #[track_caller]
-            fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let parallelism = get_global_parallelism();
-                let rhs_ncols = rhs.ncols();
-                unsafe {
-                    faer_sparse::qr::QrRef::<'_, I, E>::new_unchecked(
-                        &self.symbolic.inner,
-                        &self.indices,
-                        self.values.as_slice().into_inner(),
-                    )
-                }
-                .solve_in_place_with_conj(
-                    conj,
-                    rhs,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        self.symbolic
-                            .inner
-                            .solve_in_place_req::<E>(rhs_ncols, parallelism)
-                            .unwrap(),
-                    )),
-                );
-            }
+    impl<E: ComplexField> SelfAdjointEigendecomposition<E> {
+        #[track_caller]
+        fn __new_impl(
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            side: Side,
+            params: SymmetricEvdParams,
+        ) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
+
+            let dim = matrix.nrows();
+            let mut this = Self {
+                s: Mat::zeros(dim, 1),
+                u: Mat::zeros(dim, dim),
+                converged: false,
+                params,
+                workspace: Workspace::default(),
+            };
+            let mut workspace = core::mem::take(&mut this.workspace);
+            this.__factorize((matrix, conj), side, &mut workspace);
+            this.workspace = workspace;
+            this
         }
 
-        impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
-            #[inline]
-            fn nrows(&self) -> usize {
-                self.symbolic.inner.nrows()
-            }
-            #[inline]
-            fn ncols(&self) -> usize {
-                self.symbolic.inner.ncols()
-            }
+        #[track_caller]
+        fn __refactor_impl(&mut self, (matrix, conj): (MatRef<'_, E>, Conj), side: Side) {
+            let dim = self.u.nrows();
+            assert!(all(matrix.nrows() == dim, matrix.ncols() == dim));
 
-            #[track_caller]
-            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let parallelism = get_global_parallelism();
-                let rhs_ncols = rhs.ncols();
-                unsafe {
-                    faer_sparse::lu::LuRef::<'_, I, E>::new_unchecked(
-                        &self.symbolic.inner,
-                        &self.numeric,
-                    )
-                }
-                .solve_in_place_with_conj(
-                    conj,
-                    rhs,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        self.symbolic
-                            .inner
-                            .solve_in_place_req::<E>(rhs_ncols, parallelism)
-                            .unwrap(),
-                    )),
-                );
-            }
+            let mut workspace = core::mem::take(&mut self.workspace);
+            self.__factorize((matrix, conj), side, &mut workspace);
+            self.workspace = workspace;
+        }
 
-            #[track_caller]
-            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
-                let parallelism = get_global_parallelism();
-                let rhs_ncols = rhs.ncols();
-                unsafe {
-                    faer_sparse::lu::LuRef::<'_, I, E>::new_unchecked(
-                        &self.symbolic.inner,
-                        &self.numeric,
+        #[track_caller]
+        fn __factorize(
+            &mut self,
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            side: Side,
+            workspace: &mut Workspace,
+        ) {
+            let parallelism = get_global_parallelism();
+
+            let dim = matrix.nrows();
+
+            let matrix = match side {
+                Side::Lower => matrix,
+                Side::Upper => matrix.transpose(),
+            };
+            let conj = conj.compose(match side {
+                Side::Lower => Conj::No,
+                Side::Upper => Conj::Yes,
+            });
+
+            let params = self.params;
+            let info = faer_evd::compute_hermitian_evd(
+                matrix,
+                self.s.as_mut(),
+                Some(self.u.as_mut()),
+                parallelism,
+                workspace.stack(
+                    faer_evd::compute_hermitian_evd_req::<E>(
+                        dim,
+                        faer_evd::ComputeVectors::Yes,
+                        parallelism,
+                        params,
                     )
-                }
-                .solve_transpose_in_place_with_conj(
-                    conj,
-                    rhs,
-                    parallelism,
-                    PodStack::new(&mut GlobalPodBuffer::new(
-                        self.symbolic
-                            .inner
-                            .solve_in_place_req::<E>(rhs_ncols, parallelism)
-                            .unwrap(),
-                    )),
-                );
+                    .unwrap(),
+                ),
+                params,
+            );
+
+            if matches!(conj, Conj::Yes) {
+                zipped!(self.u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
             }
+
+            self.converged = info.converged;
         }
-    }
 
-    /// Extension trait for sparse `faer` types.
-    pub trait FaerSparseMat<I: Index, E: ComplexField> {
-        /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
-        /// stores the result in `rhs`.
-        ///
-        /// The diagonal element is assumed to be the first stored element in each column, if the
-        /// matrix is column-major, or the last stored element in each row, if it is row-major.
-        fn sp_solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-        /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
-        /// stores the result in `rhs`.
-        ///
-        /// The diagonal element is assumed to be the last stored element in each column, if the
-        /// matrix is column-major, or the first stored element in each row, if it is row-major.
-        fn sp_solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-        /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
-        /// and stores the result in `rhs`.
-        ///
-        /// The diagonal element is assumed to be the first stored element in each column, if the
-        /// matrix is column-major, or the last stored element in each row, if it is row-major.
-        fn sp_solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
-        /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
-        /// and stores the result in `rhs`.
-        ///
-        /// The diagonal element is assumed to be the last stored element in each column, if the
-        /// matrix is column-major, or the first stored element in each row, if it is row-major.
-        fn sp_solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+        #[track_caller]
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>, side: Side) -> Self {
+            Self::__new_impl(matrix.canonicalize(), side, Default::default())
+        }
 
-        /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
-        /// returns the result.
+        /// Computes the decomposition of `matrix` with the given parameters, which are also used
+        /// by subsequent calls to [`Self::refactor`]. Only the provided side is accessed.
         ///
-        /// The diagonal element is assumed to be the first stored element in each column, if the
-        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        /// If the iteration limit set by `params.max_iterations` is reached, the decomposition is
+        /// not accurate, which is reported by [`Self::converged`].
         #[track_caller]
-        fn sp_solve_lower_triangular<ViewE: Conjugate<Canonical = E>>(
-            &self,
-            rhs: impl AsMatRef<ViewE>,
-        ) -> Mat<E> {
-            let mut rhs = rhs.as_mat_ref().to_owned();
-            self.sp_solve_lower_triangular_in_place(rhs.as_mut());
-            rhs
+        pub fn new_with_params<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+            params: SymmetricEvdParams,
+        ) -> Self {
+            Self::__new_impl(matrix.canonicalize(), side, params)
         }
-        /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
-        /// returns the result.
-        ///
-        /// The diagonal element is assumed to be the last stored element in each column, if the
-        /// matrix is column-major, or the first stored element in each row, if it is row-major.
+
+        /// Recomputes the full decomposition for `matrix`, which must have the same dimensions as
+        /// the previously decomposed matrix, reusing the storage and workspace of `self`.
         #[track_caller]
-        fn sp_solve_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+        pub fn refactor<ViewE: Conjugate<Canonical = E>>(
+            &mut self,
+            matrix: MatRef<'_, ViewE>,
+            side: Side,
+        ) {
+            self.__refactor_impl(matrix.canonicalize(), side)
+        }
+
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> MatRef<'_, E> {
+            self.s.as_ref()
+        }
+        /// Returns `true` if the iterative algorithm converged within its iteration limit. If it
+        /// did not, the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
+        }
+    }
+    impl<E: ComplexField> SolveWithStack<E> for SelfAdjointEigendecomposition<E> {
+        fn solve_in_place_req(
             &self,
-            rhs: impl AsMatRef<ViewE>,
-        ) -> Mat<E> {
-            let mut rhs = rhs.as_mat_ref().to_owned();
-            self.sp_solve_upper_triangular_in_place(rhs.as_mut());
-            rhs
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            let _ = parallelism;
+            unitary_solve_in_place_req::<E>(self.s.nrows(), rhs_ncols)
         }
-        /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
-        /// and returns the result.
-        ///
-        /// The diagonal element is assumed to be the first stored element in each column, if the
-        /// matrix is column-major, or the last stored element in each row, if it is row-major.
-        #[track_caller]
-        fn sp_solve_unit_lower_triangular<ViewE: Conjugate<Canonical = E>>(
+
+        fn solve_transpose_in_place_req(
             &self,
-            rhs: impl AsMatRef<ViewE>,
-        ) -> Mat<E> {
-            let mut rhs = rhs.as_mat_ref().to_owned();
-            self.sp_solve_unit_lower_triangular_in_place(rhs.as_mut());
-            rhs
+            rhs_ncols: usize,
+            parallelism: Parallelism,
+        ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+            self.solve_in_place_req(rhs_ncols, parallelism)
         }
-        /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
-        /// and returns the result.
-        ///
-        /// The diagonal element is assumed to be the first stored element in each column, if the
-        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+
         #[track_caller]
-        fn sp_solve_unit_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+        fn solve_in_place_with_stack(
             &self,
-            rhs: impl AsMatRef<ViewE>,
-        ) -> Mat<E> {
-            let mut rhs = rhs.as_mat_ref().to_owned();
-            self.sp_solve_unit_upper_triangular_in_place(rhs.as_mut());
-            rhs
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            let u = self.u.as_ref();
+            unitary_solve_in_place(u, self.s.as_ref(), u, conj, rhs, parallelism, stack);
         }
 
-        /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
-        fn sp_cholesky(&self, side: Side)
-            -> Result<solvers::Cholesky<I, E>, sparse::CholeskyError>;
-
-        /// Returns the LU decomposition of `self` with partial (row) pivoting.
-        fn sp_lu(&self) -> Result<solvers::Lu<I, E>, LuError>;
-
-        /// Returns the QR decomposition of `self`.
-        fn sp_qr(&self) -> Result<solvers::Qr<I, E>, FaerError>;
-    }
-
-    impl<I: Index, E: ComplexField> FaerSparseMat<I, E> for SparseColMatRef<'_, I, E> {
         #[track_caller]
-        fn sp_solve_lower_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
-            faer_sparse::triangular_solve::solve_lower_triangular_in_place(
-                *self,
-                Conj::No,
-                rhs.as_mat_mut(),
-                get_global_parallelism(),
+        fn solve_transpose_in_place_with_stack(
+            &self,
+            rhs: MatMut<'_, E>,
+            conj: Conj,
+            parallelism: Parallelism,
+            stack: PodStack<'_>,
+        ) {
+            let u = self.u.as_ref();
+            unitary_solve_in_place(
+                u,
+                self.s.as_ref(),
+                u,
+                conj.compose(Conj::Yes),
+                rhs,
+                parallelism,
+                stack,
             );
         }
-        #[track_caller]
-        fn sp_solve_upper_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
-            faer_sparse::triangular_solve::solve_upper_triangular_in_place(
-                *self,
-                Conj::No,
-                rhs.as_mat_mut(),
-                get_global_parallelism(),
+    }
+    impl<E: ComplexField> SpSolverCore<E> for SelfAdjointEigendecomposition<E> {
+        fn nrows(&self) -> usize {
+            self.u.nrows()
+        }
+
+        fn ncols(&self) -> usize {
+            self.u.nrows()
+        }
+
+        #[track_caller]
+        fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+            self.solve_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
             );
         }
+
         #[track_caller]
-        fn sp_solve_unit_lower_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
-            faer_sparse::triangular_solve::solve_unit_lower_triangular_in_place(
-                *self,
-                Conj::No,
-                rhs.as_mat_mut(),
-                get_global_parallelism(),
+        fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+            let parallelism = get_global_parallelism();
+            let req = self
+                .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                .unwrap();
+            self.solve_transpose_in_place_with_stack(
+                rhs,
+                conj,
+                parallelism,
+                self.workspace.lock().stack(req),
             );
         }
+    }
+    impl<E: ComplexField> SolverCore<E> for SelfAdjointEigendecomposition<E> {
+        fn reconstruct(&self) -> Mat<E> {
+            let size = self.nrows();
+
+            let u = self.u.as_ref();
+            let s = self.s.as_ref();
+            let us = Mat::<E>::from_fn(size, size, |i, j| u.read(i, j).faer_mul(s.read(j, 0)));
+
+            us * u.adjoint()
+        }
+
+        fn inverse(&self) -> Mat<E> {
+            let dim = self.nrows();
+
+            let u = self.u.as_ref();
+            let s = self.s.as_ref();
+
+            let us_inv = Mat::<E>::from_fn(dim, dim, |i, j| {
+                u.read(i, j).faer_mul(s.read(j, 0).faer_inv())
+            });
+
+            us_inv * u.adjoint()
+        }
+    }
+
+    impl<E: ComplexField> PartialSelfAdjointEigendecomposition<E> {
         #[track_caller]
-        fn sp_solve_unit_upper_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
-            faer_sparse::triangular_solve::solve_unit_upper_triangular_in_place(
-                *self,
-                Conj::No,
-                rhs.as_mat_mut(),
-                get_global_parallelism(),
+        fn __new_impl(
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            side: Side,
+            range: EigenRange<E::Real>,
+        ) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
+            let parallelism = get_global_parallelism();
+
+            let dim = matrix.nrows();
+
+            let matrix = match side {
+                Side::Lower => matrix,
+                Side::Upper => matrix.transpose(),
+            };
+            let conj = conj.compose(match side {
+                Side::Lower => Conj::No,
+                Side::Upper => Conj::Yes,
+            });
+
+            // count the eigenvalues first, so that the eigenvectors and the workspace are only
+            // allocated for the selected ones
+            let capacity = match range {
+                EigenRange::IndexRange(lo, hi) => {
+                    assert!(all(lo <= hi, hi <= dim));
+                    hi - lo
+                }
+                EigenRange::ValueRange(_, _) => faer_evd::hermitian_evd_subset_count(
+                    matrix,
+                    range,
+                    parallelism,
+                    PodStack::new(&mut GlobalPodBuffer::new(
+                        faer_evd::hermitian_evd_subset_count_req::<E>(dim, parallelism).unwrap(),
+                    )),
+                ),
+            };
+
+            let mut s = Mat::<E>::zeros(capacity, 1);
+            let mut u = Mat::<E>::zeros(dim, capacity);
+
+            let params = Default::default();
+            let info = faer_evd::compute_hermitian_evd_subset(
+                matrix,
+                range,
+                s.as_mut(),
+                Some(u.as_mut()),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    faer_evd::compute_hermitian_evd_subset_req::<E>(
+                        dim,
+                        capacity,
+                        faer_evd::ComputeVectors::Yes,
+                        parallelism,
+                        params,
+                    )
+                    .unwrap(),
+                )),
+                params,
             );
+
+            let k = info.count;
+            s.resize_with(k, 1, |_, _| E::faer_zero());
+            u.resize_with(dim, k, |_, _| E::faer_zero());
+
+            if matches!(conj, Conj::Yes) {
+                zipped!(u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+            }
+
+            Self {
+                s,
+                u,
+                converged: info.converged,
+            }
         }
 
-        /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
+        /// Computes the eigenvalues of `matrix` selected by `range`, in nondecreasing order, as
+        /// well as the corresponding eigenvectors. Only the provided side is accessed.
         #[track_caller]
-        fn sp_cholesky(
-            &self,
+        pub fn new<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
             side: Side,
-        ) -> Result<solvers::Cholesky<I, E>, sparse::CholeskyError> {
-            solvers::Cholesky::try_new_with_symbolic(
-                solvers::SymbolicCholesky::try_new(self.symbolic(), side)?,
-                *self,
-                side,
-            )
+            range: EigenRange<E::Real>,
+        ) -> Self {
+            Self::__new_impl(matrix.canonicalize(), side, range)
         }
 
-        /// Returns the LU decomposition of `self` with partial (row) pivoting.
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> MatRef<'_, E> {
+            self.s.as_ref()
+        }
+
+        /// Returns `true` if the inverse iteration converged for every computed eigenvector. If
+        /// it did not, the eigenvectors may not be accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
+        }
+    }
+
+    impl<E: ComplexField> SelfAdjointGeneralizedEigendecomposition<E> {
+        /// Computes the eigendecomposition of the pencil $(A, B)$, where $A$ is self-adjoint and
+        /// $B$ is self-adjoint positive definite. Only the provided side of each matrix is
+        /// accessed.
+        ///
+        /// The problem is reduced to standard form using the Cholesky factorization
+        /// $B = LL^H$, and the eigenvectors are then back-transformed so that
+        /// $U^H B U = I$ for [`GeneralizedEigenProblem::AxLambdaBx`] and
+        /// [`GeneralizedEigenProblem::ABxLambdaX`], and $U^H B^{-1} U = I$ for
+        /// [`GeneralizedEigenProblem::BAxLambdaX`].
         #[track_caller]
-        fn sp_lu(&self) -> Result<solvers::Lu<I, E>, LuError> {
-            solvers::Lu::try_new_with_symbolic(
-                solvers::SymbolicLu::try_new(self.symbolic())?,
-                *self,
-            )
+        pub fn try_new<ViewA: Conjugate<Canonical = E>, ViewB: Conjugate<Canonical = E>>(
+            a: MatRef<'_, ViewA>,
+            b: MatRef<'_, ViewB>,
+            side: Side,
+            problem: GeneralizedEigenProblem,
+        ) -> Result<Self, CholeskyError> {
+            assert!(all(
+                a.nrows() == a.ncols(),
+                b.nrows() == b.ncols(),
+                a.nrows() == b.nrows(),
+            ));
+
+            let dim = a.nrows();
+            let parallelism = get_global_parallelism();
+
+            let l = Cholesky::try_new(b, side)?.compute_l();
+            let l = l.as_ref();
+
+            // full self-adjoint matrix, built from the stored triangle of `a`
+            let mut c = Mat::<E>::from_fn(dim, dim, |i, j| {
+                let stored = match side {
+                    Side::Lower => i >= j,
+                    Side::Upper => i <= j,
+                };
+                if stored {
+                    a.read(i, j).canonicalize()
+                } else {
+                    a.read(j, i).canonicalize().faer_conj()
+                }
+            });
+
+            match problem {
+                GeneralizedEigenProblem::AxLambdaBx => {
+                    // C = L^-1 A L^-H
+                    faer_core::solve::solve_lower_triangular_in_place(l, c.as_mut(), parallelism);
+                    let mut c_adjoint = c.adjoint().to_owned();
+                    faer_core::solve::solve_lower_triangular_in_place(
+                        l,
+                        c_adjoint.as_mut(),
+                        parallelism,
+                    );
+                    c = c_adjoint;
+                }
+                GeneralizedEigenProblem::ABxLambdaX | GeneralizedEigenProblem::BAxLambdaX => {
+                    // C = L^H A L
+                    c = l.adjoint() * (&c * l);
+                }
+            }
+
+            let evd = SelfAdjointEigendecomposition::<E>::new(c.as_ref(), Side::Lower);
+            let converged = evd.converged;
+            let s = evd.s;
+            let mut u = evd.u;
+
+            match problem {
+                GeneralizedEigenProblem::AxLambdaBx | GeneralizedEigenProblem::ABxLambdaX => {
+                    faer_core::solve::solve_upper_triangular_in_place(
+                        l.adjoint(),
+                        u.as_mut(),
+                        parallelism,
+                    );
+                }
+                GeneralizedEigenProblem::BAxLambdaX => {
+                    u = l * &u;
+                }
+            }
+
+            Ok(Self {
+                s,
+                u,
+                problem,
+                converged,
+            })
         }
 
-        /// Returns the QR decomposition of `self`.
+        /// Returns the problem type that was used to compute the decomposition.
+        pub fn problem(&self) -> GeneralizedEigenProblem {
+            self.problem
+        }
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> MatRef<'_, E> {
+            self.s.as_ref()
+        }
+        /// Returns `true` if the iterative algorithm converged within its iteration limit. If it
+        /// did not, the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
+        }
+    }
+
+    impl<E: ComplexField> Gsvd<E> {
+        /// Computes the generalized singular value decomposition of the pair $(A, B)$.
+        ///
+        /// # Panics
+        /// Panics if `a` and `b` don't have the same number of columns, or if the sum of their
+        /// number of rows is smaller than their number of columns.
         #[track_caller]
-        fn sp_qr(&self) -> Result<solvers::Qr<I, E>, FaerError> {
-            solvers::Qr::try_new_with_symbolic(
-                solvers::SymbolicQr::try_new(self.symbolic())?,
-                *self,
-            )
+        pub fn new<ViewA: Conjugate<Canonical = E>, ViewB: Conjugate<Canonical = E>>(
+            a: MatRef<'_, ViewA>,
+            b: MatRef<'_, ViewB>,
+        ) -> Self {
+            let parallelism = get_global_parallelism();
+            let m = a.nrows();
+            let p = b.nrows();
+            let n = a.ncols();
+
+            let a = a.to_owned();
+            let b = b.to_owned();
+
+            let mut alpha = Mat::<E>::zeros(n, 1);
+            let mut beta = Mat::<E>::zeros(n, 1);
+            let mut u = Mat::<E>::zeros(m, n);
+            let mut v = Mat::<E>::zeros(p, n);
+            let mut x = Mat::<E>::zeros(n, n);
+
+            let params = Default::default();
+
+            faer_svd::gsvd::compute_gsvd(
+                a.as_ref(),
+                b.as_ref(),
+                alpha.as_mut(),
+                beta.as_mut(),
+                Some(u.as_mut()),
+                Some(v.as_mut()),
+                Some(x.as_mut()),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    faer_svd::gsvd::compute_gsvd_req::<E>(m, p, n, parallelism, params).unwrap(),
+                )),
+                params,
+            );
+
+            Self {
+                alpha,
+                beta,
+                u,
+                v,
+                x,
+            }
+        }
+
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn v(&self) -> MatRef<'_, E> {
+            self.v.as_ref()
+        }
+        pub fn x(&self) -> MatRef<'_, E> {
+            self.x.as_ref()
+        }
+        pub fn alpha(&self) -> MatRef<'_, E> {
+            self.alpha.as_ref()
+        }
+        pub fn beta(&self) -> MatRef<'_, E> {
+            self.beta.as_ref()
+        }
+    }
+
+    impl<E: ComplexField> Polar<E> {
+        /// Computes the polar decomposition $A = UH$ from the thin SVD $A = U_s S V_s^H$, with
+        /// $U = U_s V_s^H$ and $H = V_s S V_s^H$.
+        #[track_caller]
+        pub fn new<ViewE: Conjugate<Canonical = E>>(matrix: MatRef<'_, ViewE>) -> Self {
+            let n = matrix.ncols();
+            let svd = ThinSvd::new(matrix);
+            let size = svd.s_diagonal().nrows();
+
+            let u = svd.u() * svd.v().adjoint();
+            let v = svd.v();
+            let s = svd.s_diagonal();
+            let v_s = Mat::<E>::from_fn(n, size, |i, j| {
+                v.read(i, j).faer_scale_real(s.read(j, 0).faer_real())
+            });
+            let mut h = v_s * v.adjoint();
+
+            // make the hermitian factor exactly self-adjoint
+            let half = E::Real::faer_from_f64(0.5);
+            for j in 0..n {
+                for i in 0..j {
+                    let x = h
+                        .read(i, j)
+                        .faer_add(h.read(j, i).faer_conj())
+                        .faer_scale_real(half);
+                    h.write(i, j, x);
+                    h.write(j, i, x.faer_conj());
+                }
+                h.write(j, j, E::faer_from_real(h.read(j, j).faer_real()));
+            }
+
+            Self {
+                u,
+                h,
+                converged: svd.converged(),
+            }
+        }
+
+        /// Returns the factor $U$, which has orthonormal columns if the matrix has at least as
+        /// many rows as columns, and orthonormal rows otherwise.
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
         }
+        /// Returns the self-adjoint positive semidefinite factor $H$.
+        pub fn h(&self) -> MatRef<'_, E> {
+            self.h.as_ref()
+        }
+        /// Returns `true` if the SVD that the decomposition is computed from converged within its
+        /// iteration limit. If it did not, the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
+        }
+    }
+
+    fn to_complex<E: ComplexField, ViewE: Conjugate<Canonical = E>>(
+        matrix: MatRef<'_, ViewE>,
+    ) -> Mat<num_complex::Complex<E::Real>> {
+        Mat::from_fn(matrix.nrows(), matrix.ncols(), |i, j| {
+            let x = matrix.read(i, j).canonicalize();
+            num_complex::Complex::new(x.faer_real(), x.faer_imag())
+        })
+    }
+
+    fn from_complex<E: ComplexField>(matrix: MatRef<'_, num_complex::Complex<E::Real>>) -> Mat<E> {
+        Mat::from_fn(matrix.nrows(), matrix.ncols(), |i, j| {
+            let x = matrix.read(i, j);
+            E::faer_from_real_imag(x.re, x.im)
+        })
+    }
+
+    impl<E: ComplexField> Riccati<E> {
+        /// Computes $G = BR^{-1}B^H$.
+        fn __weighted_gram(
+            b: MatRef<'_, num_complex::Complex<E::Real>>,
+            r: MatRef<'_, num_complex::Complex<E::Real>>,
+        ) -> Result<Mat<num_complex::Complex<E::Real>>, RiccatiError> {
+            let llt = Cholesky::try_new(r, Side::Lower)
+                .map_err(|_| RiccatiError::NonPositiveDefiniteWeight)?;
+            Ok(b * llt.solve(b.adjoint()))
+        }
+
+        /// Computes the solution $X = U_2 U_1^{-1}$ from the basis of the stable invariant
+        /// subspace of `matrix`, selected by `is_stable`.
+        fn __stable_subspace_solution(
+            matrix: MatRef<'_, num_complex::Complex<E::Real>>,
+            is_stable: impl FnMut(num_complex::Complex<E::Real>) -> bool,
+        ) -> Result<(Mat<num_complex::Complex<E::Real>>, E::Real), RiccatiError> {
+            let parallelism = get_global_parallelism();
+            let dim = matrix.nrows();
+            let n = dim / 2;
+
+            let mut t = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            let mut z = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            let params = Default::default();
+            faer_evd::schur::compute_schur_complex(
+                matrix,
+                t.as_mut(),
+                z.as_mut(),
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    faer_evd::schur::compute_schur_complex_req::<num_complex::Complex<E::Real>>(
+                        dim,
+                        parallelism,
+                        params,
+                    )
+                    .unwrap(),
+                )),
+                params,
+            );
+
+            let count =
+                faer_evd::schur::reorder_schur_complex(t.as_mut(), Some(z.as_mut()), is_stable);
+            if count != n {
+                return Err(RiccatiError::NoStabilizingSolution);
+            }
+
+            Self::__solution_from_basis(z.as_ref())
+        }
+
+        /// Computes the solution $X = U_2 U_1^{-1}$ from the basis of the stable right deflating
+        /// subspace of the pencil `(lhs, rhs)`, selected by `is_stable`.
+        fn __stable_deflating_subspace_solution(
+            lhs: MatRef<'_, num_complex::Complex<E::Real>>,
+            rhs: MatRef<'_, num_complex::Complex<E::Real>>,
+            is_stable: impl FnMut(num_complex::Complex<E::Real>, num_complex::Complex<E::Real>) -> bool,
+        ) -> Result<(Mat<num_complex::Complex<E::Real>>, E::Real), RiccatiError> {
+            let dim = lhs.nrows();
+            let n = dim / 2;
+
+            let mut s = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            let mut t = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            let mut q = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            let mut z = Mat::<num_complex::Complex<E::Real>>::zeros(dim, dim);
+            if !faer_evd::schur::compute_generalized_schur_complex(
+                lhs,
+                rhs,
+                s.as_mut(),
+                t.as_mut(),
+                q.as_mut(),
+                z.as_mut(),
+            ) {
+                return Err(RiccatiError::NoStabilizingSolution);
+            }
+
+            let count = faer_evd::schur::reorder_generalized_schur_complex(
+                s.as_mut(),
+                t.as_mut(),
+                None,
+                Some(z.as_mut()),
+                is_stable,
+            );
+            if count != n {
+                return Err(RiccatiError::NoStabilizingSolution);
+            }
+
+            Self::__solution_from_basis(z.as_ref())
+        }
+
+        /// Computes the self-adjoint solution $X = U_2 U_1^{-1}$ from the first half of the
+        /// columns of `z`, split into its top half $U_1$ and bottom half $U_2$.
+        fn __solution_from_basis(
+            z: MatRef<'_, num_complex::Complex<E::Real>>,
+        ) -> Result<(Mat<num_complex::Complex<E::Real>>, E::Real), RiccatiError> {
+            let n = z.nrows() / 2;
+
+            let u1 = z.submatrix(0, 0, n, n);
+            let u2 = z.submatrix(n, 0, n, n);
+
+            let s = u1.singular_values();
+            let rcond = if n == 0 {
+                E::Real::faer_one()
+            } else {
+                s[n - 1].faer_div(s[0])
+            };
+
+            // X U1 = U2, and X is self-adjoint, so U1^H X = U2^H
+            let x = u1.adjoint().partial_piv_lu().solve(u2.adjoint());
+            if !x.as_ref().is_all_finite() || rcond == E::Real::faer_zero() {
+                return Err(RiccatiError::NoStabilizingSolution);
+            }
+
+            let half = E::Real::faer_from_f64(0.5);
+            let x = Mat::from_fn(n, n, |i, j| {
+                x.read(i, j)
+                    .faer_add(x.read(j, i).faer_conj())
+                    .faer_scale_real(half)
+            });
+            Ok((x, rcond))
+        }
+
+        /// Computes the stabilizing solution of the continuous algebraic Riccati equation
+        /// $$A^HX + XA - XBR^{-1}B^HX + Q = 0,$$
+        /// where $Q$ is self-adjoint and $R$ is self-adjoint positive definite.
+        ///
+        /// The solution is obtained from the stable invariant subspace of the Hamiltonian matrix
+        /// $$\begin{bmatrix} A & -BR^{-1}B^H \\ -Q & -A^H \end{bmatrix},$$
+        /// computed with an ordered complex Schur decomposition.
+        #[track_caller]
+        pub fn try_new_continuous<
+            ViewA: Conjugate<Canonical = E>,
+            ViewB: Conjugate<Canonical = E>,
+            ViewQ: Conjugate<Canonical = E>,
+            ViewR: Conjugate<Canonical = E>,
+        >(
+            a: MatRef<'_, ViewA>,
+            b: MatRef<'_, ViewB>,
+            q: MatRef<'_, ViewQ>,
+            r: MatRef<'_, ViewR>,
+        ) -> Result<Self, RiccatiError> {
+            let n = a.nrows();
+            let m = b.ncols();
+            assert!(all(
+                a.ncols() == n,
+                b.nrows() == n,
+                q.nrows() == n,
+                q.ncols() == n,
+                r.nrows() == m,
+                r.ncols() == m,
+            ));
+
+            let a = to_complex(a);
+            let b = to_complex(b);
+            let q = to_complex(q);
+            let r = to_complex(r);
+
+            let g = Self::__weighted_gram(b.as_ref(), r.as_ref())?;
+
+            let mut h = Mat::<num_complex::Complex<E::Real>>::zeros(2 * n, 2 * n);
+            for j in 0..n {
+                for i in 0..n {
+                    h.write(i, j, a.read(i, j));
+                    h.write(i, j + n, g.read(i, j).faer_neg());
+                    h.write(i + n, j, q.read(i, j).faer_neg());
+                    h.write(i + n, j + n, a.read(j, i).faer_conj().faer_neg());
+                }
+            }
+
+            let (x, rcond) = Self::__stable_subspace_solution(h.as_ref(), |lambda| {
+                lambda.re < E::Real::faer_zero()
+            })?;
+
+            let residual = (a.adjoint() * &x + &x * &a - &x * &g * &x + &q).norm_l2();
+
+            Ok(Self {
+                x: from_complex(x.as_ref()),
+                residual,
+                rcond,
+            })
+        }
+
+        /// Computes the stabilizing solution of the discrete algebraic Riccati equation
+        /// $$A^HXA - X - A^HXB(R + B^HXB)^{-1}B^HXA + Q = 0,$$
+        /// where $Q$ is self-adjoint and $R$ is self-adjoint positive definite.
+        ///
+        /// The solution is obtained from the stable right deflating subspace of the symplectic
+        /// pencil
+        /// $$\begin{bmatrix} A & 0 \\ -Q & I \end{bmatrix} - \lambda
+        /// \begin{bmatrix} I & G \\ 0 & A^H \end{bmatrix},$$
+        /// with $G = BR^{-1}B^H$, computed with an ordered complex generalized Schur
+        /// decomposition. Since the pencil is not inverted, $A$ may be singular.
+        #[track_caller]
+        pub fn try_new_discrete<
+            ViewA: Conjugate<Canonical = E>,
+            ViewB: Conjugate<Canonical = E>,
+            ViewQ: Conjugate<Canonical = E>,
+            ViewR: Conjugate<Canonical = E>,
+        >(
+            a: MatRef<'_, ViewA>,
+            b: MatRef<'_, ViewB>,
+            q: MatRef<'_, ViewQ>,
+            r: MatRef<'_, ViewR>,
+        ) -> Result<Self, RiccatiError> {
+            let n = a.nrows();
+            let m = b.ncols();
+            assert!(all(
+                a.ncols() == n,
+                b.nrows() == n,
+                q.nrows() == n,
+                q.ncols() == n,
+                r.nrows() == m,
+                r.ncols() == m,
+            ));
+
+            let a = to_complex(a);
+            let b = to_complex(b);
+            let q = to_complex(q);
+            let r = to_complex(r);
+
+            let g = Self::__weighted_gram(b.as_ref(), r.as_ref())?;
+
+            let mut lhs = Mat::<num_complex::Complex<E::Real>>::zeros(2 * n, 2 * n);
+            let mut rhs = Mat::<num_complex::Complex<E::Real>>::zeros(2 * n, 2 * n);
+            for j in 0..n {
+                for i in 0..n {
+                    lhs.write(i, j, a.read(i, j));
+                    lhs.write(i + n, j, q.read(i, j).faer_neg());
+                    rhs.write(i, j + n, g.read(i, j));
+                    rhs.write(i + n, j + n, a.read(j, i).faer_conj());
+                }
+                lhs.write(j + n, j + n, num_complex::Complex::faer_one());
+                rhs.write(j, j, num_complex::Complex::faer_one());
+            }
+
+            // the eigenvalue alpha / beta is stable if |alpha| < |beta|, which also excludes the
+            // infinite eigenvalues
+            let (x, rcond) = Self::__stable_deflating_subspace_solution(
+                lhs.as_ref(),
+                rhs.as_ref(),
+                |alpha, beta| alpha.faer_abs() < beta.faer_abs(),
+            )?;
+
+            let xa = &x * &a;
+            let xb = &x * &b;
+            let gain = (&r + b.adjoint() * &xb)
+                .partial_piv_lu()
+                .solve(xb.adjoint() * &a);
+            let residual = (a.adjoint() * &xa - &x - a.adjoint() * &xb * &gain + &q).norm_l2();
+
+            Ok(Self {
+                x: from_complex(x.as_ref()),
+                residual,
+                rcond,
+            })
+        }
+
+        /// Returns the solution $X$.
+        pub fn x(&self) -> MatRef<'_, E> {
+            self.x.as_ref()
+        }
+        /// Returns the Frobenius norm of the residual of the equation, evaluated at the computed
+        /// solution.
+        pub fn residual_norm(&self) -> E::Real {
+            self.residual
+        }
+        /// Returns the reciprocal of the condition number of the basis $U_1$ of the stable
+        /// invariant (or deflating) subspace, which is inverted to compute $X = U_2 U_1^{-1}$. A
+        /// small value indicates that the computed solution may be inaccurate.
+        pub fn rcond(&self) -> E::Real {
+            self.rcond
+        }
+    }
+
+    impl<E: ComplexField> Eigendecomposition<E> {
+        #[track_caller]
+        /// Computes the eigenvalues of the real `matrix`, and whether the QR algorithm converged.
+        pub(crate) fn __values_from_real(matrix: MatRef<'_, E::Real>) -> (Vec<E>, bool) {
+            assert!(matrix.nrows() == matrix.ncols());
+            if coe::is_same::<E, E::Real>() {
+                panic!(
+                    "The type E ({}) must not be real-valued.",
+                    core::any::type_name::<E>(),
+                );
+            }
+
+            let parallelism = get_global_parallelism();
+
+            let dim = matrix.nrows();
+            let mut s_re = Mat::<E::Real>::zeros(dim, 1);
+            let mut s_im = Mat::<E::Real>::zeros(dim, 1);
+
+            let params = Default::default();
+
+            let info = faer_evd::compute_evd_real(
+                matrix,
+                s_re.as_mut(),
+                s_im.as_mut(),
+                None,
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    faer_evd::compute_evd_req::<E::Real>(
+                        dim,
+                        faer_evd::ComputeVectors::Yes,
+                        parallelism,
+                        params,
+                    )
+                    .unwrap(),
+                )),
+                params,
+            );
+
+            let cplx = |re: E::Real, im: E::Real| -> E { E::faer_from_real_imag(re, im) };
+
+            let values = (0..dim)
+                .map(|i| cplx(s_re.read(i, 0), s_im.read(i, 0)))
+                .collect();
+            (values, info.converged)
+        }
+
+        #[track_caller]
+        /// Computes the eigenvalues of the complex `matrix`, and whether the QR algorithm converged.
+        pub(crate) fn __values_from_complex_impl(
+            (matrix, conj): (MatRef<'_, E>, Conj),
+        ) -> (Vec<E>, bool) {
+            assert!(matrix.nrows() == matrix.ncols());
+            if coe::is_same::<E, E::Real>() {
+                panic!(
+                    "The type E ({}) must not be real-valued.",
+                    core::any::type_name::<E>(),
+                );
+            }
+
+            let parallelism = get_global_parallelism();
+            let dim = matrix.nrows();
+
+            let mut s = Mat::<E>::zeros(dim, 1);
+
+            let params = Default::default();
+
+            let info = faer_evd::compute_evd_complex(
+                matrix,
+                s.as_mut(),
+                None,
+                parallelism,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    faer_evd::compute_evd_req::<E>(
+                        dim,
+                        faer_evd::ComputeVectors::Yes,
+                        parallelism,
+                        params,
+                    )
+                    .unwrap(),
+                )),
+                params,
+            );
+
+            if matches!(conj, Conj::Yes) {
+                zipped!(s.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+            }
+
+            ((0..dim).map(|i| s.read(i, 0)).collect(), info.converged)
+        }
+
+        #[track_caller]
+        pub fn new_from_real(matrix: MatRef<'_, E::Real>) -> Self {
+            Self::new_from_real_with_params(matrix, Default::default())
+        }
+
+        /// Computes the eigendecomposition of the real `matrix` with the given parameters.
+        ///
+        /// If the iteration limit set by `params.max_iterations` is reached, the decomposition is
+        /// not accurate, which is reported by [`Self::converged`].
+        #[track_caller]
+        pub fn new_from_real_with_params(matrix: MatRef<'_, E::Real>, params: EvdParams) -> Self {
+            Self::__new_from_real_impl(matrix, params, false)
+        }
+
+        /// Computes the eigendecomposition of the real `matrix` with the given parameters, along
+        /// with its left eigenvectors.
+        ///
+        /// The left eigenvectors are required by [`Self::compute_left_eigenvectors`] and
+        /// [`Self::reciprocal_condition_numbers`].
+        #[track_caller]
+        pub fn new_from_real_with_left_eigenvectors(
+            matrix: MatRef<'_, E::Real>,
+            params: EvdParams,
+        ) -> Self {
+            Self::__new_from_real_impl(matrix, params, true)
+        }
+
+        #[track_caller]
+        fn __new_from_real_impl(
+            matrix: MatRef<'_, E::Real>,
+            params: EvdParams,
+            compute_left_eigenvectors: bool,
+        ) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
+            if coe::is_same::<E, E::Real>() {
+                panic!(
+                    "The type E ({}) must not be real-valued.",
+                    core::any::type_name::<E>(),
+                );
+            }
+
+            let parallelism = get_global_parallelism();
+
+            let dim = matrix.nrows();
+            let mut s_re = Col::<E::Real>::zeros(dim);
+            let mut s_im = Col::<E::Real>::zeros(dim);
+            let mut u_real = Mat::<E::Real>::zeros(dim, dim);
+            let mut v_real = if compute_left_eigenvectors {
+                Some(Mat::<E::Real>::zeros(dim, dim))
+            } else {
+                None
+            };
+
+            let mut mem = GlobalPodBuffer::new(
+                faer_evd::compute_evd_req::<E::Real>(
+                    dim,
+                    faer_evd::ComputeVectors::Yes,
+                    parallelism,
+                    params,
+                )
+                .unwrap(),
+            );
+            let stack = PodStack::new(&mut mem);
+
+            let info = match &mut v_real {
+                Some(v_real) => faer_evd::compute_evd_real_with_left_eigenvectors(
+                    matrix,
+                    s_re.as_mut().as_2d_mut(),
+                    s_im.as_mut().as_2d_mut(),
+                    u_real.as_mut(),
+                    v_real.as_mut(),
+                    parallelism,
+                    stack,
+                    params,
+                ),
+                None => faer_evd::compute_evd_real(
+                    matrix,
+                    s_re.as_mut().as_2d_mut(),
+                    s_im.as_mut().as_2d_mut(),
+                    Some(u_real.as_mut()),
+                    parallelism,
+                    stack,
+                    params,
+                ),
+            };
+
+            let cplx = |re: E::Real, im: E::Real| -> E { E::faer_from_real_imag(re, im) };
+
+            let s = Col::<E>::from_fn(dim, |i| cplx(s_re.read(i), s_im.read(i)));
+
+            // each complex eigenvalue pair stores the real and imaginary parts of its first
+            // eigenvector, and the second one is its conjugate
+            let unpack = |u_real: MatRef<'_, E::Real>| {
+                let mut u = Mat::<E>::zeros(dim, dim);
+
+                let mut j = 0usize;
+                while j < dim {
+                    if s_im.read(j) == E::Real::faer_zero() {
+                        zipped!(u.as_mut().col_mut(j).as_2d_mut(), u_real.col(j).as_2d()).for_each(
+                            |unzipped!(mut dst, src)| dst.write(E::faer_from_real(src.read())),
+                        );
+                        j += 1;
+                    } else {
+                        let (u_left, u_right) = u.as_mut().split_at_col_mut(j + 1);
+
+                        zipped!(
+                            u_left.col_mut(j).as_2d_mut(),
+                            u_right.col_mut(0).as_2d_mut(),
+                            u_real.col(j).as_2d(),
+                            u_real.col(j + 1).as_2d(),
+                        )
+                        .for_each(
+                            |unzipped!(mut dst, mut dst_conj, re, im)| {
+                                let re = re.read();
+                                let im = im.read();
+                                dst_conj.write(cplx(re, im.faer_neg()));
+                                dst.write(cplx(re, im));
+                            },
+                        );
+
+                        j += 2;
+                    }
+                }
+                u
+            };
+
+            Self {
+                s,
+                u: unpack(u_real.as_ref()),
+                v: v_real.map(|v_real| unpack(v_real.as_ref())),
+                converged: info.converged,
+            }
+        }
+
+        #[track_caller]
+        pub(crate) fn __new_from_complex_impl(
+            (matrix, conj): (MatRef<'_, E>, Conj),
+            params: EvdParams,
+            compute_left_eigenvectors: bool,
+        ) -> Self {
+            assert!(matrix.nrows() == matrix.ncols());
+            if coe::is_same::<E, E::Real>() {
+                panic!(
+                    "The type E ({}) must not be real-valued.",
+                    core::any::type_name::<E>(),
+                );
+            }
+
+            let parallelism = get_global_parallelism();
+            let dim = matrix.nrows();
+
+            let mut s = Col::<E>::zeros(dim);
+            let mut u = Mat::<E>::zeros(dim, dim);
+            let mut v = if compute_left_eigenvectors {
+                Some(Mat::<E>::zeros(dim, dim))
+            } else {
+                None
+            };
+
+            let mut mem = GlobalPodBuffer::new(
+                faer_evd::compute_evd_req::<E>(
+                    dim,
+                    faer_evd::ComputeVectors::Yes,
+                    parallelism,
+                    params,
+                )
+                .unwrap(),
+            );
+            let stack = PodStack::new(&mut mem);
+
+            let info = match &mut v {
+                Some(v) => faer_evd::compute_evd_complex_with_left_eigenvectors(
+                    matrix,
+                    s.as_mut().as_2d_mut(),
+                    u.as_mut(),
+                    v.as_mut(),
+                    parallelism,
+                    stack,
+                    params,
+                ),
+                None => faer_evd::compute_evd_complex(
+                    matrix,
+                    s.as_mut().as_2d_mut(),
+                    Some(u.as_mut()),
+                    parallelism,
+                    stack,
+                    params,
+                ),
+            };
+
+            if matches!(conj, Conj::Yes) {
+                zipped!(s.as_mut().as_2d_mut())
+                    .for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+                zipped!(u.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+                if let Some(v) = &mut v {
+                    zipped!(v.as_mut()).for_each(|unzipped!(mut x)| x.write(x.read().faer_conj()));
+                }
+            }
+
+            Self {
+                s,
+                u,
+                v,
+                converged: info.converged,
+            }
+        }
+
+        #[track_caller]
+        pub fn new_from_complex<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+        ) -> Self {
+            Self::__new_from_complex_impl(matrix.canonicalize(), Default::default(), false)
+        }
+
+        /// Computes the eigendecomposition of the complex `matrix` with the given parameters.
+        ///
+        /// If the iteration limit set by `params.max_iterations` is reached, the decomposition is
+        /// not accurate, which is reported by [`Self::converged`].
+        #[track_caller]
+        pub fn new_from_complex_with_params<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            params: EvdParams,
+        ) -> Self {
+            Self::__new_from_complex_impl(matrix.canonicalize(), params, false)
+        }
+
+        /// Computes the eigendecomposition of the complex `matrix` with the given parameters,
+        /// along with its left eigenvectors.
+        ///
+        /// The left eigenvectors are required by [`Self::compute_left_eigenvectors`] and
+        /// [`Self::reciprocal_condition_numbers`].
+        #[track_caller]
+        pub fn new_from_complex_with_left_eigenvectors<ViewE: Conjugate<Canonical = E>>(
+            matrix: MatRef<'_, ViewE>,
+            params: EvdParams,
+        ) -> Self {
+            Self::__new_from_complex_impl(matrix.canonicalize(), params, true)
+        }
+
+        pub fn u(&self) -> MatRef<'_, E> {
+            self.u.as_ref()
+        }
+        pub fn s_diagonal(&self) -> ColRef<'_, E> {
+            self.s.as_ref()
+        }
+        /// Returns `true` if the QR algorithm converged within its iteration limit. If it did not,
+        /// the decomposition is not accurate.
+        pub fn converged(&self) -> bool {
+            self.converged
+        }
+
+        /// Computes the left eigenvectors $V$, such that $V^H M = S V^H$. Each column is
+        /// normalized to have unit norm.
+        ///
+        /// Returns `None` if the decomposition was computed without its left eigenvectors.
+        pub fn compute_left_eigenvectors(&self) -> Option<Mat<E>> {
+            let mut v = self.v.clone()?;
+            for j in 0..v.ncols() {
+                let norm = column_norm(v.as_ref(), j);
+                if norm > E::Real::faer_zero() {
+                    let inv = norm.faer_inv();
+                    zipped!(v.as_mut().col_mut(j).as_2d_mut())
+                        .for_each(|unzipped!(mut x)| x.write(x.read().faer_scale_real(inv)));
+                }
+            }
+            Some(v)
+        }
+
+        /// Computes the reciprocal condition numbers of the eigenvalues, in the same order as
+        /// [`Self::s_diagonal`].
+        ///
+        /// The reciprocal condition number of the eigenvalue $\lambda_i$ is
+        /// $|v_i^H u_i| / (\|v_i\|_2 \|u_i\|_2)$, where $u_i$ and $v_i$ are the corresponding
+        /// right and left eigenvectors. Small values indicate eigenvalues that are sensitive to
+        /// perturbations of the matrix.
+        ///
+        /// Returns `None` if the decomposition was computed without its left eigenvectors.
+        pub fn reciprocal_condition_numbers(&self) -> Option<Vec<E::Real>> {
+            let v = self.v.as_ref()?;
+            // v_i^H u_i = 1 by construction
+            Some(
+                (0..self.u.ncols())
+                    .map(|j| {
+                        column_norm(v.as_ref(), j)
+                            .faer_mul(column_norm(self.u.as_ref(), j))
+                            .faer_inv()
+                    })
+                    .collect(),
+            )
+        }
+
+        /// Sorts the eigenvalues according to `order`, and permutes the eigenvectors to match.
+        pub fn sort(&mut self, order: EigenvalueOrder) {
+            let by_real_part = |a: &E, b: &E| {
+                total_cmp(&a.faer_real(), &b.faer_real())
+                    .then_with(|| total_cmp(&a.faer_imag(), &b.faer_imag()))
+            };
+
+            match order {
+                EigenvalueOrder::RealPart => self.sort_by(by_real_part),
+                EigenvalueOrder::Magnitude => self.sort_by(|a, b| {
+                    total_cmp(&a.faer_abs(), &b.faer_abs()).then_with(|| by_real_part(a, b))
+                }),
+            }
+        }
+
+        /// Sorts the eigenvalues using the comparator function `compare`, and permutes the
+        /// eigenvectors to match. The sort is stable.
+        pub fn sort_by(&mut self, compare: impl FnMut(&E, &E) -> core::cmp::Ordering) {
+            let mut compare = compare;
+            let n = self.s.nrows();
+
+            let s = (0..n).map(|i| self.s.read(i)).collect::<Vec<_>>();
+            let mut perm = (0..n).collect::<Vec<_>>();
+            perm.sort_by(|&i, &j| compare(&s[i], &s[j]));
+
+            self.s = Col::<E>::from_fn(n, |i| s[perm[i]]);
+            let u = self.u.as_ref();
+            self.u = Mat::<E>::from_fn(u.nrows(), n, |i, j| u.read(i, perm[j]));
+            if let Some(v) = &self.v {
+                let v = v.as_ref();
+                self.v = Some(Mat::<E>::from_fn(v.nrows(), n, |i, j| v.read(i, perm[j])));
+            }
+        }
+    }
+
+    impl_inverse_linop! {
+        {E: ComplexField} Cholesky<E>;
+        {E: ComplexField} Ldlt<E>;
+        {E: ComplexField} Lblt<E>;
+        {E: ComplexField} Ltlt<E>;
+        {E: ComplexField} PartialPivLu<E>;
+        {E: ComplexField} FullPivLu<E>;
+        {E: ComplexField} SelfAdjointEigendecomposition<E>;
+    }
+
+    impl_release_workspace! {
+        {E: Entity} Cholesky<E>;
+        {E: Entity} Ldlt<E>;
+        {E: Entity} PivotedCholesky<E>;
+        {E: Entity} Lblt<E>;
+        {E: Entity} Ltlt<E>;
+        {E: Entity} PartialPivLu<E>;
+        {E: Entity} FullPivLu<E>;
+        {E: Entity} Qr<E>;
+        {E: Entity} ColPivQr<E>;
+        {E: Entity} Svd<E>;
+        {E: Entity} SelfAdjointEigendecomposition<E>;
+    }
+
+    impl<E: Entity> ThinSvd<E> {
+        /// Frees the workspace memory that is kept by the decomposition between refactorizations
+        /// and solves.
+        ///
+        /// The memory is allocated again by the next refactorization or solve that needs it.
+        pub fn release_workspace(&mut self) {
+            self.inner.release_workspace();
+        }
+    }
+
+    fn column_norm<E: ComplexField>(mat: MatRef<'_, E>, j: usize) -> E::Real {
+        let mut norm2 = E::Real::faer_zero();
+        for i in 0..mat.nrows() {
+            norm2 = norm2.faer_add(mat.read(i, j).faer_abs2());
+        }
+        norm2.faer_sqrt()
+    }
+
+    /// Total order on real values, where NaNs compare equal to each other and greater than any
+    /// other value.
+    fn total_cmp<E: RealField>(a: &E, b: &E) -> core::cmp::Ordering {
+        match a.partial_cmp(b) {
+            Some(ord) => ord,
+            None => a.faer_is_nan().cmp(&b.faer_is_nan()),
+        }
+    }
+}
+
+/// Extension trait for `faer` types.
+pub trait FaerMat<E: ComplexField> {
+    /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
+    /// stores the result in `rhs`.
+    fn solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+    /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
+    /// stores the result in `rhs`.
+    fn solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+    /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
+    /// and stores the result in `rhs`.
+    fn solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+    /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
+    /// and stores the result in `rhs`.
+    fn solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+
+    /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
+    /// returns the result.
+    #[track_caller]
+    fn solve_lower_triangular<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> Mat<E> {
+        let mut rhs = rhs.as_mat_ref().to_owned();
+        self.solve_lower_triangular_in_place(rhs.as_mut());
+        rhs
+    }
+    /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
+    /// returns the result.
+    #[track_caller]
+    fn solve_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> Mat<E> {
+        let mut rhs = rhs.as_mat_ref().to_owned();
+        self.solve_upper_triangular_in_place(rhs.as_mut());
+        rhs
+    }
+    /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`, and
+    /// returns the result.
+    #[track_caller]
+    fn solve_unit_lower_triangular<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> Mat<E> {
+        let mut rhs = rhs.as_mat_ref().to_owned();
+        self.solve_unit_lower_triangular_in_place(rhs.as_mut());
+        rhs
+    }
+    /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`, and
+    /// returns the result.
+    #[track_caller]
+    fn solve_unit_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> Mat<E> {
+        let mut rhs = rhs.as_mat_ref().to_owned();
+        self.solve_unit_upper_triangular_in_place(rhs.as_mut());
+        rhs
+    }
+
+    /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
+    fn cholesky(&self, side: Side) -> Result<Cholesky<E>, CholeskyError>;
+    /// Returns the $LDL^H$ decomposition of `self`, without pivoting. Only the provided side is
+    /// accessed.
+    fn ldlt(&self, side: Side) -> Ldlt<E>;
+    /// Returns the Cholesky decomposition of `self` with diagonal pivoting, stopping at the
+    /// numerical rank determined by `tol`. Only the provided side is accessed.
+    fn pivoted_cholesky(&self, side: Side, tol: E::Real) -> PivotedCholesky<E>;
+    /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
+    fn lblt(&self, side: Side) -> Lblt<E>;
+    /// Returns the Aasen decomposition of `self`. Only the provided side is accessed.
+    fn ltlt(&self, side: Side) -> Ltlt<E>;
+    /// Returns the LU decomposition of `self` with partial (row) pivoting.
+    fn partial_piv_lu(&self) -> PartialPivLu<E>;
+    /// Returns the LU decomposition of `self` with full pivoting.
+    fn full_piv_lu(&self) -> FullPivLu<E>;
+    /// Returns the QR decomposition of `self`.
+    fn qr(&self) -> Qr<E>;
+    /// Returns the QR decomposition of `self`, with column pivoting.
+    fn col_piv_qr(&self) -> ColPivQr<E>;
+    /// Returns the SVD of `self`.
+    fn svd(&self) -> Svd<E>;
+    /// Returns the thin SVD of `self`.
+    fn thin_svd(&self) -> ThinSvd<E>;
+    /// Returns the eigendecomposition of `self`, assuming it is self-adjoint. Only the provided
+    /// side is accessed.
+    fn selfadjoint_eigendecomposition(&self, side: Side) -> SelfAdjointEigendecomposition<E>;
+    /// Returns the eigenvalues of `self` selected by `range`, in nondecreasing order, along with
+    /// the corresponding eigenvectors, assuming `self` is self-adjoint. Only the provided side is
+    /// accessed.
+    fn selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<E::Real>,
+    ) -> PartialSelfAdjointEigendecomposition<E>;
+    /// Returns the eigendecomposition of the pencil $(A, B)$, where $A$ is `self` and is assumed
+    /// to be self-adjoint, and $B$ is assumed to be self-adjoint positive definite, solving
+    /// $Ax = \lambda Bx$. Only the provided side of each matrix is accessed.
+    fn selfadjoint_generalized_eigendecomposition<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E>, CholeskyError>;
+    /// Returns the generalized singular value decomposition of the pair $(A, B)$, where $A$ is
+    /// `self`.
+    fn gsvd<ViewE: Conjugate<Canonical = E>>(&self, b: impl AsMatRef<ViewE>) -> Gsvd<E>;
+    /// Returns the polar decomposition of `self`.
+    fn polar(&self) -> Polar<E>;
+    /// Returns the unitary matrix $\Omega$ minimizing $\|A\Omega - B\|_F$, where $A$ is `self`,
+    /// subject to `constraint`, along with whether the SVD it is computed from converged within
+    /// its iteration limit. If it did not, the solution is not accurate.
+    fn procrustes<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> (Mat<E>, bool);
+    /// Solves the equation `self * X = rhs`, choosing the method from the structure of `self`, and
+    /// returns the solution along with the [`SolvePath`] that was taken.
+    ///
+    /// Triangular matrices are solved by substitution. Self-adjoint matrices with a positive
+    /// diagonal are solved with the Cholesky decomposition, falling back to the Bunch-Kaufman
+    /// decomposition if it fails, and other self-adjoint matrices directly with the Bunch-Kaufman
+    /// decomposition. Other square matrices are solved with the LU decomposition with partial
+    /// pivoting. Rectangular systems are solved with the QR decomposition, in the sense of least
+    /// squares if `self` has more rows than columns, and for the minimum norm solution otherwise.
+    ///
+    /// Triangular structure is detected exactly. Self-adjointness is detected up to a tolerance
+    /// relative to the largest entry of `self`, so that a matrix that is only self-adjoint up to
+    /// rounding errors, such as the product of a matrix and its adjoint, is treated as
+    /// self-adjoint, in which case only its lower triangular part is read.
+    fn solve_auto<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> (Mat<E>, SolvePath);
+    /// Returns the eigendecomposition of `self`, as a complex matrix.
+    fn eigendecomposition<ComplexE: ComplexField<Real = E::Real>>(
+        &self,
+    ) -> Eigendecomposition<ComplexE>;
+    /// Returns the eigendecomposition of `self`, when `E` is in the complex domain.
+    fn complex_eigendecomposition(&self) -> Eigendecomposition<E>;
+
+    /// Returns the determinant of `self`.
+    fn determinant(&self) -> E;
+    /// Returns the singular values of `self`, in nonincreasing order.
+    fn singular_values(&self) -> Vec<E::Real>;
+    /// Returns the eigenvalues of `self`, assuming it is self-adjoint. Only the provided
+    /// side is accessed. The order of the eigenvalues is currently unspecified.
+    fn selfadjoint_eigenvalues(&self, side: Side) -> Vec<E::Real>;
+    /// Returns the eigenvalues of `self`, as complex values. The order of the eigenvalues is
+    /// currently unspecified.
+    fn eigenvalues<ComplexE: ComplexField<Real = E::Real>>(&self) -> Vec<ComplexE>;
+    /// Returns the eigenvalues of `self`, when `E` is in the complex domain. The order of the
+    /// eigenvalues is currently unspecified.
+    fn complex_eigenvalues(&self) -> Vec<E::Canonical>;
+
+    /// Returns the Cholesky decomposition of `self`, or an error if `self` is not square, contains
+    /// non-finite values, or is not positive definite. Only the provided side is accessed.
+    fn try_cholesky(&self, side: Side) -> Result<Cholesky<E>, SolverError>;
+    /// Returns the $LDL^H$ decomposition of `self`, or an error if `self` is not square or contains
+    /// non-finite values. Only the provided side is accessed.
+    fn try_ldlt(&self, side: Side) -> Result<Ldlt<E>, SolverError>;
+    /// Returns the pivoted Cholesky decomposition of `self`, or an error if `self` is not square or
+    /// contains non-finite values. Only the provided side is accessed.
+    fn try_pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: E::Real,
+    ) -> Result<PivotedCholesky<E>, SolverError>;
+    /// Returns the Bunch-Kaufman decomposition of `self`, or an error if `self` is not square or
+    /// contains non-finite values. Only the provided side is accessed.
+    fn try_lblt(&self, side: Side) -> Result<Lblt<E>, SolverError>;
+    /// Returns the Aasen decomposition of `self`, or an error if `self` is not square or contains
+    /// non-finite values. Only the provided side is accessed.
+    fn try_ltlt(&self, side: Side) -> Result<Ltlt<E>, SolverError>;
+    /// Returns the LU decomposition of `self` with partial pivoting, or an error if `self` is not
+    /// square or contains non-finite values.
+    fn try_partial_piv_lu(&self) -> Result<PartialPivLu<E>, SolverError>;
+    /// Returns the LU decomposition of `self` with full pivoting, or an error if `self` contains
+    /// non-finite values.
+    fn try_full_piv_lu(&self) -> Result<FullPivLu<E>, SolverError>;
+    /// Returns the QR decomposition of `self`, or an error if `self` contains non-finite values.
+    fn try_qr(&self) -> Result<Qr<E>, SolverError>;
+    /// Returns the QR decomposition of `self` with column pivoting, or an error if `self` contains
+    /// non-finite values.
+    fn try_col_piv_qr(&self) -> Result<ColPivQr<E>, SolverError>;
+    /// Returns the SVD of `self`, or an error if `self` contains non-finite values, or if the
+    /// algorithm fails to converge.
+    fn try_svd(&self) -> Result<Svd<E>, SolverError>;
+    /// Returns the thin SVD of `self`, or an error if `self` contains non-finite values, or if the
+    /// algorithm fails to converge.
+    fn try_thin_svd(&self) -> Result<ThinSvd<E>, SolverError>;
+    /// Returns the eigendecomposition of `self`, assuming it is self-adjoint, or an error if `self`
+    /// is not square, contains non-finite values, or if the algorithm fails to converge. Only the
+    /// provided side is accessed.
+    fn try_selfadjoint_eigendecomposition(
+        &self,
+        side: Side,
+    ) -> Result<SelfAdjointEigendecomposition<E>, SolverError>;
+    /// Returns the eigenvalues of `self` selected by `range`, along with the corresponding
+    /// eigenvectors, assuming `self` is self-adjoint, or an error if `self` is not square, if
+    /// `range` is an index range that is out of bounds, if `self` contains non-finite values, or
+    /// if the algorithm fails to converge. Only the provided side is accessed.
+    fn try_selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<E::Real>,
+    ) -> Result<PartialSelfAdjointEigendecomposition<E>, SolverError>;
+    /// Returns the eigendecomposition of the pencil $(A, B)$, where $A$ is `self`, or an error if
+    /// the matrices are not square, don't have the same dimension, contain non-finite values, or
+    /// if $B$ is not positive definite. Only the provided side of each matrix is accessed.
+    fn try_selfadjoint_generalized_eigendecomposition<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E>, SolverError>;
+    /// Returns the generalized singular value decomposition of the pair $(A, B)$, where $A$ is
+    /// `self`, or an error if the matrices don't have the same number of columns, if the sum of
+    /// their number of rows is smaller than their number of columns, or if they contain
+    /// non-finite values.
+    fn try_gsvd<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Result<Gsvd<E>, SolverError>;
+    /// Returns the polar decomposition of `self`, or an error if `self` contains non-finite values,
+    /// or if the algorithm fails to converge.
+    fn try_polar(&self) -> Result<Polar<E>, SolverError>;
+    /// Returns the solution of the orthogonal Procrustes problem, or an error if `self` and `b`
+    /// don't have the same dimensions, if they contain non-finite values, or if the algorithm
+    /// fails to converge.
+    fn try_procrustes<ViewE: Conjugate<Canonical = E>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> Result<Mat<E>, SolverError>;
+    /// Returns the eigendecomposition of `self`, as a complex matrix, or an error if `self` is not
+    /// square, contains non-finite values, or if the algorithm fails to converge.
+    ///
+    /// [`SolverError::ScalarTypeMismatch`] is returned if `ComplexE` is real-valued, or if it
+    /// differs from `E` when `E` is in the complex domain.
+    fn try_eigendecomposition<ComplexE: ComplexField<Real = E::Real>>(
+        &self,
+    ) -> Result<Eigendecomposition<ComplexE>, SolverError>;
+    /// Returns the eigendecomposition of `self`, when `E` is in the complex domain, or an error if
+    /// `self` is not square, contains non-finite values, or if the algorithm fails to converge.
+    ///
+    /// [`SolverError::ScalarTypeMismatch`] is returned if `E` is real-valued.
+    fn try_complex_eigendecomposition(&self) -> Result<Eigendecomposition<E>, SolverError>;
+    /// Returns the determinant of `self`, or an error if `self` is not square or contains non-finite
+    /// values.
+    fn try_determinant(&self) -> Result<E, SolverError>;
+    /// Returns the singular values of `self`, in nonincreasing order, or an error if `self` contains
+    /// non-finite values, or if the algorithm fails to converge.
+    fn try_singular_values(&self) -> Result<Vec<E::Real>, SolverError>;
+    /// Returns the eigenvalues of `self`, assuming it is self-adjoint, or an error if `self` is not
+    /// square, contains non-finite values, or if the algorithm fails to converge. Only the
+    /// provided side is accessed.
+    fn try_selfadjoint_eigenvalues(&self, side: Side) -> Result<Vec<E::Real>, SolverError>;
+    /// Returns the eigenvalues of `self`, as complex values, or an error if `self` is not square,
+    /// contains non-finite values, or if the algorithm fails to converge.
+    ///
+    /// [`SolverError::ScalarTypeMismatch`] is returned if `ComplexE` is real-valued, or if it
+    /// differs from `E` when `E` is in the complex domain.
+    fn try_eigenvalues<ComplexE: ComplexField<Real = E::Real>>(
+        &self,
+    ) -> Result<Vec<ComplexE>, SolverError>;
+    /// Returns the eigenvalues of `self`, when `E` is in the complex domain, or an error if `self`
+    /// is not square, contains non-finite values, or if the algorithm fails to converge.
+    ///
+    /// [`SolverError::ScalarTypeMismatch`] is returned if `E` is real-valued.
+    fn try_complex_eigenvalues(&self) -> Result<Vec<E>, SolverError>;
+}
+
+/// Sparse solvers and traits.
+pub mod sparse {
+    use super::*;
+    use faer_core::group_helpers::{SliceGroup, VecGroup};
+
+    pub use faer_core::{
+        permutation::Index,
+        sparse::{
+            SparseColMatRef, SparseRowMatRef, SymbolicSparseColMatRef, SymbolicSparseRowMatRef,
+        },
+    };
+    pub use faer_sparse::{krylov, lu::LuError, FaerError};
+
+    /// Sparse Cholesky error.
+    #[derive(Copy, Clone, Debug)]
+    pub enum CholeskyError {
+        Generic(FaerError),
+        SymbolicSingular,
+        NotPositiveDefinite,
+    }
+
+    impl core::fmt::Display for CholeskyError {
+        #[inline]
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self, f)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for CholeskyError {}
+
+    impl From<FaerError> for CholeskyError {
+        #[inline]
+        fn from(value: FaerError) -> Self {
+            Self::Generic(value)
+        }
+    }
+
+    impl From<crate::CholeskyError> for CholeskyError {
+        #[inline]
+        fn from(_: crate::CholeskyError) -> Self {
+            Self::NotPositiveDefinite
+        }
+    }
+
+    /// Sparse solvers.
+    ///
+    /// Each solver satisfies the [`SpSolver`] and/or [`SpSolverLstsq`] traits, which can be used
+    /// to solve linear systems.
+    pub mod solvers {
+        use super::*;
+        use crate::SolveWithStack;
+        use faer_core::{assert, unzipped, zipped};
+
+        /// Object-safe base for [`SpSolver`]
+        pub trait SpSolverCore<E: Entity> {
+            /// Returns the number of rows of the matrix used to construct this decomposition.
+            fn nrows(&self) -> usize;
+            /// Returns the number of columns of the matrix used to construct this decomposition.
+            fn ncols(&self) -> usize;
+
+            #[doc(hidden)]
+            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
+            #[doc(hidden)]
+            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
+        }
+
+        pub trait SpSolverLstsqCore<E: Entity>: SpSolverCore<E> {
+            #[doc(hidden)]
+            fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj);
+        }
+
+        pub trait SpSolver<E: ComplexField>: SpSolverCore<E> {
+            /// Solves the equation `self * X = rhs`, and stores the result in `rhs`.
+            fn solve_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `conjugate(self) * X = rhs`, and stores the result in `rhs`.
+            fn solve_conj_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `transpose(self) * X = rhs`, and stores the result in `rhs`.
+            fn solve_transpose_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `adjoint(self) * X = rhs`, and stores the result in `rhs`.
+            fn solve_conj_transpose_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `self * X = rhs`, and returns the result.
+            fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: impl AsMatRef<ViewE>) -> Mat<E>;
+            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result.
+            fn solve_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E>;
+            /// Solves the equation `transpose(self) * X = rhs`, and returns the result.
+            fn solve_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E>;
+            /// Solves the equation `adjoint(self) * X = rhs`, and returns the result.
+            fn solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E>;
+
+            /// Solves the equation `self * X = rhs`, and stores the result in `out`, which must
+            /// have the same dimensions as `rhs`.
+            fn solve_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            );
+            /// Solves the equation `conjugate(self) * X = rhs`, and stores the result in `out`,
+            /// which must have the same dimensions as `rhs`.
+            fn solve_conj_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            );
+            /// Solves the equation `transpose(self) * X = rhs`, and stores the result in `out`,
+            /// which must have the same dimensions as `rhs`.
+            fn solve_transpose_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            );
+            /// Solves the equation `adjoint(self) * X = rhs`, and stores the result in `out`,
+            /// which must have the same dimensions as `rhs`.
+            fn solve_conj_transpose_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            );
+
+            /// Solves the equation `self * X = rhs`, and stores the result in `rhs`, or returns an error
+            /// if the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError>;
+            /// Solves the equation `conjugate(self) * X = rhs`, and stores the result in `rhs`, or returns an error
+            /// if the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_conj_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError>;
+            /// Solves the equation `transpose(self) * X = rhs`, and stores the result in `rhs`, or returns an error
+            /// if the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_transpose_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError>;
+            /// Solves the equation `adjoint(self) * X = rhs`, and stores the result in `rhs`, or returns an error
+            /// if the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_conj_transpose_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError>;
+            /// Solves the equation `self * X = rhs`, and returns the result, or returns an error if the
+            /// dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result, or returns an error if the
+            /// dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+            /// Solves the equation `transpose(self) * X = rhs`, and returns the result, or returns an error if the
+            /// dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+            /// Solves the equation `adjoint(self) * X = rhs`, and returns the result, or returns an error if the
+            /// dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+        }
+
+        pub trait SpSolverLstsq<E: Entity>: SpSolverLstsqCore<E> {
+            /// Solves the equation `self * X = rhs`, in the sense of least squares, and stores the
+            /// result in the top rows of `rhs`.
+            fn solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `conjugate(self) * X = rhs`, in the sense of least squares, and
+            /// stores the result in the top rows of `rhs`.
+            fn solve_lstsq_conj_in_place(&self, rhs: impl AsMatMut<E>);
+            /// Solves the equation `self * X = rhs`, and returns the result.
+            fn solve_lstsq<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E>;
+            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result.
+            fn solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E>;
+
+            /// Solves the equation `self * X = rhs`, in the sense of least squares, and stores the
+            /// result in the top rows of `rhs`, or returns an error if the dimensions don't match,
+            /// or if `rhs` contains non-finite values.
+            fn try_solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError>;
+            /// Solves the equation `conjugate(self) * X = rhs`, in the sense of least squares, and
+            /// stores the result in the top rows of `rhs`, or returns an error if the dimensions
+            /// don't match, or if `rhs` contains non-finite values.
+            fn try_solve_lstsq_conj_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError>;
+            /// Solves the equation `self * X = rhs`, and returns the result, or returns an error if
+            /// the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_lstsq<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+            /// Solves the equation `conjugate(self) * X = rhs`, and returns the result, or returns
+            /// an error if the dimensions don't match, or if `rhs` contains non-finite values.
+            fn try_solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError>;
+        }
+
+        #[track_caller]
+        fn solve_with_conj_impl<
+            E: ComplexField,
+            D: ?Sized + SpSolverCore<E>,
+            ViewE: Conjugate<Canonical = E>,
+        >(
+            d: &D,
+            rhs: MatRef<'_, ViewE>,
+            conj: Conj,
+        ) -> Mat<E> {
+            let mut rhs = rhs.to_owned();
+            d.solve_in_place_with_conj_impl(rhs.as_mut(), conj);
+            rhs
+        }
+
+        #[track_caller]
+        fn solve_transpose_with_conj_impl<
+            E: ComplexField,
+            D: ?Sized + SpSolverCore<E>,
+            ViewE: Conjugate<Canonical = E>,
+        >(
+            d: &D,
+            rhs: MatRef<'_, ViewE>,
+            conj: Conj,
+        ) -> Mat<E> {
+            let mut rhs = rhs.to_owned();
+            d.solve_transpose_in_place_with_conj_impl(rhs.as_mut(), conj);
+            rhs
+        }
+
+        #[track_caller]
+        fn copy_rhs_into<E: ComplexField, ViewE: Conjugate<Canonical = E>>(
+            rhs: MatRef<'_, ViewE>,
+            out: MatMut<'_, E>,
+        ) {
+            assert!(all(out.nrows() == rhs.nrows(), out.ncols() == rhs.ncols()));
+            zipped!(out, rhs)
+                .for_each(|unzipped!(mut dst, src)| dst.write(src.read().canonicalize()));
+        }
+
+        #[track_caller]
+        fn solve_lstsq_with_conj_impl<
+            E: ComplexField,
+            D: ?Sized + SpSolverLstsqCore<E>,
+            ViewE: Conjugate<Canonical = E>,
+        >(
+            d: &D,
+            rhs: MatRef<'_, ViewE>,
+            conj: Conj,
+        ) -> Mat<E> {
+            let mut rhs = rhs.to_owned();
+            let k = rhs.ncols();
+            d.solve_lstsq_in_place_with_conj_impl(rhs.as_mut(), conj);
+            rhs.resize_with(d.ncols(), k, |_, _| unreachable!());
+            rhs
+        }
+
+        #[track_caller]
+        fn check_solve<
+            E: ComplexField,
+            D: ?Sized + SpSolverCore<E>,
+            ViewE: Conjugate<Canonical = E>,
+        >(
+            d: &D,
+            dim: usize,
+            rhs: MatRef<'_, ViewE>,
+        ) -> Result<(), SolverError> {
+            if d.nrows() != d.ncols() {
+                return Err(SolverError::NonSquare {
+                    nrows: d.nrows(),
+                    ncols: d.ncols(),
+                });
+            }
+            check_dim(dim, rhs.nrows())?;
+            check_finite(rhs, None)
+        }
+
+        #[track_caller]
+        fn check_solve_lstsq<
+            E: ComplexField,
+            D: ?Sized + SpSolverLstsqCore<E>,
+            ViewE: Conjugate<Canonical = E>,
+        >(
+            d: &D,
+            rhs: MatRef<'_, ViewE>,
+        ) -> Result<(), SolverError> {
+            check_dim(d.nrows(), rhs.nrows())?;
+            check_finite(rhs, None)
+        }
+
+        impl<E: ComplexField, Dec: ?Sized + SpSolverCore<E>> SpSolver<E> for Dec {
+            #[track_caller]
+            fn solve_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_transpose_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj_transpose_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_transpose_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve<ViewE: Conjugate<Canonical = E>>(&self, rhs: impl AsMatRef<ViewE>) -> Mat<E> {
+                solve_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E> {
+                solve_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E> {
+                solve_transpose_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E> {
+                solve_transpose_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            ) {
+                let mut out = out;
+                let mut out = out.as_mat_mut();
+                copy_rhs_into(rhs.as_mat_ref(), out.rb_mut());
+                self.solve_in_place_with_conj_impl(out, Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            ) {
+                let mut out = out;
+                let mut out = out.as_mat_mut();
+                copy_rhs_into(rhs.as_mat_ref(), out.rb_mut());
+                self.solve_in_place_with_conj_impl(out, Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve_transpose_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            ) {
+                let mut out = out;
+                let mut out = out.as_mat_mut();
+                copy_rhs_into(rhs.as_mat_ref(), out.rb_mut());
+                self.solve_transpose_in_place_with_conj_impl(out, Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_conj_transpose_into<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+                out: impl AsMatMut<E>,
+            ) {
+                let mut out = out;
+                let mut out = out.as_mat_mut();
+                copy_rhs_into(rhs.as_mat_ref(), out.rb_mut());
+                self.solve_transpose_in_place_with_conj_impl(out, Conj::Yes)
+            }
+
+            #[track_caller]
+            fn try_solve_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve(self, self.nrows(), rhs.rb())?;
+                self.solve_in_place_with_conj_impl(rhs, Conj::No);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve_conj_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve(self, self.nrows(), rhs.rb())?;
+                self.solve_in_place_with_conj_impl(rhs, Conj::Yes);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve_transpose_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve(self, self.ncols(), rhs.rb())?;
+                self.solve_transpose_in_place_with_conj_impl(rhs, Conj::No);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve_conj_transpose_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve(self, self.ncols(), rhs.rb())?;
+                self.solve_transpose_in_place_with_conj_impl(rhs, Conj::Yes);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve(self, self.nrows(), rhs)?;
+                Ok(solve_with_conj_impl::<E, _, _>(self, rhs, Conj::No))
+            }
+
+            #[track_caller]
+            fn try_solve_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve(self, self.nrows(), rhs)?;
+                Ok(solve_with_conj_impl::<E, _, _>(self, rhs, Conj::Yes))
+            }
+
+            #[track_caller]
+            fn try_solve_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve(self, self.ncols(), rhs)?;
+                Ok(solve_transpose_with_conj_impl::<E, _, _>(
+                    self,
+                    rhs,
+                    Conj::No,
+                ))
+            }
+
+            #[track_caller]
+            fn try_solve_conj_transpose<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve(self, self.ncols(), rhs)?;
+                Ok(solve_transpose_with_conj_impl::<E, _, _>(
+                    self,
+                    rhs,
+                    Conj::Yes,
+                ))
+            }
+        }
+
+        impl<E: ComplexField, Dec: ?Sized + SpSolverLstsqCore<E>> SpSolverLstsq<E> for Dec {
+            #[track_caller]
+            fn solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_lstsq_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_lstsq_conj_in_place(&self, rhs: impl AsMatMut<E>) {
+                let mut rhs = rhs;
+                self.solve_lstsq_in_place_with_conj_impl(rhs.as_mat_mut(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn solve_lstsq<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E> {
+                solve_lstsq_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::No)
+            }
+
+            #[track_caller]
+            fn solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Mat<E> {
+                solve_lstsq_with_conj_impl::<E, _, _>(self, rhs.as_mat_ref(), Conj::Yes)
+            }
+
+            #[track_caller]
+            fn try_solve_lstsq_in_place(&self, rhs: impl AsMatMut<E>) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve_lstsq(self, rhs.rb())?;
+                self.solve_lstsq_in_place_with_conj_impl(rhs, Conj::No);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve_lstsq_conj_in_place(
+                &self,
+                rhs: impl AsMatMut<E>,
+            ) -> Result<(), SolverError> {
+                let mut rhs = rhs;
+                let rhs = rhs.as_mat_mut();
+                check_solve_lstsq(self, rhs.rb())?;
+                self.solve_lstsq_in_place_with_conj_impl(rhs, Conj::Yes);
+                Ok(())
+            }
+
+            #[track_caller]
+            fn try_solve_lstsq<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve_lstsq(self, rhs)?;
+                Ok(solve_lstsq_with_conj_impl::<E, _, _>(self, rhs, Conj::No))
+            }
+
+            #[track_caller]
+            fn try_solve_lstsq_conj<ViewE: Conjugate<Canonical = E>>(
+                &self,
+                rhs: impl AsMatRef<ViewE>,
+            ) -> Result<Mat<E>, SolverError> {
+                let rhs = rhs.as_mat_ref();
+                check_solve_lstsq(self, rhs)?;
+                Ok(solve_lstsq_with_conj_impl::<E, _, _>(self, rhs, Conj::Yes))
+            }
+        }
+
+        #[derive(Debug)]
+        pub struct SymbolicCholesky<I> {
+            inner: alloc::sync::Arc<faer_sparse::cholesky::SymbolicCholesky<I>>,
+        }
+        #[derive(Clone, Debug)]
+        pub struct Cholesky<I, E: Entity> {
+            symbolic: SymbolicCholesky<I>,
+            values: VecGroup<E>,
+            workspace: Workspace,
+        }
+
+        #[derive(Debug)]
+        pub struct SymbolicQr<I> {
+            inner: alloc::sync::Arc<faer_sparse::qr::SymbolicQr<I>>,
+        }
+        #[derive(Clone, Debug)]
+        pub struct Qr<I, E: Entity> {
+            symbolic: SymbolicQr<I>,
+            indices: alloc::vec::Vec<I>,
+            values: VecGroup<E>,
+            workspace: Workspace,
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct Lblt<I, E: Entity> {
+            symbolic: SymbolicCholesky<I>,
+            values: VecGroup<E>,
+            subdiag: VecGroup<E>,
+            perm_fwd: alloc::vec::Vec<I>,
+            perm_inv: alloc::vec::Vec<I>,
+            workspace: Workspace,
+        }
+
+        #[derive(Debug)]
+        pub struct SymbolicLu<I> {
+            inner: alloc::sync::Arc<faer_sparse::lu::SymbolicLu<I>>,
+        }
+        #[derive(Clone, Debug)]
+        pub struct Lu<I, E: Entity> {
+            symbolic: SymbolicLu<I>,
+            numeric: faer_sparse::lu::NumericLu<I, E>,
+            workspace: Workspace,
+        }
+
+        /// Decomposition chosen by [`FaerSparseMat::sp_solve_auto`].
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum SpSolvePath {
+            /// Sparse Cholesky decomposition, for self-adjoint positive definite matrices.
+            Cholesky,
+            /// Sparse Bunch-Kaufman decomposition, for self-adjoint indefinite matrices.
+            Lblt,
+            /// Sparse LU decomposition with partial pivoting, for other square matrices.
+            Lu,
+            /// Sparse QR decomposition, for matrices with more rows than columns.
+            Qr,
+        }
+
+        /// Structure detected by [`FaerSparseMat::sp_solve_auto`], and the decomposition it chose.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub struct SpSolveReport {
+            /// Whether the sparsity pattern of the matrix is symmetric. Explicitly stored zeros are
+            /// part of the sparsity pattern.
+            pub structurally_symmetric: bool,
+            /// Whether the matrix is equal to its adjoint.
+            pub selfadjoint: bool,
+            /// The decomposition that was computed.
+            pub path: SpSolvePath,
+        }
+
+        impl<I> Clone for SymbolicCholesky<I> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+        impl<I> Clone for SymbolicQr<I> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+        impl<I> Clone for SymbolicLu<I> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    inner: self.inner.clone(),
+                }
+            }
+        }
+
+        impl<I: Index> SymbolicCholesky<I> {
+            #[track_caller]
+            pub fn try_new(
+                mat: SymbolicSparseColMatRef<'_, I>,
+                side: Side,
+            ) -> Result<Self, FaerError> {
+                Ok(Self {
+                    inner: alloc::sync::Arc::new(
+                        faer_sparse::cholesky::factorize_symbolic_cholesky(
+                            mat,
+                            side,
+                            Default::default(),
+                        )?,
+                    ),
+                })
+            }
+
+            /// Computes the symbolic structure with a supernodal layout, which allows
+            /// [`Lblt::try_new_with_symbolic`] to pivot within each supernode.
+            #[track_caller]
+            pub(crate) fn try_new_supernodal(
+                mat: SymbolicSparseColMatRef<'_, I>,
+                side: Side,
+            ) -> Result<Self, FaerError> {
+                Ok(Self {
+                    inner: alloc::sync::Arc::new(
+                        faer_sparse::cholesky::factorize_symbolic_cholesky(
+                            mat,
+                            side,
+                            faer_sparse::cholesky::CholeskySymbolicParams {
+                                supernodal_flop_ratio_threshold:
+                                    faer_sparse::SupernodalThreshold::FORCE_SUPERNODAL,
+                                ..Default::default()
+                            },
+                        )?,
+                    ),
+                })
+            }
+        }
+        impl<I: Index> SymbolicQr<I> {
+            #[track_caller]
+            pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
+                Ok(Self {
+                    inner: alloc::sync::Arc::new(faer_sparse::qr::factorize_symbolic_qr(
+                        mat,
+                        Default::default(),
+                    )?),
+                })
+            }
+        }
+        impl<I: Index> SymbolicLu<I> {
+            #[track_caller]
+            pub fn try_new(mat: SymbolicSparseColMatRef<'_, I>) -> Result<Self, FaerError> {
+                Ok(Self {
+                    inner: alloc::sync::Arc::new(faer_sparse::lu::factorize_symbolic_lu(
+                        mat,
+                        Default::default(),
+                    )?),
+                })
+            }
+        }
+
+        impl<I: Index, E: ComplexField> Cholesky<I, E> {
+            #[track_caller]
+            pub fn try_new_with_symbolic(
+                symbolic: SymbolicCholesky<I>,
+                mat: SparseColMatRef<'_, I, E>,
+                side: Side,
+            ) -> Result<Self, sparse::CholeskyError> {
+                let len_values = symbolic.inner.len_values();
+                let mut values = VecGroup::new();
+                values
+                    .try_reserve_exact(len_values)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                values.resize(len_values, E::faer_zero().faer_into_units());
+                let parallelism = get_global_parallelism();
+                let mut workspace = Workspace::default();
+                workspace.reserve(
+                    symbolic
+                        .inner
+                        .solve_in_place_req::<E>(1)
+                        .map_err(|_| FaerError::OutOfMemory)?,
+                );
+                symbolic.inner.factorize_numeric_llt::<E>(
+                    values.as_slice_mut().into_inner(),
+                    mat,
+                    side,
+                    Default::default(),
+                    parallelism,
+                    workspace.stack(
+                        symbolic
+                            .inner
+                            .factorize_numeric_llt_req::<E>(parallelism)
+                            .map_err(|_| FaerError::OutOfMemory)?,
+                    ),
+                )?;
+                Ok(Self {
+                    symbolic,
+                    values,
+                    workspace,
+                })
+            }
+
+            /// Recomputes the numeric factorization for `mat`, which must have the same sparsity
+            /// pattern as the matrix that was used to compute the symbolic factorization, reusing
+            /// the storage and workspace of `self`.
+            ///
+            /// If the matrix is not positive definite, the error is returned and the contents of
+            /// `self` are unspecified until the next successful refactorization.
+            #[track_caller]
+            pub fn try_refactor(
+                &mut self,
+                mat: SparseColMatRef<'_, I, E>,
+                side: Side,
+            ) -> Result<(), sparse::CholeskyError> {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .symbolic
+                    .inner
+                    .factorize_numeric_llt_req::<E>(parallelism)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                self.symbolic.inner.factorize_numeric_llt::<E>(
+                    self.values.as_slice_mut().into_inner(),
+                    mat,
+                    side,
+                    Default::default(),
+                    parallelism,
+                    self.workspace.stack(req),
+                )?;
+                Ok(())
+            }
+
+            /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`, such
+            /// that the determinant is equal to `phase * exp(log_abs)`.
+            ///
+            /// Since the matrix is positive definite, the phase is always equal to one.
+            pub fn log_determinant(&self) -> (E, E::Real) {
+                let values = self.values.as_slice().into_inner();
+                let mut log_abs = E::Real::faer_zero();
+                match self.symbolic.inner.raw() {
+                    faer_sparse::cholesky::SymbolicCholeskyRaw::Simplicial(symbolic) => {
+                        let l = SparseColMatRef::<'_, I, E>::new(symbolic.ld_factors(), values);
+                        // the diagonal element is the first stored element in each column
+                        for j in 0..symbolic.ncols() {
+                            let d = SliceGroup::<'_, E>::new(l.values_of_col(j)).read(0);
+                            log_abs = log_abs.faer_add(d.faer_real().faer_ln());
+                        }
+                    }
+                    faer_sparse::cholesky::SymbolicCholeskyRaw::Supernodal(symbolic) => {
+                        let l =
+                            faer_sparse::cholesky::supernodal::SupernodalLltRef::<'_, I, E>::new(
+                                symbolic, values,
+                            );
+                        for s in 0..symbolic.n_supernodes() {
+                            let s_l = l.supernode(s).matrix();
+                            for j in 0..s_l.ncols() {
+                                log_abs = log_abs.faer_add(s_l.read(j, j).faer_real().faer_ln());
+                            }
+                        }
+                    }
+                }
+                (E::faer_one(), log_abs.faer_add(log_abs))
+            }
+        }
+
+        impl<I: Index, E: ComplexField> Qr<I, E> {
+            #[track_caller]
+            pub fn try_new_with_symbolic(
+                symbolic: SymbolicQr<I>,
+                mat: SparseColMatRef<'_, I, E>,
+            ) -> Result<Self, FaerError> {
+                let len_values = symbolic.inner.len_values();
+                let len_indices = symbolic.inner.len_indices();
+                let mut values = VecGroup::new();
+                let mut indices = alloc::vec::Vec::new();
+                values
+                    .try_reserve_exact(len_values)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                indices
+                    .try_reserve_exact(len_indices)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                values.resize(len_values, E::faer_zero().faer_into_units());
+                indices.resize(len_indices, I::truncate(0));
+                let parallelism = get_global_parallelism();
+                let mut workspace = Workspace::default();
+                workspace.reserve(
+                    symbolic
+                        .inner
+                        .solve_in_place_req::<E>(1, parallelism)
+                        .map_err(|_| FaerError::OutOfMemory)?,
+                );
+                symbolic.inner.factorize_numeric_qr::<E>(
+                    &mut indices,
+                    values.as_slice_mut().into_inner(),
+                    mat,
+                    parallelism,
+                    workspace.stack(
+                        symbolic
+                            .inner
+                            .factorize_numeric_qr_req::<E>(parallelism)
+                            .map_err(|_| FaerError::OutOfMemory)?,
+                    ),
+                );
+                Ok(Self {
+                    symbolic,
+                    indices,
+                    values,
+                    workspace,
+                })
+            }
+
+            /// Recomputes the numeric factorization for `mat`, which must have the same sparsity
+            /// pattern as the matrix that was used to compute the symbolic factorization, reusing
+            /// the storage and workspace of `self`.
+            #[track_caller]
+            pub fn try_refactor(
+                &mut self,
+                mat: SparseColMatRef<'_, I, E>,
+            ) -> Result<(), FaerError> {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .symbolic
+                    .inner
+                    .factorize_numeric_qr_req::<E>(parallelism)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                self.symbolic.inner.factorize_numeric_qr::<E>(
+                    &mut self.indices,
+                    self.values.as_slice_mut().into_inner(),
+                    mat,
+                    parallelism,
+                    self.workspace.stack(req),
+                );
+                Ok(())
+            }
+
+            /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`. See
+            /// [`crate::solvers::PartialPivLu::log_determinant`].
+            ///
+            /// # Panics
+            /// Panics if the matrix is not square.
+            #[track_caller]
+            pub fn log_determinant(&self) -> (E, E::Real) {
+                let n = self.ncols();
+                assert!(self.nrows() == n);
+                let qr = unsafe {
+                    faer_sparse::qr::QrRef::<'_, I, E>::new_unchecked(
+                        &self.symbolic.inner,
+                        &self.indices,
+                        self.values.as_slice().into_inner(),
+                    )
+                };
+                let mut diag = Col::<E>::zeros(n);
+                qr.r_diagonal(diag.as_mut());
+                let mut det = LogDeterminant::<E>::new();
+                for i in 0..n {
+                    det.mul(diag.read(i));
+                }
+                det.negate_if(qr.qp_determinant_is_negative());
+                det.finish()
+            }
+        }
+
+        impl<I: Index, E: ComplexField> Lu<I, E> {
+            #[track_caller]
+            pub fn try_new_with_symbolic(
+                symbolic: SymbolicLu<I>,
+                mat: SparseColMatRef<'_, I, E>,
+            ) -> Result<Self, LuError> {
+                let mut numeric = faer_sparse::lu::NumericLu::new();
+                let parallelism = get_global_parallelism();
+                let mut workspace = Workspace::default();
+                workspace.reserve(
+                    symbolic
+                        .inner
+                        .solve_in_place_req::<E>(1, parallelism)
+                        .map_err(|_| FaerError::OutOfMemory)?,
+                );
+                symbolic.inner.factorize_numeric_lu::<E>(
+                    &mut numeric,
+                    mat,
+                    parallelism,
+                    workspace.stack(
+                        symbolic
+                            .inner
+                            .factorize_numeric_lu_req::<E>(parallelism)
+                            .map_err(|_| FaerError::OutOfMemory)?,
+                    ),
+                )?;
+                Ok(Self {
+                    symbolic,
+                    numeric,
+                    workspace,
+                })
+            }
+
+            /// Recomputes the numeric factorization for `mat`, which must have the same sparsity
+            /// pattern as the matrix that was used to compute the symbolic factorization, reusing
+            /// the storage and workspace of `self`.
+            ///
+            /// If the factorization fails, the error is returned and the contents of `self` are
+            /// unspecified until the next successful refactorization.
+            #[track_caller]
+            pub fn try_refactor(&mut self, mat: SparseColMatRef<'_, I, E>) -> Result<(), LuError> {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .symbolic
+                    .inner
+                    .factorize_numeric_lu_req::<E>(parallelism)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                self.symbolic.inner.factorize_numeric_lu::<E>(
+                    &mut self.numeric,
+                    mat,
+                    parallelism,
+                    self.workspace.stack(req),
+                )?;
+                Ok(())
+            }
+
+            /// Returns the determinant of the original matrix as a pair `(phase, log_abs)`, such
+            /// that the determinant is equal to `phase * exp(log_abs)`. The phase has a unit
+            /// modulus, or is zero if the matrix is singular, in which case `log_abs` is negative
+            /// infinity.
+            pub fn log_determinant(&self) -> (E, E::Real) {
+                let n = self.nrows();
+                let lu = unsafe {
+                    faer_sparse::lu::LuRef::<'_, I, E>::new_unchecked(
+                        &self.symbolic.inner,
+                        &self.numeric,
+                    )
+                };
+                let mut diag = Col::<E>::zeros(n);
+                lu.u_diagonal(diag.as_mut());
+                let mut det = LogDeterminant::<E>::new();
+                for i in 0..n {
+                    det.mul(diag.read(i));
+                }
+                let (row_perm, _) = lu.row_perm().into_arrays();
+                let (col_perm, _) = lu.col_perm().into_arrays();
+                det.negate_if(permutation_is_odd(row_perm) != permutation_is_odd(col_perm));
+                det.finish()
+            }
+        }
+
+        impl<I: Index, E: ComplexField> Lblt<I, E> {
+            /// Computes the Bunch-Kaufman decomposition of `mat`, using a supernodal symbolic
+            /// factorization so that pivoting is performed within each supernode. Only the provided
+            /// side is accessed.
+            #[track_caller]
+            pub fn try_new(mat: SparseColMatRef<'_, I, E>, side: Side) -> Result<Self, FaerError> {
+                let symbolic = SymbolicCholesky::try_new_supernodal(mat.symbolic(), side)?;
+                Self::try_new_with_symbolic(symbolic, mat, side)
+            }
+
+            /// Computes the Bunch-Kaufman decomposition of `mat`, reusing the given symbolic
+            /// factorization.
+            ///
+            /// If the symbolic factorization is simplicial, no pivoting is performed and the
+            /// result is an LDLT decomposition.
+            #[track_caller]
+            pub fn try_new_with_symbolic(
+                symbolic: SymbolicCholesky<I>,
+                mat: SparseColMatRef<'_, I, E>,
+                side: Side,
+            ) -> Result<Self, FaerError> {
+                let n = symbolic.inner.nrows();
+                let len_values = symbolic.inner.len_values();
+
+                let mut values = VecGroup::new();
+                let mut subdiag = VecGroup::new();
+                let mut perm_fwd = alloc::vec::Vec::new();
+                let mut perm_inv = alloc::vec::Vec::new();
+                values
+                    .try_reserve_exact(len_values)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                subdiag
+                    .try_reserve_exact(n)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                perm_fwd
+                    .try_reserve_exact(n)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                perm_inv
+                    .try_reserve_exact(n)
+                    .map_err(|_| FaerError::OutOfMemory)?;
+                values.resize(len_values, E::faer_zero().faer_into_units());
+                subdiag.resize(n, E::faer_zero().faer_into_units());
+                perm_fwd.resize(n, I::truncate(0));
+                perm_inv.resize(n, I::truncate(0));
+
+                let parallelism = get_global_parallelism();
+                let mut workspace = Workspace::default();
+                workspace.reserve(
+                    symbolic
+                        .inner
+                        .solve_in_place_req::<E>(1)
+                        .map_err(|_| FaerError::OutOfMemory)?,
+                );
+                symbolic
+                    .inner
+                    .factorize_numeric_intranode_bunch_kaufman::<E>(
+                        values.as_slice_mut().into_inner(),
+                        subdiag.as_slice_mut().into_inner(),
+                        &mut perm_fwd,
+                        &mut perm_inv,
+                        mat,
+                        side,
+                        Default::default(),
+                        parallelism,
+                        workspace.stack(
+                            symbolic
+                                .inner
+                                .factorize_numeric_intranode_bunch_kaufman_req::<E>(
+                                    false,
+                                    parallelism,
+                                )
+                                .map_err(|_| FaerError::OutOfMemory)?,
+                        ),
+                    );
+                Ok(Self {
+                    symbolic,
+                    values,
+                    subdiag,
+                    perm_fwd,
+                    perm_inv,
+                    workspace,
+                })
+            }
+
+            #[inline]
+            fn as_ref(&self) -> faer_sparse::cholesky::IntranodeBunchKaufmanRef<'_, I, E> {
+                faer_sparse::cholesky::IntranodeBunchKaufmanRef::<'_, I, E>::new(
+                    &self.symbolic.inner,
+                    self.values.as_slice().into_inner(),
+                    self.subdiag.as_slice().into_inner(),
+                    unsafe {
+                        faer_core::permutation::PermutationRef::new_unchecked(
+                            &self.perm_fwd,
+                            &self.perm_inv,
+                        )
+                    },
+                )
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SolveWithStack<E> for Cholesky<I, E> {
+            fn solve_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                let _ = parallelism;
+                self.symbolic.inner.solve_in_place_req::<E>(rhs_ncols)
+            }
+
+            fn solve_transpose_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                self.solve_in_place_req(rhs_ncols, parallelism)
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                faer_sparse::cholesky::LltRef::<'_, I, E>::new(
+                    &self.symbolic.inner,
+                    self.values.as_slice().into_inner(),
+                )
+                .solve_in_place_with_conj(conj, rhs, parallelism, stack);
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SpSolverCore<E> for Cholesky<I, E> {
+            #[inline]
+            fn nrows(&self) -> usize {
+                self.symbolic.inner.nrows()
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                self.symbolic.inner.ncols()
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+                self.solve_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                    .unwrap();
+                self.solve_transpose_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SolveWithStack<E> for Lblt<I, E> {
+            fn solve_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                let _ = parallelism;
+                self.symbolic.inner.solve_in_place_req::<E>(rhs_ncols)
+            }
+
+            fn solve_transpose_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                self.solve_in_place_req(rhs_ncols, parallelism)
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                self.as_ref()
+                    .solve_in_place_with_conj(conj, rhs, parallelism, stack);
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                self.solve_in_place_with_stack(rhs, conj.compose(Conj::Yes), parallelism, stack)
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SpSolverCore<E> for Lblt<I, E> {
+            #[inline]
+            fn nrows(&self) -> usize {
+                self.symbolic.inner.nrows()
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                self.symbolic.inner.ncols()
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+                self.solve_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                    .unwrap();
+                self.solve_transpose_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SolveWithStack<E> for Qr<I, E> {
+            fn solve_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                self.symbolic
+                    .inner
+                    .solve_in_place_req::<E>(rhs_ncols, parallelism)
+            }
+
+            fn solve_transpose_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                let _ = (rhs_ncols, parallelism);
+                Ok(StackReq::empty())
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                unsafe {
+                    faer_sparse::qr::QrRef::<'_, I, E>::new_unchecked(
+                        &self.symbolic.inner,
+                        &self.indices,
+                        self.values.as_slice().into_inner(),
+                    )
+                }
+                .solve_in_place_with_conj(conj, rhs, parallelism, stack);
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                let _ = (parallelism, stack);
+                self.solve_transpose_in_place_with_conj_impl(rhs, conj)
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SpSolverCore<E> for Qr<I, E> {
+            #[inline]
+            fn nrows(&self) -> usize {
+                self.symbolic.inner.nrows()
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                self.symbolic.inner.ncols()
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                self.solve_lstsq_in_place_with_conj_impl(rhs, conj);
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let _ = (&rhs, &conj);
+                unimplemented!(
+                    "the sparse QR decomposition doesn't support solve_transpose.\n\
+                               consider using the sparse LU or Cholesky instead."
+                )
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SpSolverLstsqCore<E> for Qr<I, E> {
+            #[track_caller]
+            fn solve_lstsq_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+                self.solve_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SolveWithStack<E> for Lu<I, E> {
+            fn solve_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                self.symbolic
+                    .inner
+                    .solve_in_place_req::<E>(rhs_ncols, parallelism)
+            }
+
+            fn solve_transpose_in_place_req(
+                &self,
+                rhs_ncols: usize,
+                parallelism: Parallelism,
+            ) -> Result<StackReq, dyn_stack::SizeOverflow> {
+                self.solve_in_place_req(rhs_ncols, parallelism)
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                unsafe {
+                    faer_sparse::lu::LuRef::<'_, I, E>::new_unchecked(
+                        &self.symbolic.inner,
+                        &self.numeric,
+                    )
+                }
+                .solve_in_place_with_conj(conj, rhs, parallelism, stack);
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_stack(
+                &self,
+                rhs: MatMut<'_, E>,
+                conj: Conj,
+                parallelism: Parallelism,
+                stack: PodStack<'_>,
+            ) {
+                unsafe {
+                    faer_sparse::lu::LuRef::<'_, I, E>::new_unchecked(
+                        &self.symbolic.inner,
+                        &self.numeric,
+                    )
+                }
+                .solve_transpose_in_place_with_conj(conj, rhs, parallelism, stack);
+            }
+        }
+
+        impl<I: Index, E: ComplexField> SpSolverCore<E> for Lu<I, E> {
+            #[inline]
+            fn nrows(&self) -> usize {
+                self.symbolic.inner.nrows()
+            }
+            #[inline]
+            fn ncols(&self) -> usize {
+                self.symbolic.inner.ncols()
+            }
+
+            #[track_caller]
+            fn solve_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self.solve_in_place_req(rhs.ncols(), parallelism).unwrap();
+                self.solve_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+
+            #[track_caller]
+            fn solve_transpose_in_place_with_conj_impl(&self, rhs: MatMut<'_, E>, conj: Conj) {
+                let parallelism = get_global_parallelism();
+                let req = self
+                    .solve_transpose_in_place_req(rhs.ncols(), parallelism)
+                    .unwrap();
+                self.solve_transpose_in_place_with_stack(
+                    rhs,
+                    conj,
+                    parallelism,
+                    self.workspace.lock().stack(req),
+                );
+            }
+        }
+
+        impl_inverse_linop! {
+            {I: Index, E: ComplexField} Cholesky<I, E>;
+            {I: Index, E: ComplexField} Lblt<I, E>;
+            {I: Index, E: ComplexField} Lu<I, E>;
+        }
+
+        impl_release_workspace! {
+            {I, E: Entity} Cholesky<I, E>;
+            {I, E: Entity} Qr<I, E>;
+            {I, E: Entity} Lblt<I, E>;
+            {I, E: Entity} Lu<I, E>;
+        }
+    }
+
+    /// Extension trait for sparse `faer` types.
+    pub trait FaerSparseMat<I: Index, E: ComplexField> {
+        /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
+        /// stores the result in `rhs`.
+        ///
+        /// The diagonal element is assumed to be the first stored element in each column, if the
+        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        fn sp_solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+        /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
+        /// stores the result in `rhs`.
+        ///
+        /// The diagonal element is assumed to be the last stored element in each column, if the
+        /// matrix is column-major, or the first stored element in each row, if it is row-major.
+        fn sp_solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+        /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
+        /// and stores the result in `rhs`.
+        ///
+        /// The diagonal element is assumed to be the first stored element in each column, if the
+        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        fn sp_solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+        /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
+        /// and stores the result in `rhs`.
+        ///
+        /// The diagonal element is assumed to be the last stored element in each column, if the
+        /// matrix is column-major, or the first stored element in each row, if it is row-major.
+        fn sp_solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E>);
+
+        /// Assuming `self` is a lower triangular matrix, solves the equation `self * X = rhs`, and
+        /// returns the result.
+        ///
+        /// The diagonal element is assumed to be the first stored element in each column, if the
+        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        #[track_caller]
+        fn sp_solve_lower_triangular<ViewE: Conjugate<Canonical = E>>(
+            &self,
+            rhs: impl AsMatRef<ViewE>,
+        ) -> Mat<E> {
+            let mut rhs = rhs.as_mat_ref().to_owned();
+            self.sp_solve_lower_triangular_in_place(rhs.as_mut());
+            rhs
+        }
+        /// Assuming `self` is an upper triangular matrix, solves the equation `self * X = rhs`, and
+        /// returns the result.
+        ///
+        /// The diagonal element is assumed to be the last stored element in each column, if the
+        /// matrix is column-major, or the first stored element in each row, if it is row-major.
+        #[track_caller]
+        fn sp_solve_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+            &self,
+            rhs: impl AsMatRef<ViewE>,
+        ) -> Mat<E> {
+            let mut rhs = rhs.as_mat_ref().to_owned();
+            self.sp_solve_upper_triangular_in_place(rhs.as_mut());
+            rhs
+        }
+        /// Assuming `self` is a unit lower triangular matrix, solves the equation `self * X = rhs`,
+        /// and returns the result.
+        ///
+        /// The diagonal element is assumed to be the first stored element in each column, if the
+        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        #[track_caller]
+        fn sp_solve_unit_lower_triangular<ViewE: Conjugate<Canonical = E>>(
+            &self,
+            rhs: impl AsMatRef<ViewE>,
+        ) -> Mat<E> {
+            let mut rhs = rhs.as_mat_ref().to_owned();
+            self.sp_solve_unit_lower_triangular_in_place(rhs.as_mut());
+            rhs
+        }
+        /// Assuming `self` is a unit upper triangular matrix, solves the equation `self * X = rhs`,
+        /// and returns the result.
+        ///
+        /// The diagonal element is assumed to be the first stored element in each column, if the
+        /// matrix is column-major, or the last stored element in each row, if it is row-major.
+        #[track_caller]
+        fn sp_solve_unit_upper_triangular<ViewE: Conjugate<Canonical = E>>(
+            &self,
+            rhs: impl AsMatRef<ViewE>,
+        ) -> Mat<E> {
+            let mut rhs = rhs.as_mat_ref().to_owned();
+            self.sp_solve_unit_upper_triangular_in_place(rhs.as_mut());
+            rhs
+        }
+
+        /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
+        fn sp_cholesky(&self, side: Side)
+            -> Result<solvers::Cholesky<I, E>, sparse::CholeskyError>;
+
+        /// Returns the LU decomposition of `self` with partial (row) pivoting.
+        fn sp_lu(&self) -> Result<solvers::Lu<I, E>, LuError>;
+
+        /// Returns the QR decomposition of `self`.
+        fn sp_qr(&self) -> Result<solvers::Qr<I, E>, FaerError>;
+
+        /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
+        fn sp_lblt(&self, side: Side) -> Result<solvers::Lblt<I, E>, FaerError>;
+
+        /// Returns a decomposition of `self` chosen from its structure, along with a report of the
+        /// detected structure and the chosen decomposition.
+        ///
+        /// Self-adjoint matrices are first factorized with the Cholesky decomposition, falling back
+        /// to the Bunch-Kaufman decomposition if the matrix is not positive definite. Other square
+        /// matrices are factorized with the LU decomposition. Matrices with more rows than columns
+        /// are factorized with the QR decomposition, in which case the returned solver computes
+        /// the least squares solution in the top `self.ncols()` rows of the right-hand side.
+        ///
+        /// Returns [`SolverError::Underdetermined`](crate::SolverError::Underdetermined) if
+        /// `self` has fewer rows than columns.
+        fn sp_solve_auto(
+            &self,
+        ) -> Result<
+            (
+                alloc::boxed::Box<dyn solvers::SpSolverCore<E>>,
+                solvers::SpSolveReport,
+            ),
+            crate::SolverError,
+        >;
+    }
+
+    /// Returns whether the sparsity pattern of `mat` is symmetric, and whether `mat` is equal to
+    /// its adjoint.
+    fn sp_symmetry<I: Index, E: ComplexField>(mat: SparseColMatRef<'_, I, E>) -> (bool, bool) {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let n = mat.ncols();
+        if mat.nrows() != n {
+            return (false, false);
+        }
+
+        // transposing builds each column in increasing row order, so transposing twice
+        // yields the original matrix with sorted row indices.
+        fn transpose<E: Copy>(
+            n: usize,
+            col_ptr: &[usize],
+            row_ind: &[usize],
+            values: &[E],
+        ) -> (Vec<usize>, Vec<usize>, Vec<E>) {
+            let nnz = col_ptr[n];
+            let mut t_col_ptr = vec![0usize; n + 1];
+            for &i in row_ind {
+                t_col_ptr[i + 1] += 1;
+            }
+            for i in 0..n {
+                t_col_ptr[i + 1] += t_col_ptr[i];
+            }
+            let mut pos = t_col_ptr.clone();
+            let mut t_row_ind = vec![0usize; nnz];
+            let mut t_values = values.to_vec();
+            for j in 0..n {
+                for idx in col_ptr[j]..col_ptr[j + 1] {
+                    let i = row_ind[idx];
+                    t_row_ind[pos[i]] = j;
+                    t_values[pos[i]] = values[idx];
+                    pos[i] += 1;
+                }
+            }
+            (t_col_ptr, t_row_ind, t_values)
+        }
+
+        let mut col_ptr = Vec::with_capacity(n + 1);
+        let mut row_ind = Vec::new();
+        let mut values = Vec::<E>::new();
+        col_ptr.push(0usize);
+        for j in 0..n {
+            row_ind.extend(mat.row_indices_of_col(j));
+            values.extend(
+                SliceGroup::<'_, E>::new(mat.values_of_col(j))
+                    .into_ref_iter()
+                    .map(|x| x.read()),
+            );
+            col_ptr.push(row_ind.len());
+        }
+        let (t_col_ptr, t_row_ind, t_values) = transpose(n, &col_ptr, &row_ind, &values);
+        let (col_ptr, row_ind, values) = transpose(n, &t_col_ptr, &t_row_ind, &t_values);
+
+        if col_ptr != t_col_ptr || row_ind != t_row_ind {
+            return (false, false);
+        }
+        let selfadjoint = values
+            .iter()
+            .zip(t_values.iter())
+            .all(|(&a, &b)| a == b.faer_conj());
+        (true, selfadjoint)
+    }
+
+    impl<I: Index, E: ComplexField> FaerSparseMat<I, E> for SparseColMatRef<'_, I, E> {
+        #[track_caller]
+        fn sp_solve_lower_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
+            faer_sparse::triangular_solve::solve_lower_triangular_in_place(
+                *self,
+                Conj::No,
+                rhs.as_mat_mut(),
+                get_global_parallelism(),
+            );
+        }
+        #[track_caller]
+        fn sp_solve_upper_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
+            faer_sparse::triangular_solve::solve_upper_triangular_in_place(
+                *self,
+                Conj::No,
+                rhs.as_mat_mut(),
+                get_global_parallelism(),
+            );
+        }
+        #[track_caller]
+        fn sp_solve_unit_lower_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
+            faer_sparse::triangular_solve::solve_unit_lower_triangular_in_place(
+                *self,
+                Conj::No,
+                rhs.as_mat_mut(),
+                get_global_parallelism(),
+            );
+        }
+        #[track_caller]
+        fn sp_solve_unit_upper_triangular_in_place(&self, mut rhs: impl AsMatMut<E>) {
+            faer_sparse::triangular_solve::solve_unit_upper_triangular_in_place(
+                *self,
+                Conj::No,
+                rhs.as_mat_mut(),
+                get_global_parallelism(),
+            );
+        }
+
+        /// Returns the Cholesky decomposition of `self`. Only the provided side is accessed.
+        #[track_caller]
+        fn sp_cholesky(
+            &self,
+            side: Side,
+        ) -> Result<solvers::Cholesky<I, E>, sparse::CholeskyError> {
+            solvers::Cholesky::try_new_with_symbolic(
+                solvers::SymbolicCholesky::try_new(self.symbolic(), side)?,
+                *self,
+                side,
+            )
+        }
+
+        /// Returns the LU decomposition of `self` with partial (row) pivoting.
+        #[track_caller]
+        fn sp_lu(&self) -> Result<solvers::Lu<I, E>, LuError> {
+            solvers::Lu::try_new_with_symbolic(
+                solvers::SymbolicLu::try_new(self.symbolic())?,
+                *self,
+            )
+        }
+
+        /// Returns the QR decomposition of `self`.
+        #[track_caller]
+        fn sp_qr(&self) -> Result<solvers::Qr<I, E>, FaerError> {
+            solvers::Qr::try_new_with_symbolic(
+                solvers::SymbolicQr::try_new(self.symbolic())?,
+                *self,
+            )
+        }
+
+        /// Returns the Bunch-Kaufman decomposition of `self`. Only the provided side is accessed.
+        #[track_caller]
+        fn sp_lblt(&self, side: Side) -> Result<solvers::Lblt<I, E>, FaerError> {
+            solvers::Lblt::try_new(*self, side)
+        }
+
+        #[track_caller]
+        fn sp_solve_auto(
+            &self,
+        ) -> Result<
+            (
+                alloc::boxed::Box<dyn solvers::SpSolverCore<E>>,
+                solvers::SpSolveReport,
+            ),
+            crate::SolverError,
+        > {
+            use alloc::boxed::Box;
+            use solvers::{SpSolvePath, SpSolveReport};
+
+            if self.nrows() < self.ncols() {
+                return Err(crate::SolverError::Underdetermined {
+                    nrows: self.nrows(),
+                    ncols: self.ncols(),
+                });
+            }
+
+            let (structurally_symmetric, selfadjoint) = sp_symmetry(*self);
+            let report = |path| SpSolveReport {
+                structurally_symmetric,
+                selfadjoint,
+                path,
+            };
+
+            if self.nrows() != self.ncols() {
+                return Ok((Box::new(self.sp_qr()?), report(SpSolvePath::Qr)));
+            }
+
+            if selfadjoint {
+                // the supernodal structure is shared by both attempts, so that the fallback can
+                // pivot without recomputing the symbolic factorization
+                let symbolic =
+                    solvers::SymbolicCholesky::try_new_supernodal(self.symbolic(), Side::Lower)?;
+                match solvers::Cholesky::try_new_with_symbolic(symbolic.clone(), *self, Side::Lower)
+                {
+                    Ok(llt) => return Ok((Box::new(llt), report(SpSolvePath::Cholesky))),
+                    Err(sparse::CholeskyError::Generic(err)) => return Err(err.into()),
+                    Err(_) => {
+                        let lblt =
+                            solvers::Lblt::try_new_with_symbolic(symbolic, *self, Side::Lower)?;
+                        return Ok((Box::new(lblt), report(SpSolvePath::Lblt)));
+                    }
+                }
+            }
+
+            Ok((Box::new(self.sp_lu()?), report(SpSolvePath::Lu)))
+        }
+    }
+}
+
+/// Computes the singular values of `matrix`, and whether the SVD converged.
+#[track_caller]
+fn singular_values_impl<E: Conjugate>(
+    matrix: MatRef<'_, E>,
+) -> (Vec<<E::Canonical as ComplexField>::Real>, bool)
+where
+    E::Canonical: ComplexField,
+{
+    let dim = Ord::min(matrix.nrows(), matrix.ncols());
+    let parallelism = get_global_parallelism();
+
+    let mut s = Mat::<E::Canonical>::zeros(dim, 1);
+    let params = Default::default();
+    let info = faer_svd::compute_svd(
+        matrix.canonicalize().0,
+        s.as_mut(),
+        None,
+        None,
+        parallelism,
+        PodStack::new(&mut GlobalPodBuffer::new(
+            faer_svd::compute_svd_req::<E::Canonical>(
+                matrix.nrows(),
+                matrix.ncols(),
+                faer_svd::ComputeVectors::No,
+                faer_svd::ComputeVectors::No,
+                parallelism,
+                params,
+            )
+            .unwrap(),
+        )),
+        params,
+    );
+
+    let values = (0..dim).map(|i| s.read(i, 0).faer_real()).collect();
+    (values, info.converged)
+}
+
+/// Computes the eigenvalues of the self-adjoint `matrix`, and whether the algorithm converged.
+#[track_caller]
+fn selfadjoint_eigenvalues_impl<E: Conjugate>(
+    matrix: MatRef<'_, E>,
+    side: Side,
+) -> (Vec<<E::Canonical as ComplexField>::Real>, bool)
+where
+    E::Canonical: ComplexField,
+{
+    let matrix = match side {
+        Side::Lower => matrix,
+        Side::Upper => matrix.transpose(),
+    };
+
+    assert!(matrix.nrows() == matrix.ncols());
+    let dim = matrix.nrows();
+    let parallelism = get_global_parallelism();
+
+    let mut s = Mat::<E::Canonical>::zeros(dim, 1);
+    let params = Default::default();
+    let info = faer_evd::compute_hermitian_evd(
+        matrix.canonicalize().0,
+        s.as_mut(),
+        None,
+        parallelism,
+        PodStack::new(&mut GlobalPodBuffer::new(
+            faer_evd::compute_hermitian_evd_req::<E::Canonical>(
+                dim,
+                faer_evd::ComputeVectors::No,
+                parallelism,
+                params,
+            )
+            .unwrap(),
+        )),
+        params,
+    );
+
+    let values = (0..dim).map(|i| s.read(i, 0).faer_real()).collect();
+    (values, info.converged)
+}
+
+/// Computes the eigenvalues of `matrix`, and whether the QR algorithm converged.
+#[track_caller]
+fn eigenvalues_impl<
+    E: Conjugate,
+    ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+>(
+    matrix: MatRef<'_, E>,
+) -> (Vec<ComplexE>, bool)
+where
+    E::Canonical: ComplexField,
+{
+    if coe::is_same::<E, <E::Canonical as ComplexField>::Real>() {
+        let matrix: MatRef<'_, <E::Canonical as ComplexField>::Real> = coe::coerce(matrix);
+        Eigendecomposition::<ComplexE>::__values_from_real(matrix)
+    } else if coe::is_same::<E::Canonical, ComplexE>() {
+        let (matrix, conj) = matrix.canonicalize();
+        Eigendecomposition::<ComplexE>::__values_from_complex_impl((coe::coerce(matrix), conj))
+    } else {
+        panic!(
+            "The type ComplexE must be either E::Canonical ({}) or E::Canonical::Real ({})",
+            core::any::type_name::<E::Canonical>(),
+            core::any::type_name::<<E::Canonical as ComplexField>::Real>(),
+        );
+    }
+}
+
+/// Solves the orthogonal Procrustes problem, and returns whether the SVD converged. See
+/// [`FaerMat::procrustes`].
+#[track_caller]
+fn procrustes_impl<E: Conjugate, ViewE: Conjugate<Canonical = E::Canonical>>(
+    a: MatRef<'_, E>,
+    b: MatRef<'_, ViewE>,
+    constraint: ProcrustesConstraint,
+) -> (Mat<E::Canonical>, bool)
+where
+    E::Canonical: ComplexField,
+{
+    assert!(a.nrows() == b.nrows());
+    assert!(a.ncols() == b.ncols());
+
+    // the solution is the unitary polar factor of A^H B
+    let svd = (a.adjoint() * b).thin_svd();
+    let mut u = svd.u().to_owned();
+    let v = svd.v();
+
+    if constraint == ProcrustesConstraint::SpecialUnitary && u.ncols() > 0 {
+        // flip the phase of the direction associated with the smallest singular value
+        let det = (&u * v.adjoint()).determinant();
+        let abs = det.faer_abs();
+        if abs > <E::Canonical as ComplexField>::Real::faer_zero() {
+            let phase = det.faer_scale_real(abs.faer_inv()).faer_conj();
+            let j = u.ncols() - 1;
+            for i in 0..u.nrows() {
+                u.write(i, j, u.read(i, j).faer_mul(phase));
+            }
+        }
+    }
+
+    (u * v.adjoint(), svd.converged())
+}
+
+impl<E: Conjugate> FaerMat<E::Canonical> for MatRef<'_, E>
+where
+    E::Canonical: ComplexField,
+{
+    #[track_caller]
+    fn solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
+        let parallelism = get_global_parallelism();
+        let mut rhs = rhs;
+        faer_core::solve::solve_lower_triangular_in_place(*self, rhs.as_mat_mut(), parallelism);
+    }
+    #[track_caller]
+    fn solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
+        let parallelism = get_global_parallelism();
+        let mut rhs = rhs;
+        faer_core::solve::solve_upper_triangular_in_place(*self, rhs.as_mat_mut(), parallelism);
+    }
+    #[track_caller]
+    fn solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
+        let parallelism = get_global_parallelism();
+        let mut rhs = rhs;
+        faer_core::solve::solve_unit_lower_triangular_in_place(
+            *self,
+            rhs.as_mat_mut(),
+            parallelism,
+        );
+    }
+    #[track_caller]
+    fn solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
+        let parallelism = get_global_parallelism();
+        let mut rhs = rhs;
+        faer_core::solve::solve_unit_upper_triangular_in_place(
+            *self,
+            rhs.as_mat_mut(),
+            parallelism,
+        );
+    }
+
+    #[track_caller]
+    fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
+        Cholesky::try_new(self.as_ref(), side)
+    }
+    #[track_caller]
+    fn ldlt(&self, side: Side) -> Ldlt<E::Canonical> {
+        Ldlt::new(self.as_ref(), side)
+    }
+    #[track_caller]
+    fn pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> PivotedCholesky<E::Canonical> {
+        PivotedCholesky::new(self.as_ref(), side, tol)
+    }
+    #[track_caller]
+    fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
+        Lblt::new(self.as_ref(), side)
+    }
+    #[track_caller]
+    fn ltlt(&self, side: Side) -> Ltlt<E::Canonical> {
+        Ltlt::new(self.as_ref(), side)
+    }
+    #[track_caller]
+    fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
+        PartialPivLu::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn full_piv_lu(&self) -> FullPivLu<E::Canonical> {
+        FullPivLu::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn qr(&self) -> Qr<E::Canonical> {
+        Qr::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn col_piv_qr(&self) -> ColPivQr<E::Canonical> {
+        ColPivQr::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn svd(&self) -> Svd<E::Canonical> {
+        Svd::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn thin_svd(&self) -> ThinSvd<E::Canonical> {
+        ThinSvd::<E::Canonical>::new(self.as_ref())
+    }
+    #[track_caller]
+    fn selfadjoint_eigendecomposition(
+        &self,
+        side: Side,
+    ) -> SelfAdjointEigendecomposition<E::Canonical> {
+        SelfAdjointEigendecomposition::<E::Canonical>::new(self.as_ref(), side)
+    }
+    #[track_caller]
+    fn selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> PartialSelfAdjointEigendecomposition<E::Canonical> {
+        PartialSelfAdjointEigendecomposition::<E::Canonical>::new(self.as_ref(), side, range)
+    }
+    #[track_caller]
+    fn selfadjoint_generalized_eigendecomposition<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, CholeskyError> {
+        SelfAdjointGeneralizedEigendecomposition::<E::Canonical>::try_new(
+            self.as_ref(),
+            b.as_mat_ref(),
+            side,
+            GeneralizedEigenProblem::AxLambdaBx,
+        )
+    }
+
+    #[track_caller]
+    fn gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Gsvd<E::Canonical> {
+        Gsvd::<E::Canonical>::new(self.as_ref(), b.as_mat_ref())
+    }
+
+    #[track_caller]
+    fn polar(&self) -> Polar<E::Canonical> {
+        Polar::<E::Canonical>::new(self.as_ref())
+    }
+
+    #[track_caller]
+    fn procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> (Mat<E::Canonical>, bool) {
+        procrustes_impl(*self, b.as_mat_ref(), constraint)
+    }
+
+    #[track_caller]
+    fn solve_auto<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> (Mat<E::Canonical>, SolvePath) {
+        let rhs = rhs.as_mat_ref();
+        let m = self.nrows();
+        let n = self.ncols();
+        assert!(m == rhs.nrows());
+
+        if m > n {
+            return (self.qr().solve_lstsq(rhs), SolvePath::Qr);
+        }
+        if m < n {
+            // A = R^H Q^H, so that the minimum norm solution is Q R^-H b
+            let qr = self.adjoint().qr();
+            let r = qr.compute_thin_r();
+            let y = r.adjoint().solve_lower_triangular(rhs);
+            return (qr.compute_thin_q() * y, SolvePath::QrMinimumNorm);
+        }
+
+        let zero = E::Canonical::faer_zero();
+        let real_zero = <E::Canonical as ComplexField>::Real::faer_zero();
+
+        let mut max_abs = real_zero;
+        for j in 0..n {
+            for i in 0..n {
+                let abs = self.read(i, j).canonicalize().faer_abs();
+                if abs > max_abs {
+                    max_abs = abs;
+                }
+            }
+        }
+        // entries that are computed with a different order of operations, such as those of
+        // `A * A^H`, are only equal up to rounding errors
+        let tol = <E::Canonical as ComplexField>::Real::faer_epsilon()
+            .unwrap()
+            .faer_mul(<E::Canonical as ComplexField>::Real::faer_from_f64(n as f64))
+            .faer_mul(max_abs);
+
+        let mut lower = true;
+        let mut upper = true;
+        let mut selfadjoint = true;
+        let mut positive_diagonal = true;
+        for j in 0..n {
+            for i in 0..j {
+                let a_ij = self.read(i, j).canonicalize();
+                let a_ji = self.read(j, i).canonicalize();
+                if a_ij != zero {
+                    lower = false;
+                }
+                if a_ji != zero {
+                    upper = false;
+                }
+                if a_ij.faer_sub(a_ji.faer_conj()).faer_abs() > tol {
+                    selfadjoint = false;
+                }
+            }
+            let a_jj = self.read(j, j).canonicalize();
+            if a_jj.faer_imag().faer_abs() > tol {
+                selfadjoint = false;
+            }
+            if !(a_jj.faer_real() > real_zero) {
+                positive_diagonal = false;
+            }
+        }
+
+        if lower {
+            (self.solve_lower_triangular(rhs), SolvePath::LowerTriangular)
+        } else if upper {
+            (self.solve_upper_triangular(rhs), SolvePath::UpperTriangular)
+        } else if selfadjoint {
+            if positive_diagonal {
+                if let Ok(llt) = self.cholesky(Side::Lower) {
+                    return (llt.solve(rhs), SolvePath::Cholesky);
+                }
+            }
+            (self.lblt(Side::Lower).solve(rhs), SolvePath::Lblt)
+        } else {
+            (self.partial_piv_lu().solve(rhs), SolvePath::PartialPivLu)
+        }
+    }
+
+    #[track_caller]
+    fn eigendecomposition<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+        &self,
+    ) -> Eigendecomposition<ComplexE> {
+        if coe::is_same::<E, <E::Canonical as ComplexField>::Real>() {
+            let matrix: MatRef<'_, <E::Canonical as ComplexField>::Real> =
+                coe::coerce(self.as_ref());
+            Eigendecomposition::<ComplexE>::new_from_real(matrix)
+        } else if coe::is_same::<E::Canonical, ComplexE>() {
+            let (matrix, conj) = self.as_ref().canonicalize();
+            Eigendecomposition::<ComplexE>::__new_from_complex_impl(
+                (coe::coerce(matrix), conj),
+                Default::default(),
+                false,
+            )
+        } else {
+            panic!(
+                "The type ComplexE must be either E::Canonical ({}) or E::Canonical::Real ({})",
+                core::any::type_name::<E::Canonical>(),
+                core::any::type_name::<<E::Canonical as ComplexField>::Real>(),
+            );
+        }
+    }
+
+    #[track_caller]
+    fn complex_eigendecomposition(&self) -> Eigendecomposition<E::Canonical> {
+        Eigendecomposition::<E::Canonical>::new_from_complex(self.as_ref())
+    }
+
+    #[track_caller]
+    fn determinant(&self) -> E::Canonical {
+        assert!(self.nrows() == self.ncols());
+        let lu = self.partial_piv_lu();
+        let mut det = E::Canonical::faer_one();
+        for i in 0..self.nrows() {
+            det = det.faer_mul(lu.factors.read(i, i));
+        }
+        if lu.transposition_count() % 2 == 0 {
+            det
+        } else {
+            det.faer_neg()
+        }
+    }
+
+    #[track_caller]
+    fn selfadjoint_eigenvalues(&self, side: Side) -> Vec<<E::Canonical as ComplexField>::Real> {
+        selfadjoint_eigenvalues_impl(*self, side).0
+    }
+
+    #[track_caller]
+    fn singular_values(&self) -> Vec<<E::Canonical as ComplexField>::Real> {
+        singular_values_impl(*self).0
+    }
+
+    #[track_caller]
+    fn eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+        &self,
+    ) -> Vec<ComplexE> {
+        eigenvalues_impl(*self).0
+    }
+
+    #[track_caller]
+    fn complex_eigenvalues(&self) -> Vec<E::Canonical> {
+        Eigendecomposition::<E::Canonical>::__values_from_complex_impl(self.canonicalize()).0
+    }
+
+    #[track_caller]
+    fn try_cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        Ok(self.cholesky(side)?)
+    }
+    #[track_caller]
+    fn try_ldlt(&self, side: Side) -> Result<Ldlt<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        Ok(self.ldlt(side))
+    }
+    #[track_caller]
+    fn try_pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<PivotedCholesky<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        Ok(self.pivoted_cholesky(side, tol))
+    }
+    #[track_caller]
+    fn try_lblt(&self, side: Side) -> Result<Lblt<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        Ok(self.lblt(side))
+    }
+    #[track_caller]
+    fn try_ltlt(&self, side: Side) -> Result<Ltlt<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        Ok(self.ltlt(side))
+    }
+    #[track_caller]
+    fn try_partial_piv_lu(&self) -> Result<PartialPivLu<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), None)?;
+        Ok(self.partial_piv_lu())
+    }
+    #[track_caller]
+    fn try_full_piv_lu(&self) -> Result<FullPivLu<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        Ok(self.full_piv_lu())
+    }
+    #[track_caller]
+    fn try_qr(&self) -> Result<Qr<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        Ok(self.qr())
+    }
+    #[track_caller]
+    fn try_col_piv_qr(&self) -> Result<ColPivQr<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        Ok(self.col_piv_qr())
+    }
+    #[track_caller]
+    fn try_svd(&self) -> Result<Svd<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        let svd = self.svd();
+        check_converged(svd.converged())?;
+        Ok(svd)
+    }
+    #[track_caller]
+    fn try_thin_svd(&self) -> Result<ThinSvd<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        let svd = self.thin_svd();
+        check_converged(svd.converged())?;
+        Ok(svd)
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigendecomposition(
+        &self,
+        side: Side,
+    ) -> Result<SelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        let evd = self.selfadjoint_eigendecomposition(side);
+        check_converged(evd.converged())?;
+        Ok(evd)
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> Result<PartialSelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        if let EigenRange::IndexRange(lo, hi) = range {
+            if hi > self.nrows() {
+                return Err(SolverError::DimensionMismatch {
+                    expected: self.nrows(),
+                    found: hi,
+                });
+            }
+            if lo > hi {
+                return Err(SolverError::DimensionMismatch {
+                    expected: hi,
+                    found: lo,
+                });
+            }
+        }
+        check_finite(self.as_ref(), Some(side))?;
+        let evd = self.selfadjoint_eigendecomposition_range(side, range);
+        check_converged(evd.converged())?;
+        Ok(evd)
+    }
+    #[track_caller]
+    fn try_selfadjoint_generalized_eigendecomposition<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+    >(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, SolverError> {
+        let b = b.as_mat_ref();
+        check_square(self.as_ref())?;
+        check_square(b)?;
+        check_dim(self.nrows(), b.nrows())?;
+        check_finite(self.as_ref(), Some(side))?;
+        check_finite(b, Some(side))?;
+        let evd = self.selfadjoint_generalized_eigendecomposition(b, side)?;
+        check_converged(evd.converged())?;
+        Ok(evd)
+    }
+    #[track_caller]
+    fn try_gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Result<Gsvd<E::Canonical>, SolverError> {
+        let b = b.as_mat_ref();
+        check_dim(self.ncols(), b.ncols())?;
+        if self.nrows() + b.nrows() < self.ncols() {
+            return Err(SolverError::DimensionMismatch {
+                expected: self.ncols(),
+                found: self.nrows() + b.nrows(),
+            });
+        }
+        check_finite(self.as_ref(), None)?;
+        check_finite(b, None)?;
+        Ok(self.gsvd(b))
+    }
+    #[track_caller]
+    fn try_polar(&self) -> Result<Polar<E::Canonical>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        let polar = self.polar();
+        check_converged(polar.converged())?;
+        Ok(polar)
+    }
+    #[track_caller]
+    fn try_procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> Result<Mat<E::Canonical>, SolverError> {
+        let b = b.as_mat_ref();
+        check_dim(self.nrows(), b.nrows())?;
+        check_dim(self.ncols(), b.ncols())?;
+        check_finite(self.as_ref(), None)?;
+        check_finite(b, None)?;
+        let (omega, converged) = procrustes_impl(*self, b, constraint);
+        check_converged(converged)?;
+        Ok(omega)
+    }
+    #[track_caller]
+    fn try_eigendecomposition<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+    ) -> Result<Eigendecomposition<ComplexE>, SolverError> {
+        check_square(self.as_ref())?;
+        check_complex_scalar::<E, ComplexE>()?;
+        check_finite(self.as_ref(), None)?;
+        let evd = self.eigendecomposition::<ComplexE>();
+        check_converged(evd.converged())?;
+        Ok(evd)
+    }
+    #[track_caller]
+    fn try_complex_eigendecomposition(
+        &self,
+    ) -> Result<Eigendecomposition<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_complex_scalar::<E, E::Canonical>()?;
+        check_finite(self.as_ref(), None)?;
+        let evd = self.complex_eigendecomposition();
+        check_converged(evd.converged())?;
+        Ok(evd)
+    }
+    #[track_caller]
+    fn try_determinant(&self) -> Result<E::Canonical, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), None)?;
+        Ok(self.determinant())
+    }
+    #[track_caller]
+    fn try_singular_values(
+        &self,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        check_finite(self.as_ref(), None)?;
+        let (s, converged) = singular_values_impl(*self);
+        check_converged(converged)?;
+        Ok(s)
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigenvalues(
+        &self,
+        side: Side,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        check_square(self.as_ref())?;
+        check_finite(self.as_ref(), Some(side))?;
+        let (s, converged) = selfadjoint_eigenvalues_impl(*self, side);
+        check_converged(converged)?;
+        Ok(s)
+    }
+    #[track_caller]
+    fn try_eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+        &self,
+    ) -> Result<Vec<ComplexE>, SolverError> {
+        check_square(self.as_ref())?;
+        check_complex_scalar::<E, ComplexE>()?;
+        check_finite(self.as_ref(), None)?;
+        let (s, converged) = eigenvalues_impl(*self);
+        check_converged(converged)?;
+        Ok(s)
+    }
+    #[track_caller]
+    fn try_complex_eigenvalues(&self) -> Result<Vec<E::Canonical>, SolverError> {
+        check_square(self.as_ref())?;
+        check_complex_scalar::<E, E::Canonical>()?;
+        check_finite(self.as_ref(), None)?;
+        let (s, converged) =
+            Eigendecomposition::<E::Canonical>::__values_from_complex_impl(self.canonicalize());
+        check_converged(converged)?;
+        Ok(s)
     }
 }
 
-impl<E: Conjugate> FaerMat<E::Canonical> for MatRef<'_, E>
+impl<E: Conjugate> FaerMat<E::Canonical> for MatMut<'_, E>
 where
     E::Canonical: ComplexField,
 {
     #[track_caller]
     fn solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        let parallelism = get_global_parallelism();
-        let mut rhs = rhs;
-        faer_core::solve::solve_lower_triangular_in_place(*self, rhs.as_mat_mut(), parallelism);
+        self.as_ref().solve_lower_triangular_in_place(rhs)
     }
     #[track_caller]
     fn solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        let parallelism = get_global_parallelism();
-        let mut rhs = rhs;
-        faer_core::solve::solve_upper_triangular_in_place(*self, rhs.as_mat_mut(), parallelism);
+        self.as_ref().solve_upper_triangular_in_place(rhs)
     }
     #[track_caller]
     fn solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        let parallelism = get_global_parallelism();
-        let mut rhs = rhs;
-        faer_core::solve::solve_unit_lower_triangular_in_place(
-            *self,
-            rhs.as_mat_mut(),
-            parallelism,
-        );
+        self.as_ref().solve_unit_lower_triangular_in_place(rhs)
     }
     #[track_caller]
     fn solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        let parallelism = get_global_parallelism();
-        let mut rhs = rhs;
-        faer_core::solve::solve_unit_upper_triangular_in_place(
-            *self,
-            rhs.as_mat_mut(),
-            parallelism,
-        );
+        self.as_ref().solve_unit_upper_triangular_in_place(rhs)
     }
 
     #[track_caller]
     fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
-        Cholesky::try_new(self.as_ref(), side)
+        self.as_ref().cholesky(side)
+    }
+    #[track_caller]
+    fn ldlt(&self, side: Side) -> Ldlt<E::Canonical> {
+        self.as_ref().ldlt(side)
+    }
+    #[track_caller]
+    fn pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> PivotedCholesky<E::Canonical> {
+        self.as_ref().pivoted_cholesky(side, tol)
     }
     #[track_caller]
     fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
-        Lblt::new(self.as_ref(), side)
+        self.as_ref().lblt(side)
+    }
+    #[track_caller]
+    fn ltlt(&self, side: Side) -> Ltlt<E::Canonical> {
+        self.as_ref().ltlt(side)
     }
     #[track_caller]
     fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
-        PartialPivLu::<E::Canonical>::new(self.as_ref())
+        self.as_ref().partial_piv_lu()
     }
     #[track_caller]
     fn full_piv_lu(&self) -> FullPivLu<E::Canonical> {
-        FullPivLu::<E::Canonical>::new(self.as_ref())
+        self.as_ref().full_piv_lu()
     }
     #[track_caller]
     fn qr(&self) -> Qr<E::Canonical> {
-        Qr::<E::Canonical>::new(self.as_ref())
+        self.as_ref().qr()
     }
     #[track_caller]
     fn col_piv_qr(&self) -> ColPivQr<E::Canonical> {
-        ColPivQr::<E::Canonical>::new(self.as_ref())
+        self.as_ref().col_piv_qr()
     }
     #[track_caller]
     fn svd(&self) -> Svd<E::Canonical> {
-        Svd::<E::Canonical>::new(self.as_ref())
+        self.as_ref().svd()
     }
     #[track_caller]
     fn thin_svd(&self) -> ThinSvd<E::Canonical> {
-        ThinSvd::<E::Canonical>::new(self.as_ref())
+        self.as_ref().thin_svd()
     }
     #[track_caller]
     fn selfadjoint_eigendecomposition(
         &self,
         side: Side,
     ) -> SelfAdjointEigendecomposition<E::Canonical> {
-        SelfAdjointEigendecomposition::<E::Canonical>::new(self.as_ref(), side)
+        self.as_ref().selfadjoint_eigendecomposition(side)
+    }
+    #[track_caller]
+    fn selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> PartialSelfAdjointEigendecomposition<E::Canonical> {
+        self.as_ref()
+            .selfadjoint_eigendecomposition_range(side, range)
+    }
+    #[track_caller]
+    fn selfadjoint_generalized_eigendecomposition<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, CholeskyError> {
+        self.as_ref()
+            .selfadjoint_generalized_eigendecomposition(b, side)
+    }
+
+    #[track_caller]
+    fn gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Gsvd<E::Canonical> {
+        self.as_ref().gsvd(b)
+    }
+
+    #[track_caller]
+    fn polar(&self) -> Polar<E::Canonical> {
+        self.as_ref().polar()
+    }
+
+    #[track_caller]
+    fn procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> (Mat<E::Canonical>, bool) {
+        self.as_ref().procrustes(b, constraint)
+    }
+
+    #[track_caller]
+    fn solve_auto<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> (Mat<E::Canonical>, SolvePath) {
+        self.as_ref().solve_auto(rhs)
     }
 
     #[track_caller]
     fn eigendecomposition<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
         &self,
     ) -> Eigendecomposition<ComplexE> {
-        if coe::is_same::<E, <E::Canonical as ComplexField>::Real>() {
-            let matrix: MatRef<'_, <E::Canonical as ComplexField>::Real> =
-                coe::coerce(self.as_ref());
-            Eigendecomposition::<ComplexE>::new_from_real(matrix)
-        } else if coe::is_same::<E::Canonical, ComplexE>() {
-            let (matrix, conj) = self.as_ref().canonicalize();
-            Eigendecomposition::<ComplexE>::__new_from_complex_impl((coe::coerce(matrix), conj))
-        } else {
-            panic!(
-                "The type ComplexE must be either E::Canonical ({}) or E::Canonical::Real ({})",
-                core::any::type_name::<E::Canonical>(),
-                core::any::type_name::<<E::Canonical as ComplexField>::Real>(),
-            );
-        }
+        self.as_ref().eigendecomposition::<ComplexE>()
     }
 
     #[track_caller]
     fn complex_eigendecomposition(&self) -> Eigendecomposition<E::Canonical> {
-        Eigendecomposition::<E::Canonical>::new_from_complex(self.as_ref())
+        self.as_ref().complex_eigendecomposition()
     }
 
     #[track_caller]
     fn determinant(&self) -> E::Canonical {
-        assert!(self.nrows() == self.ncols());
-        let lu = self.partial_piv_lu();
-        let mut det = E::Canonical::faer_one();
-        for i in 0..self.nrows() {
-            det = det.faer_mul(lu.factors.read(i, i));
-        }
-        if lu.transposition_count() % 2 == 0 {
-            det
-        } else {
-            det.faer_neg()
-        }
+        self.as_ref().determinant()
     }
 
     #[track_caller]
     fn selfadjoint_eigenvalues(&self, side: Side) -> Vec<<E::Canonical as ComplexField>::Real> {
-        let matrix = match side {
-            Side::Lower => *self,
-            Side::Upper => self.transpose(),
-        };
-
-        assert!(matrix.nrows() == matrix.ncols());
-        let dim = matrix.nrows();
-        let parallelism = get_global_parallelism();
-
-        let mut s = Mat::<E::Canonical>::zeros(dim, 1);
-        let params = Default::default();
-        faer_evd::compute_hermitian_evd(
-            matrix.canonicalize().0,
-            s.as_mut(),
-            None,
-            parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
-                faer_evd::compute_hermitian_evd_req::<E::Canonical>(
-                    dim,
-                    faer_evd::ComputeVectors::No,
-                    parallelism,
-                    params,
-                )
-                .unwrap(),
-            )),
-            params,
-        );
-
-        (0..dim).map(|i| s.read(i, 0).faer_real()).collect()
+        self.as_ref().selfadjoint_eigenvalues(side)
     }
 
     #[track_caller]
     fn singular_values(&self) -> Vec<<E::Canonical as ComplexField>::Real> {
-        let dim = Ord::min(self.nrows(), self.ncols());
-        let parallelism = get_global_parallelism();
-
-        let mut s = Mat::<E::Canonical>::zeros(dim, 1);
-        let params = Default::default();
-        faer_svd::compute_svd(
-            self.canonicalize().0,
-            s.as_mut(),
-            None,
-            None,
-            parallelism,
-            PodStack::new(&mut GlobalPodBuffer::new(
-                faer_svd::compute_svd_req::<E::Canonical>(
-                    self.nrows(),
-                    self.ncols(),
-                    faer_svd::ComputeVectors::No,
-                    faer_svd::ComputeVectors::No,
-                    parallelism,
-                    params,
-                )
-                .unwrap(),
-            )),
-            params,
-        );
-
-        (0..dim).map(|i| s.read(i, 0).faer_real()).collect()
+        self.as_ref().singular_values()
     }
 
     #[track_caller]
     fn eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
         &self,
     ) -> Vec<ComplexE> {
-        if coe::is_same::<E, <E::Canonical as ComplexField>::Real>() {
-            let matrix: MatRef<'_, <E::Canonical as ComplexField>::Real> =
-                coe::coerce(self.as_ref());
-            Eigendecomposition::<ComplexE>::__values_from_real(matrix)
-        } else if coe::is_same::<E::Canonical, ComplexE>() {
-            let (matrix, conj) = self.as_ref().canonicalize();
-            Eigendecomposition::<ComplexE>::__values_from_complex_impl((coe::coerce(matrix), conj))
-        } else {
-            panic!(
-                "The type ComplexE must be either E::Canonical ({}) or E::Canonical::Real ({})",
-                core::any::type_name::<E::Canonical>(),
-                core::any::type_name::<<E::Canonical as ComplexField>::Real>(),
-            );
-        }
+        self.as_ref().eigenvalues()
     }
 
     #[track_caller]
     fn complex_eigenvalues(&self) -> Vec<E::Canonical> {
-        Eigendecomposition::<E::Canonical>::__values_from_complex_impl(self.canonicalize())
+        self.as_ref().complex_eigenvalues()
+    }
+
+    #[track_caller]
+    fn try_cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, SolverError> {
+        self.as_ref().try_cholesky(side)
+    }
+    #[track_caller]
+    fn try_ldlt(&self, side: Side) -> Result<Ldlt<E::Canonical>, SolverError> {
+        self.as_ref().try_ldlt(side)
+    }
+    #[track_caller]
+    fn try_pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<PivotedCholesky<E::Canonical>, SolverError> {
+        self.as_ref().try_pivoted_cholesky(side, tol)
+    }
+    #[track_caller]
+    fn try_lblt(&self, side: Side) -> Result<Lblt<E::Canonical>, SolverError> {
+        self.as_ref().try_lblt(side)
+    }
+    #[track_caller]
+    fn try_ltlt(&self, side: Side) -> Result<Ltlt<E::Canonical>, SolverError> {
+        self.as_ref().try_ltlt(side)
+    }
+    #[track_caller]
+    fn try_partial_piv_lu(&self) -> Result<PartialPivLu<E::Canonical>, SolverError> {
+        self.as_ref().try_partial_piv_lu()
+    }
+    #[track_caller]
+    fn try_full_piv_lu(&self) -> Result<FullPivLu<E::Canonical>, SolverError> {
+        self.as_ref().try_full_piv_lu()
+    }
+    #[track_caller]
+    fn try_qr(&self) -> Result<Qr<E::Canonical>, SolverError> {
+        self.as_ref().try_qr()
+    }
+    #[track_caller]
+    fn try_col_piv_qr(&self) -> Result<ColPivQr<E::Canonical>, SolverError> {
+        self.as_ref().try_col_piv_qr()
+    }
+    #[track_caller]
+    fn try_svd(&self) -> Result<Svd<E::Canonical>, SolverError> {
+        self.as_ref().try_svd()
+    }
+    #[track_caller]
+    fn try_thin_svd(&self) -> Result<ThinSvd<E::Canonical>, SolverError> {
+        self.as_ref().try_thin_svd()
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigendecomposition(
+        &self,
+        side: Side,
+    ) -> Result<SelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref().try_selfadjoint_eigendecomposition(side)
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> Result<PartialSelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref()
+            .try_selfadjoint_eigendecomposition_range(side, range)
+    }
+    #[track_caller]
+    fn try_selfadjoint_generalized_eigendecomposition<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+    >(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref()
+            .try_selfadjoint_generalized_eigendecomposition(b, side)
+    }
+    #[track_caller]
+    fn try_gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Result<Gsvd<E::Canonical>, SolverError> {
+        self.as_ref().try_gsvd(b)
+    }
+    #[track_caller]
+    fn try_polar(&self) -> Result<Polar<E::Canonical>, SolverError> {
+        self.as_ref().try_polar()
+    }
+    #[track_caller]
+    fn try_procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> Result<Mat<E::Canonical>, SolverError> {
+        self.as_ref().try_procrustes(b, constraint)
+    }
+    #[track_caller]
+    fn try_eigendecomposition<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+    ) -> Result<Eigendecomposition<ComplexE>, SolverError> {
+        self.as_ref().try_eigendecomposition::<ComplexE>()
+    }
+    #[track_caller]
+    fn try_complex_eigendecomposition(
+        &self,
+    ) -> Result<Eigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref().try_complex_eigendecomposition()
+    }
+    #[track_caller]
+    fn try_determinant(&self) -> Result<E::Canonical, SolverError> {
+        self.as_ref().try_determinant()
+    }
+    #[track_caller]
+    fn try_singular_values(
+        &self,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        self.as_ref().try_singular_values()
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigenvalues(
+        &self,
+        side: Side,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        self.as_ref().try_selfadjoint_eigenvalues(side)
+    }
+    #[track_caller]
+    fn try_eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+        &self,
+    ) -> Result<Vec<ComplexE>, SolverError> {
+        self.as_ref().try_eigenvalues::<ComplexE>()
+    }
+    #[track_caller]
+    fn try_complex_eigenvalues(&self) -> Result<Vec<E::Canonical>, SolverError> {
+        self.as_ref().try_complex_eigenvalues()
     }
 }
 
-impl<E: Conjugate> FaerMat<E::Canonical> for MatMut<'_, E>
+impl<E: Conjugate> FaerMat<E::Canonical> for Mat<E>
 where
     E::Canonical: ComplexField,
 {
@@ -3101,10 +8335,26 @@ where
         self.as_ref().cholesky(side)
     }
     #[track_caller]
+    fn ldlt(&self, side: Side) -> Ldlt<E::Canonical> {
+        self.as_ref().ldlt(side)
+    }
+    #[track_caller]
+    fn pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> PivotedCholesky<E::Canonical> {
+        self.as_ref().pivoted_cholesky(side, tol)
+    }
+    #[track_caller]
     fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
         self.as_ref().lblt(side)
     }
     #[track_caller]
+    fn ltlt(&self, side: Side) -> Ltlt<E::Canonical> {
+        self.as_ref().ltlt(side)
+    }
+    #[track_caller]
     fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
         self.as_ref().partial_piv_lu()
     }
@@ -3135,6 +8385,54 @@ where
     ) -> SelfAdjointEigendecomposition<E::Canonical> {
         self.as_ref().selfadjoint_eigendecomposition(side)
     }
+    #[track_caller]
+    fn selfadjoint_eigendecomposition_range(
+        &self,
+        side: Side,
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> PartialSelfAdjointEigendecomposition<E::Canonical> {
+        self.as_ref()
+            .selfadjoint_eigendecomposition_range(side, range)
+    }
+    #[track_caller]
+    fn selfadjoint_generalized_eigendecomposition<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, CholeskyError> {
+        self.as_ref()
+            .selfadjoint_generalized_eigendecomposition(b, side)
+    }
+
+    #[track_caller]
+    fn gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Gsvd<E::Canonical> {
+        self.as_ref().gsvd(b)
+    }
+
+    #[track_caller]
+    fn polar(&self) -> Polar<E::Canonical> {
+        self.as_ref().polar()
+    }
+
+    #[track_caller]
+    fn procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> (Mat<E::Canonical>, bool) {
+        self.as_ref().procrustes(b, constraint)
+    }
+
+    #[track_caller]
+    fn solve_auto<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        rhs: impl AsMatRef<ViewE>,
+    ) -> (Mat<E::Canonical>, SolvePath) {
+        self.as_ref().solve_auto(rhs)
+    }
 
     #[track_caller]
     fn eigendecomposition<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
@@ -3174,106 +8472,141 @@ where
     fn complex_eigenvalues(&self) -> Vec<E::Canonical> {
         self.as_ref().complex_eigenvalues()
     }
-}
 
-impl<E: Conjugate> FaerMat<E::Canonical> for Mat<E>
-where
-    E::Canonical: ComplexField,
-{
     #[track_caller]
-    fn solve_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        self.as_ref().solve_lower_triangular_in_place(rhs)
+    fn try_cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, SolverError> {
+        self.as_ref().try_cholesky(side)
     }
     #[track_caller]
-    fn solve_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        self.as_ref().solve_upper_triangular_in_place(rhs)
+    fn try_ldlt(&self, side: Side) -> Result<Ldlt<E::Canonical>, SolverError> {
+        self.as_ref().try_ldlt(side)
     }
     #[track_caller]
-    fn solve_unit_lower_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        self.as_ref().solve_unit_lower_triangular_in_place(rhs)
+    fn try_pivoted_cholesky(
+        &self,
+        side: Side,
+        tol: <E::Canonical as ComplexField>::Real,
+    ) -> Result<PivotedCholesky<E::Canonical>, SolverError> {
+        self.as_ref().try_pivoted_cholesky(side, tol)
     }
     #[track_caller]
-    fn solve_unit_upper_triangular_in_place(&self, rhs: impl AsMatMut<E::Canonical>) {
-        self.as_ref().solve_unit_upper_triangular_in_place(rhs)
+    fn try_lblt(&self, side: Side) -> Result<Lblt<E::Canonical>, SolverError> {
+        self.as_ref().try_lblt(side)
     }
-
     #[track_caller]
-    fn cholesky(&self, side: Side) -> Result<Cholesky<E::Canonical>, CholeskyError> {
-        self.as_ref().cholesky(side)
+    fn try_ltlt(&self, side: Side) -> Result<Ltlt<E::Canonical>, SolverError> {
+        self.as_ref().try_ltlt(side)
     }
     #[track_caller]
-    fn lblt(&self, side: Side) -> Lblt<E::Canonical> {
-        self.as_ref().lblt(side)
+    fn try_partial_piv_lu(&self) -> Result<PartialPivLu<E::Canonical>, SolverError> {
+        self.as_ref().try_partial_piv_lu()
     }
     #[track_caller]
-    fn partial_piv_lu(&self) -> PartialPivLu<E::Canonical> {
-        self.as_ref().partial_piv_lu()
+    fn try_full_piv_lu(&self) -> Result<FullPivLu<E::Canonical>, SolverError> {
+        self.as_ref().try_full_piv_lu()
     }
     #[track_caller]
-    fn full_piv_lu(&self) -> FullPivLu<E::Canonical> {
-        self.as_ref().full_piv_lu()
+    fn try_qr(&self) -> Result<Qr<E::Canonical>, SolverError> {
+        self.as_ref().try_qr()
     }
     #[track_caller]
-    fn qr(&self) -> Qr<E::Canonical> {
-        self.as_ref().qr()
+    fn try_col_piv_qr(&self) -> Result<ColPivQr<E::Canonical>, SolverError> {
+        self.as_ref().try_col_piv_qr()
     }
     #[track_caller]
-    fn col_piv_qr(&self) -> ColPivQr<E::Canonical> {
-        self.as_ref().col_piv_qr()
+    fn try_svd(&self) -> Result<Svd<E::Canonical>, SolverError> {
+        self.as_ref().try_svd()
     }
     #[track_caller]
-    fn svd(&self) -> Svd<E::Canonical> {
-        self.as_ref().svd()
+    fn try_thin_svd(&self) -> Result<ThinSvd<E::Canonical>, SolverError> {
+        self.as_ref().try_thin_svd()
     }
     #[track_caller]
-    fn thin_svd(&self) -> ThinSvd<E::Canonical> {
-        self.as_ref().thin_svd()
+    fn try_selfadjoint_eigendecomposition(
+        &self,
+        side: Side,
+    ) -> Result<SelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref().try_selfadjoint_eigendecomposition(side)
     }
     #[track_caller]
-    fn selfadjoint_eigendecomposition(
+    fn try_selfadjoint_eigendecomposition_range(
         &self,
         side: Side,
-    ) -> SelfAdjointEigendecomposition<E::Canonical> {
-        self.as_ref().selfadjoint_eigendecomposition(side)
+        range: EigenRange<<E::Canonical as ComplexField>::Real>,
+    ) -> Result<PartialSelfAdjointEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref()
+            .try_selfadjoint_eigendecomposition_range(side, range)
     }
-
     #[track_caller]
-    fn eigendecomposition<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+    fn try_selfadjoint_generalized_eigendecomposition<
+        ViewE: Conjugate<Canonical = E::Canonical>,
+    >(
         &self,
-    ) -> Eigendecomposition<ComplexE> {
-        self.as_ref().eigendecomposition::<ComplexE>()
+        b: impl AsMatRef<ViewE>,
+        side: Side,
+    ) -> Result<SelfAdjointGeneralizedEigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref()
+            .try_selfadjoint_generalized_eigendecomposition(b, side)
     }
-
     #[track_caller]
-    fn complex_eigendecomposition(&self) -> Eigendecomposition<E::Canonical> {
-        self.as_ref().complex_eigendecomposition()
+    fn try_gsvd<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+    ) -> Result<Gsvd<E::Canonical>, SolverError> {
+        self.as_ref().try_gsvd(b)
     }
-
     #[track_caller]
-    fn determinant(&self) -> E::Canonical {
-        self.as_ref().determinant()
+    fn try_polar(&self) -> Result<Polar<E::Canonical>, SolverError> {
+        self.as_ref().try_polar()
     }
-
     #[track_caller]
-    fn selfadjoint_eigenvalues(&self, side: Side) -> Vec<<E::Canonical as ComplexField>::Real> {
-        self.as_ref().selfadjoint_eigenvalues(side)
+    fn try_procrustes<ViewE: Conjugate<Canonical = E::Canonical>>(
+        &self,
+        b: impl AsMatRef<ViewE>,
+        constraint: ProcrustesConstraint,
+    ) -> Result<Mat<E::Canonical>, SolverError> {
+        self.as_ref().try_procrustes(b, constraint)
     }
-
     #[track_caller]
-    fn singular_values(&self) -> Vec<<E::Canonical as ComplexField>::Real> {
-        self.as_ref().singular_values()
+    fn try_eigendecomposition<
+        ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>,
+    >(
+        &self,
+    ) -> Result<Eigendecomposition<ComplexE>, SolverError> {
+        self.as_ref().try_eigendecomposition::<ComplexE>()
     }
-
     #[track_caller]
-    fn eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+    fn try_complex_eigendecomposition(
         &self,
-    ) -> Vec<ComplexE> {
-        self.as_ref().eigenvalues()
+    ) -> Result<Eigendecomposition<E::Canonical>, SolverError> {
+        self.as_ref().try_complex_eigendecomposition()
     }
-
     #[track_caller]
-    fn complex_eigenvalues(&self) -> Vec<E::Canonical> {
-        self.as_ref().complex_eigenvalues()
+    fn try_determinant(&self) -> Result<E::Canonical, SolverError> {
+        self.as_ref().try_determinant()
+    }
+    #[track_caller]
+    fn try_singular_values(
+        &self,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        self.as_ref().try_singular_values()
+    }
+    #[track_caller]
+    fn try_selfadjoint_eigenvalues(
+        &self,
+        side: Side,
+    ) -> Result<Vec<<E::Canonical as ComplexField>::Real>, SolverError> {
+        self.as_ref().try_selfadjoint_eigenvalues(side)
+    }
+    #[track_caller]
+    fn try_eigenvalues<ComplexE: ComplexField<Real = <E::Canonical as ComplexField>::Real>>(
+        &self,
+    ) -> Result<Vec<ComplexE>, SolverError> {
+        self.as_ref().try_eigenvalues::<ComplexE>()
+    }
+    #[track_caller]
+    fn try_complex_eigenvalues(&self) -> Result<Vec<E::Canonical>, SolverError> {
+        self.as_ref().try_complex_eigenvalues()
     }
 }
 
@@ -4331,115 +9664,778 @@ mod tests {
         let n = H.nrows();
         let k = 2;
 
-        let random = |_, _| rand::random::<f64>();
-        let rhs = Mat::from_fn(n, k, random);
+        let random = |_, _| rand::random::<f64>();
+        let rhs = Mat::from_fn(n, k, random);
+
+        let I = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                f64::faer_one()
+            } else {
+                f64::faer_zero()
+            }
+        });
+
+        let sol = decomp.solve(&rhs);
+        assert_approx_eq(H * &sol, &rhs);
+
+        let sol = decomp.solve_conj(&rhs);
+        assert_approx_eq(H.conjugate() * &sol, &rhs);
+
+        let sol = decomp.solve_transpose(&rhs);
+        assert_approx_eq(H.transpose() * &sol, &rhs);
+
+        let sol = decomp.solve_conj_transpose(&rhs);
+        assert_approx_eq(H.adjoint() * &sol, &rhs);
+
+        assert_approx_eq(decomp.reconstruct(), H);
+        assert_approx_eq(H * decomp.inverse(), I);
+    }
+
+    fn test_solver(H: impl AsMatRef<c64>, decomp: &dyn SolverCore<c64>) {
+        let H = H.as_mat_ref();
+        let n = H.nrows();
+        let k = 2;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let rhs = Mat::from_fn(n, k, random);
+
+        let I = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                c64::faer_one()
+            } else {
+                c64::faer_zero()
+            }
+        });
+
+        let sol = decomp.solve(&rhs);
+        assert_approx_eq(H * &sol, &rhs);
+
+        let sol = decomp.solve_conj(&rhs);
+        assert_approx_eq(H.conjugate() * &sol, &rhs);
+
+        let sol = decomp.solve_transpose(&rhs);
+        assert_approx_eq(H.transpose() * &sol, &rhs);
+
+        let sol = decomp.solve_conj_transpose(&rhs);
+        assert_approx_eq(H.adjoint() * &sol, &rhs);
+
+        let mut sol = Mat::zeros(n, k);
+        decomp.solve_into(&rhs, &mut sol);
+        assert_approx_eq(H * &sol, &rhs);
+        decomp.solve_conj_transpose_into(&rhs, &mut sol);
+        assert_approx_eq(H.adjoint() * &sol, &rhs);
+
+        assert_approx_eq(decomp.reconstruct(), H);
+        assert_approx_eq(H * decomp.inverse(), I);
+    }
+
+    fn test_solver_lstsq(H: impl AsMatRef<c64>, decomp: &dyn SolverLstsqCore<c64>) {
+        let H = H.as_mat_ref();
+
+        let m = H.nrows();
+        let k = 2;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let rhs = Mat::from_fn(m, k, random);
+
+        let sol = decomp.solve_lstsq(&rhs);
+        assert_approx_eq(H.adjoint() * H * &sol, H.adjoint() * &rhs);
+
+        let sol = decomp.solve_lstsq_conj(&rhs);
+        assert_approx_eq(H.transpose() * H.conjugate() * &sol, H.transpose() * &rhs);
+    }
+
+    #[test]
+    fn test_lblt_real() {
+        let n = 7;
+
+        let random = |_, _| rand::random::<f64>();
+        let H = Mat::from_fn(n, n, random);
+        let H = &H + H.adjoint();
+
+        test_solver_real(&H, &H.lblt(Side::Lower));
+        test_solver_real(&H, &H.lblt(Side::Upper));
+    }
+
+    #[test]
+    fn test_lblt() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H + H.adjoint();
+
+        test_solver(&H, &H.lblt(Side::Lower));
+        test_solver(&H, &H.lblt(Side::Upper));
+    }
+
+    #[test]
+    fn test_ltlt() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H + H.adjoint();
+
+        test_solver(&H, &H.ltlt(Side::Lower));
+        test_solver(&H, &H.ltlt(Side::Upper));
+
+        let ltlt = H.ltlt(Side::Lower);
+        let L = ltlt.compute_l();
+        let T = ltlt.compute_t();
+        let (perm, _) = ltlt.row_permutation().into_arrays();
+        let PHP = Mat::from_fn(n, n, |i, j| H.read(perm[i], perm[j]));
+        assert_approx_eq(&L * &T * L.adjoint(), PHP);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let mut H = Mat::from_fn(n, n, random);
+
+        let lu = H.partial_piv_lu();
+        assert!(lu.first_zero_pivot().is_none());
+        assert!(!lu.nan_encountered());
+        assert!(lu.pivot_growth() > 0.0 && lu.pivot_growth().is_finite());
+
+        H.as_mut().col_mut(n - 1).fill_zero();
+        assert_eq!(
+            H.partial_piv_lu().first_zero_pivot(),
+            Some((n - 1, c64::new(0.0, 0.0)))
+        );
+        assert_eq!(
+            H.full_piv_lu().first_zero_pivot(),
+            Some((n - 1, c64::new(0.0, 0.0)))
+        );
+
+        let mut I = Mat::<c64>::identity(n, n);
+        I.write(3, 3, c64::new(-1.0, 0.0));
+        let err = I.cholesky(Side::Lower).err().unwrap();
+        assert_eq!(err.non_positive_definite_minor, 3);
+        assert_eq!(err.pivot, -1.0);
+        assert!(!err.nan_encountered);
+    }
+
+    #[test]
+    fn test_try_variants() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let rhs = Mat::from_fn(n, 2, random);
+
+        assert_eq!(
+            Mat::from_fn(3, 4, random)
+                .try_partial_piv_lu()
+                .err()
+                .unwrap(),
+            SolverError::NonSquare { nrows: 3, ncols: 4 },
+        );
+
+        let mut H_nan = H.clone();
+        H_nan.write(1, 2, c64::new(f64::NAN, 0.0));
+        assert_eq!(
+            H_nan.try_partial_piv_lu().err().unwrap(),
+            SolverError::NonFinite
+        );
+        // the upper triangular part is not accessed
+        assert!(H_nan.try_ldlt(Side::Lower).is_ok());
+        assert_eq!(
+            H_nan.try_ldlt(Side::Upper).err().unwrap(),
+            SolverError::NonFinite
+        );
+
+        let lu = H.try_partial_piv_lu().unwrap();
+        assert_eq!(
+            lu.try_solve(Mat::from_fn(n + 1, 2, random)).unwrap_err(),
+            SolverError::DimensionMismatch {
+                expected: n,
+                found: n + 1,
+            },
+        );
+        assert_approx_eq(lu.try_solve(&rhs).unwrap(), lu.solve(&rhs));
+
+        let mut x = rhs.clone();
+        lu.try_solve_conj_transpose_in_place(x.as_mut()).unwrap();
+        assert_approx_eq(x, lu.solve_conj_transpose(&rhs));
+
+        let H = &H + H.adjoint();
+        assert_eq!(
+            (-&H * H.adjoint()).try_cholesky(Side::Lower).err().unwrap(),
+            SolverError::NotPositiveDefinite,
+        );
+        assert!(H.try_selfadjoint_eigendecomposition(Side::Lower).is_ok());
+        assert!(H.try_svd().is_ok());
+        assert!(H.svd().converged());
+        assert!(H.thin_svd().converged());
+        assert!(H.complex_eigendecomposition().converged());
+        assert!(H.try_complex_eigendecomposition().is_ok());
+        assert!(H.try_polar().unwrap().converged());
+
+        let R = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+        assert!(R.try_eigendecomposition::<c64>().is_ok());
+        assert!(R.try_eigenvalues::<c64>().is_ok());
+        assert_eq!(
+            R.try_eigendecomposition::<f64>().err().unwrap(),
+            SolverError::ScalarTypeMismatch,
+        );
+        assert_eq!(
+            R.try_eigenvalues::<f64>().unwrap_err(),
+            SolverError::ScalarTypeMismatch,
+        );
+        assert_eq!(
+            R.try_complex_eigendecomposition().err().unwrap(),
+            SolverError::ScalarTypeMismatch,
+        );
+        assert_eq!(
+            R.try_complex_eigenvalues().unwrap_err(),
+            SolverError::ScalarTypeMismatch,
+        );
+        assert_eq!(
+            H.try_eigendecomposition::<f64>().err().unwrap(),
+            SolverError::ScalarTypeMismatch,
+        );
+    }
+
+    #[test]
+    fn test_with_params() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let A = &H + H.adjoint();
+
+        let mut evd_params = EvdParams::default();
+        let mut svd_params = SvdParams::default();
+        let mut sym_params = SymmetricEvdParams::default();
+
+        assert!(Svd::new_with_params(H.as_ref(), svd_params).converged());
+        assert!(ThinSvd::new_with_params(H.as_ref(), svd_params).converged());
+        assert!(
+            Eigendecomposition::new_from_complex_with_params(H.as_ref(), evd_params).converged()
+        );
+        assert!(SelfAdjointEigendecomposition::new_with_params(
+            A.as_ref(),
+            Side::Lower,
+            sym_params
+        )
+        .converged());
+
+        evd_params.max_iterations = Some(0);
+        svd_params.max_iterations = Some(0);
+        sym_params.max_iterations = Some(0);
+
+        assert!(!Svd::new_with_params(H.as_ref(), svd_params).converged());
+        assert!(!ThinSvd::new_with_params(H.as_ref(), svd_params).converged());
+        assert!(
+            !Eigendecomposition::new_from_complex_with_params(H.as_ref(), evd_params).converged()
+        );
+        assert!(!Eigendecomposition::<c64>::new_from_real_with_params(
+            Mat::from_fn(n, n, |_, _| rand::random::<f64>()).as_ref(),
+            evd_params,
+        )
+        .converged());
+
+        let mut evd =
+            SelfAdjointEigendecomposition::new_with_params(A.as_ref(), Side::Lower, sym_params);
+        assert!(!evd.converged());
+        // the parameters are kept by refactor
+        evd.refactor(A.as_ref(), Side::Lower);
+        assert!(!evd.converged());
+    }
+
+    #[test]
+    fn test_refactor() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let H = Mat::from_fn(n, n, random);
+        let A_hpd = &A * A.adjoint();
+        let H_hpd = &H * H.adjoint();
+        let H_herm = &H + H.adjoint();
+
+        let mut chol = A_hpd.cholesky(Side::Lower).unwrap();
+        chol.try_refactor(H_hpd.as_ref(), Side::Lower).unwrap();
+        test_solver(&H_hpd, &chol);
+        assert!(chol.try_refactor((-&H_hpd).as_ref(), Side::Lower).is_err());
+
+        let mut piv_llt = A_hpd.pivoted_cholesky(Side::Lower, -1.0);
+        piv_llt.refactor(H_hpd.as_ref(), Side::Lower, -1.0);
+        let fresh = H_hpd.pivoted_cholesky(Side::Lower, -1.0);
+        assert!(piv_llt.rank() == fresh.rank());
+        assert!(piv_llt.compute_l() == fresh.compute_l());
+
+        let mut ldlt = A_hpd.ldlt(Side::Lower);
+        ldlt.refactor(H_hpd.as_ref(), Side::Upper);
+        test_solver(&H_hpd, &ldlt);
+
+        let mut lblt = (&A + A.adjoint()).lblt(Side::Lower);
+        lblt.refactor(H_herm.as_ref(), Side::Lower);
+        test_solver(&H_herm, &lblt);
+
+        let mut ltlt = (&A + A.adjoint()).ltlt(Side::Lower);
+        ltlt.refactor(H_herm.as_ref(), Side::Lower);
+        test_solver(&H_herm, &ltlt);
+
+        let mut lu = A.partial_piv_lu();
+        lu.refactor(H.as_ref());
+        test_solver(&H, &lu);
+        lu.release_workspace();
+        test_solver(&H, &lu);
+        lu.refactor(A.as_ref());
+        test_solver(&A, &lu);
+
+        let mut lu = A.full_piv_lu();
+        lu.refactor(H.as_ref());
+        test_solver(&H, &lu);
+
+        let mut qr = A.qr();
+        qr.refactor(H.as_ref());
+        test_solver(&H, &qr);
+
+        let mut qr = A.col_piv_qr();
+        qr.refactor(H.as_ref());
+        test_solver(&H, &qr);
+
+        let mut svd = A.svd();
+        svd.refactor(H.as_ref());
+        test_solver(&H, &svd);
+
+        let mut svd = A.thin_svd();
+        svd.refactor(H.as_ref());
+        test_solver(&H, &svd);
+        svd.release_workspace();
+        test_solver(&H, &svd);
+
+        let mut evd = A_hpd.selfadjoint_eigendecomposition(Side::Lower);
+        evd.refactor(H_herm.as_ref(), Side::Lower);
+        test_solver(&H_herm, &evd);
+    }
+
+    #[test]
+    fn test_log_determinant() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let A_hpd = &A * A.adjoint();
+        let A_herm = &A + A.adjoint();
+
+        let check = |(phase, log_abs): (c64, f64), det: c64| {
+            let abs = det.faer_abs();
+            assert!((log_abs - abs.ln()).abs() < 1e-8);
+            assert!(
+                phase
+                    .faer_sub(det.faer_scale_real(abs.faer_inv()))
+                    .faer_abs()
+                    < 1e-8
+            );
+        };
 
-        let I = Mat::from_fn(n, n, |i, j| {
+        check(A.partial_piv_lu().log_determinant(), A.determinant());
+        check(A.full_piv_lu().log_determinant(), A.determinant());
+        check(A.qr().log_determinant(), A.determinant());
+        check(A.svd().log_determinant(), A.determinant());
+        check(
+            A_hpd.cholesky(Side::Lower).unwrap().log_determinant(),
+            A_hpd.determinant(),
+        );
+        check(
+            A_herm.lblt(Side::Lower).log_determinant(),
+            A_herm.determinant(),
+        );
+
+        // the zero diagonal forces 2x2 pivots in the bunch-kaufman factorization
+        let indefinite = Mat::from_fn(n, n, |i, j| {
             if i == j {
-                f64::faer_one()
+                c64::faer_zero()
+            } else if i + 1 == j || j + 1 == i {
+                c64::new(3.0, 0.0)
             } else {
-                f64::faer_zero()
+                A_herm.read(i, j)
             }
         });
+        let (phase, log_abs) = indefinite.lblt(Side::Lower).log_determinant();
+        let (phase_lu, log_abs_lu) = indefinite.partial_piv_lu().log_determinant();
+        assert!((log_abs - log_abs_lu).abs() < 1e-8);
+        assert!((phase.faer_sub(phase_lu)).faer_abs() < 1e-8);
 
-        let sol = decomp.solve(&rhs);
-        assert_approx_eq(H * &sol, &rhs);
-
-        let sol = decomp.solve_conj(&rhs);
-        assert_approx_eq(H.conjugate() * &sol, &rhs);
+        let (phase, log_abs) = Mat::<f64>::zeros(n, n).partial_piv_lu().log_determinant();
+        assert!(all(phase == 0.0, log_abs == f64::NEG_INFINITY));
+    }
 
-        let sol = decomp.solve_transpose(&rhs);
-        assert_approx_eq(H.transpose() * &sol, &rhs);
+    #[test]
+    fn test_sp_log_determinant() {
+        use sparse::{FaerSparseMat, SparseColMatRef, SymbolicSparseColMatRef};
+
+        // a sparse matrix, which uses the simplicial factorizations, and a dense one, which uses
+        // the supernodal factorizations
+        for (n, density) in [(7, 0.3), (60, 1.0)] {
+            let A = Mat::from_fn(n, n, |i, j| {
+                if i == j || rand::random::<f64>() < density {
+                    rand::random::<f64>() - 0.5
+                } else {
+                    0.0
+                }
+            });
 
-        let sol = decomp.solve_conj_transpose(&rhs);
-        assert_approx_eq(H.adjoint() * &sol, &rhs);
+            let mut col_ptrs = vec![0usize];
+            let mut row_indices = vec![];
+            let mut values = vec![];
+            for j in 0..n {
+                for i in 0..n {
+                    if A.read(i, j) != 0.0 {
+                        row_indices.push(i);
+                        values.push(A.read(i, j));
+                    }
+                }
+                col_ptrs.push(row_indices.len());
+            }
+            let A_sparse = SparseColMatRef::<'_, usize, f64>::new(
+                SymbolicSparseColMatRef::new_checked(n, n, &col_ptrs, None, &row_indices),
+                &values,
+            );
 
-        assert_approx_eq(decomp.reconstruct(), H);
-        assert_approx_eq(H * decomp.inverse(), I);
+            let (phase, log_abs) = A.partial_piv_lu().log_determinant();
+            for (sp_phase, sp_log_abs) in [
+                A_sparse.sp_qr().unwrap().log_determinant(),
+                A_sparse.sp_lu().unwrap().log_determinant(),
+            ] {
+                assert!((sp_log_abs - log_abs).abs() < 1e-8);
+                assert!((sp_phase - phase).abs() < 1e-8);
+            }
+        }
     }
 
-    fn test_solver(H: impl AsMatRef<c64>, decomp: &dyn SolverCore<c64>) {
-        let H = H.as_mat_ref();
-        let n = H.nrows();
+    #[test]
+    fn test_solve_auto() {
+        let n = 7;
         let k = 2;
 
         let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
         let rhs = Mat::from_fn(n, k, random);
 
-        let I = Mat::from_fn(n, n, |i, j| {
+        let lower = Mat::from_fn(n, n, |i, j| {
+            if i > j {
+                A.read(i, j)
+            } else if i == j {
+                c64::new(n as f64, 0.0)
+            } else {
+                c64::faer_zero()
+            }
+        });
+        let upper = lower.adjoint().to_owned();
+        let hpd = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+        let indefinite = Mat::from_fn(n, n, |i, j| {
             if i == j {
-                c64::faer_one()
+                c64::new(1.0, 0.0)
+            } else if i + 1 == j || j + 1 == i {
+                c64::new(3.0, 0.0)
             } else {
                 c64::faer_zero()
             }
         });
 
-        let sol = decomp.solve(&rhs);
-        assert_approx_eq(H * &sol, &rhs);
+        for (mat, path) in [
+            (&lower, SolvePath::LowerTriangular),
+            (&upper, SolvePath::UpperTriangular),
+            (&hpd, SolvePath::Cholesky),
+            (&indefinite, SolvePath::Lblt),
+            (&A, SolvePath::PartialPivLu),
+        ] {
+            let (sol, chosen) = mat.solve_auto(&rhs);
+            assert!(chosen == path);
+            assert_approx_eq(mat * &sol, &rhs);
+        }
+
+        let tall = Mat::from_fn(n + 3, n, random);
+        let rhs_tall = Mat::from_fn(n + 3, k, random);
+        let (sol, chosen) = tall.solve_auto(&rhs_tall);
+        assert!(chosen == SolvePath::Qr);
+        assert_approx_eq(
+            tall.adjoint() * (&tall * &sol - &rhs_tall),
+            Mat::<c64>::zeros(n, k),
+        );
 
-        let sol = decomp.solve_conj(&rhs);
-        assert_approx_eq(H.conjugate() * &sol, &rhs);
+        let wide = Mat::from_fn(n, n + 3, random);
+        let (sol, chosen) = wide.solve_auto(&rhs);
+        assert!(chosen == SolvePath::QrMinimumNorm);
+        assert_approx_eq(&wide * &sol, &rhs);
+        // the minimum norm solution lies in the range of the adjoint
+        let proj = wide.adjoint() * wide.adjoint().solve_auto(&sol).0;
+        assert_approx_eq(&proj, &sol);
+    }
 
-        let sol = decomp.solve_transpose(&rhs);
-        assert_approx_eq(H.transpose() * &sol, &rhs);
+    #[test]
+    fn test_sp_solve_auto() {
+        use sparse::{
+            solvers::{SpSolvePath, SpSolveReport, SpSolver},
+            FaerSparseMat, SparseColMatRef, SymbolicSparseColMatRef,
+        };
 
-        let sol = decomp.solve_conj_transpose(&rhs);
-        assert_approx_eq(H.adjoint() * &sol, &rhs);
+        let n = 7;
+        let k = 2;
 
-        assert_approx_eq(decomp.reconstruct(), H);
-        assert_approx_eq(H * decomp.inverse(), I);
+        // column-major storage of the nonzero entries of `mat`
+        let to_sparse = |mat: &Mat<f64>| {
+            let mut col_ptrs = vec![0usize];
+            let mut row_indices = vec![];
+            let mut values = vec![];
+            for j in 0..mat.ncols() {
+                for i in 0..mat.nrows() {
+                    if mat.read(i, j) != 0.0 {
+                        row_indices.push(i);
+                        values.push(mat.read(i, j));
+                    }
+                }
+                col_ptrs.push(row_indices.len());
+            }
+            (col_ptrs, row_indices, values)
+        };
+
+        let tridiag = |diag: &dyn Fn(usize) -> f64, lower: f64, upper: f64| {
+            Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    diag(i)
+                } else if i == j + 1 {
+                    lower
+                } else if i + 1 == j {
+                    upper
+                } else {
+                    0.0
+                }
+            })
+        };
+
+        let spd = tridiag(&|_| 4.0, -1.0, -1.0);
+        let indefinite = tridiag(&|i| if i % 2 == 0 { 4.0 } else { -4.0 }, 1.0, 1.0);
+        let unsymmetric = tridiag(&|_| 4.0, 1.0, -2.0);
+        let pattern_unsymmetric = tridiag(&|_| 4.0, 1.0, 0.0);
+
+        let rhs = Mat::from_fn(n, k, |_, _| rand::random::<f64>());
+
+        for (mat, structurally_symmetric, selfadjoint, path) in [
+            (&spd, true, true, SpSolvePath::Cholesky),
+            (&indefinite, true, true, SpSolvePath::Lblt),
+            (&unsymmetric, true, false, SpSolvePath::Lu),
+            (&pattern_unsymmetric, false, false, SpSolvePath::Lu),
+        ] {
+            let (col_ptrs, row_indices, values) = to_sparse(mat);
+            let sparse = SparseColMatRef::<'_, usize, f64>::new(
+                SymbolicSparseColMatRef::new_checked(n, n, &col_ptrs, None, &row_indices),
+                &values,
+            );
+            let (solver, report) = sparse.sp_solve_auto().unwrap();
+            assert!(
+                report
+                    == SpSolveReport {
+                        structurally_symmetric,
+                        selfadjoint,
+                        path,
+                    }
+            );
+            let sol = solver.solve(&rhs);
+            assert_approx_eq(mat * &sol, &rhs);
+        }
+
+        let tall = Mat::from_fn(n + 3, n, |i, j| {
+            if i == j || i == j + 3 {
+                rand::random::<f64>() + 1.0
+            } else {
+                0.0
+            }
+        });
+        let rhs_tall = Mat::from_fn(n + 3, k, |_, _| rand::random::<f64>());
+        let (col_ptrs, row_indices, values) = to_sparse(&tall);
+        let sparse = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n + 3, n, &col_ptrs, None, &row_indices),
+            &values,
+        );
+        let (solver, report) = sparse.sp_solve_auto().unwrap();
+        assert!(report.path == SpSolvePath::Qr);
+
+        let (col_ptrs, row_indices, values) = to_sparse(&tall.transpose().to_owned());
+        let wide = SparseColMatRef::<'_, usize, f64>::new(
+            SymbolicSparseColMatRef::new_checked(n, n + 3, &col_ptrs, None, &row_indices),
+            &values,
+        );
+        assert!(
+            wide.sp_solve_auto().err().unwrap()
+                == SolverError::Underdetermined {
+                    nrows: n,
+                    ncols: n + 3,
+                }
+        );
+        let mut sol = rhs_tall.clone();
+        solver.solve_in_place(sol.as_mut());
+        let sol = sol.as_ref().subrows(0, n);
+        assert_approx_eq(
+            tall.transpose() * (&tall * sol - &rhs_tall),
+            Mat::<f64>::zeros(n, k),
+        );
     }
 
-    fn test_solver_lstsq(H: impl AsMatRef<c64>, decomp: &dyn SolverLstsqCore<c64>) {
-        let H = H.as_mat_ref();
+    #[test]
+    fn test_inverse_linop() {
+        use faer_core::linop::{Adjoint, LinearOperator, Product};
 
-        let m = H.nrows();
+        let n = 7;
         let k = 2;
 
         let random = |_, _| c64::new(rand::random(), rand::random());
-        let rhs = Mat::from_fn(m, k, random);
+        let A = Mat::from_fn(n, n, random);
+        let rhs = Mat::from_fn(n, k, random);
 
-        let sol = decomp.solve_lstsq(&rhs);
-        assert_approx_eq(H.adjoint() * H * &sol, H.adjoint() * &rhs);
+        let apply = |op: &dyn LinearOperator<c64>| {
+            let mut out = Mat::<c64>::zeros(op.nrows(), k);
+            op.apply(
+                out.as_mut(),
+                rhs.as_ref(),
+                Parallelism::None,
+                PodStack::new(&mut GlobalPodBuffer::new(
+                    op.apply_req(k, Parallelism::None).unwrap(),
+                )),
+            );
+            out
+        };
 
-        let sol = decomp.solve_lstsq_conj(&rhs);
-        assert_approx_eq(H.transpose() * H.conjugate() * &sol, H.transpose() * &rhs);
+        let lu = A.partial_piv_lu();
+        assert_approx_eq(&A * apply(&lu), &rhs);
+        assert_approx_eq(A.adjoint() * apply(&Adjoint(&lu)), &rhs);
+        assert_approx_eq(apply(&Product(&lu, A.as_ref())), &rhs);
+
+        let hpd = &A * A.adjoint() + Mat::<c64>::identity(n, n);
+        let llt = hpd.cholesky(Side::Lower).unwrap();
+        assert_approx_eq(&hpd * apply(&llt), &rhs);
+
+        let lu = A.full_piv_lu();
+        assert_approx_eq(&A * apply(&lu), &rhs);
+        assert_approx_eq(A.adjoint() * apply(&Adjoint(&lu)), &rhs);
+
+        let evd = hpd.selfadjoint_eigendecomposition(Side::Lower);
+        assert_approx_eq(&hpd * apply(&evd), &rhs);
     }
 
     #[test]
-    fn test_lblt_real() {
+    fn test_lblt_pivoting() {
         let n = 7;
 
-        let random = |_, _| rand::random::<f64>();
+        let random = |_, _| c64::new(rand::random(), rand::random());
         let H = Mat::from_fn(n, n, random);
         let H = &H + H.adjoint();
 
-        test_solver_real(&H, &H.lblt(Side::Lower));
-        test_solver_real(&H, &H.lblt(Side::Upper));
+        for pivoting in [
+            PivotingStrategy::Diagonal,
+            PivotingStrategy::Rook,
+            PivotingStrategy::Bounded,
+        ] {
+            for side in [Side::Lower, Side::Upper] {
+                let lblt = Lblt::new_with_pivoting(H.as_ref(), side, pivoting);
+                test_solver(&H, &lblt);
+                assert!(lblt.block_sizes().iter().sum::<usize>() == n);
+                let (perm, _) = lblt.row_permutation().into_arrays();
+                let mut sorted = perm.to_vec();
+                sorted.sort();
+                assert!(sorted == (0..n).collect::<Vec<_>>());
+            }
+        }
     }
 
     #[test]
-    fn test_lblt() {
+    fn test_cholesky() {
         let n = 7;
 
         let random = |_, _| c64::new(rand::random(), rand::random());
         let H = Mat::from_fn(n, n, random);
-        let H = &H + H.adjoint();
+        let H = &H * H.adjoint();
 
-        test_solver(&H, &H.lblt(Side::Lower));
-        test_solver(&H, &H.lblt(Side::Upper));
+        test_solver(&H, &H.cholesky(Side::Lower).unwrap());
+        test_solver(&H, &H.cholesky(Side::Upper).unwrap());
     }
 
     #[test]
-    fn test_cholesky() {
+    fn test_ldlt() {
         let n = 7;
 
         let random = |_, _| c64::new(rand::random(), rand::random());
         let H = Mat::from_fn(n, n, random);
         let H = &H * H.adjoint();
 
-        test_solver(&H, &H.cholesky(Side::Lower).unwrap());
-        test_solver(&H, &H.cholesky(Side::Upper).unwrap());
+        test_solver(&H, &H.ldlt(Side::Lower));
+        test_solver(&H, &H.ldlt(Side::Upper));
+
+        let ldlt = H.ldlt(Side::Lower);
+        let L = ldlt.compute_l();
+        let D = ldlt.compute_d();
+        let D = Mat::from_fn(n, n, |i, j| {
+            if i == j {
+                D.read(i, 0)
+            } else {
+                c64::faer_zero()
+            }
+        });
+        assert_approx_eq(&L * &D * L.adjoint(), &H);
+        assert!(ldlt.inertia() == (n, 0, 0));
+    }
+
+    #[test]
+    fn test_ldlt_quasi_definite() {
+        let n = 4;
+        let m = 3;
+
+        // [A B^H; B -C] with A and C positive definite
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let A = &A * A.adjoint();
+        let B = Mat::from_fn(m, n, random);
+        let C = Mat::from_fn(m, m, random);
+        let C = &C * C.adjoint();
+        let H = Mat::from_fn(n + m, n + m, |i, j| match (i < n, j < n) {
+            (true, true) => A.read(i, j),
+            (true, false) => B.read(j - n, i).faer_conj(),
+            (false, true) => B.read(i - n, j),
+            (false, false) => C.read(i - n, j - n).faer_neg(),
+        });
+
+        test_solver(&H, &H.ldlt(Side::Lower));
+        assert!(H.ldlt(Side::Lower).inertia() == (n, m, 0));
+
+        let signs = [1, 1, 1, 1, -1, -1, -1];
+        let ldlt = Ldlt::new_with_regularization(
+            H.as_ref(),
+            Side::Lower,
+            LdltRegularization {
+                dynamic_regularization_signs: Some(&signs),
+                dynamic_regularization_delta: 1e-8,
+                dynamic_regularization_epsilon: 1e-13,
+            },
+        );
+        assert!(ldlt.dynamic_regularization_count() == 0);
+        assert!(ldlt.inertia() == (n, m, 0));
+    }
+
+    #[test]
+    fn test_pivoted_cholesky() {
+        let n = 10;
+        let r = 4;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let B = Mat::from_fn(n, r, random);
+        let H = &B * B.adjoint();
+
+        for side in [Side::Lower, Side::Upper] {
+            let chol = H.pivoted_cholesky(side, -1.0);
+            assert!(chol.rank() == r);
+
+            let L = chol.compute_l();
+            let (perm, _) = chol.row_permutation().into_arrays();
+            let PHP = Mat::from_fn(n, n, |i, j| H.read(perm[i], perm[j]));
+            assert_approx_eq(&L * L.adjoint(), PHP);
+        }
     }
 
     #[test]
@@ -4587,6 +10583,273 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_selfadjoint_eigendecomposition_range() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+        let H = &H * H.adjoint();
+
+        let full = H.selfadjoint_eigenvalues(Side::Lower);
+
+        for side in [Side::Lower, Side::Upper] {
+            for range in [
+                EigenRange::IndexRange(0, 3),
+                EigenRange::ValueRange(full[2] - 1e-8, full[5] - 1e-8),
+            ] {
+                let evd = H.selfadjoint_eigendecomposition_range(side, range);
+                let offset = match range {
+                    EigenRange::IndexRange(lo, _) => lo,
+                    EigenRange::ValueRange(_, _) => 2,
+                };
+
+                let u = evd.u();
+                let s = evd.s_diagonal();
+                assert!(u.ncols() == 3);
+                assert!(s.nrows() == 3);
+
+                let us = Mat::from_fn(n, 3, |i, j| u.read(i, j) * s.read(j, 0));
+                assert_approx_eq(&H * u, &us);
+                assert_approx_eq(u.adjoint() * u, Mat::<c64>::identity(3, 3));
+                for j in 0..3 {
+                    assert!((s.read(j, 0).re - full[offset + j]).abs() < 1e-8);
+                }
+                assert!(evd.converged());
+            }
+        }
+
+        assert!(H
+            .try_selfadjoint_eigendecomposition_range(Side::Lower, EigenRange::IndexRange(2, n + 1))
+            .is_err());
+        assert!(H
+            .try_selfadjoint_eigendecomposition_range(Side::Lower, EigenRange::IndexRange(3, 2))
+            .is_err());
+    }
+
+    #[test]
+    fn test_selfadjoint_generalized_eigendecomposition() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let A = Mat::from_fn(n, n, random);
+        let A = &A + A.adjoint();
+        let B = Mat::from_fn(n, n, random);
+        let B = &B * B.adjoint()
+            + Mat::from_fn(n, n, |i, j| {
+                if i == j {
+                    c64::faer_one()
+                } else {
+                    c64::faer_zero()
+                }
+            });
+
+        for side in [Side::Lower, Side::Upper] {
+            // only the stored triangle must be accessed, so fill the other one with garbage
+            let stored = |mat: &Mat<c64>| {
+                Mat::from_fn(n, n, |i, j| {
+                    let is_stored = match side {
+                        Side::Lower => i >= j,
+                        Side::Upper => i <= j,
+                    };
+                    if is_stored {
+                        mat.read(i, j)
+                    } else {
+                        random(i, j)
+                    }
+                })
+            };
+            let A_stored = stored(&A);
+            let B_stored = stored(&B);
+
+            for problem in [
+                GeneralizedEigenProblem::AxLambdaBx,
+                GeneralizedEigenProblem::ABxLambdaX,
+                GeneralizedEigenProblem::BAxLambdaX,
+            ] {
+                let evd = SelfAdjointGeneralizedEigendecomposition::try_new(
+                    A_stored.as_ref(),
+                    B_stored.as_ref(),
+                    side,
+                    problem,
+                )
+                .unwrap();
+                let u = evd.u();
+                let s = evd.s_diagonal();
+                let us = Mat::from_fn(n, n, |i, j| u.read(i, j) * s.read(j, 0));
+
+                match problem {
+                    GeneralizedEigenProblem::AxLambdaBx => {
+                        assert_approx_eq(&A * u, &B * &us);
+                        assert_approx_eq(u.adjoint() * &B * u, Mat::<c64>::identity(n, n));
+                    }
+                    GeneralizedEigenProblem::ABxLambdaX => {
+                        assert_approx_eq(&A * &B * u, &us);
+                        assert_approx_eq(u.adjoint() * &B * u, Mat::<c64>::identity(n, n));
+                    }
+                    GeneralizedEigenProblem::BAxLambdaX => {
+                        assert_approx_eq(&B * &A * u, &us);
+                    }
+                }
+            }
+        }
+
+        let evd = A
+            .selfadjoint_generalized_eigendecomposition(&B, Side::Lower)
+            .unwrap();
+        for i in 0..n - 1 {
+            assert!(evd.s_diagonal()[(i, 0)].re <= evd.s_diagonal()[(i + 1, 0)].re);
+        }
+    }
+
+    #[test]
+    fn test_gsvd() {
+        let random = |_, _| c64::new(rand::random(), rand::random());
+
+        for (m, p, n) in [(9, 7, 5), (3, 7, 5), (7, 3, 5)] {
+            let A = Mat::from_fn(m, n, random);
+            let B = Mat::from_fn(p, n, random);
+
+            let gsvd = A.gsvd(&B);
+            let alpha = gsvd.alpha();
+            let beta = gsvd.beta();
+            let x = gsvd.x();
+
+            let u_alpha = Mat::from_fn(m, n, |i, j| gsvd.u().read(i, j) * alpha.read(j, 0));
+            let v_beta = Mat::from_fn(p, n, |i, j| gsvd.v().read(i, j) * beta.read(j, 0));
+            assert_approx_eq(&A * x, &u_alpha);
+            assert_approx_eq(&B * x, &v_beta);
+
+            let ones = Mat::from_fn(n, 1, |_, _| c64::faer_one());
+            let sum_sq = Mat::from_fn(n, 1, |i, _| {
+                alpha.read(i, 0) * alpha.read(i, 0) + beta.read(i, 0) * beta.read(i, 0)
+            });
+            assert_approx_eq(&sum_sq, &ones);
+        }
+    }
+
+    #[test]
+    fn test_polar() {
+        let random = |_, _| c64::new(rand::random(), rand::random());
+
+        for (m, n) in [(7, 7), (9, 5), (5, 9)] {
+            let A = Mat::from_fn(m, n, random);
+            let polar = A.polar();
+            let u = polar.u();
+            let h = polar.h();
+
+            assert_approx_eq(u * h, &A);
+            assert_approx_eq(h, h.adjoint().to_owned());
+            if m >= n {
+                assert_approx_eq(u.adjoint() * u, Mat::<c64>::identity(n, n));
+            } else {
+                assert_approx_eq(u * u.adjoint(), Mat::<c64>::identity(m, m));
+            }
+            for s in h.selfadjoint_eigenvalues(Side::Lower) {
+                assert!(s > -1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_procrustes() {
+        let n = 3;
+        let m = 10;
+
+        let A = Mat::from_fn(m, n, |_, _| rand::random::<f64>());
+        // rotation around the z axis
+        let (sin, cos) = (0.3f64.sin(), 0.3f64.cos());
+        let R = mat![[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]];
+        let B = &A * &R;
+
+        let (omega, converged) = A.procrustes(&B, ProcrustesConstraint::Unitary);
+        assert!(converged);
+        assert_approx_eq(&omega, &R);
+
+        let (rotation, converged) = A.procrustes(&B, ProcrustesConstraint::SpecialUnitary);
+        assert!(converged);
+        assert_approx_eq(&rotation, &R);
+
+        // a reflection can only be approximated by a rotation
+        let F = mat![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        let B = &A * &F;
+        let (omega, _) = A.procrustes(&B, ProcrustesConstraint::Unitary);
+        assert_approx_eq(&omega, &F);
+        let (rotation, _) = A.procrustes(&B, ProcrustesConstraint::SpecialUnitary);
+        assert!((rotation.determinant() - 1.0).abs() < 1e-10);
+        assert_approx_eq(rotation.transpose() * &rotation, Mat::<f64>::identity(n, n));
+    }
+
+    #[test]
+    fn test_riccati() {
+        let n = 6;
+        let m = 2;
+
+        let A = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+        let B = Mat::from_fn(n, m, |_, _| rand::random::<f64>());
+        let Q = Mat::<f64>::identity(n, n);
+        let R = Mat::<f64>::identity(m, m);
+
+        let care =
+            Riccati::try_new_continuous(A.as_ref(), B.as_ref(), Q.as_ref(), R.as_ref()).unwrap();
+        let X = care.x();
+        assert_approx_eq(X, X.transpose());
+        assert_approx_eq(
+            A.transpose() * X + X * &A - X * &B * B.transpose() * X + &Q,
+            Mat::<f64>::zeros(n, n),
+        );
+        assert!(care.residual_norm() < 1e-8);
+        assert!(care.rcond() > 0.0);
+        // the closed loop system is stable
+        for lambda in (&A - &B * B.transpose() * X).eigenvalues::<c64>() {
+            assert!(lambda.re < 0.0);
+        }
+
+        let dare =
+            Riccati::try_new_discrete(A.as_ref(), B.as_ref(), Q.as_ref(), R.as_ref()).unwrap();
+        let X = dare.x();
+        let gain = (&R + B.transpose() * X * &B)
+            .partial_piv_lu()
+            .solve(B.transpose() * X * &A);
+        assert_approx_eq(
+            A.transpose() * X * &A - X - A.transpose() * X * &B * &gain + &Q,
+            Mat::<f64>::zeros(n, n),
+        );
+        assert!(dare.residual_norm() < 1e-8);
+        for lambda in (&A - &B * &gain).eigenvalues::<c64>() {
+            assert!(lambda.faer_abs() < 1.0);
+        }
+
+        // a singular state matrix is supported by the discrete solver
+        let A = Mat::from_fn(
+            n,
+            n,
+            |_, j| if j == 0 { 0.0 } else { rand::random::<f64>() },
+        );
+        let dare =
+            Riccati::try_new_discrete(A.as_ref(), B.as_ref(), Q.as_ref(), R.as_ref()).unwrap();
+        let X = dare.x();
+        let gain = (&R + B.transpose() * X * &B)
+            .partial_piv_lu()
+            .solve(B.transpose() * X * &A);
+        assert_approx_eq(
+            A.transpose() * X * &A - X - A.transpose() * X * &B * &gain + &Q,
+            Mat::<f64>::zeros(n, n),
+        );
+        assert!(dare.residual_norm() < 1e-8);
+
+        // R must be positive definite
+        assert!(matches!(
+            Riccati::try_new_continuous(
+                A.as_ref(),
+                B.as_ref(),
+                Q.as_ref(),
+                Mat::from_fn(m, m, |i, j| -R.read(i, j)).as_ref()
+            ),
+            Err(RiccatiError::NonPositiveDefiniteWeight)
+        ));
+    }
+
     #[test]
     fn test_eigendecomposition() {
         let n = 7;
@@ -4643,6 +10906,89 @@ mod tests {
         assert_approx_eq(u * &s, &H * u);
     }
 
+    #[test]
+    fn test_eigendecomposition_left_sorted() {
+        let n = 7;
+
+        let random = |_, _| c64::new(rand::random(), rand::random());
+        let H = Mat::from_fn(n, n, random);
+
+        for order in [EigenvalueOrder::RealPart, EigenvalueOrder::Magnitude] {
+            let mut eigen = Eigendecomposition::new_from_complex_with_left_eigenvectors(
+                H.as_ref(),
+                Default::default(),
+            );
+            eigen.sort(order);
+
+            let s = eigen.s_diagonal();
+            for i in 0..n - 1 {
+                match order {
+                    EigenvalueOrder::RealPart => assert!(s.read(i).re <= s.read(i + 1).re),
+                    EigenvalueOrder::Magnitude => {
+                        assert!(s.read(i).faer_abs() <= s.read(i + 1).faer_abs())
+                    }
+                }
+            }
+
+            let mut s_mat = Mat::zeros(n, n);
+            s_mat
+                .as_mut()
+                .diagonal_mut()
+                .column_vector_mut()
+                .copy_from(s);
+            let u = eigen.u();
+            assert_approx_eq(u * &s_mat, &H * u);
+
+            let v = eigen.compute_left_eigenvectors().unwrap();
+            assert_approx_eq(v.adjoint() * &H, &s_mat * v.adjoint());
+
+            for rcond in eigen.reciprocal_condition_numbers().unwrap() {
+                assert!(rcond > 0.0 && rcond <= 1.0 + 1e-10);
+            }
+        }
+
+        let mut eigen = H.complex_eigendecomposition();
+        assert!(eigen.compute_left_eigenvectors().is_none());
+        assert!(eigen.reciprocal_condition_numbers().is_none());
+        eigen.sort_by(|a, b| b.im.partial_cmp(&a.im).unwrap());
+        let s = eigen.s_diagonal();
+        for i in 0..n - 1 {
+            assert!(s.read(i).im >= s.read(i + 1).im);
+        }
+    }
+
+    #[test]
+    fn test_eigendecomposition_left_real() {
+        let n = 9;
+
+        let H = Mat::from_fn(n, n, |_, _| rand::random::<f64>());
+
+        let mut eigen = Eigendecomposition::<c64>::new_from_real_with_left_eigenvectors(
+            H.as_ref(),
+            Default::default(),
+        );
+        eigen.sort(EigenvalueOrder::RealPart);
+
+        let s = eigen.s_diagonal();
+        let mut s_mat = Mat::zeros(n, n);
+        s_mat
+            .as_mut()
+            .diagonal_mut()
+            .column_vector_mut()
+            .copy_from(s);
+        let H = Mat::from_fn(n, n, |i, j| c64::new(H.read(i, j), 0.0));
+
+        let u = eigen.u();
+        assert_approx_eq(u * &s_mat, &H * u);
+
+        let v = eigen.compute_left_eigenvectors().unwrap();
+        assert_approx_eq(v.adjoint() * &H, &s_mat * v.adjoint());
+
+        for rcond in eigen.reciprocal_condition_numbers().unwrap() {
+            assert!(rcond > 0.0 && rcond <= 1.0 + 1e-10);
+        }
+    }
+
     #[cfg(feature = "ndarray")]
     #[test]
     fn test_ext_ndarray() {